@@ -39,6 +39,12 @@ pub enum RPCError {
     /// HTTP error
     #[error("HTTP code {0}")]
     HttpError(u32),
+    /// Conditional write lost to another writer of the same slot
+    #[error("Slot version conflict: the replica is at version {current_version}")]
+    Conflict {
+        /// The slot version the replica holds
+        current_version: u32,
+    },
 }
 
 /// Errors originating from receiving event data from the Stacks node
@@ -68,6 +74,9 @@ pub enum EventError {
     /// Unrecognized event error
     #[error("Unrecognized event: {0}")]
     UnrecognizedEvent(String),
+    /// The request was answered by the event receiver itself, and produced no event
+    #[error("Request handled by the event receiver: {0}")]
+    RequestHandled(String),
     /// Unrecognized stacker DB contract error
     #[error("Unrecognized StackerDB contract: {0}")]
     UnrecognizedStackerDBContract(QualifiedContractIdentifier),
@@ -19,7 +19,8 @@ use std::io::{Read, Write};
 use std::net::{SocketAddr, TcpListener, TcpStream};
 use std::sync::atomic::{AtomicBool, Ordering};
 use std::sync::mpsc::Sender;
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
+use std::thread;
 use std::time::SystemTime;
 
 use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
@@ -53,6 +54,7 @@ use tiny_http::{
 
 use crate::http::{decode_http_body, decode_http_request};
 use crate::v0::messages::BLOCK_RESPONSE_DATA_MAX_SIZE;
+use crate::v0::sse::BlockResponseSseForwarder;
 use crate::EventError;
 
 /// Define the trait for the event processor
@@ -192,11 +194,16 @@ pub enum SignerEvent<T: SignerEventTrait> {
     MinerMessages(Vec<T>, StacksPublicKey),
     /// The signer messages for other signers and miners to observe
     /// The u32 is the signer set to which the message belongs (either 0 or 1)
-    SignerMessages(u32, Vec<T>),
+    /// The `Vec<u32>` holds the slot IDs of any modified chunks that could not be decoded
+    SignerMessages(u32, Vec<T>, Vec<u32>),
     /// A new block proposal validation response from the node
     BlockValidationResponse(BlockValidateResponse),
     /// Status endpoint request
     StatusCheck,
+    /// An operator asked the signer to re-evaluate the block with this signer signature hash
+    ReevaluateBlock(Sha512Trunc256Sum),
+    /// An operator asked the signer to re-read its reloadable options from its config file
+    ReloadConfig,
     /// A new burn block event was received with the given burnchain block height
     NewBurnBlock {
         /// the burn height for the newly processed burn block
@@ -257,6 +264,10 @@ pub trait EventReceiver<T: SignerEventTrait> {
                     // got an event that we don't care about (not a problem)
                     continue;
                 }
+                Err(EventError::RequestHandled(..)) => {
+                    // the request was answered without producing an event
+                    continue;
+                }
                 Err(EventError::Terminated) => {
                     // we're done
                     info!("Caught termination signal");
@@ -288,6 +299,11 @@ pub struct SignerEventReceiver<T: SignerEventTrait> {
     stop_signal: Arc<AtomicBool>,
     /// Whether the receiver is running on mainnet
     is_mainnet: bool,
+    /// Password that admin requests must carry in their `Authorization` header.  If not set,
+    /// admin requests are refused.
+    admin_password: Option<String>,
+    /// Forwards StackerDB block responses to `GET /block_responses` subscribers, if enabled
+    block_response_sse: Option<Mutex<BlockResponseSseForwarder>>,
 }
 
 impl<T: SignerEventTrait> SignerEventReceiver<T> {
@@ -300,9 +316,29 @@ impl<T: SignerEventTrait> SignerEventReceiver<T> {
             out_channels: vec![],
             stop_signal: Arc::new(AtomicBool::new(false)),
             is_mainnet,
+            admin_password: None,
+            block_response_sse: None,
         }
     }
 
+    /// Accept admin requests (e.g. `/reevaluate_block`, `/reload_config`) that carry `password`
+    /// in their `Authorization` header
+    pub fn with_admin_password(mut self, password: String) -> Self {
+        self.admin_password = Some(password);
+        self
+    }
+
+    /// Serve the block responses observed on the signers' StackerDBs as Server-Sent Events at
+    /// `GET /block_responses`, to at most `max_subscribers` clients at once.  Subscription
+    /// requests must carry the admin password, so this has no effect without
+    /// `with_admin_password`.
+    pub fn with_block_response_sse(mut self, max_subscribers: usize) -> Self {
+        self.block_response_sse = Some(Mutex::new(BlockResponseSseForwarder::new(
+            max_subscribers,
+        )));
+        self
+    }
+
     /// Do something with the socket
     pub fn with_server<F, R>(&mut self, todo: F) -> Result<R, EventError>
     where
@@ -391,6 +427,16 @@ impl<T: SignerEventTrait> EventReceiver<T> for SignerEventReceiver<T> {
                 return Ok(SignerEvent::StatusCheck);
             }
 
+            if request.url() == "/block_responses" && request.method() == &HttpMethod::Get {
+                if let Some(block_response_sse) = event_receiver.block_response_sse.as_ref() {
+                    return process_block_response_sse_request(
+                        request,
+                        block_response_sse,
+                        event_receiver.admin_password.as_deref(),
+                    );
+                }
+            }
+
             if request.method() != &HttpMethod::Post {
                 return Err(EventError::MalformedRequest(format!(
                     "Unrecognized method '{}'",
@@ -399,7 +445,14 @@ impl<T: SignerEventTrait> EventReceiver<T> for SignerEventReceiver<T> {
             }
             debug!("Processing {} event", request.url());
             if request.url() == "/stackerdb_chunks" {
-                process_event::<T, StackerDBChunksEvent>(request)
+                let chunks_event: StackerDBChunksEvent = read_event(request)?;
+                if let Some(block_response_sse) = event_receiver.block_response_sse.as_ref() {
+                    block_response_sse
+                        .lock()
+                        .expect("FATAL: block response SSE forwarder lock poisoned")
+                        .forward(&chunks_event);
+                }
+                chunks_event.try_into()
             } else if request.url() == "/proposal_response" {
                 process_event::<T, BlockValidateResponse>(request)
             } else if request.url() == "/new_burn_block" {
@@ -409,6 +462,10 @@ impl<T: SignerEventTrait> EventReceiver<T> for SignerEventReceiver<T> {
                 Err(EventError::Terminated)
             } else if request.url() == "/new_block" {
                 process_event::<T, BlockEvent>(request)
+            } else if request.url() == "/reevaluate_block" {
+                process_reevaluate_block_request(request, event_receiver.admin_password.as_deref())
+            } else if request.url() == "/reload_config" {
+                process_reload_config_request(request, event_receiver.admin_password.as_deref())
             } else {
                 let url = request.url().to_string();
                 debug!(
@@ -478,13 +535,135 @@ fn ack_dispatcher(request: HttpRequest) {
     };
 }
 
+/// Body of an admin request to re-evaluate a block
+#[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
+pub struct ReevaluateBlockRequest {
+    /// The signer signature hash of the block to re-evaluate
+    pub signer_signature_hash: Sha512Trunc256Sum,
+}
+
+/// Respond to a request with a plain-text message and the given status code
+fn respond_with_status(request: HttpRequest, status_code: u16, message: &str) {
+    if let Err(e) =
+        request.respond(HttpResponse::from_string(message).with_status_code(status_code))
+    {
+        error!("Failed to respond to request: {:?}", &e);
+    };
+}
+
+/// Does the admin request carry the admin password in its `Authorization` header?  Always false
+/// if no admin password is set.
+fn is_authorized_admin_request(request: &HttpRequest, admin_password: Option<&str>) -> bool {
+    admin_password.is_some_and(|password| {
+        request
+            .headers()
+            .iter()
+            .any(|header| header.field.equiv("Authorization") && header.value.as_str() == password)
+    })
+}
+
+/// Process an admin request to re-evaluate a block.  The request must carry the admin password
+/// in its `Authorization` header; it is refused if no admin password is set.
+fn process_reevaluate_block_request<T: SignerEventTrait>(
+    mut request: HttpRequest,
+    admin_password: Option<&str>,
+) -> Result<SignerEvent<T>, EventError> {
+    if !is_authorized_admin_request(&request, admin_password) {
+        respond_with_status(request, 401, "Unauthorized");
+        return Err(EventError::MalformedRequest(
+            "Unauthorized re-evaluate block request".to_string(),
+        ));
+    }
+
+    let mut body = String::new();
+    if let Err(e) = request.as_reader().read_to_string(&mut body) {
+        respond_with_status(request, 400, "Failed to read body");
+        return Err(EventError::MalformedRequest(format!(
+            "Failed to read body: {:?}",
+            &e
+        )));
+    }
+    let reevaluate_request: ReevaluateBlockRequest = match serde_json::from_str(&body) {
+        Ok(reevaluate_request) => reevaluate_request,
+        Err(e) => {
+            respond_with_status(request, 400, "Invalid re-evaluate block request");
+            return Err(EventError::Deserialize(format!(
+                "Could not decode body to JSON: {:?}",
+                &e
+            )));
+        }
+    };
+    ack_dispatcher(request);
+    Ok(SignerEvent::ReevaluateBlock(
+        reevaluate_request.signer_signature_hash,
+    ))
+}
+
+/// Process an admin request to reload the signer's config.  Like a re-evaluate block request, it
+/// must carry the admin password in its `Authorization` header.
+fn process_reload_config_request<T: SignerEventTrait>(
+    request: HttpRequest,
+    admin_password: Option<&str>,
+) -> Result<SignerEvent<T>, EventError> {
+    if !is_authorized_admin_request(&request, admin_password) {
+        respond_with_status(request, 401, "Unauthorized");
+        return Err(EventError::MalformedRequest(
+            "Unauthorized reload config request".to_string(),
+        ));
+    }
+    ack_dispatcher(request);
+    Ok(SignerEvent::ReloadConfig)
+}
+
+/// Subscribe a client to the block response SSE stream, and serve it on its own thread until
+/// either side goes away.  Like other admin requests, the request must carry the admin password
+/// in its `Authorization` header.  There is no event to forward, so this always returns an
+/// error: `EventError::RequestHandled` if the client was subscribed.
+fn process_block_response_sse_request<T: SignerEventTrait>(
+    request: HttpRequest,
+    block_response_sse: &Mutex<BlockResponseSseForwarder>,
+    admin_password: Option<&str>,
+) -> Result<SignerEvent<T>, EventError> {
+    if !is_authorized_admin_request(&request, admin_password) {
+        respond_with_status(request, 401, "Unauthorized");
+        return Err(EventError::MalformedRequest(
+            "Unauthorized block response subscription request".to_string(),
+        ));
+    }
+    let Some(sse_stream) = block_response_sse
+        .lock()
+        .expect("FATAL: block response SSE forwarder lock poisoned")
+        .subscribe()
+    else {
+        respond_with_status(request, 503, "Too many block response subscribers");
+        return Err(EventError::MalformedRequest(
+            "Too many block response subscribers".to_string(),
+        ));
+    };
+    let mut client = request.into_writer();
+    thread::spawn(move || {
+        if let Err(e) = BlockResponseSseForwarder::serve(&mut client, sse_stream) {
+            debug!("Block response SSE client went away: {e:?}");
+        }
+    });
+    Err(EventError::RequestHandled("/block_responses".to_string()))
+}
+
 // TODO: add tests from mutation testing results #4835
 #[cfg_attr(test, mutants::skip)]
-fn process_event<T, E>(mut request: HttpRequest) -> Result<SignerEvent<T>, EventError>
+fn process_event<T, E>(request: HttpRequest) -> Result<SignerEvent<T>, EventError>
 where
     T: SignerEventTrait,
     E: serde::de::DeserializeOwned + TryInto<SignerEvent<T>, Error = EventError>,
 {
+    let json_event: E = read_event(request)?;
+    let signer_event: SignerEvent<T> = json_event.try_into()?;
+
+    Ok(signer_event)
+}
+
+/// Read and decode the JSON body of an event posted by the node, acking the node either way
+fn read_event<E: serde::de::DeserializeOwned>(mut request: HttpRequest) -> Result<E, EventError> {
     let mut body = String::new();
 
     if let Err(e) = request.as_reader().read_to_string(&mut body) {
@@ -497,12 +676,8 @@ where
     }
     // Regardless of whether we successfully deserialize, we should ack the dispatcher so they don't keep resending it
     ack_dispatcher(request);
-    let json_event: E = serde_json::from_slice(body.as_bytes())
-        .map_err(|e| EventError::Deserialize(format!("Could not decode body to JSON: {:?}", &e)))?;
-
-    let signer_event: SignerEvent<T> = json_event.try_into()?;
-
-    Ok(signer_event)
+    serde_json::from_slice(body.as_bytes())
+        .map_err(|e| EventError::Deserialize(format!("Could not decode body to JSON: {:?}", &e)))
 }
 
 impl<T: SignerEventTrait> TryFrom<StackerDBChunksEvent> for SignerEvent<T> {
@@ -534,12 +709,17 @@ impl<T: SignerEventTrait> TryFrom<StackerDBChunksEvent> for SignerEvent<T> {
                 return Err(EventError::UnrecognizedStackerDBContract(event.contract_id));
             };
             // signer-XXX-YYY boot contract
-            let signer_messages: Vec<T> = event
-                .modified_slots
-                .iter()
-                .filter_map(|chunk| read_next::<T, _>(&mut &chunk.data[..]).ok())
-                .collect();
-            SignerEvent::SignerMessages(signer_set, signer_messages)
+            let mut signer_messages = vec![];
+            let mut undecodable_slot_ids = vec![];
+            for chunk in event.modified_slots.iter() {
+                match read_next::<T, _>(&mut &chunk.data[..]) {
+                    Ok(msg) => signer_messages.push(msg),
+                    // an empty chunk is a cleared slot, not a corrupt message
+                    Err(_) if chunk.data.is_empty() => {}
+                    Err(_) => undecodable_slot_ids.push(chunk.slot_id),
+                }
+            }
+            SignerEvent::SignerMessages(signer_set, signer_messages, undecodable_slot_ids)
         } else {
             return Err(EventError::UnrecognizedStackerDBContract(event.contract_id));
         };
@@ -627,8 +807,10 @@ pub fn get_signers_db_signer_set_message_id(name: &str) -> Option<(u32, u32)> {
 #[cfg(test)]
 mod tests {
     use blockstack_lib::chainstate::nakamoto::NakamotoBlockHeader;
+    use libstackerdb::StackerDBChunkData;
 
     use super::*;
+    use crate::v0::messages::SignerMessage;
 
     #[test]
     fn test_get_signers_db_signer_set_message_id() {
@@ -646,6 +828,27 @@ mod tests {
         assert!(get_signers_db_signer_set_message_id(name).is_none());
     }
 
+    #[test]
+    fn test_signer_messages_event_reports_undecodable_slots() {
+        let message = SignerMessage::ResponseRequest {
+            signer_signature_hash: Sha512Trunc256Sum([0x01; 32]),
+        };
+        let event = StackerDBChunksEvent {
+            contract_id: boot_code_id(&format!("{SIGNERS_NAME}-1-1"), false),
+            modified_slots: vec![
+                StackerDBChunkData::new(0, 1, message.serialize_to_vec()),
+                StackerDBChunkData::new(1, 1, vec![0xff, 0xff, 0xff]),
+                // a cleared slot is not reported
+                StackerDBChunkData::new(2, 2, vec![]),
+            ],
+        };
+        let signer_event = SignerEvent::<SignerMessage>::try_from(event).unwrap();
+        assert_eq!(
+            signer_event,
+            SignerEvent::SignerMessages(1, vec![message], vec![1])
+        );
+    }
+
     // Older version of BlockProposal to ensure backwards compatibility
 
     #[derive(Clone, Debug, PartialEq, Serialize, Deserialize)]
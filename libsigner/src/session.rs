@@ -17,6 +17,7 @@
 use std::net::{SocketAddr, TcpStream};
 use std::str;
 
+use blockstack_lib::net::api::poststackerdbchunk::StackerDBErrorCodes;
 use clarity::vm::types::QualifiedContractIdentifier;
 use libstackerdb::{
     stackerdb_get_chunk_path, stackerdb_get_metadata_path, stackerdb_post_chunk_path, SlotMetadata,
@@ -24,6 +25,7 @@ use libstackerdb::{
     STACKERDB_MAX_CHUNK_SIZE,
 };
 use stacks_common::codec::StacksMessageCodec;
+use stacks_common::types::chainstate::StacksPrivateKey;
 
 use crate::error::RPCError;
 use crate::http::run_http_request;
@@ -157,6 +159,39 @@ impl StackerDBSession {
             run_http_request(sock, &session.host, verb, path, content_type, payload)
         })?
     }
+
+    /// Upload a chunk only if its slot is still at version `expected_prev_version`.  The chunk is
+    /// given the next slot version and re-signed with `private_key`.
+    /// Returns Ok(ack) if the replica answered without a version conflict.  The ack may still
+    /// report a rejection for some other reason (e.g. a bad signer).
+    /// Returns Err(RPCError::Conflict { .. }) if the slot has already moved past
+    /// `expected_prev_version`, so the caller can retry from the replica's current version.
+    pub fn put_chunk_if_version(
+        &mut self,
+        chunk: &mut StackerDBChunkData,
+        expected_prev_version: u32,
+        private_key: &StacksPrivateKey,
+    ) -> Result<StackerDBChunkAckData, RPCError> {
+        chunk.slot_version = expected_prev_version
+            .checked_add(1)
+            .ok_or_else(|| RPCError::MalformedRequest("Slot version would overflow".to_string()))?;
+        chunk
+            .sign(private_key)
+            .map_err(|e| RPCError::MalformedRequest(format!("Failed to sign chunk: {e}")))?;
+        let ack = self.put_chunk(chunk)?;
+        if ack.accepted || ack.code != Some(StackerDBErrorCodes::DataAlreadyExists.code()) {
+            return Ok(ack);
+        }
+        let current_version = ack
+            .metadata
+            .map(|metadata| metadata.slot_version)
+            .ok_or_else(|| {
+                RPCError::MalformedResponse(
+                    "Version conflict ack is missing the slot metadata".to_string(),
+                )
+            })?;
+        Err(RPCError::Conflict { current_version })
+    }
 }
 
 impl SignerSession for StackerDBSession {
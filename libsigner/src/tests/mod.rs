@@ -18,7 +18,7 @@ mod http;
 
 use std::fmt::Debug;
 use std::io::{Read, Write};
-use std::net::{SocketAddr, TcpStream, ToSocketAddrs};
+use std::net::{SocketAddr, TcpListener, TcpStream, ToSocketAddrs};
 use std::sync::mpsc::{channel, Receiver, Sender};
 use std::time::Duration;
 use std::{mem, thread};
@@ -41,8 +41,8 @@ use stacks_common::codec::{
 use stacks_common::util::secp256k1::Secp256k1PrivateKey;
 use stacks_common::util::sleep_ms;
 
-use crate::events::{BlockProposalData, SignerEvent, SignerEventTrait};
-use crate::v0::messages::{BlockRejection, SignerMessage};
+use crate::events::{BlockProposalData, ReevaluateBlockRequest, SignerEvent, SignerEventTrait};
+use crate::v0::messages::{BlockRejection, BlockResponse, RejectCode, SignerMessage};
 use crate::{BlockProposal, Signer, SignerEventReceiver, SignerRunLoop};
 
 /// Simple runloop implementation.  It receives `max_events` events and returns `events` from the
@@ -189,7 +189,7 @@ fn test_simple_signer() {
         .map(|chunk| {
             let msg = chunk.modified_slots[0].data.clone();
             let signer_message = read_next::<SignerMessage, _>(&mut &msg[..]).unwrap();
-            SignerEvent::SignerMessages(0, vec![signer_message])
+            SignerEvent::SignerMessages(0, vec![signer_message], vec![])
         })
         .collect();
 
@@ -238,3 +238,199 @@ fn test_status_endpoint() {
     assert_eq!(sent_events, accepted_events);
     mock_stacks_node.join().unwrap();
 }
+
+#[test]
+fn test_reevaluate_block_endpoint() {
+    let ev = SignerEventReceiver::new(false).with_admin_password("12345".to_string());
+    let (res_send, _res_recv) = channel();
+    let max_events = 1;
+    let mut signer = Signer::new(SimpleRunLoop::new(max_events), ev, res_send);
+    let endpoint: SocketAddr = "127.0.0.1:31001".parse().unwrap();
+    let signer_signature_hash = Sha512Trunc256Sum([0x22; 32]);
+
+    // simulate an operator asking for a re-evaluation, first without the password
+    let mock_operator = thread::spawn(move || {
+        let body = serde_json::to_string(&ReevaluateBlockRequest {
+            signer_signature_hash,
+        })
+        .unwrap();
+        for (password, expected_status_res) in [
+            ("wrong", "HTTP/1.1 401 Unauthorized\r\n"),
+            ("12345", "HTTP/1.1 200 OK\r\n"),
+        ] {
+            let mut sock = loop {
+                match TcpStream::connect(endpoint) {
+                    Ok(sock) => break sock,
+                    Err(..) => sleep_ms(100),
+                }
+            };
+            let req = format!(
+                "POST /reevaluate_block HTTP/1.1\r\nHost: {endpoint}\r\nConnection: close\r\nAuthorization: {password}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+                body.len()
+            );
+            sock.write_all(req.as_bytes()).unwrap();
+            let mut buf = [0; 128];
+            let _ = sock.read(&mut buf).unwrap();
+            let res_str = std::str::from_utf8(&buf).unwrap();
+            assert_eq!(expected_status_res, &res_str[..expected_status_res.len()]);
+        }
+    });
+
+    let running_signer = signer.spawn(endpoint).unwrap();
+    sleep_ms(3000);
+    let accepted_events = running_signer.stop().unwrap();
+
+    // only the authorized request is forwarded
+    let sent_events: Vec<SignerEvent<SignerMessage>> =
+        vec![SignerEvent::ReevaluateBlock(signer_signature_hash)];
+
+    assert_eq!(sent_events, accepted_events);
+    mock_operator.join().unwrap();
+}
+
+#[test]
+fn test_reload_config_endpoint() {
+    let ev = SignerEventReceiver::new(false).with_admin_password("12345".to_string());
+    let (res_send, _res_recv) = channel();
+    let max_events = 1;
+    let mut signer = Signer::new(SimpleRunLoop::new(max_events), ev, res_send);
+    let endpoint: SocketAddr = "127.0.0.1:31002".parse().unwrap();
+
+    // simulate an operator asking for a config reload, first without the password
+    let mock_operator = thread::spawn(move || {
+        for (password, expected_status_res) in [
+            ("wrong", "HTTP/1.1 401 Unauthorized\r\n"),
+            ("12345", "HTTP/1.1 200 OK\r\n"),
+        ] {
+            let mut sock = loop {
+                match TcpStream::connect(endpoint) {
+                    Ok(sock) => break sock,
+                    Err(..) => sleep_ms(100),
+                }
+            };
+            let req = format!(
+                "POST /reload_config HTTP/1.1\r\nHost: {endpoint}\r\nConnection: close\r\nAuthorization: {password}\r\nContent-Length: 0\r\n\r\n"
+            );
+            sock.write_all(req.as_bytes()).unwrap();
+            let mut buf = [0; 128];
+            let _ = sock.read(&mut buf).unwrap();
+            let res_str = std::str::from_utf8(&buf).unwrap();
+            assert_eq!(expected_status_res, &res_str[..expected_status_res.len()]);
+        }
+    });
+
+    let running_signer = signer.spawn(endpoint).unwrap();
+    sleep_ms(3000);
+    let accepted_events = running_signer.stop().unwrap();
+
+    // only the authorized request is forwarded
+    let sent_events: Vec<SignerEvent<SignerMessage>> = vec![SignerEvent::ReloadConfig];
+
+    assert_eq!(sent_events, accepted_events);
+    mock_operator.join().unwrap();
+}
+
+#[test]
+fn test_block_response_sse_forwarding() {
+    let ev = SignerEventReceiver::new(false)
+        .with_admin_password("12345".to_string())
+        .with_block_response_sse(1);
+    let (res_send, _res_recv) = channel();
+    let mut signer = Signer::new(SimpleRunLoop::new(1), ev, res_send);
+    let endpoint = free_local_endpoint();
+    let running_signer = signer.spawn(endpoint).unwrap();
+
+    let subscribe = |password: &str| {
+        let mut client = loop {
+            match TcpStream::connect(endpoint) {
+                Ok(sock) => break sock,
+                Err(..) => sleep_ms(100),
+            }
+        };
+        client
+            .set_read_timeout(Some(Duration::from_secs(10)))
+            .unwrap();
+        client
+            .write_all(
+                format!(
+                    "GET /block_responses HTTP/1.1\r\nHost: {endpoint}\r\nAuthorization: {password}\r\n\r\n"
+                )
+                .as_bytes(),
+            )
+            .unwrap();
+        let preamble = read_until(&mut client, b"\r\n\r\n");
+        (client, preamble)
+    };
+
+    // subscribing requires the admin password
+    let (_, preamble) = subscribe("wrong");
+    assert!(preamble.starts_with("HTTP/1.1 401 Unauthorized\r\n"));
+
+    // dashboard subscribing to block responses
+    let (mut client, preamble) = subscribe("12345");
+    assert!(preamble.starts_with("HTTP/1.1 200 OK\r\n"));
+    assert!(preamble.contains("Content-Type: text/event-stream"));
+
+    // only one subscriber is allowed at a time
+    let (_, preamble) = subscribe("12345");
+    assert!(preamble.starts_with("HTTP/1.1 503 Service Unavailable\r\n"));
+
+    // a node posts a block response written to StackerDB
+    let block_response = BlockResponse::rejected(
+        Sha512Trunc256Sum([0x11; 32]),
+        RejectCode::ConnectivityIssues,
+        &Secp256k1PrivateKey::random(),
+        false,
+        1,
+    );
+    let message = SignerMessage::BlockResponse(block_response.clone());
+    let mut chunk = StackerDBChunkData::new(0, 1, message.serialize_to_vec());
+    chunk.sign(&Secp256k1PrivateKey::random()).unwrap();
+    let chunk_event = StackerDBChunksEvent {
+        contract_id: NakamotoSigners::make_signers_db_contract_id(0, 1, false),
+        modified_slots: vec![chunk],
+    };
+    let body = serde_json::to_string(&chunk_event).unwrap();
+    let mut sock = TcpStream::connect(endpoint).unwrap();
+    sock.write_all(
+        format!(
+            "POST /stackerdb_chunks HTTP/1.1\r\nHost: {endpoint}\r\nConnection: close\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\r\n{body}",
+            body.len()
+        )
+        .as_bytes(),
+    )
+    .unwrap();
+
+    let frame = read_until(&mut client, b"\n\n");
+    let data = frame
+        .strip_prefix("data: ")
+        .and_then(|data| data.strip_suffix("\n\n"))
+        .expect("Malformed SSE frame");
+    let received: BlockResponse = serde_json::from_str(data).unwrap();
+    assert_eq!(received, block_response);
+
+    // the block response still reaches the signer runloop
+    let accepted_events = running_signer.stop().unwrap();
+    assert_eq!(
+        accepted_events,
+        vec![SignerEvent::SignerMessages(0, vec![message], vec![])]
+    );
+}
+
+/// Find a local endpoint that nothing is listening on
+fn free_local_endpoint() -> SocketAddr {
+    TcpListener::bind("127.0.0.1:0")
+        .and_then(|listener| listener.local_addr())
+        .expect("Failed to find a free local port")
+}
+
+/// Read from `sock` until the bytes read end with `terminator`
+fn read_until(sock: &mut TcpStream, terminator: &[u8]) -> String {
+    let mut buf = vec![];
+    let mut byte = [0u8; 1];
+    while !buf.ends_with(terminator) {
+        sock.read_exact(&mut byte).unwrap();
+        buf.push(byte[0]);
+    }
+    String::from_utf8(buf).unwrap()
+}
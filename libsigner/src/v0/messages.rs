@@ -122,7 +122,9 @@ SignerMessageTypePrefix {
     /// Mock block signature message from Epoch 2.5 signers
     MockSignature = 4,
     /// Mock block message from Epoch 2.5 miners
-    MockBlock = 5
+    MockBlock = 5,
+    /// Block response re-broadcast request from miners
+    ResponseRequest = 6
 });
 
 #[cfg_attr(test, mutants::skip)]
@@ -168,6 +170,7 @@ impl From<&SignerMessage> for SignerMessageTypePrefix {
             SignerMessage::MockProposal(_) => SignerMessageTypePrefix::MockProposal,
             SignerMessage::MockSignature(_) => SignerMessageTypePrefix::MockSignature,
             SignerMessage::MockBlock(_) => SignerMessageTypePrefix::MockBlock,
+            SignerMessage::ResponseRequest { .. } => SignerMessageTypePrefix::ResponseRequest,
         }
     }
 }
@@ -187,6 +190,11 @@ pub enum SignerMessage {
     MockProposal(MockProposal),
     /// A mock block from the epoch 2.5 miners
     MockBlock(MockBlock),
+    /// A request from miners for signers to re-broadcast their response to a block
+    ResponseRequest {
+        /// The signer signature hash of the block whose responses are requested
+        signer_signature_hash: Sha512Trunc256Sum,
+    },
 }
 
 impl SignerMessage {
@@ -199,7 +207,8 @@ impl SignerMessage {
             Self::BlockProposal(_)
             | Self::BlockPushed(_)
             | Self::MockProposal(_)
-            | Self::MockBlock(_) => None,
+            | Self::MockBlock(_)
+            | Self::ResponseRequest { .. } => None,
             Self::BlockResponse(_) | Self::MockSignature(_) => Some(MessageSlotID::BlockResponse), // Mock signature uses the same slot as block response since its exclusively for epoch 2.5 testing
         }
     }
@@ -217,6 +226,9 @@ impl StacksMessageCodec for SignerMessage {
             SignerMessage::MockSignature(signature) => signature.consensus_serialize(fd),
             SignerMessage::MockProposal(message) => message.consensus_serialize(fd),
             SignerMessage::MockBlock(block) => block.consensus_serialize(fd),
+            SignerMessage::ResponseRequest {
+                signer_signature_hash,
+            } => write_next(fd, signer_signature_hash),
         }?;
         Ok(())
     }
@@ -250,6 +262,12 @@ impl StacksMessageCodec for SignerMessage {
                 let block = StacksMessageCodec::consensus_deserialize(fd)?;
                 SignerMessage::MockBlock(block)
             }
+            SignerMessageTypePrefix::ResponseRequest => {
+                let signer_signature_hash = read_next::<Sha512Trunc256Sum, _>(fd)?;
+                SignerMessage::ResponseRequest {
+                    signer_signature_hash,
+                }
+            }
         };
         Ok(message)
     }
@@ -539,7 +557,16 @@ RejectCodeTypePrefix {
     /// The block was rejected due to a mismatch with expected sortition view
     SortitionViewMismatch = 4,
     /// The block was rejected due to a testing directive
-    TestingDirective = 5
+    TestingDirective = 5,
+    /// The block was rejected because it would exceed the signer's tenure budget
+    TenureBudgetExceeded = 6,
+    /// The block was rejected because the miner's block-commit is not yet sufficiently confirmed
+    CommitNotConfirmed = 7,
+    /// The block was rejected because its serialized size exceeds the signer's limit
+    BlockTooLarge = 8,
+    /// The block was rejected because it was proposed from a burnchain view older than the
+    /// signer's
+    StaleView = 9
 });
 
 impl TryFrom<u8> for RejectCodeTypePrefix {
@@ -560,6 +587,10 @@ impl From<&RejectCode> for RejectCodeTypePrefix {
             RejectCode::NoSortitionView => RejectCodeTypePrefix::NoSortitionView,
             RejectCode::SortitionViewMismatch => RejectCodeTypePrefix::SortitionViewMismatch,
             RejectCode::TestingDirective => RejectCodeTypePrefix::TestingDirective,
+            RejectCode::TenureBudgetExceeded => RejectCodeTypePrefix::TenureBudgetExceeded,
+            RejectCode::CommitNotConfirmed => RejectCodeTypePrefix::CommitNotConfirmed,
+            RejectCode::BlockTooLarge => RejectCodeTypePrefix::BlockTooLarge,
+            RejectCode::StaleView => RejectCodeTypePrefix::StaleView,
         }
     }
 }
@@ -575,10 +606,22 @@ pub enum RejectCode {
     ConnectivityIssues,
     /// The block was rejected in a prior round
     RejectedInPriorRound,
-    /// The block was rejected due to a mismatch with expected sortition view
+    /// The block was rejected due to a mismatch with expected sortition view, e.g. because it is
+    /// from an unknown sortition or a miner the signer considers invalid
     SortitionViewMismatch,
     /// The block was rejected due to a testing directive
     TestingDirective,
+    /// The block was rejected because it would push its tenure's cumulative execution cost over
+    /// the signer's tenure budget
+    TenureBudgetExceeded,
+    /// The block starts a tenure whose winning block-commit has not yet been confirmed by enough
+    /// burn blocks
+    CommitNotConfirmed,
+    /// The serialized block is larger than the signer's configured maximum block size
+    BlockTooLarge,
+    /// The block does not match the signer's sortition view, and it was proposed from a
+    /// burnchain view older than the signer's current burn tip
+    StaleView,
 }
 
 define_u8_enum!(
@@ -1074,7 +1117,11 @@ impl StacksMessageCodec for RejectCode {
             | RejectCode::RejectedInPriorRound
             | RejectCode::NoSortitionView
             | RejectCode::SortitionViewMismatch
-            | RejectCode::TestingDirective => {
+            | RejectCode::TestingDirective
+            | RejectCode::TenureBudgetExceeded
+            | RejectCode::CommitNotConfirmed
+            | RejectCode::BlockTooLarge
+            | RejectCode::StaleView => {
                 // No additional data to serialize / deserialize
             }
         };
@@ -1098,6 +1145,10 @@ impl StacksMessageCodec for RejectCode {
             RejectCodeTypePrefix::NoSortitionView => RejectCode::NoSortitionView,
             RejectCodeTypePrefix::SortitionViewMismatch => RejectCode::SortitionViewMismatch,
             RejectCodeTypePrefix::TestingDirective => RejectCode::TestingDirective,
+            RejectCodeTypePrefix::TenureBudgetExceeded => RejectCode::TenureBudgetExceeded,
+            RejectCodeTypePrefix::CommitNotConfirmed => RejectCode::CommitNotConfirmed,
+            RejectCodeTypePrefix::BlockTooLarge => RejectCode::BlockTooLarge,
+            RejectCodeTypePrefix::StaleView => RejectCode::StaleView,
         };
         Ok(code)
     }
@@ -1128,6 +1179,30 @@ impl std::fmt::Display for RejectCode {
             RejectCode::TestingDirective => {
                 write!(f, "The block was rejected due to a testing directive.")
             }
+            RejectCode::TenureBudgetExceeded => {
+                write!(
+                    f,
+                    "The block was rejected because it would exceed the signer's tenure budget."
+                )
+            }
+            RejectCode::CommitNotConfirmed => {
+                write!(
+                    f,
+                    "The block was rejected because the miner's block-commit is not yet sufficiently confirmed."
+                )
+            }
+            RejectCode::BlockTooLarge => {
+                write!(
+                    f,
+                    "The block was rejected because it exceeds the signer's maximum block size."
+                )
+            }
+            RejectCode::StaleView => {
+                write!(
+                    f,
+                    "The block was rejected because it was proposed from a burnchain view older than the signer's."
+                )
+            }
         }
     }
 }
@@ -1185,6 +1260,40 @@ mod test {
         let deserialized_code = read_next::<RejectCode, _>(&mut &serialized_code[..])
             .expect("Failed to deserialize RejectCode");
         assert_eq!(code, deserialized_code);
+
+        let code = RejectCode::TenureBudgetExceeded;
+        let serialized_code = code.serialize_to_vec();
+        assert_eq!(serialized_code, vec![6]);
+        let deserialized_code = read_next::<RejectCode, _>(&mut &serialized_code[..])
+            .expect("Failed to deserialize RejectCode");
+        assert_eq!(code, deserialized_code);
+
+        let code = RejectCode::CommitNotConfirmed;
+        let serialized_code = code.serialize_to_vec();
+        assert_eq!(serialized_code, vec![7]);
+        let deserialized_code = read_next::<RejectCode, _>(&mut &serialized_code[..])
+            .expect("Failed to deserialize RejectCode");
+        assert_eq!(code, deserialized_code);
+
+        let code = RejectCode::BlockTooLarge;
+        let serialized_code = code.serialize_to_vec();
+        assert_eq!(serialized_code, vec![8]);
+        let deserialized_code = read_next::<RejectCode, _>(&mut &serialized_code[..])
+            .expect("Failed to deserialize RejectCode");
+        assert_eq!(code, deserialized_code);
+
+        let code = RejectCode::StaleView;
+        let serialized_code = code.serialize_to_vec();
+        assert_eq!(serialized_code, vec![9]);
+        let deserialized_code = read_next::<RejectCode, _>(&mut &serialized_code[..])
+            .expect("Failed to deserialize RejectCode");
+        assert_eq!(code, deserialized_code);
+
+        // a view mismatch and a stale view are distinct on the wire
+        assert_ne!(
+            RejectCode::SortitionViewMismatch.serialize_to_vec(),
+            RejectCode::StaleView.serialize_to_vec()
+        );
     }
 
     #[test]
@@ -1284,6 +1393,16 @@ mod test {
             read_next::<SignerMessage, _>(&mut &serialized_signer_message[..])
                 .expect("Failed to deserialize SignerMessage");
         assert_eq!(signer_message, deserialized_signer_message);
+
+        let signer_message = SignerMessage::ResponseRequest {
+            signer_signature_hash: Sha512Trunc256Sum([3u8; 32]),
+        };
+        let serialized_signer_message = signer_message.serialize_to_vec();
+        assert_eq!(serialized_signer_message.len(), 33);
+        let deserialized_signer_message =
+            read_next::<SignerMessage, _>(&mut &serialized_signer_message[..])
+                .expect("Failed to deserialize SignerMessage");
+        assert_eq!(signer_message, deserialized_signer_message);
     }
 
     fn random_peer_data() -> PeerInfo {
@@ -15,3 +15,5 @@
 
 /// Messages for the v0 signer
 pub mod messages;
+/// Server-Sent Events forwarding of signer messages
+pub mod sse;
@@ -0,0 +1,132 @@
+// Copyright (C) 2020-2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::io::{self, Write};
+use std::sync::mpsc::{sync_channel, SyncSender, TrySendError};
+
+use blockstack_lib::chainstate::stacks::boot::SIGNERS_NAME;
+use blockstack_lib::chainstate::stacks::events::StackerDBChunksEvent;
+use blockstack_lib::net::http::stream::SseEventStream;
+use blockstack_lib::net::http::HttpChunkGenerator;
+use stacks_common::codec::read_next;
+
+use crate::v0::messages::SignerMessage;
+
+/// How many block responses may be queued for a subscriber before it is considered too slow and
+/// dropped
+pub const BLOCK_RESPONSE_SSE_BUFFER_LEN: usize = 256;
+/// The default maximum number of concurrent block response SSE subscribers
+pub const DEFAULT_MAX_BLOCK_RESPONSE_SSE_SUBSCRIBERS: usize = 8;
+
+/// Forwards the `BlockResponse`s that signers write to StackerDB to Server-Sent Events
+/// subscribers (e.g. dashboards).  The signer event receiver serves it at `GET /block_responses`
+/// once enabled with `SignerEventReceiver::with_block_response_sse`.
+pub struct BlockResponseSseForwarder {
+    /// One sender per subscribed SSE stream
+    subscribers: Vec<SyncSender<serde_json::Value>>,
+    /// The maximum number of subscribers
+    max_subscribers: usize,
+}
+
+impl Default for BlockResponseSseForwarder {
+    fn default() -> Self {
+        Self::new(DEFAULT_MAX_BLOCK_RESPONSE_SSE_SUBSCRIBERS)
+    }
+}
+
+impl BlockResponseSseForwarder {
+    /// Make a forwarder that serves at most `max_subscribers` subscribers at once
+    pub fn new(max_subscribers: usize) -> Self {
+        Self {
+            subscribers: vec![],
+            max_subscribers,
+        }
+    }
+
+    /// Add a subscriber, returning the SSE stream to serve to it, or `None` if there are already
+    /// `max_subscribers` subscribers.  Subscribers that went away are only noticed, and stop
+    /// counting towards the limit, once the next block response is forwarded.
+    pub fn subscribe(&mut self) -> Option<SseEventStream> {
+        if self.subscribers.len() >= self.max_subscribers {
+            return None;
+        }
+        let (events_tx, events_rx) = sync_channel(BLOCK_RESPONSE_SSE_BUFFER_LEN);
+        self.subscribers.push(events_tx);
+        Some(SseEventStream::new(events_rx))
+    }
+
+    /// The number of subscribers that were still connected as of the last forwarded block
+    /// response, plus any that have subscribed since
+    pub fn num_subscribers(&self) -> usize {
+        self.subscribers.len()
+    }
+
+    /// Forward the block responses written to a signers StackerDB to every subscriber.
+    /// Subscribers whose stream has gone away, or who have fallen `BLOCK_RESPONSE_SSE_BUFFER_LEN`
+    /// block responses behind, are dropped.
+    /// Returns the number of block responses forwarded.
+    pub fn forward(&mut self, event: &StackerDBChunksEvent) -> usize {
+        if !event.contract_id.is_boot() || !event.contract_id.name.starts_with(SIGNERS_NAME) {
+            return 0;
+        }
+        let mut num_forwarded = 0;
+        for chunk in event.modified_slots.iter() {
+            let Ok(SignerMessage::BlockResponse(block_response)) =
+                read_next::<SignerMessage, _>(&mut &chunk.data[..])
+            else {
+                continue;
+            };
+            let event = match serde_json::to_value(&block_response) {
+                Ok(event) => event,
+                Err(e) => {
+                    warn!("Failed to encode block response as JSON: {e:?}");
+                    continue;
+                }
+            };
+            self.subscribers
+                .retain(|subscriber| match subscriber.try_send(event.clone()) {
+                    Ok(()) => true,
+                    Err(TrySendError::Full(_)) => {
+                        debug!("Dropping block response SSE subscriber that fell behind");
+                        false
+                    }
+                    Err(TrySendError::Disconnected(_)) => false,
+                });
+            num_forwarded += 1;
+        }
+        num_forwarded
+    }
+
+    /// Serve an SSE stream to a connected client, flushing each event as it arrives.
+    /// Returns once the stream ends (i.e. the forwarder is dropped) or the client goes away.
+    pub fn serve<W: Write>(client: &mut W, mut stream: SseEventStream) -> io::Result<()> {
+        let preamble = format!(
+            "HTTP/1.1 200 OK\r\nContent-Type: {}\r\nCache-Control: no-cache\r\nConnection: close\r\n\r\n",
+            SseEventStream::CONTENT_TYPE
+        );
+        client.write_all(preamble.as_bytes())?;
+        client.flush()?;
+        loop {
+            let frame = stream
+                .generate_next_chunk()
+                .map_err(|e| io::Error::new(io::ErrorKind::Other, e))?;
+            if frame.is_empty() {
+                return Ok(());
+            }
+            client.write_all(&frame)?;
+            client.flush()?;
+        }
+    }
+}
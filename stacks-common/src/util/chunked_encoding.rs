@@ -20,7 +20,10 @@ use std::{error, fmt, io};
 use crate::codec::MAX_MESSAGE_LEN;
 use crate::deps_common::httparse;
 
-/// NOTE: it is imperative that the given Read and Write impls here _never_ fail with EWOULDBLOCK.
+// NOTE: it is imperative that the given Read and Write impls here _never_ fail with EWOULDBLOCK.
+
+/// Maximum number of bytes of trailer headers accepted after the last chunk
+pub const HTTP_CHUNKED_MAX_TRAILER_LEN: usize = 4096;
 
 #[derive(Debug)]
 pub enum ChunkedError {
@@ -52,10 +55,12 @@ enum HttpChunkedTransferParseMode {
     ChunkBoundary,
     Chunk,
     ChunkTrailer,
+    /// trailer headers after the last (empty) chunk
+    TrailerHeaders,
     EOF,
 }
 
-#[derive(Debug, Clone, PartialEq, Copy)]
+#[derive(Debug, Clone, PartialEq)]
 pub struct HttpChunkedTransferReaderState {
     parse_step: HttpChunkedTransferParseMode,
     chunk_size: u64,
@@ -68,6 +73,11 @@ pub struct HttpChunkedTransferReaderState {
     // (we don't use extensions, so 16 bytes for size + 2 for \r\n delimiter ought to be enough)
     chunk_buffer: [u8; 18],
     i: usize,
+
+    // for parsing trailer headers after the last chunk
+    trailer_line: Vec<u8>,
+    trailer_len: usize,
+    trailers: Vec<(String, String)>,
 }
 
 impl HttpChunkedTransferReaderState {
@@ -81,12 +91,21 @@ impl HttpChunkedTransferReaderState {
             last_chunk_size: u64::MAX, // if this ever becomes 0, then we should expect chunk boundary '0\r\n\r\n' and EOF
             chunk_buffer: [0u8; 18],
             i: 0,
+            trailer_line: vec![],
+            trailer_len: 0,
+            trailers: vec![],
         }
     }
 
     pub fn is_eof(&self) -> bool {
         self.parse_step == HttpChunkedTransferParseMode::EOF
     }
+
+    /// Trailer headers sent after the last chunk, as (name, value) pairs in the order they were
+    /// given.  Names are lowercased.  Only complete once `is_eof()` is true.
+    pub fn trailers(&self) -> &[(String, String)] {
+        &self.trailers
+    }
 }
 
 /// read adapter for chunked transfer encoding
@@ -109,6 +128,12 @@ impl<'a, R: Read> HttpChunkedTransferReader<'a, R> {
     ) -> HttpChunkedTransferReader<'a, R> {
         HttpChunkedTransferReader { fd: r, state }
     }
+
+    /// Trailer headers sent after the last chunk.  Only complete once the stream has been read to
+    /// the end.
+    pub fn trailers(&self) -> &[(String, String)] {
+        self.state.trailers()
+    }
 }
 
 impl HttpChunkedTransferReaderState {
@@ -221,7 +246,12 @@ impl HttpChunkedTransferReaderState {
                 self.chunk_read,
                 self.chunk_size
             );
-            self.parse_step = HttpChunkedTransferParseMode::ChunkTrailer;
+            if self.chunk_size == 0 {
+                // the last chunk is followed by zero or more trailer headers, not a bare \r\n
+                self.parse_step = HttpChunkedTransferParseMode::TrailerHeaders;
+            } else {
+                self.parse_step = HttpChunkedTransferParseMode::ChunkTrailer;
+            }
         }
 
         self.total_size += nr;
@@ -271,6 +301,59 @@ impl HttpChunkedTransferReaderState {
         Ok(nr)
     }
 
+    /// Read the trailer headers after the last chunk, up to and including the empty line that
+    /// ends them.
+    /// Returns number of bytes read on success
+    /// Reads at most 1 byte.
+    fn read_trailer_headers<R: Read>(&mut self, fd: &mut R) -> io::Result<usize> {
+        assert_eq!(
+            self.parse_step,
+            HttpChunkedTransferParseMode::TrailerHeaders
+        );
+
+        let mut b = [0u8; 1];
+        let nr = fd.read(&mut b)?;
+        if nr == 0 {
+            return Ok(nr);
+        }
+
+        self.trailer_len += nr;
+        if self.trailer_len > HTTP_CHUNKED_MAX_TRAILER_LEN {
+            return Err(io::Error::new(
+                io::ErrorKind::InvalidData,
+                ChunkedError::DeserializeError("Invalid HTTP trailer: too long".to_string()),
+            ));
+        }
+
+        self.trailer_line.push(b[0]);
+        if !self.trailer_line.ends_with(b"\r\n") {
+            return Ok(nr);
+        }
+
+        let line = &self.trailer_line[0..(self.trailer_line.len() - 2)];
+        if line.is_empty() {
+            // end of trailers, and of the stream
+            trace!("finished trailers");
+            self.last_chunk_size = 0;
+            self.parse_step = HttpChunkedTransferParseMode::EOF;
+            return Ok(nr);
+        }
+
+        let (name, value) = std::str::from_utf8(line)
+            .ok()
+            .and_then(|line| line.split_once(':'))
+            .ok_or_else(|| {
+                io::Error::new(
+                    io::ErrorKind::InvalidData,
+                    ChunkedError::DeserializeError("Invalid HTTP trailer".to_string()),
+                )
+            })?;
+        self.trailers
+            .push((name.trim().to_lowercase(), value.trim().to_string()));
+        self.trailer_line.clear();
+        Ok(nr)
+    }
+
     /// Read from a Read.
     /// Returns (number of bytes decoded, number of bytes consumed from the Read)
     pub fn do_read<R: Read>(&mut self, fd: &mut R, buf: &mut [u8]) -> io::Result<(usize, usize)> {
@@ -307,6 +390,16 @@ impl HttpChunkedTransferReaderState {
                         break;
                     }
                 }
+                HttpChunkedTransferParseMode::TrailerHeaders => {
+                    let count = self.read_trailer_headers(fd)?;
+                    if count == 0 {
+                        break;
+                    }
+                    consumed += count;
+                    if self.parse_step == HttpChunkedTransferParseMode::EOF {
+                        break;
+                    }
+                }
                 HttpChunkedTransferParseMode::EOF => {
                     break;
                 }
@@ -399,6 +492,31 @@ impl<'a, 'state, W: Write> HttpChunkedTransferWriter<'a, 'state, W> {
     pub fn corked(&self) -> bool {
         self.state.corked
     }
+
+    /// Send any buffered data as a chunk now, without ending the stream
+    pub fn flush_buffered(&mut self) -> io::Result<()> {
+        if self.state.corked || self.state.chunk_buf.is_empty() {
+            return Ok(());
+        }
+        self.flush_chunk().map(|_nw| ())
+    }
+
+    /// End the stream: send any buffered data, then the last (empty) chunk followed by the given
+    /// trailer headers.  The stream is corked afterwards.
+    pub fn finish_with_trailers(&mut self, trailers: &[(String, String)]) -> io::Result<()> {
+        if self.state.corked {
+            return Ok(());
+        }
+        self.flush_buffered()?;
+        self.fd.write_all(b"0\r\n")?;
+        for (name, value) in trailers.iter() {
+            self.fd
+                .write_all(format!("{}: {}\r\n", name, value).as_bytes())?;
+        }
+        self.fd.write_all(b"\r\n")?;
+        self.cork();
+        Ok(())
+    }
 }
 
 impl<W: Write> Write for HttpChunkedTransferWriter<'_, '_, W> {
@@ -763,4 +881,63 @@ mod test {
             assert_eq!(data, decoded_data);
         }
     }
+
+    #[test]
+    fn test_http_chunked_trailers() {
+        let mut encoded_data = vec![];
+        {
+            let mut write_state = HttpChunkedTransferWriterState::new(4);
+            let mut encoder =
+                HttpChunkedTransferWriter::from_writer_state(&mut encoded_data, &mut write_state);
+            encoder.write_all(b"hello world").unwrap();
+            encoder
+                .finish_with_trailers(&[
+                    ("Digest".to_string(), "sha-256=abcd".to_string()),
+                    ("X-Count".to_string(), "11".to_string()),
+                ])
+                .unwrap();
+            assert!(encoder.corked());
+
+            // nothing more is sent once the stream is finished
+            encoder.flush().unwrap();
+        }
+        assert_eq!(
+            std::str::from_utf8(&encoded_data).unwrap(),
+            "4\r\nhell\r\n4\r\no wo\r\n3\r\nrld\r\n0\r\nDigest: sha-256=abcd\r\nX-Count: 11\r\n\r\n"
+        );
+
+        // trailers are decoded no matter how much is read at a time
+        for buf_len in [1, 3, 64] {
+            let mut cursor = io::Cursor::new(&encoded_data);
+            let mut decoder = HttpChunkedTransferReader::from_reader(&mut cursor, 50);
+            let mut decoded_data = vec![];
+            let mut buf = vec![0u8; buf_len];
+            loop {
+                let nr = decoder.read(&mut buf).unwrap();
+                if nr == 0 {
+                    break;
+                }
+                decoded_data.extend_from_slice(&buf[0..nr]);
+            }
+
+            assert_eq!(decoded_data, b"hello world");
+            assert!(decoder.state.is_eof());
+            assert_eq!(
+                decoder.trailers(),
+                &[
+                    ("digest".to_string(), "sha-256=abcd".to_string()),
+                    ("x-count".to_string(), "11".to_string()),
+                ]
+            );
+        }
+
+        // a malformed trailer is an error
+        let mut cursor = io::Cursor::new(b"1\r\na\r\n0\r\nnope\r\n\r\n".to_vec());
+        let mut decoder = HttpChunkedTransferReader::from_reader(&mut cursor, 50);
+        let err = decoder.read_to_end(&mut vec![]).unwrap_err();
+        assert!(
+            format!("{err:?}").contains("Invalid HTTP trailer"),
+            "{err:?}"
+        );
+    }
 }
@@ -312,6 +312,53 @@ impl Write for PipeWrite {
     }
 }
 
+/// In-memory stand-in for a `PipeWrite`, for use in tests.  It collects everything written to it,
+/// so serialized messages can be checked byte-for-byte.
+///
+/// If it has a `max_write`, then each call to `write()` accepts at most that many bytes, which
+/// mimics a peer that drains the pipe slowly.
+#[cfg(any(test, feature = "testing"))]
+#[derive(Debug, Default)]
+pub struct VecPipeWrite {
+    /// All bytes written so far
+    pub bytes: Vec<u8>,
+    /// Maximum number of bytes accepted per `write()` call, if any
+    pub max_write: Option<usize>,
+    /// Number of `write()` calls that accepted at least one byte
+    pub num_writes: usize,
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl VecPipeWrite {
+    pub fn new() -> VecPipeWrite {
+        VecPipeWrite::default()
+    }
+
+    /// Make a writer that accepts at most `max_write` bytes per `write()` call
+    pub fn with_max_write(max_write: usize) -> VecPipeWrite {
+        VecPipeWrite {
+            max_write: Some(max_write),
+            ..VecPipeWrite::default()
+        }
+    }
+}
+
+#[cfg(any(test, feature = "testing"))]
+impl Write for VecPipeWrite {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        let len = self.max_write.map_or(buf.len(), |max| buf.len().min(max));
+        if len > 0 {
+            self.bytes.extend_from_slice(&buf[..len]);
+            self.num_writes += 1;
+        }
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
 #[cfg(test)]
 mod test {
     use std::io::{Read, Write};
@@ -624,4 +671,20 @@ mod test {
             assert!(res);
         }
     }
+
+    #[test]
+    fn test_vec_pipe_write() {
+        let mut pipe_write = VecPipeWrite::new();
+        assert_eq!(pipe_write.write(&[0, 1, 2, 3, 4]).unwrap(), 5);
+        assert_eq!(pipe_write.bytes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(pipe_write.num_writes, 1);
+
+        // writes are capped at max_write, but write_all() still gets everything through
+        let mut pipe_write = VecPipeWrite::with_max_write(2);
+        assert_eq!(pipe_write.write(&[0, 1, 2, 3, 4]).unwrap(), 2);
+        assert_eq!(pipe_write.bytes, vec![0, 1]);
+        pipe_write.write_all(&[2, 3, 4]).unwrap();
+        assert_eq!(pipe_write.bytes, vec![0, 1, 2, 3, 4]);
+        assert_eq!(pipe_write.num_writes, 3);
+    }
 }
@@ -13,21 +13,28 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::time::{Duration, UNIX_EPOCH};
+use std::time::{Duration, SystemTime, UNIX_EPOCH};
 
 use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
-use blockstack_lib::chainstate::stacks::TenureChangePayload;
+use blockstack_lib::chainstate::stacks::{
+    TenureChangeCause, TenureChangePayload, TransactionPayload,
+};
 use blockstack_lib::net::api::getsortition::SortitionInfo;
 use blockstack_lib::util_lib::db::Error as DBError;
-use slog::{slog_info, slog_warn};
+use clarity::vm::costs::ExecutionCost;
+use libsigner::v0::messages::RejectCode;
+use serde::Deserialize;
+use slog::{slog_debug, slog_info, slog_warn};
 use stacks_common::types::chainstate::{BurnchainHeaderHash, ConsensusHash, StacksPublicKey};
 use stacks_common::util::get_epoch_time_secs;
 use stacks_common::util::hash::Hash160;
-use stacks_common::{info, warn};
+use stacks_common::{debug, info, warn};
 
 use crate::client::{ClientError, CurrentAndLastSortition, StacksClient};
 use crate::config::SignerConfig;
 use crate::signerdb::{BlockInfo, BlockState, SignerDb};
+#[cfg(any(test, feature = "testing"))]
+use crate::v0::tests::TEST_SIGNER_CLOCK_OFFSET;
 
 #[derive(thiserror::Error, Debug)]
 /// Error type for the signer chainstate module
@@ -76,6 +83,8 @@ pub struct SortitionState {
     pub burn_header_timestamp: u64,
     /// the burn header hash of the burn block that performed this sortition
     pub burn_block_hash: BurnchainHeaderHash,
+    /// the height of the burn block that performed this sortition
+    pub burn_block_height: u64,
 }
 
 impl SortitionState {
@@ -83,6 +92,7 @@ impl SortitionState {
     pub fn is_timed_out(
         &self,
         timeout: Duration,
+        clock: &SignerClock,
         signer_db: &SignerDb,
     ) -> Result<bool, SignerChainstateError> {
         // if the miner has already been invalidated, we don't need to check if they've timed out.
@@ -99,16 +109,155 @@ impl SortitionState {
             return Ok(false);
         };
         let received_time = UNIX_EPOCH + Duration::from_secs(received_ts);
-        let last_activity = signer_db
+        let mut last_activity = signer_db
             .get_last_activity_time(&self.consensus_hash)?
             .map(|time| UNIX_EPOCH + Duration::from_secs(time))
             .unwrap_or(received_time);
+        // a burnchain reorg restarts the clock, since the sortition may only have looked empty
+        // while the reorg was in progress.
+        if let Some(reorg_ts) = signer_db.get_last_burnchain_reorg_time()? {
+            last_activity = last_activity.max(UNIX_EPOCH + Duration::from_secs(reorg_ts));
+        }
 
-        let Ok(elapsed) = std::time::SystemTime::now().duration_since(last_activity) else {
+        let Ok(elapsed) = clock.now().duration_since(last_activity) else {
             return Ok(false);
         };
         Ok(elapsed > timeout)
     }
+
+    /// Record the miner's public key if it is not yet known: either from a block proposed in this
+    /// sortition by `block_pk`, or from a block this signer already signed in this sortition's tenure.
+    fn learn_miner_pubkey(
+        &mut self,
+        block: &NakamotoBlock,
+        block_pk: &StacksPublicKey,
+        signer_db: &SignerDb,
+    ) -> Result<(), SignerChainstateError> {
+        if self.miner_pubkey.is_some() {
+            return Ok(());
+        }
+        let candidate_pk = if block.header.consensus_hash == self.consensus_hash {
+            Some(*block_pk)
+        } else {
+            signer_db
+                .get_first_signed_block_in_tenure(&self.consensus_hash)?
+                .and_then(|block_info| block_info.block.header.recover_miner_pk())
+        };
+        self.miner_pubkey = candidate_pk
+            .filter(|pk| Hash160::from_data(&pk.to_bytes_compressed()) == self.miner_pkh);
+        Ok(())
+    }
+}
+
+/// The source of wall-clock time used by the signer when evaluating proposal timeouts.
+/// In testing, a per-signer offset can be injected to simulate clock skew between signers.
+#[derive(Debug, Clone, Default)]
+pub struct SignerClock {
+    /// The public key of the signer that owns this clock
+    pub signer_public_key: Option<StacksPublicKey>,
+}
+
+impl SignerClock {
+    /// Create a clock for the signer with the given public key
+    pub fn new(signer_public_key: StacksPublicKey) -> Self {
+        Self {
+            signer_public_key: Some(signer_public_key),
+        }
+    }
+
+    /// The current time, according to this signer
+    pub fn now(&self) -> SystemTime {
+        SystemTime::now() + self.offset()
+    }
+
+    /// The current time in seconds since the epoch, according to this signer
+    pub fn epoch_time_secs(&self) -> u64 {
+        get_epoch_time_secs().saturating_add(self.offset().as_secs())
+    }
+
+    #[cfg(any(test, feature = "testing"))]
+    fn offset(&self) -> Duration {
+        self.signer_public_key
+            .as_ref()
+            .and_then(|pk| TEST_SIGNER_CLOCK_OFFSET.get().get(pk).copied())
+            .unwrap_or(Duration::ZERO)
+    }
+
+    #[cfg(not(any(test, feature = "testing")))]
+    fn offset(&self) -> Duration {
+        Duration::ZERO
+    }
+}
+
+/// The kinds of block that a signer's signing policy distinguishes between
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum BlockKind {
+    /// A block without user transactions, such as the first block of an empty tenure
+    Empty,
+    /// A block that starts or extends a tenure, and carries user transactions
+    TenureChange,
+    /// A block within a tenure that carries user transactions
+    UserTransactions,
+}
+
+impl BlockKind {
+    /// Classify a block by the transactions it contains
+    pub fn of_block(block: &NakamotoBlock) -> Self {
+        let has_user_txs = block.txs.iter().any(|tx| {
+            !matches!(
+                tx.payload,
+                TransactionPayload::TenureChange(..) | TransactionPayload::Coinbase(..)
+            )
+        });
+        if !has_user_txs {
+            BlockKind::Empty
+        } else if block
+            .txs
+            .iter()
+            .any(|tx| matches!(tx.payload, TransactionPayload::TenureChange(..)))
+        {
+            BlockKind::TenureChange
+        } else {
+            BlockKind::UserTransactions
+        }
+    }
+
+    fn bit(self) -> u8 {
+        1 << (self as u8)
+    }
+}
+
+/// The set of block kinds that a signer signs. The signer abstains on blocks of any other kind:
+/// it neither accepts nor rejects them.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct BlockKindMask(u8);
+
+impl BlockKindMask {
+    /// Sign every kind of block
+    pub const ALL: BlockKindMask = BlockKindMask(0b111);
+
+    /// Does this mask include blocks of `kind`?
+    pub fn contains(&self, kind: BlockKind) -> bool {
+        self.0 & kind.bit() != 0
+    }
+
+    /// This mask, without blocks of `kind`
+    pub fn without(self, kind: BlockKind) -> Self {
+        BlockKindMask(self.0 & !kind.bit())
+    }
+}
+
+impl Default for BlockKindMask {
+    fn default() -> Self {
+        Self::ALL
+    }
+}
+
+impl FromIterator<BlockKind> for BlockKindMask {
+    fn from_iter<I: IntoIterator<Item = BlockKind>>(kinds: I) -> Self {
+        BlockKindMask(kinds.into_iter().fold(0, |mask, kind| mask | kind.bit()))
+    }
 }
 
 /// Captures the configuration settings used by the signer when evaluating block proposals.
@@ -129,6 +278,23 @@ pub struct ProposalEvalConfig {
     /// Time following the last block of the previous tenure's global acceptance that a signer will consider an attempt by
     /// the new miner to reorg it as valid towards miner activity
     pub reorg_attempts_activity_timeout: Duration,
+    /// The clock used for timeout comparisons
+    pub clock: SignerClock,
+    /// The maximum cumulative execution cost of the blocks in a tenure that the signer will accept
+    pub max_tenure_cost: Option<ExecutionCost>,
+    /// How many burn blocks must confirm a miner's block-commit before signing the first block
+    /// of its tenure (0 disables the check)
+    pub require_commit_confirmations: u64,
+    /// The maximum serialized size, in bytes, of a block that the signer will accept
+    pub max_block_bytes: Option<u64>,
+    /// How many burn blocks ahead of the signer's burn view a block proposal may be before the
+    /// signer defers evaluating it
+    pub max_burn_height_ahead: u64,
+    /// The kinds of block the signer signs. It abstains on all others.
+    pub sign_block_kinds: BlockKindMask,
+    /// How long after detecting a burnchain reorg the signer waits before marking a miner as
+    /// timed out
+    pub burnchain_reorg_grace_period: Duration,
 }
 
 impl From<&SignerConfig> for ProposalEvalConfig {
@@ -140,6 +306,13 @@ impl From<&SignerConfig> for ProposalEvalConfig {
             tenure_idle_timeout: value.tenure_idle_timeout,
             reorg_attempts_activity_timeout: value.reorg_attempts_activity_timeout,
             tenure_idle_timeout_buffer: value.tenure_idle_timeout_buffer,
+            clock: SignerClock::new(StacksPublicKey::from_private(&value.stacks_private_key)),
+            max_tenure_cost: value.max_tenure_cost.clone(),
+            require_commit_confirmations: value.require_commit_confirmations,
+            max_block_bytes: value.max_block_bytes,
+            max_burn_height_ahead: value.max_burn_height_ahead,
+            sign_block_kinds: value.sign_block_kinds,
+            burnchain_reorg_grace_period: value.burnchain_reorg_grace_period,
         }
     }
 }
@@ -173,12 +346,25 @@ impl TryFrom<SortitionInfo> for SortitionState {
                 .ok_or_else(|| ClientError::UnexpectedSortitionInfo)?,
             burn_header_timestamp: value.burn_header_timestamp,
             burn_block_hash: value.burn_block_hash,
+            burn_block_height: value.burn_block_height,
             miner_status: SortitionMinerStatus::Valid,
         })
     }
 }
 
-enum ProposedBy<'a> {
+/// The outcome of evaluating a block proposal against a sortition view at an explicit burn height
+#[derive(Debug, Clone, PartialEq)]
+pub enum ProposalVerdict {
+    /// The proposal passes the view's checks
+    Accept,
+    /// The proposal is more than `max_burn_height_ahead` burn blocks ahead of the view, so it
+    /// is neither accepted nor rejected
+    Defer,
+    /// The proposal should be rejected with the given reject code
+    Reject(RejectCode),
+}
+
+pub(crate) enum ProposedBy<'a> {
     LastSortition(&'a SortitionState),
     CurrentSortition(&'a SortitionState),
 }
@@ -192,6 +378,46 @@ impl ProposedBy<'_> {
     }
 }
 
+/// Why a block proposal does not match a sortition view
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub(crate) enum ViewMismatch {
+    /// The block's PoX bitvec punishes signers
+    PunishingBitvec,
+    /// The block is from neither the current nor the last sortition
+    UnknownSortition,
+    /// The block is not signed by its sortition's winner
+    NotSortitionWinner,
+    /// The block's miner is not the valid miner for this view
+    InvalidMiner,
+}
+
+impl ViewMismatch {
+    /// A description of the mismatch, for logs and rejection reasons
+    pub(crate) fn reason(&self) -> &'static str {
+        match self {
+            ViewMismatch::PunishingBitvec => "block's PoX bitvec punishes signers",
+            ViewMismatch::UnknownSortition => {
+                "block is from neither the current nor the last sortition"
+            }
+            ViewMismatch::NotSortitionWinner => "block is not signed by its sortition's winner",
+            ViewMismatch::InvalidMiner => "block's miner is not the valid miner for this view",
+        }
+    }
+}
+
+/// Choose the reject code for a block proposal that does not match the sortition view. If the
+/// proposal's burn height is below the burn view, the miner proposed it from a stale view;
+/// otherwise, its sortition does not match ours.
+pub fn view_mismatch_reject_code(
+    proposal_burn_height: u64,
+    burn_view_height: Option<u64>,
+) -> RejectCode {
+    match burn_view_height {
+        Some(burn_view_height) if proposal_burn_height < burn_view_height => RejectCode::StaleView,
+        _ => RejectCode::SortitionViewMismatch,
+    }
+}
+
 impl SortitionsView {
     /// Apply checks from the SortitionsView on the block proposal.
     pub fn check_proposal(
@@ -202,9 +428,54 @@ impl SortitionsView {
         block_pk: &StacksPublicKey,
         reset_view_if_wrong_consensus_hash: bool,
     ) -> Result<bool, SignerChainstateError> {
-        if self
-            .cur_sortition
-            .is_timed_out(self.config.block_proposal_timeout, signer_db)?
+        self.update_view_for_proposal(
+            client,
+            signer_db,
+            block,
+            block_pk,
+            reset_view_if_wrong_consensus_hash,
+        )?;
+        if let Err(mismatch) = self.check_view(block, block_pk) {
+            warn!(
+                "Miner block proposal does not match the sortition view. Considering invalid.";
+                "reason" => mismatch.reason(),
+                "proposed_block_consensus_hash" => %block.header.consensus_hash,
+                "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
+                "proposed_block_pubkey" => &block_pk.to_hex(),
+                "current_sortition_consensus_hash" => ?self.cur_sortition.consensus_hash,
+                "current_sortition_miner_status" => ?self.cur_sortition.miner_status,
+                "last_sortition_consensus_hash" => ?self.last_sortition.as_ref().map(|x| x.consensus_hash),
+            );
+            return Ok(false);
+        }
+        self.check_proposal_against_chainstate(client, signer_db, block, block_pk)
+    }
+
+    /// Bring the view up to date for a block proposal before checking it: invalidate miners
+    /// that timed out or chose the wrong parent tenure, and learn the proposer's public key.
+    /// If the block is from neither the current nor the last sortition and
+    /// `reset_view_if_wrong_consensus_hash` is set, the view is re-fetched from the node.
+    pub fn update_view_for_proposal(
+        &mut self,
+        client: &StacksClient,
+        signer_db: &mut SignerDb,
+        block: &NakamotoBlock,
+        block_pk: &StacksPublicKey,
+        reset_view_if_wrong_consensus_hash: bool,
+    ) -> Result<(), SignerChainstateError> {
+        let in_reorg_grace_period = self.in_burnchain_reorg_grace_period(signer_db)?;
+        if in_reorg_grace_period {
+            debug!(
+                "Within the grace period following a burnchain reorg, not checking for miner timeouts";
+                "burnchain_reorg_grace_period" => ?self.config.burnchain_reorg_grace_period,
+            );
+        }
+        if !in_reorg_grace_period
+            && self.cur_sortition.is_timed_out(
+                self.config.block_proposal_timeout,
+                &self.config.clock,
+                signer_db,
+            )?
         {
             info!(
                 "Current miner timed out, marking as invalid.";
@@ -248,7 +519,13 @@ impl SortitionsView {
         }
 
         if let Some(last_sortition) = self.last_sortition.as_mut() {
-            if last_sortition.is_timed_out(self.config.block_proposal_timeout, signer_db)? {
+            if !in_reorg_grace_period
+                && last_sortition.is_timed_out(
+                    self.config.block_proposal_timeout,
+                    &self.config.clock,
+                    signer_db,
+                )?
+            {
                 info!(
                     "Last miner timed out, marking as invalid.";
                     "block_height" => block.header.chain_length,
@@ -257,95 +534,42 @@ impl SortitionsView {
                 last_sortition.miner_status = SortitionMinerStatus::InvalidatedBeforeFirstBlock;
             }
         }
-        let bitvec_all_1s = block.header.pox_treatment.iter().all(|entry| entry);
-        if !bitvec_all_1s {
-            warn!(
-                "Miner block proposal has bitvec field which punishes in disagreement with signer. Considering invalid.";
-                "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
-                "current_sortition_consensus_hash" => ?self.cur_sortition.consensus_hash,
-                "last_sortition_consensus_hash" => ?self.last_sortition.as_ref().map(|x| x.consensus_hash),
-            );
-            return Ok(false);
+        self.cur_sortition
+            .learn_miner_pubkey(block, block_pk, signer_db)?;
+        if let Some(last_sortition) = self.last_sortition.as_mut() {
+            last_sortition.learn_miner_pubkey(block, block_pk, signer_db)?;
         }
-
-        let block_pkh = Hash160::from_data(&block_pk.to_bytes_compressed());
-        let Some(proposed_by) =
-            (if block.header.consensus_hash == self.cur_sortition.consensus_hash {
-                Some(ProposedBy::CurrentSortition(&self.cur_sortition))
-            } else {
-                None
-            })
-            .or_else(|| {
-                self.last_sortition.as_ref().and_then(|last_sortition| {
-                    if block.header.consensus_hash == last_sortition.consensus_hash {
-                        Some(ProposedBy::LastSortition(last_sortition))
-                    } else {
-                        None
-                    }
-                })
-            })
-        else {
-            if reset_view_if_wrong_consensus_hash {
-                info!(
-                    "Miner block proposal has consensus hash that is neither the current or last sortition. Resetting view.";
-                    "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                    "current_sortition_consensus_hash" => ?self.cur_sortition.consensus_hash,
-                    "last_sortition_consensus_hash" => ?self.last_sortition.as_ref().map(|x| x.consensus_hash),
-                );
-                self.reset_view(client)?;
-                return self.check_proposal(client, signer_db, block, block_pk, false);
-            }
-            warn!(
-                "Miner block proposal has consensus hash that is neither the current or last sortition. Considering invalid.";
+        if reset_view_if_wrong_consensus_hash
+            && matches!(
+                self.check_view(block, block_pk),
+                Err(ViewMismatch::UnknownSortition)
+            )
+        {
+            info!(
+                "Miner block proposal has consensus hash that is neither the current or last sortition. Resetting view.";
                 "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
                 "current_sortition_consensus_hash" => ?self.cur_sortition.consensus_hash,
                 "last_sortition_consensus_hash" => ?self.last_sortition.as_ref().map(|x| x.consensus_hash),
             );
-            return Ok(false);
-        };
-
-        if proposed_by.state().miner_pkh != block_pkh {
-            warn!(
-                "Miner block proposal pubkey does not match the winning pubkey hash for its sortition. Considering invalid.";
-                "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
-                "proposed_block_pubkey" => &block_pk.to_hex(),
-                "proposed_block_pubkey_hash" => %block_pkh,
-                "sortition_winner_pubkey_hash" => %proposed_by.state().miner_pkh,
-            );
-            return Ok(false);
+            self.reset_view(client)?;
+            return self.update_view_for_proposal(client, signer_db, block, block_pk, false);
         }
+        Ok(())
+    }
 
-        // check that this miner is the most recent sortition
-        match proposed_by {
-            ProposedBy::CurrentSortition(sortition) => {
-                if sortition.miner_status != SortitionMinerStatus::Valid {
-                    warn!(
-                        "Current miner behaved improperly, this signer views the miner as invalid.";
-                        "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                        "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
-                    );
-                    return Ok(false);
-                }
-            }
-            ProposedBy::LastSortition(last_sortition) => {
-                // should only consider blocks from the last sortition if the new sortition was invalidated
-                //  before we signed their first block.
-                if self.cur_sortition.miner_status
-                    != SortitionMinerStatus::InvalidatedBeforeFirstBlock
-                {
-                    warn!(
-                        "Miner block proposal is from last sortition winner, when the new sortition winner is still valid. Considering proposal invalid.";
-                        "proposed_block_consensus_hash" => %block.header.consensus_hash,
-                        "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
-                        "current_sortition_miner_status" => ?self.cur_sortition.miner_status,
-                        "last_sortition" => %last_sortition.consensus_hash
-                    );
-                    return Ok(false);
-                }
-            }
+    /// Apply the checks of `check_proposal` that need the signer database or the node: a tenure
+    /// change must confirm the expected parent block and build off a valid parent tenure, any
+    /// other block must confirm the last block we accepted in its tenure, and a tenure extend
+    /// must either change the burn view or come after the tenure idle timeout.
+    pub fn check_proposal_against_chainstate(
+        &self,
+        client: &StacksClient,
+        signer_db: &mut SignerDb,
+        block: &NakamotoBlock,
+        block_pk: &StacksPublicKey,
+    ) -> Result<bool, SignerChainstateError> {
+        let Ok(proposed_by) = self.check_view(block, block_pk) else {
+            return Ok(false);
         };
 
         if let Some(tenure_change) = block.get_tenure_change_tx_payload() {
@@ -379,7 +603,7 @@ impl SortitionsView {
                 block,
                 false,
             );
-            let epoch_time = get_epoch_time_secs();
+            let epoch_time = self.config.clock.epoch_time_secs();
             let enough_time_passed = epoch_time > extend_timestamp;
             if !changed_burn_view && !enough_time_passed {
                 warn!(
@@ -396,6 +620,125 @@ impl SortitionsView {
         Ok(true)
     }
 
+    /// Apply the checks of `check_proposal` that depend on the view alone: the block's PoX
+    /// bitvec must not punish anyone, and the block must be signed by the winner of the current
+    /// sortition, or of the last one if the current miner was invalidated before its first
+    /// block. Returns the sortition the block was proposed in.
+    pub(crate) fn check_view(
+        &self,
+        block: &NakamotoBlock,
+        block_pk: &StacksPublicKey,
+    ) -> Result<ProposedBy<'_>, ViewMismatch> {
+        if !block.header.pox_treatment.iter().all(|entry| entry) {
+            return Err(ViewMismatch::PunishingBitvec);
+        }
+        let proposed_by = if block.header.consensus_hash == self.cur_sortition.consensus_hash {
+            ProposedBy::CurrentSortition(&self.cur_sortition)
+        } else if let Some(last_sortition) = self
+            .last_sortition
+            .as_ref()
+            .filter(|last| last.consensus_hash == block.header.consensus_hash)
+        {
+            ProposedBy::LastSortition(last_sortition)
+        } else {
+            return Err(ViewMismatch::UnknownSortition);
+        };
+        let block_pkh = Hash160::from_data(&block_pk.to_bytes_compressed());
+        if proposed_by.state().miner_pkh != block_pkh {
+            return Err(ViewMismatch::NotSortitionWinner);
+        }
+        let miner_valid = match proposed_by {
+            ProposedBy::CurrentSortition(sortition) => {
+                sortition.miner_status == SortitionMinerStatus::Valid
+            }
+            // should only consider blocks from the last sortition if the new sortition was
+            //  invalidated before we signed their first block.
+            ProposedBy::LastSortition(_) => {
+                self.cur_sortition.miner_status == SortitionMinerStatus::InvalidatedBeforeFirstBlock
+            }
+        };
+        if !miner_valid {
+            return Err(ViewMismatch::InvalidMiner);
+        }
+        Ok(proposed_by)
+    }
+
+    /// Evaluate a block proposal against this view as if the burn tip were at `burn_height`.
+    /// Unlike `check_proposal`, this consults neither the node nor the signer database, so only
+    /// the checks that depend on the view alone apply (see `proposal_mismatch`).  A proposal
+    /// that fails them is rejected with `view_mismatch_reject_code`, taking the view's current
+    /// sortition as the burn view.
+    pub fn check_proposal_at_burn_height(
+        &self,
+        block: &NakamotoBlock,
+        burn_height: u64,
+    ) -> ProposalVerdict {
+        let view_burn_height = self.cur_sortition.burn_block_height;
+        if burn_height.saturating_sub(view_burn_height) > self.config.max_burn_height_ahead {
+            return ProposalVerdict::Defer;
+        }
+        match self.proposal_mismatch(block) {
+            Some(_) => ProposalVerdict::Reject(view_mismatch_reject_code(
+                burn_height,
+                Some(view_burn_height),
+            )),
+            None => ProposalVerdict::Accept,
+        }
+    }
+
+    /// Apply the checks of `check_proposal` that depend on the view alone, returning why the
+    /// block does not match the view, if it does not.
+    pub fn proposal_mismatch(&self, block: &NakamotoBlock) -> Option<&'static str> {
+        let Some(block_pk) = block.header.recover_miner_pk() else {
+            return Some("block has no valid miner signature");
+        };
+        self.check_view(block, &block_pk)
+            .err()
+            .map(|mismatch| mismatch.reason())
+    }
+
+    /// Check that the block-commit which won the proposed block's sortition has been confirmed by
+    /// at least `require_commit_confirmations` burn blocks, counting the sortition's own burn
+    /// block. Only the first block of a tenure is checked: tenure extends do not start a tenure,
+    /// so their commit was already checked.
+    pub fn check_commit_confirmations(
+        &self,
+        client: &StacksClient,
+        block: &NakamotoBlock,
+    ) -> Result<bool, SignerChainstateError> {
+        let required = self.config.require_commit_confirmations;
+        let is_first_block = block
+            .get_tenure_change_tx_payload()
+            .is_some_and(|payload| payload.cause == TenureChangeCause::BlockFound);
+        if required == 0 || !is_first_block {
+            return Ok(true);
+        }
+        let Some(sortition) = std::iter::once(&self.cur_sortition)
+            .chain(self.last_sortition.as_ref())
+            .find(|sortition| sortition.consensus_hash == block.header.consensus_hash)
+        else {
+            // check_proposal() rejects blocks from unknown sortitions
+            return Ok(true);
+        };
+        let burn_block_height = client.get_peer_info()?.burn_block_height;
+        let confirmations = burn_block_height
+            .saturating_add(1)
+            .saturating_sub(sortition.burn_block_height);
+        if confirmations < required {
+            info!(
+                "Miner's block-commit does not have enough confirmations to sign the first block of its tenure.";
+                "proposed_block_consensus_hash" => %block.header.consensus_hash,
+                "proposed_block_signer_sighash" => %block.header.signer_signature_hash(),
+                "sortition_burn_block_height" => sortition.burn_block_height,
+                "burn_block_height" => burn_block_height,
+                "confirmations" => confirmations,
+                "require_commit_confirmations" => required,
+            );
+            return Ok(false);
+        }
+        Ok(true)
+    }
+
     fn check_parent_tenure_choice(
         sortition_state: &SortitionState,
         block: &NakamotoBlock,
@@ -718,6 +1061,30 @@ impl SortitionsView {
         }
     }
 
+    /// The public keys of the miners of the current and last sortitions that this signer still
+    /// considers valid. A miner is only listed once its public key is known to the signer.
+    pub fn valid_miners(&self) -> Vec<StacksPublicKey> {
+        std::iter::once(&self.cur_sortition)
+            .chain(self.last_sortition.as_ref())
+            .filter(|sortition| sortition.miner_status == SortitionMinerStatus::Valid)
+            .filter_map(|sortition| sortition.miner_pubkey)
+            .collect()
+    }
+
+    /// Is it still within `burnchain_reorg_grace_period` of the most recent burnchain reorg?
+    /// Miners are not marked as timed out during this period.
+    pub fn in_burnchain_reorg_grace_period(
+        &self,
+        signer_db: &SignerDb,
+    ) -> Result<bool, SignerChainstateError> {
+        let Some(reorg_ts) = signer_db.get_last_burnchain_reorg_time()? else {
+            return Ok(false);
+        };
+        let grace_period_end =
+            UNIX_EPOCH + Duration::from_secs(reorg_ts) + self.config.burnchain_reorg_grace_period;
+        Ok(self.config.clock.now() < grace_period_end)
+    }
+
     /// Fetch a new view of the recent sortitions
     pub fn fetch_view(
         config: ProposalEvalConfig,
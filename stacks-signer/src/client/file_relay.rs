@@ -0,0 +1,171 @@
+// Copyright (C) 2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::fs;
+use std::path::{Path, PathBuf};
+
+use clarity::vm::types::QualifiedContractIdentifier;
+use libstackerdb::StackerDBChunkData;
+use serde::{Deserialize, Serialize};
+use slog::slog_warn;
+use stacks_common::warn;
+
+use crate::client::ClientError;
+
+/// A signed StackerDB chunk relayed through a file, along with the StackerDB it belongs to.
+/// The file is self-contained: the chunk is exactly what would be posted to StackerDB.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct RelayedChunk {
+    /// The StackerDB contract the chunk is written to
+    pub contract_id: QualifiedContractIdentifier,
+    /// The signed chunk
+    pub chunk: StackerDBChunkData,
+}
+
+impl RelayedChunk {
+    /// The name of the file this chunk is relayed in
+    fn file_name(&self) -> String {
+        format!(
+            "{}.{}.{}.{}.json",
+            &self.contract_id,
+            self.chunk.slot_id,
+            self.chunk.slot_version,
+            self.chunk.data_hash().to_hex()
+        )
+    }
+}
+
+/// Relays StackerDB chunks through a pair of directories, for signers that cannot reach a
+/// stacks node directly.  Chunks the signer writes go to the `outbound` directory, from which
+/// the operator carries them to the node; chunks from other signers are dropped by the operator
+/// into the `inbound` directory.
+#[derive(Debug)]
+pub struct FileRelay {
+    /// The directory chunks written by this signer are relayed from
+    outbound_dir: PathBuf,
+    /// The directory chunks written by other signers are relayed to
+    inbound_dir: PathBuf,
+    /// Whether chunks are only relayed, and not also posted to StackerDB
+    relay_only: bool,
+}
+
+impl FileRelay {
+    /// Name of the outbound subdirectory of the relay directory
+    pub const OUTBOUND_DIR: &'static str = "outbound";
+    /// Name of the inbound subdirectory of the relay directory
+    pub const INBOUND_DIR: &'static str = "inbound";
+    /// Extension given to inbound files that could not be decoded
+    const INVALID_EXTENSION: &'static str = "invalid";
+
+    /// Create a file relay over the `outbound` and `inbound` subdirectories of `relay_dir`.
+    /// If `relay_only` is set, chunks are only relayed, and not also posted to StackerDB.
+    pub fn new(relay_dir: &Path, relay_only: bool) -> Self {
+        Self {
+            outbound_dir: relay_dir.join(Self::OUTBOUND_DIR),
+            inbound_dir: relay_dir.join(Self::INBOUND_DIR),
+            relay_only,
+        }
+    }
+
+    /// Whether chunks are only relayed, and not also posted to StackerDB
+    pub fn relay_only(&self) -> bool {
+        self.relay_only
+    }
+
+    /// The directory chunks written by this signer are relayed from
+    pub fn outbound_dir(&self) -> &Path {
+        &self.outbound_dir
+    }
+
+    /// The directory chunks written by other signers are relayed to
+    pub fn inbound_dir(&self) -> &Path {
+        &self.inbound_dir
+    }
+
+    /// Write a chunk to the outbound directory, returning the path of the new file.
+    /// The file is written under a temporary name and then renamed, so a process watching the
+    /// directory never sees a partially-written file.
+    pub fn write_outbound(&self, relayed: &RelayedChunk) -> Result<PathBuf, ClientError> {
+        fs::create_dir_all(&self.outbound_dir).map_err(|e| {
+            ClientError::FileRelayError(format!(
+                "Failed to create {}: {e}",
+                self.outbound_dir.display()
+            ))
+        })?;
+        let path = self.outbound_dir.join(relayed.file_name());
+        let tmp_path = path.with_extension("json.tmp");
+        let bytes = serde_json::to_vec(relayed)
+            .map_err(|e| ClientError::FileRelayError(format!("Failed to encode chunk: {e}")))?;
+        fs::write(&tmp_path, bytes)
+            .and_then(|_| fs::rename(&tmp_path, &path))
+            .map_err(|e| {
+                ClientError::FileRelayError(format!("Failed to write {}: {e}", path.display()))
+            })?;
+        Ok(path)
+    }
+
+    /// Take all chunks in the inbound directory that belong to one of `contract_ids`, in file
+    /// name order.  Each file is removed once it is read.  Files for other contracts are left in
+    /// place, and files that cannot be decoded are renamed with an `.invalid` extension so they
+    /// are not read again.
+    pub fn take_inbound(
+        &self,
+        contract_ids: &[QualifiedContractIdentifier],
+    ) -> Result<Vec<RelayedChunk>, ClientError> {
+        let entries = match fs::read_dir(&self.inbound_dir) {
+            Ok(entries) => entries,
+            Err(e) if e.kind() == std::io::ErrorKind::NotFound => return Ok(vec![]),
+            Err(e) => {
+                return Err(ClientError::FileRelayError(format!(
+                    "Failed to read {}: {e}",
+                    self.inbound_dir.display()
+                )))
+            }
+        };
+        let mut paths: Vec<_> = entries
+            .filter_map(|entry| entry.ok().map(|entry| entry.path()))
+            .filter(|path| path.extension().is_some_and(|ext| ext == "json"))
+            .collect();
+        paths.sort();
+
+        let mut relayed_chunks = vec![];
+        for path in paths {
+            let relayed = fs::read(&path)
+                .map_err(|e| e.to_string())
+                .and_then(|bytes| {
+                    serde_json::from_slice::<RelayedChunk>(&bytes).map_err(|e| e.to_string())
+                });
+            let relayed = match relayed {
+                Ok(relayed) => relayed,
+                Err(e) => {
+                    warn!("Failed to read relayed chunk"; "path" => %path.display(), "error" => e);
+                    if let Err(e) = fs::rename(&path, path.with_extension(Self::INVALID_EXTENSION))
+                    {
+                        warn!("Failed to set aside relayed chunk"; "path" => %path.display(), "error" => %e);
+                    }
+                    continue;
+                }
+            };
+            if !contract_ids.contains(&relayed.contract_id) {
+                continue;
+            }
+            fs::remove_file(&path).map_err(|e| {
+                ClientError::FileRelayError(format!("Failed to remove {}: {e}", path.display()))
+            })?;
+            relayed_chunks.push(relayed);
+        }
+        Ok(relayed_chunks)
+    }
+}
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+/// The file relay module for relaying stackerdb chunks through local directories
+pub(crate) mod file_relay;
 /// The stacker db module for communicating with the stackerdb contract
 pub(crate) mod stackerdb;
 /// The stacks node client module for communicating with the stacks node
@@ -23,6 +25,7 @@ use std::time::Duration;
 
 use clarity::vm::errors::Error as ClarityError;
 use clarity::vm::types::serialization::SerializationError;
+pub use file_relay::*;
 use libsigner::RPCError;
 use libstackerdb::Error as StackerDBError;
 use slog::slog_debug;
@@ -95,6 +98,9 @@ pub enum ClientError {
     /// An RPC libsigner error occurred
     #[error("A libsigner RPC error occurred: {0}")]
     RPCError(#[from] RPCError),
+    /// Failed to relay a stacker-db chunk through the file relay directory
+    #[error("File relay error: {0}")]
+    FileRelayError(String),
 }
 
 /// Retry a function F with an exponential backoff and notification on transient failure
@@ -122,7 +128,10 @@ where
 pub(crate) mod tests {
     use std::collections::{BTreeMap, HashMap};
     use std::io::{Read, Write};
-    use std::net::{SocketAddr, TcpListener};
+    use std::net::{SocketAddr, TcpListener, TcpStream};
+    use std::sync::atomic::{AtomicBool, Ordering};
+    use std::sync::{Arc, Mutex};
+    use std::thread::{self, JoinHandle};
 
     use blockstack_lib::chainstate::stacks::boot::POX_4_NAME;
     use blockstack_lib::chainstate::stacks::db::StacksBlockHeaderTypes;
@@ -130,10 +139,13 @@ pub(crate) mod tests {
     use blockstack_lib::net::api::getpoxinfo::{
         RPCPoxCurrentCycleInfo, RPCPoxEpoch, RPCPoxInfoData, RPCPoxNextCycleInfo,
     };
+    use blockstack_lib::net::api::poststackerdbchunk::StackerDBErrorCodes;
     use blockstack_lib::util_lib::boot::boot_code_id;
     use clarity::vm::costs::ExecutionCost;
+    use clarity::vm::types::QualifiedContractIdentifier;
     use clarity::vm::Value as ClarityValue;
     use libsigner::SignerEntries;
+    use libstackerdb::{StackerDBChunkAckData, StackerDBChunkData};
     use rand::distributions::Standard;
     use rand::{thread_rng, Rng};
     use rand_core::RngCore;
@@ -207,6 +219,195 @@ pub(crate) mod tests {
         request_bytes
     }
 
+    /// An in-process StackerDB replica that serves the StackerDB HTTP endpoints out of an
+    /// in-memory slot map, so `StackerDB` and `StackerDBSession` can be exercised without a node.
+    /// Slots are keyed by the stringified contract ID and the slot ID.
+    pub struct MockStackerDBServer {
+        pub addr: SocketAddr,
+        slots: Arc<Mutex<HashMap<(String, u32), StackerDBChunkData>>>,
+        stop: Arc<AtomicBool>,
+        handle: Option<JoinHandle<()>>,
+    }
+
+    impl MockStackerDBServer {
+        /// Start a new mock StackerDB replica on a random port
+        pub fn new() -> Self {
+            let (server, addr) = mock_server_random();
+            let slots = Arc::new(Mutex::new(HashMap::new()));
+            let stop = Arc::new(AtomicBool::new(false));
+            let handle = {
+                let slots = slots.clone();
+                let stop = stop.clone();
+                thread::spawn(move || {
+                    for stream in server.incoming() {
+                        if stop.load(Ordering::SeqCst) {
+                            break;
+                        }
+                        let Ok(mut stream) = stream else {
+                            continue;
+                        };
+                        let response = Self::handle_request(&slots, &mut stream);
+                        let _ = stream.write_all(&response);
+                    }
+                })
+            };
+            Self {
+                addr,
+                slots,
+                stop,
+                handle: Some(handle),
+            }
+        }
+
+        /// Store a chunk directly, bypassing the version check (e.g. to simulate another writer)
+        pub fn insert_chunk(
+            &self,
+            contract_id: &QualifiedContractIdentifier,
+            chunk: StackerDBChunkData,
+        ) {
+            self.slots
+                .lock()
+                .unwrap()
+                .insert((contract_id.to_string(), chunk.slot_id), chunk);
+        }
+
+        /// Get the chunk currently stored in the given slot, if any
+        pub fn get_chunk(
+            &self,
+            contract_id: &QualifiedContractIdentifier,
+            slot_id: u32,
+        ) -> Option<StackerDBChunkData> {
+            self.slots
+                .lock()
+                .unwrap()
+                .get(&(contract_id.to_string(), slot_id))
+                .cloned()
+        }
+
+        /// Read a single HTTP request off of the stream and produce the raw response bytes
+        fn handle_request(
+            slots: &Mutex<HashMap<(String, u32), StackerDBChunkData>>,
+            stream: &mut TcpStream,
+        ) -> Vec<u8> {
+            let Some((verb, path, body)) = Self::read_request(stream) else {
+                return b"HTTP/1.1 400 Bad Request\r\nConnection: close\r\n\r\n".to_vec();
+            };
+            let Some(path) = path.strip_prefix("/v2/stackerdb/") else {
+                return b"HTTP/1.1 404 Not Found\r\nConnection: close\r\n\r\n".to_vec();
+            };
+            let parts: Vec<_> = path.split('/').collect();
+            let mut slots = slots.lock().unwrap();
+            match (verb.as_str(), parts.as_slice()) {
+                ("POST", [addr, name, "chunks"]) => {
+                    let contract = format!("{addr}.{name}");
+                    let Ok(chunk) = serde_json::from_slice::<StackerDBChunkData>(&body) else {
+                        return b"HTTP/1.1 400 Bad Request\r\nConnection: close\r\n\r\n".to_vec();
+                    };
+                    let key = (contract, chunk.slot_id);
+                    let ack = match slots.get(&key) {
+                        Some(existing) if existing.slot_version >= chunk.slot_version => {
+                            let err_code = StackerDBErrorCodes::DataAlreadyExists;
+                            StackerDBChunkAckData {
+                                accepted: false,
+                                reason: Some(err_code.reason().to_string()),
+                                metadata: Some(existing.get_slot_metadata()),
+                                code: Some(err_code.code()),
+                            }
+                        }
+                        _ => {
+                            let metadata = chunk.get_slot_metadata();
+                            slots.insert(key, chunk);
+                            StackerDBChunkAckData {
+                                accepted: true,
+                                reason: None,
+                                metadata: Some(metadata),
+                                code: None,
+                            }
+                        }
+                    };
+                    let payload = serde_json::to_vec(&ack).expect("Failed to serialize ack");
+                    Self::ok_response("application/json", &payload)
+                }
+                ("GET", [addr, name]) => {
+                    let contract = format!("{addr}.{name}");
+                    let mut metadata: Vec<_> = slots
+                        .iter()
+                        .filter(|((c, _), _)| *c == contract)
+                        .map(|(_, chunk)| chunk.get_slot_metadata())
+                        .collect();
+                    metadata.sort_by_key(|md| md.slot_id);
+                    let payload =
+                        serde_json::to_vec(&metadata).expect("Failed to serialize metadata");
+                    Self::ok_response("application/json", &payload)
+                }
+                ("GET", [addr, name, slot_id, version @ ..]) if version.len() <= 1 => {
+                    let contract = format!("{addr}.{name}");
+                    let chunk = slot_id
+                        .parse::<u32>()
+                        .ok()
+                        .and_then(|slot_id| slots.get(&(contract, slot_id)))
+                        .filter(|chunk| {
+                            version
+                                .first()
+                                .is_none_or(|v| v.parse::<u32>() == Ok(chunk.slot_version))
+                        });
+                    match chunk {
+                        Some(chunk) => Self::ok_response("application/octet-stream", &chunk.data),
+                        None => b"HTTP/1.1 404 Not Found\r\nConnection: close\r\n\r\n".to_vec(),
+                    }
+                }
+                _ => b"HTTP/1.1 404 Not Found\r\nConnection: close\r\n\r\n".to_vec(),
+            }
+        }
+
+        /// Read the request line, headers, and body of an HTTP request.
+        /// Returns (verb, path, body)
+        fn read_request(stream: &mut TcpStream) -> Option<(String, String, Vec<u8>)> {
+            let mut buf = vec![];
+            let mut byte = [0u8; 1];
+            while !buf.ends_with(b"\r\n\r\n") {
+                if stream.read(&mut byte).ok()? == 0 {
+                    return None;
+                }
+                buf.push(byte[0]);
+            }
+            let preamble = std::str::from_utf8(&buf).ok()?;
+            let mut lines = preamble.lines();
+            let mut request_line = lines.next()?.split_whitespace();
+            let verb = request_line.next()?.to_string();
+            let path = request_line.next()?.to_string();
+            let content_length = lines
+                .filter_map(|line| line.split_once(':'))
+                .find(|(name, _)| name.trim().eq_ignore_ascii_case("content-length"))
+                .and_then(|(_, value)| value.trim().parse::<usize>().ok())
+                .unwrap_or(0);
+            let mut body = vec![0u8; content_length];
+            stream.read_exact(&mut body).ok()?;
+            Some((verb, path, body))
+        }
+
+        fn ok_response(content_type: &str, payload: &[u8]) -> Vec<u8> {
+            let mut response = format!(
+                "HTTP/1.1 200 OK\r\nContent-Type: {content_type}\r\nContent-Length: {}\r\nConnection: close\r\n\r\n",
+                payload.len()
+            )
+            .into_bytes();
+            response.extend_from_slice(payload);
+            response
+        }
+    }
+
+    impl Drop for MockStackerDBServer {
+        fn drop(&mut self) {
+            self.stop.store(true, Ordering::SeqCst);
+            // wake up the accept loop so it can observe the stop flag
+            let _ = TcpStream::connect(self.addr);
+            if let Some(handle) = self.handle.take() {
+                let _ = handle.join();
+            }
+        }
+    }
+
     pub fn generate_random_consensus_hash() -> ConsensusHash {
         let rng = rand::thread_rng();
         let bytes: Vec<u8> = rng.sample_iter(Standard).take(20).collect();
@@ -336,6 +537,7 @@ pub(crate) mod tests {
                     .map(|cid| format!("{}", cid))
                     .collect(),
             ),
+            burn_headers_height: None,
         };
         let peer_info_json =
             serde_json::to_string(&peer_info).expect("Failed to serialize peer info");
@@ -409,6 +611,9 @@ pub(crate) mod tests {
             signer_slot_ids,
             stacks_private_key: config.stacks_private_key,
             node_host: config.node_host.to_string(),
+            stackerdb_replica_hosts: config.stackerdb_replica_hosts.clone(),
+            file_relay_dir: config.file_relay_dir.clone(),
+            file_relay_only: config.file_relay_only,
             mainnet: config.network.is_mainnet(),
             db_path: config.db_path.clone(),
             first_proposal_burn_block_timing: config.first_proposal_burn_block_timing,
@@ -419,6 +624,14 @@ pub(crate) mod tests {
             tenure_idle_timeout_buffer: config.tenure_idle_timeout_buffer,
             block_proposal_max_age_secs: config.block_proposal_max_age_secs,
             reorg_attempts_activity_timeout: config.reorg_attempts_activity_timeout,
+            max_tenure_cost: config.max_tenure_cost.clone(),
+            require_commit_confirmations: config.require_commit_confirmations,
+            max_block_bytes: config.max_block_bytes,
+            max_burn_height_ahead: config.max_burn_height_ahead,
+            sign_block_kinds: config.sign_block_kinds,
+            burnchain_reorg_grace_period: config.burnchain_reorg_grace_period,
+            undecodable_message_resync_threshold: config.undecodable_message_resync_threshold,
+            max_node_burn_lag: config.max_node_burn_lag,
         }
     }
 
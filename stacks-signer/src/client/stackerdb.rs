@@ -16,7 +16,9 @@
 //
 use blockstack_lib::net::api::poststackerdbchunk::StackerDBErrorCodes;
 use clarity::codec::read_next;
+use clarity::vm::types::QualifiedContractIdentifier;
 use hashbrown::HashMap;
+use libsigner::v0::messages::{MessageSlotID as MessageSlotIDV0, SignerMessage as SignerMessageV0};
 use libsigner::{MessageSlotID, SignerMessage, SignerSession, StackerDBSession};
 use libstackerdb::{StackerDBChunkAckData, StackerDBChunkData};
 use slog::{slog_debug, slog_info, slog_warn};
@@ -24,7 +26,7 @@ use stacks_common::types::chainstate::StacksPrivateKey;
 use stacks_common::util::hash::to_hex;
 use stacks_common::{debug, info, warn};
 
-use crate::client::{retry_with_exponential_backoff, ClientError};
+use crate::client::{retry_with_exponential_backoff, ClientError, FileRelay, RelayedChunk};
 use crate::config::{SignerConfig, SignerConfigMode};
 
 /// The signer StackerDB slot ID, purposefully wrapped to prevent conflation with SignerID
@@ -43,6 +45,15 @@ enum StackerDBMode {
     Normal { signer_slot_id: SignerSlotID },
 }
 
+/// An additional StackerDB replica that signer messages are mirrored to
+#[derive(Debug)]
+struct StackerDBReplica<M: MessageSlotID + std::cmp::Eq> {
+    /// The stacker-db sessions on this replica for each message type
+    sessions: HashMap<M, StackerDBSession>,
+    /// A map of a message ID to last chunk version for each session on this replica
+    slot_versions: HashMap<M, HashMap<SignerSlotID, u32>>,
+}
+
 /// The StackerDB client for communicating with the .signers contract
 #[derive(Debug)]
 pub struct StackerDB<M: MessageSlotID + std::cmp::Eq> {
@@ -58,6 +69,12 @@ pub struct StackerDB<M: MessageSlotID + std::cmp::Eq> {
     mode: StackerDBMode,
     /// The reward cycle of the connecting signer
     reward_cycle: u64,
+    /// Whether the signer is connected to mainnet
+    is_mainnet: bool,
+    /// Additional replicas that sent messages are mirrored to
+    replicas: Vec<StackerDBReplica<M>>,
+    /// The directories through which sent messages are also relayed as files, if any
+    file_relay: Option<FileRelay>,
 }
 
 impl<M: MessageSlotID + 'static> From<&SignerConfig> for StackerDB<M> {
@@ -73,6 +90,11 @@ impl<M: MessageSlotID + 'static> From<&SignerConfig> for StackerDB<M> {
 
         Self::new(
             &config.node_host,
+            &config.stackerdb_replica_hosts,
+            config
+                .file_relay_dir
+                .as_deref()
+                .map(|relay_dir| FileRelay::new(relay_dir, config.file_relay_only)),
             config.stacks_private_key,
             config.mainnet,
             config.reward_cycle,
@@ -93,6 +115,8 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
     ) -> Self {
         Self::new(
             host,
+            &[],
+            None,
             stacks_private_key,
             is_mainnet,
             reward_cycle,
@@ -103,17 +127,32 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
     /// Create a new StackerDB client
     fn new(
         host: &str,
+        replica_hosts: &[String],
+        file_relay: Option<FileRelay>,
         stacks_private_key: StacksPrivateKey,
         is_mainnet: bool,
         reward_cycle: u64,
         signer_mode: StackerDBMode,
     ) -> Self {
-        let mut signers_message_stackerdb_sessions = HashMap::new();
-        for msg_id in M::all() {
-            let session =
-                StackerDBSession::new(host, msg_id.stacker_db_contract(is_mainnet, reward_cycle));
-            signers_message_stackerdb_sessions.insert(*msg_id, session);
-        }
+        let make_sessions = |host: &str| {
+            let mut sessions = HashMap::new();
+            for msg_id in M::all() {
+                let session = StackerDBSession::new(
+                    host,
+                    msg_id.stacker_db_contract(is_mainnet, reward_cycle),
+                );
+                sessions.insert(*msg_id, session);
+            }
+            sessions
+        };
+        let signers_message_stackerdb_sessions = make_sessions(host);
+        let replicas = replica_hosts
+            .iter()
+            .map(|replica_host| StackerDBReplica {
+                sessions: make_sessions(replica_host),
+                slot_versions: HashMap::new(),
+            })
+            .collect();
 
         Self {
             signers_message_stackerdb_sessions,
@@ -121,6 +160,9 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
             slot_versions: HashMap::new(),
             mode: signer_mode,
             reward_cycle,
+            is_mainnet,
+            replicas,
+            file_relay,
         }
     }
 
@@ -138,8 +180,85 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
         self.send_message_bytes_with_retry(&msg_id, message_bytes)
     }
 
+    /// Sends a message to the .signers stacker-db with an exponential backoff retry, and relays
+    /// it through the file relay directory, if one is configured.  The relayed chunk has the
+    /// version the message was posted at, or the next version if the post failed, so it can be
+    /// relayed even if the node cannot be reached.  In relay-only mode, the message is not
+    /// posted at all.
+    pub fn send_and_relay_message_with_retry<T: SignerMessage<M>>(
+        &mut self,
+        message: T,
+    ) -> Result<StackerDBChunkAckData, ClientError> {
+        let Some(relay_only) = self.file_relay.as_ref().map(FileRelay::relay_only) else {
+            return self.send_message_with_retry(message);
+        };
+        let StackerDBMode::Normal {
+            signer_slot_id: slot_id,
+        } = self.mode
+        else {
+            return self.send_message_with_retry(message);
+        };
+        let msg_id = message.msg_id().ok_or_else(|| {
+            ClientError::PutChunkRejected(
+                "Tried to send a SignerMessage which does not have a corresponding .signers slot identifier".into()
+            )
+        })?;
+        let message_bytes = message.serialize_to_vec();
+        let result = if relay_only {
+            Ok(StackerDBChunkAckData {
+                accepted: true,
+                reason: None,
+                metadata: None,
+                code: None,
+            })
+        } else {
+            self.send_message_bytes_with_retry(&msg_id, message_bytes.clone())
+        };
+
+        let slot_versions = self.slot_versions.entry(msg_id).or_default();
+        let next_slot_version = slot_versions.get(&slot_id).copied().unwrap_or(0).max(1);
+        let slot_version = match result.as_ref() {
+            Ok(ack) if !relay_only => ack
+                .metadata
+                .as_ref()
+                .map(|metadata| metadata.slot_version)
+                .unwrap_or(next_slot_version.saturating_sub(1)),
+            _ => {
+                // Nothing was posted, so relay the next version and skip over it, so it is
+                // not reused for a different message.
+                slot_versions.insert(slot_id, next_slot_version.saturating_add(1));
+                next_slot_version
+            }
+        };
+        let (Some(file_relay), Some(session)) = (
+            self.file_relay.as_ref(),
+            self.signers_message_stackerdb_sessions.get(&msg_id),
+        ) else {
+            return result;
+        };
+        if let Err(e) = Self::relay_chunk(
+            file_relay,
+            &session.stackerdb_contract_id,
+            slot_id,
+            slot_version,
+            &self.stacks_private_key,
+            &message_bytes,
+        ) {
+            warn!(
+                "Failed to relay message through the file relay directory";
+                "message_id" => ?msg_id,
+                "error" => ?e
+            );
+            if relay_only {
+                return Err(e);
+            }
+        }
+        result
+    }
+
     /// Sends message (as a raw msg ID and bytes) to the .signers stacker-db with an
-    /// exponential backoff retry
+    /// exponential backoff retry. If additional replicas are configured, the message is
+    /// mirrored to each of them and the write succeeds if at least one of them accepts it.
     pub fn send_message_bytes_with_retry(
         &mut self,
         msg_id: &M,
@@ -161,42 +280,100 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
                 code: None,
             });
         };
-        loop {
-            let mut slot_version = if let Some(versions) = self.slot_versions.get_mut(msg_id) {
-                if let Some(version) = versions.get(slot_id) {
-                    *version
-                } else {
-                    versions.insert(*slot_id, 0);
-                    1
+        let slot_id = *slot_id;
+        let Some(session) = self.signers_message_stackerdb_sessions.get_mut(msg_id) else {
+            panic!("FATAL: would loop forever trying to send a message with ID {msg_id:?}, for which we don't have a session");
+        };
+        let primary_result = Self::put_chunk_with_retry(
+            session,
+            self.slot_versions.entry(*msg_id).or_default(),
+            slot_id,
+            &self.stacks_private_key,
+            msg_id,
+            &message_bytes,
+        );
+        if self.replicas.is_empty() {
+            return primary_result;
+        }
+
+        // Mirror the message to every additional replica. The write succeeds if at least one
+        // replica (including the node itself) accepted the chunk.
+        let (mut accepted_ack, mut first_error) = match primary_result {
+            Ok(ack) => (Some(ack), None),
+            Err(e) => {
+                warn!(
+                    "Failed to send message to stackerdb replica";
+                    "host" => &session.host,
+                    "message_id" => ?msg_id,
+                    "error" => ?e
+                );
+                (None, Some(e))
+            }
+        };
+        for replica in self.replicas.iter_mut() {
+            let Some(session) = replica.sessions.get_mut(msg_id) else {
+                continue;
+            };
+            match Self::put_chunk_with_retry(
+                session,
+                replica.slot_versions.entry(*msg_id).or_default(),
+                slot_id,
+                &self.stacks_private_key,
+                msg_id,
+                &message_bytes,
+            ) {
+                Ok(ack) => {
+                    accepted_ack.get_or_insert(ack);
                 }
+                Err(e) => {
+                    warn!(
+                        "Failed to send message to stackerdb replica";
+                        "host" => &session.host,
+                        "message_id" => ?msg_id,
+                        "error" => ?e
+                    );
+                    first_error.get_or_insert(e);
+                }
+            }
+        }
+        match (accepted_ack, first_error) {
+            (Some(ack), _) => Ok(ack),
+            (None, Some(e)) => Err(e),
+            (None, None) => Err(ClientError::NotConnected),
+        }
+    }
+
+    /// Put a chunk into the given session's slot, retrying with an exponential backoff on
+    /// connection failures and with an updated version on version conflicts
+    fn put_chunk_with_retry(
+        session: &mut StackerDBSession,
+        slot_versions: &mut HashMap<SignerSlotID, u32>,
+        slot_id: SignerSlotID,
+        stacks_private_key: &StacksPrivateKey,
+        msg_id: &M,
+        message_bytes: &[u8],
+    ) -> Result<StackerDBChunkAckData, ClientError> {
+        loop {
+            let mut slot_version = if let Some(version) = slot_versions.get(&slot_id) {
+                *version
             } else {
-                let mut versions = HashMap::new();
-                versions.insert(*slot_id, 0);
-                self.slot_versions.insert(*msg_id, versions);
+                slot_versions.insert(slot_id, 0);
                 1
             };
 
-            let mut chunk = StackerDBChunkData::new(slot_id.0, slot_version, message_bytes.clone());
-            chunk.sign(&self.stacks_private_key)?;
-
-            let Some(session) = self.signers_message_stackerdb_sessions.get_mut(msg_id) else {
-                panic!("FATAL: would loop forever trying to send a message with ID {msg_id:?}, for which we don't have a session");
-            };
+            let mut chunk =
+                StackerDBChunkData::new(slot_id.0, slot_version, message_bytes.to_vec());
+            chunk.sign(stacks_private_key)?;
 
             debug!(
-                "Sending a chunk to stackerdb slot ID {slot_id} with version {slot_version} and message ID {msg_id:?} to contract {:?}!\n{chunk:?}",
-                &session.stackerdb_contract_id
+                "Sending a chunk to stackerdb slot ID {slot_id} with version {slot_version} and message ID {msg_id:?} to contract {:?} on {}!\n{chunk:?}",
+                &session.stackerdb_contract_id, &session.host
             );
 
             let send_request = || session.put_chunk(&chunk).map_err(backoff::Error::transient);
             let chunk_ack: StackerDBChunkAckData = retry_with_exponential_backoff(send_request)?;
 
-            if let Some(versions) = self.slot_versions.get_mut(msg_id) {
-                // NOTE: per the above, this is always executed
-                versions.insert(*slot_id, slot_version.saturating_add(1));
-            } else {
-                return Err(ClientError::NotConnected);
-            }
+            slot_versions.insert(slot_id, slot_version.saturating_add(1));
 
             if chunk_ack.accepted {
                 debug!("Chunk accepted by stackerdb: {chunk_ack:?}");
@@ -213,12 +390,7 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
                         } else {
                             warn!("Failed to send message to stackerdb due to wrong version number. Attempted {}. Expected unknown version number. Incrementing and retrying...", slot_version);
                         }
-                        if let Some(versions) = self.slot_versions.get_mut(msg_id) {
-                            // NOTE: per the above, this is always executed
-                            versions.insert(*slot_id, slot_version.saturating_add(1));
-                        } else {
-                            return Err(ClientError::NotConnected);
-                        }
+                        slot_versions.insert(slot_id, slot_version.saturating_add(1));
                     }
                     _ => {
                         warn!("Failed to send message to stackerdb: {:?}", chunk_ack);
@@ -233,6 +405,63 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
         }
     }
 
+    /// Sign a chunk and write it to the file relay's outbound directory
+    fn relay_chunk(
+        file_relay: &FileRelay,
+        contract_id: &QualifiedContractIdentifier,
+        slot_id: SignerSlotID,
+        slot_version: u32,
+        stacks_private_key: &StacksPrivateKey,
+        message_bytes: &[u8],
+    ) -> Result<(), ClientError> {
+        let mut chunk = StackerDBChunkData::new(slot_id.0, slot_version, message_bytes.to_vec());
+        chunk.sign(stacks_private_key)?;
+        let path = file_relay.write_outbound(&RelayedChunk {
+            contract_id: contract_id.clone(),
+            chunk,
+        })?;
+        debug!(
+            "Relayed a chunk for stackerdb slot ID {slot_id} with version {slot_version} to {}",
+            path.display()
+        );
+        Ok(())
+    }
+
+    /// Take the signer messages that were relayed to the file relay's inbound directory for
+    /// this client's StackerDBs.  The chunk signatures are not checked here, so the messages
+    /// must be authenticated by their own signatures.
+    pub fn take_relayed_messages<T: SignerMessage<M>>(&self) -> Vec<T> {
+        let Some(file_relay) = self.file_relay.as_ref() else {
+            return vec![];
+        };
+        let contract_ids: Vec<_> = self
+            .signers_message_stackerdb_sessions
+            .values()
+            .map(|session| session.stackerdb_contract_id.clone())
+            .collect();
+        let relayed_chunks = match file_relay.take_inbound(&contract_ids) {
+            Ok(relayed_chunks) => relayed_chunks,
+            Err(e) => {
+                warn!("Failed to read relayed messages"; "error" => ?e);
+                return vec![];
+            }
+        };
+        relayed_chunks
+            .into_iter()
+            .filter_map(|relayed| {
+                let message = read_next::<T, _>(&mut &relayed.chunk.data[..]);
+                if message.is_err() {
+                    warn!(
+                        "Failed to deserialize relayed chunk data into a SignerMessage";
+                        "contract_id" => %relayed.contract_id,
+                        "slot_id" => relayed.chunk.slot_id
+                    );
+                }
+                message.ok()
+            })
+            .collect()
+    }
+
     /// Get all signer messages from stackerdb for the given slot IDs
     pub fn get_messages<T: SignerMessage<M>>(
         session: &mut StackerDBSession,
@@ -272,22 +501,72 @@ impl<M: MessageSlotID + 'static> StackerDB<M> {
     }
 }
 
+impl StackerDB<MessageSlotIDV0> {
+    /// Get the latest block response in each signer slot of the given reward cycle, tagged with
+    /// the slot it was read from. Slots without a decodable block response are skipped.
+    pub fn get_all_block_responses(
+        &mut self,
+        reward_cycle: u64,
+    ) -> Result<Vec<(SignerSlotID, SignerMessageV0)>, ClientError> {
+        let contract_id =
+            MessageSlotIDV0::BlockResponse.stacker_db_contract(self.is_mainnet, reward_cycle);
+        let session = self
+            .signers_message_stackerdb_sessions
+            .get_mut(&MessageSlotIDV0::BlockResponse)
+            .ok_or(ClientError::NotConnected)?;
+        // The other signer set's responses live in a different contract on the same node
+        let mut other_set_session;
+        let session = if session.stackerdb_contract_id == contract_id {
+            session
+        } else {
+            other_set_session = StackerDBSession::new(&session.host, contract_id);
+            &mut other_set_session
+        };
+        let slot_ids: Vec<_> = retry_with_exponential_backoff(|| {
+            session.list_chunks().map_err(backoff::Error::transient)
+        })?
+        .into_iter()
+        .map(|metadata| metadata.slot_id)
+        .collect();
+        let chunks = retry_with_exponential_backoff(|| {
+            session
+                .get_latest_chunks(&slot_ids)
+                .map_err(backoff::Error::transient)
+        })?;
+        Ok(slot_ids
+            .into_iter()
+            .zip(chunks)
+            .filter_map(|(slot_id, chunk)| {
+                let message = read_next::<SignerMessageV0, _>(&mut &chunk?[..]).ok()?;
+                matches!(message, SignerMessageV0::BlockResponse(_))
+                    .then_some((SignerSlotID(slot_id), message))
+            })
+            .collect())
+    }
+}
+
 #[cfg(test)]
 mod tests {
+    use std::sync::{Arc, Barrier};
     use std::thread::spawn;
     use std::time::Duration;
 
     use blockstack_lib::chainstate::nakamoto::{NakamotoBlock, NakamotoBlockHeader};
+    use clarity::codec::StacksMessageCodec;
     use clarity::util::hash::{MerkleTree, Sha512Trunc256Sum};
     use clarity::util::secp256k1::MessageSignature;
     use libsigner::v0::messages::{
-        BlockRejection, BlockResponse, BlockResponseData, RejectCode, SignerMessage,
+        BlockRejection, BlockResponse, BlockResponseData, MessageSlotID, RejectCode, SignerMessage,
         SignerMessageMetadata,
     };
+    use libsigner::RPCError;
     use rand::{thread_rng, RngCore};
 
     use super::*;
-    use crate::client::tests::{generate_signer_config, mock_server_from_config, write_response};
+    use crate::client::tests::{
+        generate_signer_config, mock_server_from_config, mock_server_random, write_response,
+        MockStackerDBServer,
+    };
     use crate::config::{build_signer_config_tomls, GlobalConfig, Network};
 
     #[test]
@@ -352,4 +631,425 @@ mod tests {
         write_response(mock_server, response_bytes.as_slice());
         assert_eq!(ack, sender_thread.join().unwrap());
     }
+
+    fn mock_block_rejection(reason_code: RejectCode) -> SignerMessage {
+        let block = NakamotoBlock {
+            header: NakamotoBlockHeader::empty(),
+            txs: vec![],
+        };
+        SignerMessage::BlockResponse(BlockResponse::Rejected(BlockRejection {
+            reason: "Did not like it".into(),
+            reason_code,
+            signer_signature_hash: block.header.signer_signature_hash(),
+            chain_id: thread_rng().next_u32(),
+            signature: MessageSignature::empty(),
+            metadata: SignerMessageMetadata::empty(),
+            response_data: BlockResponseData::new(thread_rng().next_u64()),
+        }))
+    }
+
+    #[test]
+    fn put_chunk_is_acked_by_mock_stackerdb() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let mut stackerdb = StackerDB::<MessageSlotID>::new_normal(
+            &server.addr.to_string(),
+            StacksPrivateKey::random(),
+            false,
+            reward_cycle,
+            SignerSlotID(3),
+        );
+        let message = mock_block_rejection(RejectCode::RejectedInPriorRound);
+        let ack = stackerdb
+            .send_message_with_retry(message.clone())
+            .expect("Failed to send message");
+        assert!(ack.accepted);
+        assert_eq!(ack.code, None);
+        let metadata = ack.metadata.expect("Accepted chunk should have metadata");
+        assert_eq!(metadata.slot_id, 3);
+        assert_eq!(metadata.slot_version, 1);
+
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        let stored = server
+            .get_chunk(&contract_id, 3)
+            .expect("Chunk was not stored");
+        assert_eq!(stored.data, message.serialize_to_vec());
+
+        // A second write bumps the slot version
+        let ack = stackerdb
+            .send_message_with_retry(message)
+            .expect("Failed to send message");
+        assert!(ack.accepted);
+        assert_eq!(ack.metadata.unwrap().slot_version, 2);
+    }
+
+    /// Read the chunks in the file relay's outbound directory, in file name order
+    fn read_outbound(file_relay: &FileRelay) -> Vec<(std::path::PathBuf, RelayedChunk)> {
+        let mut paths: Vec<_> = std::fs::read_dir(file_relay.outbound_dir())
+            .expect("Failed to read outbound directory")
+            .map(|entry| entry.unwrap().path())
+            .collect();
+        paths.sort();
+        paths
+            .into_iter()
+            .map(|path| {
+                let relayed = serde_json::from_slice(&std::fs::read(&path).unwrap()).unwrap();
+                (path, relayed)
+            })
+            .collect()
+    }
+
+    #[test]
+    fn file_relayed_message_matches_posted_message() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let relay_dir = std::env::temp_dir().join(format!(
+            "stacks-signer-file-relay-test-{}",
+            thread_rng().next_u64()
+        ));
+        let mut stackerdb = StackerDB::<MessageSlotID>::new(
+            &server.addr.to_string(),
+            &[],
+            Some(FileRelay::new(&relay_dir, false)),
+            StacksPrivateKey::random(),
+            false,
+            reward_cycle,
+            StackerDBMode::Normal {
+                signer_slot_id: SignerSlotID(3),
+            },
+        );
+        // The slot already holds a chunk from before a restart, so the first post is retried
+        // at a higher version
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        server.insert_chunk(
+            &contract_id,
+            StackerDBChunkData::new(3, 5, vec![0xff, 0xff, 0xff]),
+        );
+        let message = mock_block_rejection(RejectCode::RejectedInPriorRound);
+        let ack = stackerdb
+            .send_and_relay_message_with_retry(message.clone())
+            .expect("Failed to send message");
+        assert!(ack.accepted);
+
+        let posted = server
+            .get_chunk(&contract_id, 3)
+            .expect("Chunk was not stored");
+        assert_eq!(posted.slot_version, 6);
+
+        // The outbound file holds exactly the chunk that was posted to StackerDB
+        let file_relay = FileRelay::new(&relay_dir, false);
+        let outbound = read_outbound(&file_relay);
+        assert_eq!(outbound.len(), 1);
+        let (path, relayed) = &outbound[0];
+        assert_eq!(relayed.contract_id, contract_id);
+        assert_eq!(relayed.chunk, posted);
+
+        // Carry the file over to the inbound directory, where it is read back as the same message
+        std::fs::create_dir_all(file_relay.inbound_dir()).unwrap();
+        std::fs::rename(
+            path,
+            file_relay.inbound_dir().join(path.file_name().unwrap()),
+        )
+        .unwrap();
+        let received: Vec<SignerMessage> = stackerdb.take_relayed_messages();
+        assert_eq!(received, vec![message]);
+
+        // The inbound file is consumed
+        assert!(stackerdb
+            .take_relayed_messages::<SignerMessage>()
+            .is_empty());
+
+        std::fs::remove_dir_all(&relay_dir).unwrap();
+    }
+
+    #[test]
+    fn file_relay_only_advances_slot_version_without_posting() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let relay_dir = std::env::temp_dir().join(format!(
+            "stacks-signer-file-relay-only-test-{}",
+            thread_rng().next_u64()
+        ));
+        let mut stackerdb = StackerDB::<MessageSlotID>::new(
+            &server.addr.to_string(),
+            &[],
+            Some(FileRelay::new(&relay_dir, true)),
+            StacksPrivateKey::random(),
+            false,
+            reward_cycle,
+            StackerDBMode::Normal {
+                signer_slot_id: SignerSlotID(3),
+            },
+        );
+        let messages = [
+            mock_block_rejection(RejectCode::RejectedInPriorRound),
+            mock_block_rejection(RejectCode::NoSortitionView),
+        ];
+        for message in messages.iter() {
+            let ack = stackerdb
+                .send_and_relay_message_with_retry(message.clone())
+                .expect("Failed to relay message");
+            assert!(ack.accepted);
+        }
+
+        // Nothing is posted to the node
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        assert!(server.get_chunk(&contract_id, 3).is_none());
+
+        // Each message is relayed at its own version
+        let mut relayed: Vec<_> = read_outbound(&FileRelay::new(&relay_dir, true))
+            .into_iter()
+            .map(|(_, relayed)| relayed.chunk)
+            .collect();
+        relayed.sort_by_key(|chunk| chunk.slot_version);
+        let versions: Vec<_> = relayed.iter().map(|chunk| chunk.slot_version).collect();
+        assert_eq!(versions, vec![1, 2]);
+        for (chunk, message) in relayed.iter().zip(messages.iter()) {
+            assert_eq!(chunk.data, message.serialize_to_vec());
+        }
+
+        std::fs::remove_dir_all(&relay_dir).unwrap();
+    }
+
+    #[test]
+    fn get_messages_decodes_mock_stackerdb_chunks() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        let messages = [
+            mock_block_rejection(RejectCode::RejectedInPriorRound),
+            mock_block_rejection(RejectCode::NoSortitionView),
+        ];
+        server.insert_chunk(
+            &contract_id,
+            StackerDBChunkData::new(0, 1, messages[0].serialize_to_vec()),
+        );
+        // An undecodable chunk is skipped
+        server.insert_chunk(
+            &contract_id,
+            StackerDBChunkData::new(1, 1, vec![0xff, 0xff, 0xff]),
+        );
+        server.insert_chunk(
+            &contract_id,
+            StackerDBChunkData::new(2, 4, messages[1].serialize_to_vec()),
+        );
+
+        let mut stackerdb = StackerDB::<MessageSlotID>::new_normal(
+            &server.addr.to_string(),
+            StacksPrivateKey::random(),
+            false,
+            reward_cycle,
+            SignerSlotID(0),
+        );
+        let session = stackerdb
+            .get_session_mut(&MessageSlotID::BlockResponse)
+            .expect("Failed to get BlockResponse session");
+        // Slot 3 is empty and so is also skipped
+        let received: Vec<SignerMessage> =
+            StackerDB::get_messages(session, &[0, 1, 2, 3]).expect("Failed to get messages");
+        assert_eq!(received, messages);
+    }
+
+    #[test]
+    fn get_all_block_responses_returns_one_per_signer() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64() / 2;
+        let num_signers = 5;
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        let rejections: Vec<_> = (0..num_signers)
+            .map(|_| mock_block_rejection(RejectCode::SortitionViewMismatch))
+            .collect();
+        for (slot_id, rejection) in rejections.iter().enumerate() {
+            server.insert_chunk(
+                &contract_id,
+                StackerDBChunkData::new(slot_id as u32, 1, rejection.serialize_to_vec()),
+            );
+        }
+        // An undecodable chunk is skipped
+        server.insert_chunk(
+            &contract_id,
+            StackerDBChunkData::new(num_signers as u32, 1, vec![0xff, 0xff, 0xff]),
+        );
+        // The other signer set's responses are kept apart
+        let next_contract_id =
+            MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle + 1);
+        let next_rejection = mock_block_rejection(RejectCode::NoSortitionView);
+        server.insert_chunk(
+            &next_contract_id,
+            StackerDBChunkData::new(2, 1, next_rejection.serialize_to_vec()),
+        );
+
+        let mut stackerdb = StackerDB::<MessageSlotID>::new_normal(
+            &server.addr.to_string(),
+            StacksPrivateKey::random(),
+            false,
+            reward_cycle,
+            SignerSlotID(0),
+        );
+        let responses = stackerdb
+            .get_all_block_responses(reward_cycle)
+            .expect("Failed to get block responses");
+        let expected: Vec<_> = rejections
+            .into_iter()
+            .enumerate()
+            .map(|(slot_id, rejection)| (SignerSlotID(slot_id as u32), rejection))
+            .collect();
+        assert_eq!(responses, expected);
+
+        let responses = stackerdb
+            .get_all_block_responses(reward_cycle + 1)
+            .expect("Failed to get block responses");
+        assert_eq!(responses, vec![(SignerSlotID(2), next_rejection)]);
+    }
+
+    #[test]
+    fn mock_stackerdb_rejects_stale_versions() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        let private_key = StacksPrivateKey::random();
+
+        let mut session = StackerDBSession::new(&server.addr.to_string(), contract_id.clone());
+        let mut chunk = StackerDBChunkData::new(0, 1, vec![1, 2, 3]);
+        chunk.sign(&private_key).unwrap();
+        assert!(session.put_chunk(&chunk).unwrap().accepted);
+
+        let mut conflicting = StackerDBChunkData::new(0, 1, vec![4, 5, 6]);
+        conflicting.sign(&private_key).unwrap();
+        let ack = session.put_chunk(&conflicting).unwrap();
+        assert!(!ack.accepted);
+        assert_eq!(
+            ack.code.and_then(StackerDBErrorCodes::from_code),
+            Some(StackerDBErrorCodes::DataAlreadyExists)
+        );
+        assert_eq!(ack.metadata.unwrap().slot_version, 1);
+        assert_eq!(
+            session.get_latest_chunk(0).unwrap(),
+            Some(vec![1, 2, 3]),
+            "Rejected chunk should not overwrite the slot"
+        );
+
+        // The StackerDB client recovers from a version conflict by adopting the replica's version
+        server.insert_chunk(&contract_id, StackerDBChunkData::new(0, 5, vec![7, 8, 9]));
+        let mut stackerdb = StackerDB::<MessageSlotID>::new_normal(
+            &server.addr.to_string(),
+            private_key,
+            false,
+            reward_cycle,
+            SignerSlotID(0),
+        );
+        let message = mock_block_rejection(RejectCode::ConnectivityIssues);
+        let ack = stackerdb
+            .send_message_with_retry(message.clone())
+            .expect("Failed to send message");
+        assert!(ack.accepted);
+        assert_eq!(ack.metadata.unwrap().slot_version, 6);
+        assert_eq!(
+            server.get_chunk(&contract_id, 0).unwrap().data,
+            message.serialize_to_vec()
+        );
+    }
+
+    #[test]
+    fn racing_conditional_puts_see_one_conflict() {
+        let server = MockStackerDBServer::new();
+        let reward_cycle = thread_rng().next_u64();
+        let contract_id = MessageSlotID::BlockResponse.stacker_db_contract(false, reward_cycle);
+        let private_key = StacksPrivateKey::random();
+        let barrier = Arc::new(Barrier::new(2));
+
+        // Both writers saw the slot empty, and race to write its first version
+        let writers: Vec<_> = [vec![1, 2, 3], vec![4, 5, 6]]
+            .into_iter()
+            .map(|data| {
+                let host = server.addr.to_string();
+                let contract_id = contract_id.clone();
+                let private_key = private_key.clone();
+                let barrier = barrier.clone();
+                spawn(move || {
+                    let mut session = StackerDBSession::new(&host, contract_id);
+                    let mut chunk = StackerDBChunkData::new(0, 0, data.clone());
+                    barrier.wait();
+                    (
+                        data,
+                        session.put_chunk_if_version(&mut chunk, 0, &private_key),
+                    )
+                })
+            })
+            .collect();
+        let results: Vec<_> = writers.into_iter().map(|w| w.join().unwrap()).collect();
+
+        let winners: Vec<_> = results
+            .iter()
+            .filter_map(|(data, result)| result.as_ref().ok().map(|ack| (data, ack)))
+            .collect();
+        assert_eq!(winners.len(), 1, "Expected exactly one write to succeed");
+        let (winning_data, ack) = winners[0];
+        assert!(ack.accepted);
+        assert_eq!(ack.metadata.as_ref().unwrap().slot_version, 1);
+        assert_eq!(
+            &server.get_chunk(&contract_id, 0).unwrap().data,
+            winning_data
+        );
+
+        let conflicts: Vec<_> = results
+            .iter()
+            .filter_map(|(_, result)| result.as_ref().err())
+            .collect();
+        assert_eq!(conflicts.len(), 1, "Expected exactly one write to conflict");
+        assert!(
+            matches!(conflicts[0], RPCError::Conflict { current_version: 1 }),
+            "Unexpected error: {:?}",
+            conflicts[0]
+        );
+
+        // The losing writer can retry from the replica's current version
+        let mut session = StackerDBSession::new(&server.addr.to_string(), contract_id.clone());
+        let mut chunk = StackerDBChunkData::new(0, 0, vec![7, 8, 9]);
+        let ack = session
+            .put_chunk_if_version(&mut chunk, 1, &private_key)
+            .expect("Retry from the current version should not conflict");
+        assert!(ack.accepted);
+        assert_eq!(chunk.slot_version, 2);
+        assert_eq!(
+            server.get_chunk(&contract_id, 0).unwrap().data,
+            vec![7, 8, 9]
+        );
+    }
+
+    #[test]
+    fn send_message_lands_on_healthy_replica() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        let mut signer_config = generate_signer_config(&config, 5);
+        let SignerConfigMode::Normal { signer_slot_id, .. } = signer_config.signer_mode else {
+            panic!("Expected a normal signer config");
+        };
+        // Nothing is listening on the node host, so writes to it fail
+        let (down_server, down_addr) = mock_server_random();
+        drop(down_server);
+        let healthy = MockStackerDBServer::new();
+        signer_config.node_host = down_addr.to_string();
+        signer_config.stackerdb_replica_hosts = vec![healthy.addr.to_string()];
+
+        let mut stackerdb = StackerDB::<MessageSlotID>::from(&signer_config);
+        let message = mock_block_rejection(RejectCode::RejectedInPriorRound);
+        let ack = stackerdb
+            .send_message_with_retry(message.clone())
+            .expect("Write should succeed if at least one replica acks");
+        assert!(ack.accepted);
+
+        // A miner reading from the healthy replica sees the response
+        let contract_id =
+            MessageSlotID::BlockResponse.stacker_db_contract(false, signer_config.reward_cycle);
+        let mut miner_session = StackerDBSession::new(&healthy.addr.to_string(), contract_id);
+        let received: Vec<SignerMessage> =
+            StackerDB::get_messages(&mut miner_session, &[signer_slot_id.0])
+                .expect("Failed to get messages");
+        assert_eq!(received, vec![message]);
+
+        // If every replica is down, the write fails
+        drop(healthy);
+        let message = mock_block_rejection(RejectCode::ConnectivityIssues);
+        assert!(stackerdb.send_message_with_retry(message).is_err());
+    }
 }
@@ -30,6 +30,7 @@ use blockstack_lib::net::api::get_tenures_fork_info::{
     TenureForkingInfo, RPC_TENURE_FORKING_INFO_PATH,
 };
 use blockstack_lib::net::api::getaccount::AccountEntryResponse;
+use blockstack_lib::net::api::getinfo::RPCPeerInfoData;
 use blockstack_lib::net::api::getpoxinfo::RPCPoxInfoData;
 use blockstack_lib::net::api::getsortition::{SortitionInfo, RPC_SORTITION_INFO_PATH};
 use blockstack_lib::net::api::getstackers::GetStackersResponse;
@@ -549,6 +550,32 @@ impl StacksClient {
         self.get_peer_info().map(|info| info.burn_block_height)
     }
 
+    /// Get how many burn blocks the stacks node's burnchain view lags the bitcoin tip, as far as
+    /// the node has downloaded block headers.  Nodes that do not report their headers height are
+    /// taken not to lag.
+    pub fn get_node_burn_lag(&self) -> Result<u64, ClientError> {
+        debug!("StacksClient: Getting node burn lag");
+        let timer = crate::monitoring::actions::new_rpc_call_timer(
+            &self.core_info_path(),
+            &self.http_origin,
+        );
+        let send_request = || {
+            self.stacks_node_client
+                .get(self.core_info_path())
+                .send()
+                .map_err(backoff::Error::transient)
+        };
+        let response = retry_with_exponential_backoff(send_request)?;
+        timer.stop_and_record();
+        if !response.status().is_success() {
+            return Err(ClientError::RequestFailure(response.status()));
+        }
+        let peer_info = response.json::<RPCPeerInfoData>()?;
+        Ok(peer_info.burn_headers_height.map_or(0, |headers_height| {
+            headers_height.saturating_sub(peer_info.burn_block_height)
+        }))
+    }
+
     /// Get the current reward cycle info from the stacks node
     pub fn get_current_reward_cycle_info(&self) -> Result<RewardCycleInfo, ClientError> {
         debug!("StacksClient: Getting current reward cycle info");
@@ -1194,6 +1221,28 @@ mod tests {
         assert_eq!(reduced_peer_info.server_version, peer_info.server_version);
     }
 
+    #[test]
+    fn get_node_burn_lag_should_succeed() {
+        // a node that does not report its headers height does not lag
+        let mock = MockServerClient::new();
+        let (response, _) = build_get_peer_info_response(Some(100), None);
+        let h = spawn(move || mock.client.get_node_burn_lag());
+        write_response(mock.server, response.as_bytes());
+        assert_eq!(h.join().unwrap().unwrap(), 0);
+
+        // a node that has downloaded headers past its burn view lags by the difference
+        let mock = MockServerClient::new();
+        let (_, mut peer_info) = build_get_peer_info_response(Some(100), None);
+        peer_info.burn_headers_height = Some(107);
+        let response = format!(
+            "HTTP/1.1 200 OK\n\n{}",
+            serde_json::to_string(&peer_info).unwrap()
+        );
+        let h = spawn(move || mock.client.get_node_burn_lag());
+        write_response(mock.server, response.as_bytes());
+        assert_eq!(h.join().unwrap().unwrap(), 7);
+    }
+
     #[test]
     fn get_reward_set_should_succeed() {
         let mock = MockServerClient::new();
@@ -23,6 +23,7 @@ use std::time::Duration;
 use blockstack_lib::chainstate::stacks::TransactionVersion;
 use blockstack_lib::net::connection::DEFAULT_BLOCK_PROPOSAL_MAX_AGE_SECS;
 use clarity::util::hash::to_hex;
+use clarity::vm::costs::ExecutionCost;
 use libsigner::SignerEntries;
 use serde::Deserialize;
 use stacks_common::address::{
@@ -32,6 +33,7 @@ use stacks_common::consts::{CHAIN_ID_MAINNET, CHAIN_ID_TESTNET};
 use stacks_common::types::chainstate::{StacksAddress, StacksPrivateKey, StacksPublicKey};
 use stacks_common::util::hash::Hash160;
 
+use crate::chainstate::{BlockKind, BlockKindMask};
 use crate::client::SignerSlotID;
 
 const EVENT_TIMEOUT_MS: u64 = 5000;
@@ -40,11 +42,18 @@ const BLOCK_PROPOSAL_VALIDATION_TIMEOUT_MS: u64 = 120_000;
 const DEFAULT_FIRST_PROPOSAL_BURN_BLOCK_TIMING_SECS: u64 = 60;
 const DEFAULT_TENURE_LAST_BLOCK_PROPOSAL_TIMEOUT_SECS: u64 = 30;
 const DEFAULT_DRY_RUN: bool = false;
+const DEFAULT_EXIT_ON_UNREGISTERED_KEY: bool = false;
 const TENURE_IDLE_TIMEOUT_SECS: u64 = 120;
 const DEFAULT_REORG_ATTEMPTS_ACTIVITY_TIMEOUT_MS: u64 = 200_000;
 /// Default number of seconds to add to the tenure extend time, after computing the idle timeout,
 /// to allow for clock skew between the signer and the miner
 const DEFAULT_TENURE_IDLE_TIMEOUT_BUFFER_SECS: u64 = 2;
+/// Default number of burn blocks a block proposal may be ahead of the signer's burn view before
+/// the signer defers evaluating it (no limit)
+const DEFAULT_MAX_BURN_HEIGHT_AHEAD: u64 = u64::MAX;
+/// Default number of burn blocks the node's burnchain view may lag the bitcoin tip before the
+/// signer abstains on block proposals (no limit)
+const DEFAULT_MAX_NODE_BURN_LAG: u64 = u64::MAX;
 
 #[derive(thiserror::Error, Debug)]
 /// An error occurred parsing the provided configuration
@@ -149,6 +158,13 @@ pub struct SignerConfig {
     pub stacks_private_key: StacksPrivateKey,
     /// The node host for this signer
     pub node_host: String,
+    /// Additional StackerDB replica hosts that this signer mirrors its messages to
+    pub stackerdb_replica_hosts: Vec<String>,
+    /// The directory through which block responses are relayed as files, if any
+    pub file_relay_dir: Option<PathBuf>,
+    /// Whether block responses are only relayed through `file_relay_dir`, and not posted to
+    /// the node
+    pub file_relay_only: bool,
     /// Whether this signer is running on mainnet or not
     pub mainnet: bool,
     /// The path to the signer's database file
@@ -173,6 +189,28 @@ pub struct SignerConfig {
     /// Time following the last block of the previous tenure's global acceptance that a signer will consider an attempt by
     /// the new miner to reorg it as valid towards miner activity
     pub reorg_attempts_activity_timeout: Duration,
+    /// The maximum cumulative execution cost of the blocks in a tenure that the signer will accept
+    pub max_tenure_cost: Option<ExecutionCost>,
+    /// How many burn blocks must confirm a miner's block-commit before the signer will sign the
+    /// first block of its tenure
+    pub require_commit_confirmations: u64,
+    /// The maximum serialized size, in bytes, of a block that the signer will accept
+    pub max_block_bytes: Option<u64>,
+    /// How many burn blocks ahead of the signer's burn view a block proposal may be before the
+    /// signer defers evaluating it
+    pub max_burn_height_ahead: u64,
+    /// The kinds of block the signer signs. It abstains on all others.
+    pub sign_block_kinds: BlockKindMask,
+    /// How long after detecting a burnchain reorg the signer waits before marking a miner as
+    /// misbehaving for not proposing a block in time
+    pub burnchain_reorg_grace_period: Duration,
+    /// How many undecodable messages from the same signer slot the signer tolerates before
+    /// re-reading every signer's latest block response from StackerDB. If not set, undecodable
+    /// messages are skipped.
+    pub undecodable_message_resync_threshold: Option<u32>,
+    /// How many burn blocks the node's burnchain view may lag the bitcoin tip before the signer
+    /// abstains on block proposals
+    pub max_node_burn_lag: u64,
     /// The running mode for the signer (dry-run or normal)
     pub signer_mode: SignerConfigMode,
 }
@@ -182,6 +220,15 @@ pub struct SignerConfig {
 pub struct GlobalConfig {
     /// endpoint to the stacks node
     pub node_host: String,
+    /// Additional StackerDB replica endpoints that signer messages are mirrored to
+    pub stackerdb_replica_hosts: Vec<String>,
+    /// The directory through which block responses are relayed as files, for air-gapped
+    /// setups.  Signed responses are written to its `outbound` subdirectory, and responses from
+    /// other signers are read from its `inbound` subdirectory.
+    pub file_relay_dir: Option<PathBuf>,
+    /// Whether block responses are only relayed through `file_relay_dir`, and not posted to
+    /// the node.  Requires `file_relay_dir`.
+    pub file_relay_only: bool,
     /// endpoint to the event receiver
     pub endpoint: SocketAddr,
     /// The signer's Stacks private key
@@ -223,6 +270,39 @@ pub struct GlobalConfig {
     pub reorg_attempts_activity_timeout: Duration,
     /// Is this signer binary going to be running in dry-run mode?
     pub dry_run: bool,
+    /// Is this signer binary only observing the signer set? An observer-only signer processes
+    /// events and serves its status and metrics, but never posts to StackerDB. It does not need
+    /// a signing key, and uses an ephemeral one to talk to the node.
+    pub observer_only: bool,
+    /// The maximum number of clients the signer streams the block responses it observes to, at
+    /// `GET /block_responses`. If not set, the endpoint is disabled.
+    pub max_block_response_subscribers: Option<usize>,
+    /// Should the signer refuse to run if its key is not in the current or next reward set?
+    pub exit_on_unregistered_key: bool,
+    /// The maximum cumulative execution cost of the blocks in a tenure that the signer will accept
+    pub max_tenure_cost: Option<ExecutionCost>,
+    /// How many burn blocks must confirm a miner's block-commit before the signer will sign the
+    /// first block of its tenure
+    pub require_commit_confirmations: u64,
+    /// The maximum serialized size, in bytes, of a block that the signer will accept
+    pub max_block_bytes: Option<u64>,
+    /// How many burn blocks ahead of the signer's burn view a block proposal may be before the
+    /// signer defers evaluating it
+    pub max_burn_height_ahead: u64,
+    /// The kinds of block the signer signs. It abstains on all others.
+    pub sign_block_kinds: BlockKindMask,
+    /// How long after detecting a burnchain reorg the signer waits before marking a miner as
+    /// misbehaving for not proposing a block in time
+    pub burnchain_reorg_grace_period: Duration,
+    /// How many undecodable messages from the same signer slot the signer tolerates before
+    /// re-reading every signer's latest block response from StackerDB. If not set, undecodable
+    /// messages are skipped.
+    pub undecodable_message_resync_threshold: Option<u32>,
+    /// How many burn blocks the node's burnchain view may lag the bitcoin tip before the signer
+    /// abstains on block proposals
+    pub max_node_burn_lag: u64,
+    /// The file this config was loaded from, if any. Reloadable options are re-read from it.
+    pub config_path: Option<PathBuf>,
 }
 
 /// Internal struct for loading up the config file
@@ -230,11 +310,19 @@ pub struct GlobalConfig {
 struct RawConfigFile {
     /// endpoint to stacks node
     pub node_host: String,
+    /// Additional StackerDB replica endpoints that signer messages are mirrored to
+    pub stackerdb_replica_hosts: Option<Vec<String>>,
+    /// The directory through which block responses are relayed as files, for air-gapped setups
+    pub file_relay_dir: Option<String>,
+    /// Whether block responses are only relayed through `file_relay_dir`, and not posted to
+    /// the node
+    pub file_relay_only: Option<bool>,
     /// endpoint to event receiver
     pub endpoint: String,
     /// The hex representation of the signer's Stacks private key used for communicating
-    /// with the Stacks Node, including writing to the Stacker DB instance.
-    pub stacks_private_key: String,
+    /// with the Stacks Node, including writing to the Stacker DB instance. Not required (and
+    /// ignored) if `observer_only` is set.
+    pub stacks_private_key: Option<String>,
     /// The network to use. One of "mainnet" or "testnet".
     pub network: Network,
     /// The time to wait (in millisecs) for a response from the stacker-db instance
@@ -270,6 +358,47 @@ struct RawConfigFile {
     pub reorg_attempts_activity_timeout_ms: Option<u64>,
     /// Is this signer binary going to be running in dry-run mode?
     pub dry_run: Option<bool>,
+    /// Only observe the signer set: process events and serve status and metrics, but never
+    /// post to StackerDB. No signing key is required. Defaults to false.
+    pub observer_only: Option<bool>,
+    /// Stream the block responses observed on the signers' StackerDBs as Server-Sent Events at
+    /// `GET /block_responses`, to at most this many clients at once. Requests must carry
+    /// `auth_password` in their `Authorization` header. Disabled if not set.
+    pub max_block_response_subscribers: Option<usize>,
+    /// Should the signer refuse to run if its key is not in the current or next reward set?
+    pub exit_on_unregistered_key: Option<bool>,
+    /// The maximum cumulative execution cost of the blocks in a tenure that the signer will accept.
+    /// Blocks that would push a tenure over this budget are rejected.
+    pub max_tenure_cost: Option<ExecutionCost>,
+    /// How many burn blocks must confirm a miner's block-commit before the signer will sign the
+    /// first block of its tenure. The sortition's own burn block counts as one confirmation.
+    /// Defaults to 0 (disabled).
+    pub require_commit_confirmations: Option<u64>,
+    /// The maximum serialized size, in bytes, of a block that the signer will accept.
+    /// Larger block proposals are rejected without being submitted to the node for validation.
+    pub max_block_bytes: Option<u64>,
+    /// How many burn blocks ahead of the signer's burn view a block proposal may be. Proposals
+    /// further ahead are neither accepted nor rejected, since the signer cannot evaluate them
+    /// until it catches up. Defaults to no limit.
+    pub max_burn_height_ahead: Option<u64>,
+    /// The kinds of block the signer signs, from `empty`, `tenure_change`, and
+    /// `user_transactions`. The signer abstains on (neither accepts nor rejects) blocks of any
+    /// other kind. Defaults to all kinds.
+    pub sign_block_kinds: Option<Vec<BlockKind>>,
+    /// How many seconds after detecting a burnchain reorg the signer waits before marking a
+    /// miner as misbehaving for not proposing a block in time. The block proposal timeout is
+    /// also measured from the reorg, since a sortition may only transiently look empty during
+    /// one. Defaults to 0 (no additional grace period).
+    pub burnchain_reorg_grace_period_secs: Option<u64>,
+    /// How many undecodable messages (e.g. corrupt, or from a newer protocol version) the signer
+    /// may receive from the same signer slot before it re-reads every signer's latest block
+    /// response from StackerDB. Defaults to skipping undecodable messages without re-reading.
+    pub undecodable_message_resync_threshold: Option<u32>,
+    /// How many burn blocks the node's burnchain view may lag the bitcoin tip (as far as the node
+    /// has downloaded block headers) before the signer abstains on block proposals, since the
+    /// node's view of the chain is stale. The signer resumes once the node catches up. Defaults
+    /// to no limit.
+    pub max_node_burn_lag: Option<u64>,
 }
 
 impl RawConfigFile {
@@ -306,6 +435,20 @@ impl TryFrom<RawConfigFile> for GlobalConfig {
             ConfigError::BadField("node_host".to_string(), raw_data.node_host.clone())
         })?;
 
+        let stackerdb_replica_hosts = raw_data.stackerdb_replica_hosts.unwrap_or_default();
+        for replica_host in stackerdb_replica_hosts.iter() {
+            url::Url::parse(&format!("http://{replica_host}")).map_err(|_| {
+                ConfigError::BadField("stackerdb_replica_hosts".to_string(), replica_host.clone())
+            })?;
+        }
+
+        let file_relay_only = raw_data.file_relay_only.unwrap_or(false);
+        if file_relay_only && raw_data.file_relay_dir.is_none() {
+            return Err(ConfigError::InvalidConfig(
+                "file_relay_only requires file_relay_dir to be set".to_string(),
+            ));
+        }
+
         let endpoint = raw_data
             .endpoint
             .to_socket_addrs()
@@ -315,8 +458,20 @@ impl TryFrom<RawConfigFile> for GlobalConfig {
                 ConfigError::BadField("endpoint".to_string(), raw_data.endpoint.clone())
             })?;
 
-        let stacks_private_key = StacksPrivateKey::from_hex(&raw_data.stacks_private_key)
-            .map_err(|e| ConfigError::BadField("stacks_private_key".to_string(), e.into()))?;
+        let observer_only = raw_data.observer_only.unwrap_or(false);
+        let stacks_private_key = if observer_only {
+            // An observer never signs anything, so it only needs a key to talk to the node
+            StacksPrivateKey::random()
+        } else {
+            let Some(stacks_private_key) = raw_data.stacks_private_key.as_ref() else {
+                return Err(ConfigError::InvalidConfig(
+                    "Missing `stacks_private_key` (required unless `observer_only` is set)"
+                        .to_string(),
+                ));
+            };
+            StacksPrivateKey::from_hex(stacks_private_key)
+                .map_err(|e| ConfigError::BadField("stacks_private_key".to_string(), e.into()))?
+        };
         let stacks_public_key = StacksPublicKey::from_private(&stacks_private_key);
         let signer_hash = Hash160::from_data(stacks_public_key.to_bytes_compressed().as_slice());
         let stacks_address =
@@ -379,6 +534,10 @@ impl TryFrom<RawConfigFile> for GlobalConfig {
 
         let dry_run = raw_data.dry_run.unwrap_or(DEFAULT_DRY_RUN);
 
+        let exit_on_unregistered_key = raw_data
+            .exit_on_unregistered_key
+            .unwrap_or(DEFAULT_EXIT_ON_UNREGISTERED_KEY);
+
         let tenure_idle_timeout_buffer = Duration::from_secs(
             raw_data
                 .tenure_idle_timeout_buffer_secs
@@ -387,6 +546,9 @@ impl TryFrom<RawConfigFile> for GlobalConfig {
 
         Ok(Self {
             node_host: raw_data.node_host,
+            stackerdb_replica_hosts,
+            file_relay_dir: raw_data.file_relay_dir.map(PathBuf::from),
+            file_relay_only,
             endpoint,
             stacks_private_key,
             stacks_address,
@@ -404,7 +566,28 @@ impl TryFrom<RawConfigFile> for GlobalConfig {
             block_proposal_max_age_secs,
             reorg_attempts_activity_timeout,
             dry_run,
+            observer_only,
+            max_block_response_subscribers: raw_data.max_block_response_subscribers,
+            exit_on_unregistered_key,
             tenure_idle_timeout_buffer,
+            max_tenure_cost: raw_data.max_tenure_cost,
+            require_commit_confirmations: raw_data.require_commit_confirmations.unwrap_or(0),
+            max_block_bytes: raw_data.max_block_bytes,
+            max_burn_height_ahead: raw_data
+                .max_burn_height_ahead
+                .unwrap_or(DEFAULT_MAX_BURN_HEIGHT_AHEAD),
+            sign_block_kinds: raw_data
+                .sign_block_kinds
+                .map(|kinds| kinds.into_iter().collect())
+                .unwrap_or_default(),
+            burnchain_reorg_grace_period: Duration::from_secs(
+                raw_data.burnchain_reorg_grace_period_secs.unwrap_or(0),
+            ),
+            undecodable_message_resync_threshold: raw_data.undecodable_message_resync_threshold,
+            max_node_burn_lag: raw_data
+                .max_node_burn_lag
+                .unwrap_or(DEFAULT_MAX_NODE_BURN_LAG),
+            config_path: None,
         })
     }
 }
@@ -413,7 +596,9 @@ impl TryFrom<&PathBuf> for GlobalConfig {
     type Error = ConfigError;
     fn try_from(path: &PathBuf) -> Result<Self, ConfigError> {
         let config_file = RawConfigFile::try_from(path)?;
-        Self::try_from(config_file)
+        let mut config = Self::try_from(config_file)?;
+        config.config_path = Some(path.clone());
+        Ok(config)
     }
 }
 
@@ -428,6 +613,22 @@ impl GlobalConfig {
         Self::try_from(&PathBuf::from(path))
     }
 
+    /// Re-read the options that can be changed without a restart from the file this config was
+    /// loaded from: `first_proposal_burn_block_timing` and `block_proposal_timeout`. Returns a copy
+    /// of this config with them updated, or an error if there is no config file or it is invalid.
+    pub fn reload_proposal_timeouts(&self) -> Result<Self, ConfigError> {
+        let Some(path) = self.config_path.as_ref() else {
+            return Err(ConfigError::InvalidConfig(
+                "Config was not loaded from a file".to_string(),
+            ));
+        };
+        let reloaded = Self::try_from(path)?;
+        let mut config = self.clone();
+        config.first_proposal_burn_block_timing = reloaded.first_proposal_burn_block_timing;
+        config.block_proposal_timeout = reloaded.block_proposal_timeout;
+        Ok(config)
+    }
+
     /// Return a string with non-sensitive configuration
     /// information for logging purposes
     pub fn config_to_log_string(&self) -> String {
@@ -717,4 +918,168 @@ db_path = ":memory:"
         let global_config = GlobalConfig::try_from(config).unwrap();
         assert_eq!(global_config.to_chain_id(), 0x80000100);
     }
+
+    #[test]
+    fn test_stackerdb_replica_hosts() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert!(config.stackerdb_replica_hosts.is_empty());
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+stackerdb_replica_hosts = ["127.0.0.1:20443", "replica.example.com:20443"]
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert_eq!(
+            config.stackerdb_replica_hosts,
+            vec![
+                "127.0.0.1:20443".to_string(),
+                "replica.example.com:20443".to_string()
+            ]
+        );
+
+        let bad_config_toml = config_toml.replace("replica.example.com:20443", "bad host");
+        assert!(matches!(
+            GlobalConfig::load_from_str(&bad_config_toml),
+            Err(ConfigError::BadField(field, _)) if field == "stackerdb_replica_hosts"
+        ));
+    }
+
+    #[test]
+    fn test_file_relay_dir() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert!(config.file_relay_dir.is_none());
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+file_relay_dir = "/var/lib/stacks-signer/relay"
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert_eq!(
+            config.file_relay_dir,
+            Some(PathBuf::from("/var/lib/stacks-signer/relay"))
+        );
+        assert!(!config.file_relay_only);
+
+        let config =
+            GlobalConfig::load_from_str(&format!("{config_toml}\nfile_relay_only = true")).unwrap();
+        assert!(config.file_relay_only);
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+file_relay_only = true
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+            "#;
+        assert!(matches!(
+            GlobalConfig::load_from_str(config_toml),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_observer_only() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert!(!config.observer_only);
+
+        // No signing key is needed to observe
+        let config_toml = r#"
+node_host = "localhost"
+observer_only = true
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert!(config.observer_only);
+
+        // ...but it is needed to sign
+        let signer_config_toml = config_toml.replace("observer_only = true", "");
+        assert!(matches!(
+            GlobalConfig::load_from_str(&signer_config_toml),
+            Err(ConfigError::InvalidConfig(_))
+        ));
+    }
+
+    #[test]
+    fn test_max_node_burn_lag() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert_eq!(config.max_node_burn_lag, u64::MAX);
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+max_node_burn_lag = 3
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert_eq!(config.max_node_burn_lag, 3);
+    }
+
+    #[test]
+    fn test_max_block_response_subscribers() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert!(config.max_block_response_subscribers.is_none());
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+max_block_response_subscribers = 4
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert_eq!(config.max_block_response_subscribers, Some(4));
+    }
+
+    #[test]
+    fn test_max_tenure_cost() {
+        let config = GlobalConfig::load_from_file("./src/tests/conf/signer-0.toml").unwrap();
+        assert!(config.max_tenure_cost.is_none());
+
+        let config_toml = r#"
+stacks_private_key = "2de4e77aab89c0c2570bb8bb90824f5cf2a5204a975905fee450ff9dad0fcf28"
+node_host = "localhost"
+endpoint = "localhost:30000"
+network = "mainnet"
+auth_password = "abcd"
+db_path = ":memory:"
+
+[max_tenure_cost]
+write_length = 1000
+write_count = 10
+read_length = 2000
+read_count = 20
+runtime = 30000
+            "#;
+        let config = GlobalConfig::load_from_str(config_toml).unwrap();
+        assert_eq!(
+            config.max_tenure_cost,
+            Some(ExecutionCost {
+                write_length: 1000,
+                write_count: 10,
+                read_length: 2000,
+                read_count: 20,
+                runtime: 30000,
+            })
+        );
+    }
 }
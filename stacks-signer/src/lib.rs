@@ -47,6 +47,7 @@ mod tests;
 
 use std::fmt::{Debug, Display};
 use std::sync::mpsc::{channel, Receiver, Sender};
+use std::time::Duration;
 
 use chainstate::SortitionsView;
 use config::GlobalConfig;
@@ -76,6 +77,12 @@ pub trait Signer<T: SignerEventTrait>: Debug + Display {
     );
     /// Check if the signer is in the middle of processing blocks
     fn has_unprocessed_blocks(&self) -> bool;
+    /// Apply reloaded block proposal timeouts to the signer's block proposal evaluation
+    fn update_proposal_timeouts(
+        &mut self,
+        first_proposal_burn_block_timing: Duration,
+        block_proposal_timeout: Duration,
+    );
 }
 
 /// A wrapper around the running signer type for the signer
@@ -124,7 +131,11 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SpawnedSigner
             https://docs.stacks.co/guides-and-tutorials/running-a-signer#preflight-setup"
         );
         let (res_send, res_recv) = channel();
-        let ev = SignerEventReceiver::new(config.network.is_mainnet());
+        let mut ev = SignerEventReceiver::new(config.network.is_mainnet())
+            .with_admin_password(config.auth_password.clone());
+        if let Some(max_subscribers) = config.max_block_response_subscribers {
+            ev = ev.with_block_response_sse(max_subscribers);
+        }
         crate::monitoring::actions::start_serving_monitoring_metrics(config.clone()).ok();
         let runloop = RunLoop::new(config.clone());
         let mut signer: RunLoopSigner<S, T> = libsigner::Signer::new(runloop, ev, res_send);
@@ -23,6 +23,8 @@ mod server;
 /// Actions for updating metrics
 #[cfg(feature = "monitoring_prom")]
 pub mod actions {
+    use std::time::Duration;
+
     use ::prometheus::HistogramTimer;
     use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
     use slog::slog_error;
@@ -60,6 +62,46 @@ pub mod actions {
         BLOCK_PROPOSALS_RECEIVED.inc();
     }
 
+    /// Increment the number of block proposals deferred for being too far ahead of the signer's
+    /// burn view
+    pub fn increment_block_proposals_deferred_ahead() {
+        BLOCK_PROPOSALS_DEFERRED_AHEAD.inc();
+    }
+
+    /// Increment the number of block proposals ignored for being from a future reward cycle
+    pub fn increment_future_cycle_proposals() {
+        FUTURE_CYCLE_PROPOSALS.inc();
+    }
+
+    /// Increment the number of messages from other signers that could not be decoded
+    pub fn increment_undecodable_messages() {
+        UNDECODABLE_MESSAGES.inc();
+    }
+
+    /// Increment the number of times a block that was already signed was not signed again
+    pub fn increment_resign_skipped() {
+        RESIGN_SKIPPED.inc();
+    }
+
+    /// Increment the number of block proposals validated, both in total and for `reward_cycle`.
+    /// The per-cycle count restarts from zero once a proposal from a later reward cycle is
+    /// validated.
+    pub fn increment_proposals_validated(reward_cycle: u64) {
+        PROPOSALS_VALIDATED.inc();
+        let mut counted_cycle = PROPOSALS_VALIDATED_REWARD_CYCLE
+            .lock()
+            .expect("FATAL: proposals validated lock poisoned");
+        match *counted_cycle {
+            Some(cycle) if cycle > reward_cycle => return,
+            Some(cycle) if cycle == reward_cycle => {}
+            _ => {
+                PROPOSALS_VALIDATED_CURRENT_CYCLE.set(0);
+                *counted_cycle = Some(reward_cycle);
+            }
+        }
+        PROPOSALS_VALIDATED_CURRENT_CYCLE.inc();
+    }
+
     /// Update the stx balance of the signer
     pub fn update_signer_stx_balance(balance: i64) {
         SIGNER_STX_BALANCE.set(balance);
@@ -100,6 +142,23 @@ pub mod actions {
             .observe(latency_ms as f64 / 1000.0);
     }
 
+    /// Record the margin by which a block's signing weight exceeded the threshold weight.
+    ///
+    /// Call this once per block, when it is first observed to reach the threshold
+    pub fn record_finalization_margin_weight(reached_weight: u32, threshold_weight: u32) {
+        SIGNER_FINALIZATION_MARGIN_WEIGHT_HISTOGRAM
+            .with_label_values(&[])
+            .observe(reached_weight.saturating_sub(threshold_weight) as f64);
+    }
+
+    /// Record the time taken to process a burn block, from receipt of the burn block event
+    /// until the signer's state has been refreshed for it.
+    pub fn record_burn_block_processing_time(elapsed: Duration) {
+        SIGNER_BURN_BLOCK_PROCESSING_HISTOGRAM
+            .with_label_values(&[])
+            .observe(elapsed.as_secs_f64());
+    }
+
     /// Start serving monitoring metrics.
     /// This will only serve the metrics if the `monitoring_prom` feature is enabled.
     pub fn start_serving_monitoring_metrics(config: GlobalConfig) -> Result<(), String> {
@@ -120,6 +179,8 @@ pub mod actions {
 /// No-op actions for updating metrics
 #[cfg(not(feature = "monitoring_prom"))]
 pub mod actions {
+    use std::time::Duration;
+
     use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
     use slog::slog_info;
     use stacks_common::info;
@@ -141,6 +202,22 @@ pub mod actions {
     /// Increment the number of block proposals received
     pub fn increment_block_proposals_received() {}
 
+    /// Increment the number of block proposals deferred for being too far ahead of the signer's
+    /// burn view
+    pub fn increment_block_proposals_deferred_ahead() {}
+
+    /// Increment the number of block proposals ignored for being from a future reward cycle
+    pub fn increment_future_cycle_proposals() {}
+
+    /// Increment the number of messages from other signers that could not be decoded
+    pub fn increment_undecodable_messages() {}
+
+    /// Increment the number of times a block that was already signed was not signed again
+    pub fn increment_resign_skipped() {}
+
+    /// Increment the number of block proposals validated, both in total and for `reward_cycle`
+    pub fn increment_proposals_validated(_reward_cycle: u64) {}
+
     /// Update the stx balance of the signer
     pub fn update_signer_stx_balance(_balance: i64) {}
 
@@ -168,6 +245,15 @@ pub mod actions {
     /// Record the time taken to validate a block, as reported by the Stacks node.
     pub fn record_block_validation_latency(_latency_ms: u64) {}
 
+    /// Record the margin by which a block's signing weight exceeded the threshold weight.
+    ///
+    /// Call this once per block, when it is first observed to reach the threshold
+    pub fn record_finalization_margin_weight(_reached_weight: u32, _threshold_weight: u32) {}
+
+    /// Record the time taken to process a burn block, from receipt of the burn block event
+    /// until the signer's state has been refreshed for it.
+    pub fn record_burn_block_processing_time(_elapsed: Duration) {}
+
     /// Start serving monitoring metrics.
     /// This will only serve the metrics if the `monitoring_prom` feature is enabled.
     pub fn start_serving_monitoring_metrics(config: GlobalConfig) -> Result<(), String> {
@@ -14,6 +14,8 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use std::sync::Mutex;
+
 use lazy_static::lazy_static;
 use prometheus::{
     gather, histogram_opts, opts, register_histogram_vec, register_int_counter,
@@ -44,6 +46,38 @@ lazy_static! {
         "The number of block proposals received by the signer"
     ))
     .unwrap();
+    pub static ref BLOCK_PROPOSALS_DEFERRED_AHEAD: IntCounter = register_int_counter!(opts!(
+        "stacks_signer_deferred_ahead_total",
+        "The number of block proposals deferred because they were too far ahead of the signer's burn view"
+    ))
+    .unwrap();
+    pub static ref FUTURE_CYCLE_PROPOSALS: IntCounter = register_int_counter!(opts!(
+        "stacks_signer_future_cycle_proposals_total",
+        "The number of block proposals ignored because they were for a reward cycle that has not started yet"
+    ))
+    .unwrap();
+    pub static ref UNDECODABLE_MESSAGES: IntCounter = register_int_counter!(opts!(
+        "stacks_signer_undecodable_messages_total",
+        "The number of messages from other signers that could not be decoded"
+    ))
+    .unwrap();
+    pub static ref RESIGN_SKIPPED: IntCounter = register_int_counter!(opts!(
+        "stacks_signer_resign_skipped_total",
+        "The number of times the signer declined to re-sign a block that it had already signed"
+    ))
+    .unwrap();
+    pub static ref PROPOSALS_VALIDATED: IntCounter = register_int_counter!(opts!(
+        "stacks_signer_proposals_validated_total",
+        "The number of block proposals validated by the Stacks node on behalf of the signer, whatever the outcome"
+    ))
+    .unwrap();
+    pub static ref PROPOSALS_VALIDATED_CURRENT_CYCLE: IntGauge = register_int_gauge!(opts!(
+        "stacks_signer_proposals_validated_current_cycle",
+        "The number of block proposals validated in the latest reward cycle. Resets when the reward cycle rolls over"
+    ))
+    .unwrap();
+    /// The reward cycle counted by `PROPOSALS_VALIDATED_CURRENT_CYCLE`
+    pub static ref PROPOSALS_VALIDATED_REWARD_CYCLE: Mutex<Option<u64>> = Mutex::new(None);
     pub static ref CURRENT_REWARD_CYCLE: IntGauge = register_int_gauge!(opts!(
         "stacks_signer_current_reward_cycle",
         "The current reward cycle"
@@ -74,6 +108,18 @@ lazy_static! {
         "Time (seconds) measuring end-to-end time to respond to a block",
         vec![0.005, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 20.0, 30.0, 60.0, 120.0]
     ), &[]).unwrap();
+
+    pub static ref SIGNER_FINALIZATION_MARGIN_WEIGHT_HISTOGRAM: HistogramVec = register_histogram_vec!(histogram_opts!(
+        "stacks_signer_finalization_margin_weight",
+        "Signing weight by which an observed block exceeded the threshold weight when it reached it",
+        vec![0.0, 1.0, 2.0, 5.0, 10.0, 25.0, 50.0, 100.0, 250.0, 500.0, 1000.0]
+    ), &[]).unwrap();
+
+    pub static ref SIGNER_BURN_BLOCK_PROCESSING_HISTOGRAM: HistogramVec = register_histogram_vec!(histogram_opts!(
+        "stacks_signer_burn_block_processing_seconds",
+        "Time (seconds) from receipt of a burn block event to completion of the resulting signer state update",
+        vec![0.005, 0.01, 0.025, 0.05, 0.1, 0.25, 0.5, 1.0, 2.5, 5.0, 10.0, 30.0]
+    ), &[]).unwrap();
 }
 
 pub fn gather_metrics_string() -> String {
@@ -17,12 +17,13 @@
 use std::net::SocketAddr;
 use std::time::Instant;
 
+use blockstack_lib::net::http::MetricsContentType;
 use clarity::util::hash::to_hex;
 use clarity::util::secp256k1::Secp256k1PublicKey;
 use libsigner::VERSION_STRING;
 use slog::{slog_debug, slog_error, slog_info, slog_warn};
 use stacks_common::{debug, error, info, warn};
-use tiny_http::{Response as HttpResponse, Server as HttpServer};
+use tiny_http::{Header, Response as HttpResponse, Server as HttpServer};
 
 use super::actions::{update_reward_cycle, update_signer_stx_balance};
 use crate::client::{ClientError, StacksClient};
@@ -130,7 +131,18 @@ impl MonitoringServer {
             debug!("{}: received request {}", self, request.url());
 
             if request.url() == "/metrics" {
-                let response = HttpResponse::from_string(gather_metrics_string());
+                let content_type = MetricsContentType::from_accept_header(
+                    request
+                        .headers()
+                        .iter()
+                        .find(|header| header.field.equiv("Accept"))
+                        .map(|header| header.value.as_str()),
+                );
+                let content_type_header = Header::from_bytes("Content-Type", content_type.as_str())
+                    .expect("Failed to build Content-Type header");
+                let response =
+                    HttpResponse::from_string(content_type.format_body(gather_metrics_string()))
+                        .with_header(content_type_header);
                 request.respond(response).expect("Failed to send response");
                 continue;
             }
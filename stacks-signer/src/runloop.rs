@@ -21,6 +21,7 @@ use clarity::codec::StacksMessageCodec;
 use hashbrown::HashMap;
 use libsigner::{SignerEntries, SignerEvent, SignerRunLoop};
 use slog::{slog_debug, slog_error, slog_info, slog_warn};
+use stacks_common::types::chainstate::StacksPublicKey;
 use stacks_common::{debug, error, info, warn};
 
 use crate::chainstate::SortitionsView;
@@ -53,6 +54,67 @@ pub struct StateInfo {
     pub reward_cycle_info: Option<RewardCycleInfo>,
     /// The current running signers reward cycles
     pub running_signers: Vec<u64>,
+    /// The result of the startup check that the signer's key is in the reward set
+    pub key_self_check: KeySelfCheck,
+    /// The public keys of the miners the signer currently considers valid
+    pub valid_miners: Vec<StacksPublicKey>,
+    /// The height of the last burn block the signer processed, if any
+    pub last_processed_burn_block_height: Option<u64>,
+}
+
+/// The result of the signer's startup check that its configured key is in the reward set
+#[derive(PartialEq, Eq, Debug, Clone, Copy)]
+pub enum KeySelfCheck {
+    /// The check has not been run (yet)
+    NotChecked,
+    /// The signer's key is in the reward set of the given reward cycle
+    InRewardSet {
+        /// The reward cycle whose reward set contains the signer's key
+        reward_cycle: u64,
+    },
+    /// The signer's key is not in the reward set of the current or next reward cycle
+    NotInRewardSet,
+    /// There is no reward set to check the signer's key against
+    NoRewardSet,
+}
+
+impl std::fmt::Display for KeySelfCheck {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotChecked => write!(f, "not checked"),
+            Self::InRewardSet { reward_cycle } => {
+                write!(f, "in reward set for reward cycle {reward_cycle}")
+            }
+            Self::NotInRewardSet => write!(f, "not in reward set"),
+            Self::NoRewardSet => write!(f, "no reward set"),
+        }
+    }
+}
+
+impl KeySelfCheck {
+    /// Check whether the given public key is in any of the given (reward cycle, reward set) pairs
+    pub fn check(
+        public_key: &StacksPublicKey,
+        reward_sets: &[(u64, Option<SignerEntries>)],
+    ) -> Self {
+        let mut found_reward_set = false;
+        for (reward_cycle, entries) in reward_sets {
+            let Some(entries) = entries else {
+                continue;
+            };
+            found_reward_set = true;
+            if entries.signer_pk_to_id.contains_key(public_key) {
+                return Self::InRewardSet {
+                    reward_cycle: *reward_cycle,
+                };
+            }
+        }
+        if found_reward_set {
+            Self::NotInRewardSet
+        } else {
+            Self::NoRewardSet
+        }
+    }
 }
 
 /// The signer result that can be sent across threads
@@ -188,6 +250,10 @@ where
     pub current_reward_cycle_info: Option<RewardCycleInfo>,
     /// Cache sortitin data from `stacks-node`
     pub sortition_state: Option<SortitionsView>,
+    /// The result of the startup check that the signer's key is in the reward set
+    pub key_self_check: KeySelfCheck,
+    /// The height of the last burn block event processed by the signers, if any
+    pub last_processed_burn_block_height: Option<u64>,
 }
 
 impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLoop<Signer, T> {
@@ -201,6 +267,8 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
             state: State::Uninitialized,
             current_reward_cycle_info: None,
             sortition_state: None,
+            key_self_check: KeySelfCheck::NotChecked,
+            last_processed_burn_block_height: None,
         }
     }
     /// Get the registered signers for a specific reward cycle
@@ -222,6 +290,37 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
         Ok(Some(entries))
     }
 
+    /// Check that the signer's key is in the reward set of the current or next reward cycle
+    pub fn run_key_self_check(&self, current_reward_cycle: u64) -> KeySelfCheck {
+        let public_key = StacksPublicKey::from_private(&self.config.stacks_private_key);
+        let reward_sets: Vec<_> = [current_reward_cycle, current_reward_cycle.saturating_add(1)]
+            .into_iter()
+            .map(|reward_cycle| {
+                let entries = self
+                    .get_parsed_reward_set(reward_cycle)
+                    .unwrap_or_else(|e| {
+                        warn!("Failed to fetch reward set for key self-check: {e}"; "reward_cycle" => reward_cycle);
+                        None
+                    });
+                (reward_cycle, entries)
+            })
+            .collect();
+        let key_self_check = KeySelfCheck::check(&public_key, &reward_sets);
+        match key_self_check {
+            KeySelfCheck::NotInRewardSet => error!(
+                "Signer key self-check FAILED: the configured signer key is not in the reward set for the current or next reward cycle. Check that `stacks_private_key` is correct.";
+                "public_key" => public_key.to_hex(),
+                "current_reward_cycle" => current_reward_cycle,
+            ),
+            _ => info!(
+                "Signer key self-check: {key_self_check}";
+                "public_key" => public_key.to_hex(),
+                "current_reward_cycle" => current_reward_cycle,
+            ),
+        }
+        key_self_check
+    }
+
     /// Get a signer configuration for a specific reward cycle from the stacks node
     fn get_signer_config(
         &mut self,
@@ -265,7 +364,11 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
         let dry_run = self.config.dry_run;
         let current_addr = self.stacks_client.get_signer_address();
 
-        let signer_config_mode = if !dry_run {
+        let signer_config_mode = if self.config.observer_only {
+            // An observer's key is ephemeral, so it cannot be registered
+            info!("Observer-only signer is observing reward cycle {reward_cycle}.");
+            SignerConfigMode::DryRun
+        } else if !dry_run {
             let Some(signer_slot_id) = signer_slot_ids.get(current_addr) else {
                 warn!(
                     "Signer {current_addr} was not found in stacker db. Must not be registered for this reward cycle {reward_cycle}."
@@ -308,6 +411,9 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
             first_proposal_burn_block_timing: self.config.first_proposal_burn_block_timing,
             stacks_private_key: self.config.stacks_private_key,
             node_host: self.config.node_host.to_string(),
+            stackerdb_replica_hosts: self.config.stackerdb_replica_hosts.clone(),
+            file_relay_dir: self.config.file_relay_dir.clone(),
+            file_relay_only: self.config.file_relay_only,
             mainnet: self.config.network.is_mainnet(),
             db_path: self.config.db_path.clone(),
             block_proposal_timeout: self.config.block_proposal_timeout,
@@ -317,6 +423,14 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
             tenure_idle_timeout_buffer: self.config.tenure_idle_timeout_buffer,
             block_proposal_max_age_secs: self.config.block_proposal_max_age_secs,
             reorg_attempts_activity_timeout: self.config.reorg_attempts_activity_timeout,
+            max_tenure_cost: self.config.max_tenure_cost.clone(),
+            require_commit_confirmations: self.config.require_commit_confirmations,
+            max_block_bytes: self.config.max_block_bytes,
+            max_burn_height_ahead: self.config.max_burn_height_ahead,
+            sign_block_kinds: self.config.sign_block_kinds,
+            burnchain_reorg_grace_period: self.config.burnchain_reorg_grace_period,
+            undecodable_message_resync_threshold: self.config.undecodable_message_resync_threshold,
+            max_node_burn_lag: self.config.max_node_burn_lag,
         }))
     }
 
@@ -363,9 +477,36 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
         } else {
             self.state = State::RegisteredSigners;
         }
+        // A dry-run or observer-only signer is never in the reward set, so there is nothing to
+        // check
+        if !self.config.dry_run && !self.config.observer_only {
+            self.key_self_check = self.run_key_self_check(current_reward_cycle);
+        }
+        self.check_node_burn_lag();
         Ok(())
     }
 
+    /// Warn at boot if the node's burnchain view lags the bitcoin tip by more than
+    /// `max_node_burn_lag` blocks. Signers abstain on block proposals until the node catches up.
+    fn check_node_burn_lag(&self) {
+        if self.config.max_node_burn_lag == u64::MAX {
+            return;
+        }
+        match self.stacks_client.get_node_burn_lag() {
+            Ok(node_burn_lag) if node_burn_lag > self.config.max_node_burn_lag => {
+                warn!(
+                    "The stacks node's burnchain view is behind the bitcoin tip. Abstaining on block proposals until it catches up.";
+                    "node_burn_lag" => node_burn_lag,
+                    "max_node_burn_lag" => self.config.max_node_burn_lag,
+                );
+            }
+            Ok(_) => {}
+            Err(e) => {
+                warn!("Failed to get the node's burnchain sync status: {e:?}");
+            }
+        }
+    }
+
     fn refresh_runloop(&mut self, ev_burn_block_height: u64) -> Result<(), ClientError> {
         let current_burn_block_height = std::cmp::max(
             self.stacks_client.get_peer_info()?.burn_block_height,
@@ -474,6 +615,40 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug> RunLo
             self.stacks_signers.remove(&idx);
         }
     }
+
+    /// Re-read the block proposal timeouts from the config file, and apply them to the running
+    /// signers and the cached sortition view.  Signers configured later pick them up from
+    /// `self.config`.
+    fn reload_proposal_timeouts(&mut self) {
+        let config = match self.config.reload_proposal_timeouts() {
+            Ok(config) => config,
+            Err(e) => {
+                warn!("Failed to reload the signer config: {e}");
+                return;
+            }
+        };
+        let first_proposal_burn_block_timing = config.first_proposal_burn_block_timing;
+        let block_proposal_timeout = config.block_proposal_timeout;
+        info!(
+            "Reloaded block proposal timeouts";
+            "first_proposal_burn_block_timing" => ?first_proposal_burn_block_timing,
+            "block_proposal_timeout" => ?block_proposal_timeout,
+        );
+        self.config = config;
+        if let Some(sortition_state) = self.sortition_state.as_mut() {
+            sortition_state.config.first_proposal_burn_block_timing =
+                first_proposal_burn_block_timing;
+            sortition_state.config.block_proposal_timeout = block_proposal_timeout;
+        }
+        for configured_signer in self.stacks_signers.values_mut() {
+            if let ConfiguredSigner::RegisteredSigner(signer) = configured_signer {
+                signer.update_proposal_timeouts(
+                    first_proposal_burn_block_timing,
+                    block_proposal_timeout,
+                );
+            }
+        }
+    }
 }
 
 impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug>
@@ -507,12 +682,24 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug>
                     .values()
                     .map(|s| s.reward_cycle())
                     .collect(),
+                key_self_check: self.key_self_check,
+                valid_miners: self
+                    .sortition_state
+                    .as_ref()
+                    .map(SortitionsView::valid_miners)
+                    .unwrap_or_default(),
+                last_processed_burn_block_height: self.last_processed_burn_block_height,
             }
             .into()])
             {
                 error!("Failed to send status check result: {e}.");
             }
         }
+        // Config reloads are applied to the signers by the outer signer runloop
+        if let Some(SignerEvent::ReloadConfig) = event {
+            self.reload_proposal_timeouts();
+            return None;
+        }
 
         if self.state == State::Uninitialized {
             if let Err(e) = self.initialize_runloop() {
@@ -522,6 +709,12 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug>
                 }
                 return None;
             }
+            if self.config.exit_on_unregistered_key
+                && self.key_self_check == KeySelfCheck::NotInRewardSet
+            {
+                error!("Signer key is not in the reward set and `exit_on_unregistered_key` is set. Refusing to proceed.");
+                return Some(vec![]);
+            }
         } else if let Some(SignerEvent::NewBurnBlock { burn_height, .. }) = event {
             if let Err(e) = self.refresh_runloop(burn_height) {
                 error!("Failed to refresh signer runloop: {e}.");
@@ -547,6 +740,17 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug>
                 current_reward_cycle,
             );
         }
+        if let Some(SignerEvent::NewBurnBlock {
+            burn_height,
+            received_time,
+            ..
+        }) = event
+        {
+            self.last_processed_burn_block_height = Some(burn_height);
+            if let Ok(elapsed) = received_time.elapsed() {
+                crate::monitoring::actions::record_burn_block_processing_time(elapsed);
+            }
+        }
         if self.state == State::NoRegisteredSigners && event.is_some() {
             let next_reward_cycle = current_reward_cycle.saturating_add(1);
             info!("Signer is not registered for the current reward cycle ({current_reward_cycle}). Reward set is not yet determined or signer is not registered for the upcoming reward cycle ({next_reward_cycle}).");
@@ -557,12 +761,206 @@ impl<Signer: SignerTrait<T>, T: StacksMessageCodec + Clone + Send + Debug>
 
 #[cfg(test)]
 mod tests {
-    use blockstack_lib::chainstate::stacks::boot::NakamotoSignerEntry;
-    use libsigner::SignerEntries;
+    use std::fs;
+    use std::thread::spawn;
+    use std::time::{Duration, SystemTime};
+
+    use blockstack_lib::chainstate::stacks::address::PoxAddress;
+    use blockstack_lib::chainstate::stacks::boot::{
+        NakamotoSignerEntry, PoxStartCycleInfo, RewardSet,
+    };
+    use blockstack_lib::net::api::getstackers::GetStackersResponse;
+    use libsigner::v0::messages::SignerMessage;
+    use libsigner::{SignerEntries, SignerEvent, SignerRunLoop};
     use rand::{thread_rng, Rng, RngCore};
-    use stacks_common::types::chainstate::{StacksPrivateKey, StacksPublicKey};
+    use stacks_common::types::chainstate::{
+        BurnchainHeaderHash, ConsensusHash, StacksPrivateKey, StacksPublicKey,
+    };
+    use stacks_common::util::get_epoch_time_secs;
+    use stacks_common::util::hash::Hash160;
+
+    use super::{KeySelfCheck, RewardCycleInfo, RunLoop};
+    use crate::chainstate::{
+        BlockKindMask, ProposalEvalConfig, SignerClock, SortitionMinerStatus, SortitionState,
+        SortitionsView,
+    };
+    use crate::client::tests::{write_response, MockServerClient};
+    use crate::config::GlobalConfig;
+    use crate::signerdb::SignerDb;
+    use crate::v0::signer::Signer;
+
+    fn signer_entry(public_key: &StacksPublicKey) -> NakamotoSignerEntry {
+        let mut signing_key = [0u8; 33];
+        signing_key.copy_from_slice(&public_key.to_bytes_compressed());
+        NakamotoSignerEntry {
+            signing_key,
+            stacked_amt: 0,
+            weight: 1,
+        }
+    }
+
+    fn build_get_reward_set_response(signers: Vec<NakamotoSignerEntry>) -> String {
+        let stackers_response = GetStackersResponse {
+            stacker_set: RewardSet {
+                rewarded_addresses: vec![PoxAddress::standard_burn_address(false)],
+                start_cycle_state: PoxStartCycleInfo {
+                    missed_reward_slots: vec![],
+                },
+                signers: Some(signers),
+                pox_ustx_threshold: None,
+            },
+        };
+        let stackers_response_json = serde_json::to_string(&stackers_response)
+            .expect("Failed to serialize get stacker response");
+        format!("HTTP/1.1 200 OK\n\n{stackers_response_json}")
+    }
+
+    #[test]
+    fn key_self_check() {
+        let public_key = StacksPublicKey::from_private(&StacksPrivateKey::random());
+        let other_key = StacksPublicKey::from_private(&StacksPrivateKey::random());
+        let with_key = SignerEntries::parse(
+            false,
+            &[signer_entry(&other_key), signer_entry(&public_key)],
+        )
+        .unwrap();
+        let without_key = SignerEntries::parse(false, &[signer_entry(&other_key)]).unwrap();
 
-    use super::RewardCycleInfo;
+        assert_eq!(
+            KeySelfCheck::check(&public_key, &[(1, Some(without_key.clone())), (2, None)]),
+            KeySelfCheck::NotInRewardSet
+        );
+        assert_eq!(
+            KeySelfCheck::check(&public_key, &[(1, Some(without_key)), (2, Some(with_key))]),
+            KeySelfCheck::InRewardSet { reward_cycle: 2 }
+        );
+        assert_eq!(
+            KeySelfCheck::check(&public_key, &[(1, None), (2, None)]),
+            KeySelfCheck::NoRewardSet
+        );
+    }
+
+    #[test]
+    fn key_self_check_reports_key_not_in_reward_set() {
+        let mock = MockServerClient::new();
+        let runloop = RunLoop::<Signer, SignerMessage>::new(mock.config.clone());
+        // Neither the current nor the next reward set contains the signer's key
+        let response = build_get_reward_set_response(vec![signer_entry(
+            &StacksPublicKey::from_private(&StacksPrivateKey::random()),
+        )]);
+
+        let h = spawn(move || runloop.run_key_self_check(10));
+        write_response(mock.server.try_clone().unwrap(), response.as_bytes());
+        write_response(mock.server, response.as_bytes());
+        let key_self_check = h.join().unwrap();
+        assert_eq!(key_self_check, KeySelfCheck::NotInRewardSet);
+        assert_eq!(key_self_check.to_string(), "not in reward set");
+    }
+
+    #[test]
+    fn reload_proposal_timeouts() {
+        let test_dir = "/tmp/stacks-node-tests/signer-units/";
+        fs::create_dir_all(test_dir).unwrap();
+        let config_path = format!("{test_dir}reload_config.{}.toml", get_epoch_time_secs());
+        let base_config = fs::read_to_string("./src/tests/conf/signer-0.toml").unwrap();
+        fs::write(
+            &config_path,
+            format!("{base_config}\nblock_proposal_timeout_ms = 3600000\n"),
+        )
+        .unwrap();
+
+        let config = GlobalConfig::load_from_file(&config_path).unwrap();
+        assert_eq!(config.block_proposal_timeout, Duration::from_secs(3600));
+        let mut runloop = RunLoop::<Signer, SignerMessage>::new(config);
+
+        let signer_db_path = format!("{test_dir}reload_config.{}.sqlite", get_epoch_time_secs());
+        let mut signer_db = SignerDb::new(signer_db_path).unwrap();
+        let block_pk = StacksPublicKey::from_private(&StacksPrivateKey::from_seed(&[0, 1]));
+        let cur_sortition = SortitionState {
+            miner_pkh: Hash160::from_node_public_key(&block_pk),
+            miner_pubkey: None,
+            prior_sortition: ConsensusHash([0; 20]),
+            parent_tenure_id: ConsensusHash([0; 20]),
+            consensus_hash: ConsensusHash([1; 20]),
+            miner_status: SortitionMinerStatus::Valid,
+            burn_header_timestamp: 2,
+            burn_block_hash: BurnchainHeaderHash([1; 32]),
+            burn_block_height: 1,
+        };
+        signer_db
+            .insert_burn_block(&cur_sortition.burn_block_hash, 1, &SystemTime::now())
+            .unwrap();
+        runloop.sortition_state = Some(SortitionsView {
+            cur_sortition,
+            last_sortition: None,
+            config: ProposalEvalConfig {
+                first_proposal_burn_block_timing: runloop.config.first_proposal_burn_block_timing,
+                block_proposal_timeout: runloop.config.block_proposal_timeout,
+                tenure_last_block_proposal_timeout: Duration::from_secs(30),
+                tenure_idle_timeout: Duration::from_secs(300),
+                tenure_idle_timeout_buffer: Duration::from_secs(2),
+                reorg_attempts_activity_timeout: Duration::from_secs(3),
+                clock: SignerClock::default(),
+                max_tenure_cost: None,
+                require_commit_confirmations: 0,
+                max_block_bytes: None,
+                max_burn_height_ahead: u64::MAX,
+                sign_block_kinds: BlockKindMask::ALL,
+                burnchain_reorg_grace_period: Duration::from_secs(0),
+            },
+        });
+
+        let is_timed_out = |runloop: &RunLoop<Signer, SignerMessage>| {
+            let view = runloop.sortition_state.as_ref().unwrap();
+            view.cur_sortition
+                .is_timed_out(
+                    view.config.block_proposal_timeout,
+                    &view.config.clock,
+                    &signer_db,
+                )
+                .unwrap()
+        };
+
+        std::thread::sleep(Duration::from_secs(2));
+        // The miner has plenty of time left under the original timeout
+        assert!(!is_timed_out(&runloop));
+
+        // Shorten the timeout on disk; nothing changes until the signer is told to reload
+        fs::write(
+            &config_path,
+            format!("{base_config}\nblock_proposal_timeout_ms = 1000\n"),
+        )
+        .unwrap();
+        assert!(!is_timed_out(&runloop));
+
+        let (res_send, _res_recv) = std::sync::mpsc::channel();
+        assert!(runloop
+            .run_one_pass(Some(SignerEvent::ReloadConfig), &res_send)
+            .is_none());
+        assert_eq!(
+            runloop.config.block_proposal_timeout,
+            Duration::from_secs(1)
+        );
+        assert_eq!(
+            runloop
+                .sortition_state
+                .as_ref()
+                .unwrap()
+                .config
+                .block_proposal_timeout,
+            Duration::from_secs(1)
+        );
+        // The same miner is now considered timed out
+        assert!(is_timed_out(&runloop));
+
+        // A config file that no longer parses leaves the running timeouts untouched
+        fs::write(&config_path, "not a config").unwrap();
+        runloop.run_one_pass(Some(SignerEvent::ReloadConfig), &res_send);
+        assert_eq!(
+            runloop.config.block_proposal_timeout,
+            Duration::from_secs(1)
+        );
+    }
 
     #[test]
     fn parse_nakamoto_signer_entries_test() {
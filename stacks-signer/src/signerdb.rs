@@ -27,6 +27,7 @@ use blockstack_lib::util_lib::db::{
 #[cfg(any(test, feature = "testing"))]
 use blockstack_lib::util_lib::db::{FromColumn, FromRow};
 use clarity::types::chainstate::{BurnchainHeaderHash, StacksAddress};
+use clarity::vm::costs::ExecutionCost;
 use libsigner::BlockProposal;
 use rusqlite::functions::FunctionFlags;
 use rusqlite::{
@@ -163,6 +164,8 @@ pub struct BlockInfo {
     pub state: BlockState,
     /// Consumed processing time in milliseconds to validate this block
     pub validation_time_ms: Option<u64>,
+    /// The execution cost of this block, as reported by its validation
+    pub validation_cost: Option<ExecutionCost>,
     /// Extra data specific to v0, v1, etc.
     pub ext: ExtraBlockInfo,
 }
@@ -182,6 +185,7 @@ impl From<BlockProposal> for BlockInfo {
             ext: ExtraBlockInfo::default(),
             state: BlockState::Unprocessed,
             validation_time_ms: None,
+            validation_cost: None,
         }
     }
 }
@@ -500,6 +504,13 @@ CREATE TABLE IF NOT EXISTS tenure_activity (
     last_activity_time INTEGER NOT NULL
 ) STRICT;"#;
 
+static CREATE_BURNCHAIN_REORGS_TABLE: &str = r#"
+CREATE TABLE IF NOT EXISTS burnchain_reorgs (
+    burn_block_hash TEXT NOT NULL PRIMARY KEY,
+    burn_block_height INTEGER NOT NULL,
+    detected_time INTEGER NOT NULL
+) STRICT;"#;
+
 static SCHEMA_1: &[&str] = &[
     DROP_SCHEMA_0,
     CREATE_DB_CONFIG,
@@ -564,9 +575,14 @@ static SCHEMA_8: &[&str] = &[
     "INSERT INTO db_config (version) VALUES (8);",
 ];
 
+static SCHEMA_9: &[&str] = &[
+    CREATE_BURNCHAIN_REORGS_TABLE,
+    "INSERT INTO db_config (version) VALUES (9);",
+];
+
 impl SignerDb {
     /// The current schema version used in this build of the signer binary.
-    pub const SCHEMA_VERSION: u32 = 8;
+    pub const SCHEMA_VERSION: u32 = 9;
 
     /// Create a new `SignerState` instance.
     /// This will create a new SQLite database at the given path
@@ -708,6 +724,20 @@ impl SignerDb {
         Ok(())
     }
 
+    /// Migrate from schema 8 to schema 9
+    fn schema_9_migration(tx: &Transaction) -> Result<(), DBError> {
+        if Self::get_schema_version(tx)? >= 9 {
+            // no migration necessary
+            return Ok(());
+        }
+
+        for statement in SCHEMA_9.iter() {
+            tx.execute_batch(statement)?;
+        }
+
+        Ok(())
+    }
+
     /// Register custom scalar functions used by the database
     fn register_scalar_functions(&self) -> Result<(), DBError> {
         // Register helper function for determining if a block is a tenure change transaction
@@ -749,7 +779,8 @@ impl SignerDb {
                 5 => Self::schema_6_migration(&sql_tx)?,
                 6 => Self::schema_7_migration(&sql_tx)?,
                 7 => Self::schema_8_migration(&sql_tx)?,
-                8 => break,
+                8 => Self::schema_9_migration(&sql_tx)?,
+                9 => break,
                 x => return Err(DBError::Other(format!(
                     "Database schema is newer than supported by this binary. Expected version = {}, Database version = {x}",
                     Self::SCHEMA_VERSION,
@@ -842,6 +873,26 @@ impl SignerDb {
         try_deserialize(result)
     }
 
+    /// Return the accepted blocks in a tenure (identified by its consensus hash), ordered by
+    /// height and then by the time they were proposed
+    pub fn get_accepted_blocks_in_tenure(
+        &self,
+        tenure: &ConsensusHash,
+    ) -> Result<Vec<BlockInfo>, DBError> {
+        let query = "SELECT block_info FROM blocks WHERE consensus_hash = ?1 AND state IN (?2, ?3) ORDER BY stacks_height ASC, proposed_time ASC";
+        let args = params![
+            tenure,
+            &BlockState::GloballyAccepted.to_string(),
+            &BlockState::LocallyAccepted.to_string()
+        ];
+        let result: Vec<String> = query_rows(&self.db, query, args)?;
+
+        result
+            .iter()
+            .map(|info| serde_json::from_str(info).map_err(DBError::SerializationError))
+            .collect()
+    }
+
     /// Return the last globally accepted block in a tenure (identified by its consensus hash).
     pub fn get_last_globally_accepted_block(
         &self,
@@ -903,6 +954,74 @@ impl SignerDb {
         Ok(Some(receive_time))
     }
 
+    /// Get the height of the highest burn block received over the event dispatcher by this
+    /// signer, if it has received any
+    pub fn get_last_burn_block_height(&self) -> Result<Option<u64>, DBError> {
+        let query = "SELECT block_height FROM burn_blocks ORDER BY block_height DESC LIMIT 1";
+        let Some(height_i64) = query_row::<i64, _>(&self.db, query, params![])? else {
+            return Ok(None);
+        };
+        let height = u64::try_from(height_i64).map_err(|e| {
+            error!("Failed to parse db block_height as u64: {e}");
+            DBError::Corruption
+        })?;
+        Ok(Some(height))
+    }
+
+    /// Would receiving the given burn block be a burnchain reorg? That is, is it a burn block
+    /// that has not been received before, at or below the height of the highest burn block
+    /// received so far?
+    pub fn is_burnchain_reorg(
+        &self,
+        burn_hash: &BurnchainHeaderHash,
+        burn_height: u64,
+    ) -> Result<bool, DBError> {
+        if self.get_burn_block_receive_time(burn_hash)?.is_some() {
+            return Ok(false);
+        }
+        Ok(self
+            .get_last_burn_block_height()?
+            .is_some_and(|last_height| burn_height <= last_height))
+    }
+
+    /// Record that receiving the given burn block reorged the burnchain
+    pub fn insert_burnchain_reorg(
+        &mut self,
+        burn_hash: &BurnchainHeaderHash,
+        burn_height: u64,
+        detected_time: &SystemTime,
+    ) -> Result<(), DBError> {
+        let detected_ts = detected_time
+            .duration_since(std::time::UNIX_EPOCH)
+            .map_err(|e| DBError::Other(format!("Bad system time: {e}")))?
+            .as_secs();
+        debug!("Inserting burnchain reorg"; "burn_block_height" => burn_height, "burn_hash" => %burn_hash, "detected" => detected_ts);
+        self.db.execute(
+            "INSERT OR REPLACE INTO burnchain_reorgs (burn_block_hash, burn_block_height, detected_time) VALUES (?1, ?2, ?3)",
+            params![
+                burn_hash,
+                u64_to_sql(burn_height)?,
+                u64_to_sql(detected_ts)?,
+            ],
+        )?;
+        Ok(())
+    }
+
+    /// Get the timestamp (epoch seconds) at which this signer most recently detected a burnchain
+    /// reorg, if it has detected any
+    pub fn get_last_burnchain_reorg_time(&self) -> Result<Option<u64>, DBError> {
+        let query =
+            "SELECT detected_time FROM burnchain_reorgs ORDER BY detected_time DESC LIMIT 1";
+        let Some(detected_time_i64) = query_row::<i64, _>(&self.db, query, params![])? else {
+            return Ok(None);
+        };
+        let detected_time = u64::try_from(detected_time_i64).map_err(|e| {
+            error!("Failed to parse db detected_time as u64: {e}");
+            DBError::Corruption
+        })?;
+        Ok(Some(detected_time))
+    }
+
     /// Insert or replace a block into the database.
     /// Preserves the `broadcast` column if replacing an existing block.
     pub fn insert_block(&mut self, block_info: &BlockInfo) -> Result<(), DBError> {
@@ -1436,6 +1555,61 @@ mod tests {
         assert_eq!(stored_time, time_to_epoch);
     }
 
+    #[test]
+    fn insert_burn_block_get_last_height() {
+        let db_path = tmp_db_path();
+        let mut db = SignerDb::new(db_path).expect("Failed to create signer db");
+        assert_eq!(db.get_last_burn_block_height().unwrap(), None);
+
+        let stime = SystemTime::now();
+        db.insert_burn_block(&BurnchainHeaderHash([11; 32]), 11, &stime)
+            .unwrap();
+        db.insert_burn_block(&BurnchainHeaderHash([10; 32]), 10, &stime)
+            .unwrap();
+        assert_eq!(db.get_last_burn_block_height().unwrap(), Some(11));
+    }
+
+    #[test]
+    fn insert_burnchain_reorg_get_time() {
+        let db_path = tmp_db_path();
+        let mut db = SignerDb::new(db_path).expect("Failed to create signer db");
+        let stime = SystemTime::now();
+        db.insert_burn_block(&BurnchainHeaderHash([10; 32]), 10, &stime)
+            .unwrap();
+        db.insert_burn_block(&BurnchainHeaderHash([11; 32]), 11, &stime)
+            .unwrap();
+
+        // Burn blocks that were already received, or that extend the highest one, are not reorgs
+        assert!(!db
+            .is_burnchain_reorg(&BurnchainHeaderHash([11; 32]), 11)
+            .unwrap());
+        assert!(!db
+            .is_burnchain_reorg(&BurnchainHeaderHash([12; 32]), 12)
+            .unwrap());
+        // A new burn block at or below the highest height is
+        assert!(db
+            .is_burnchain_reorg(&BurnchainHeaderHash([13; 32]), 11)
+            .unwrap());
+        assert!(db
+            .is_burnchain_reorg(&BurnchainHeaderHash([13; 32]), 10)
+            .unwrap());
+
+        assert_eq!(db.get_last_burnchain_reorg_time().unwrap(), None);
+        let earlier = stime - Duration::from_secs(10);
+        db.insert_burnchain_reorg(&BurnchainHeaderHash([13; 32]), 11, &stime)
+            .unwrap();
+        db.insert_burnchain_reorg(&BurnchainHeaderHash([14; 32]), 10, &earlier)
+            .unwrap();
+        let time_to_epoch = stime
+            .duration_since(SystemTime::UNIX_EPOCH)
+            .unwrap()
+            .as_secs();
+        assert_eq!(
+            db.get_last_burnchain_reorg_time().unwrap(),
+            Some(time_to_epoch)
+        );
+    }
+
     #[test]
     fn test_write_signer_state() {
         let db_path = tmp_db_path();
@@ -1709,6 +1883,13 @@ mod tests {
         block_info_2.mark_locally_accepted(false).unwrap();
         block_info_3.mark_locally_accepted(false).unwrap();
         block_info_4.mark_globally_accepted().unwrap();
+        block_info_2.validation_cost = Some(ExecutionCost {
+            write_length: 1,
+            write_count: 2,
+            read_length: 3,
+            read_count: 4,
+            runtime: 5,
+        });
 
         db.insert_block(&block_info_1).unwrap();
         db.insert_block(&block_info_2).unwrap();
@@ -1739,11 +1920,20 @@ mod tests {
             .unwrap();
         assert_eq!(block_info, block_info_4);
 
+        assert_eq!(
+            db.get_accepted_blocks_in_tenure(&consensus_hash_1).unwrap(),
+            vec![block_info_1, block_info_2, block_info_3]
+        );
+
         // Verify tenure consensus_hash_3
         assert!(db
             .get_last_accepted_block(&consensus_hash_3)
             .unwrap()
             .is_none());
+        assert!(db
+            .get_accepted_blocks_in_tenure(&consensus_hash_3)
+            .unwrap()
+            .is_empty());
         assert!(db
             .get_last_globally_accepted_block(&consensus_hash_3)
             .unwrap()
@@ -21,32 +21,36 @@ use blockstack_lib::chainstate::nakamoto::{NakamotoBlock, NakamotoBlockHeader};
 use blockstack_lib::chainstate::stacks::db::StacksBlockHeaderTypes;
 use blockstack_lib::chainstate::stacks::{
     CoinbasePayload, SinglesigHashMode, SinglesigSpendingCondition, StacksTransaction,
-    TenureChangeCause, TenureChangePayload, TransactionAnchorMode, TransactionAuth,
-    TransactionPayload, TransactionPostConditionMode, TransactionPublicKeyEncoding,
-    TransactionSpendingCondition, TransactionVersion,
+    TenureChangeCause, TenureChangePayload, TokenTransferMemo, TransactionAnchorMode,
+    TransactionAuth, TransactionPayload, TransactionPostConditionMode,
+    TransactionPublicKeyEncoding, TransactionSpendingCondition, TransactionVersion,
 };
 use blockstack_lib::net::api::get_tenures_fork_info::TenureForkingInfo;
 use blockstack_lib::net::api::getsortition::SortitionInfo;
 use clarity::types::chainstate::{BurnchainHeaderHash, SortitionId};
 use clarity::util::vrf::VRFProof;
+use libsigner::v0::messages::RejectCode;
 use libsigner::{BlockProposal, BlockProposalData};
 use slog::slog_info;
 use stacks_common::bitvec::BitVec;
 use stacks_common::consts::CHAIN_ID_TESTNET;
 use stacks_common::info;
 use stacks_common::types::chainstate::{
-    ConsensusHash, StacksBlockId, StacksPrivateKey, StacksPublicKey, TrieHash,
+    ConsensusHash, StacksAddress, StacksBlockId, StacksPrivateKey, StacksPublicKey, TrieHash,
 };
 use stacks_common::util::get_epoch_time_secs;
 use stacks_common::util::hash::{Hash160, Sha512Trunc256Sum};
 use stacks_common::util::secp256k1::MessageSignature;
 
 use crate::chainstate::{
-    ProposalEvalConfig, SignerChainstateError, SortitionMinerStatus, SortitionState, SortitionsView,
+    BlockKind, BlockKindMask, ProposalEvalConfig, ProposalVerdict, SignerChainstateError,
+    SignerClock, SortitionMinerStatus, SortitionState, SortitionsView,
 };
 use crate::client::tests::MockServerClient;
 use crate::client::StacksClient;
 use crate::signerdb::{BlockInfo, SignerDb};
+use crate::v0::tests::TEST_SIGNER_CLOCK_OFFSET;
+use crate::v0::{evaluate_block_proposal, BlockProposalDecision};
 
 fn setup_test_environment(
     fn_name: &str,
@@ -70,6 +74,7 @@ fn setup_test_environment(
         miner_status: SortitionMinerStatus::Valid,
         burn_header_timestamp: 2,
         burn_block_hash: BurnchainHeaderHash([1; 32]),
+        burn_block_height: 2,
     };
 
     let last_sortition = Some(SortitionState {
@@ -81,6 +86,7 @@ fn setup_test_environment(
         miner_status: SortitionMinerStatus::Valid,
         burn_header_timestamp: 1,
         burn_block_hash: BurnchainHeaderHash([0; 32]),
+        burn_block_height: 1,
     });
 
     let view = SortitionsView {
@@ -93,6 +99,13 @@ fn setup_test_environment(
             tenure_idle_timeout: Duration::from_secs(300),
             tenure_idle_timeout_buffer: Duration::from_secs(2),
             reorg_attempts_activity_timeout: Duration::from_secs(3),
+            clock: SignerClock::default(),
+            max_tenure_cost: None,
+            require_commit_confirmations: 0,
+            max_block_bytes: None,
+            max_burn_height_ahead: u64::MAX,
+            sign_block_kinds: BlockKindMask::ALL,
+            burnchain_reorg_grace_period: Duration::from_secs(0),
         },
     };
 
@@ -173,6 +186,141 @@ fn check_proposal_miner_pkh_mismatch() {
         .unwrap());
 }
 
+#[test]
+fn check_proposal_at_burn_height_one_block_behind() {
+    let (_stacks_client, _signer_db, _block_pk, mut view, mut block) =
+        setup_test_environment("check_proposal_at_burn_height_one_block_behind");
+    let block_sk = StacksPrivateKey::from_seed(&[0, 1]);
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    block.header.sign_miner(&block_sk).unwrap();
+    let behind = view.cur_sortition.burn_block_height - 1;
+
+    // A proposal that matches the view is fine even if the proposer is behind it
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, behind + 1),
+        ProposalVerdict::Accept
+    );
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, behind),
+        ProposalVerdict::Accept
+    );
+
+    // The last sortition's miner may not propose while the current miner is valid. From
+    // behind the view, that is because the proposer has not seen the current sortition.
+    block.header.consensus_hash = view.last_sortition.as_ref().unwrap().consensus_hash;
+    block.header.sign_miner(&block_sk).unwrap();
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, behind + 1),
+        ProposalVerdict::Reject(RejectCode::SortitionViewMismatch)
+    );
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, behind),
+        ProposalVerdict::Reject(RejectCode::StaleView)
+    );
+
+    // Once the current miner is invalidated, the last sortition's miner may propose
+    view.cur_sortition.miner_status = SortitionMinerStatus::InvalidatedBeforeFirstBlock;
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, behind),
+        ProposalVerdict::Accept
+    );
+}
+
+#[test]
+fn check_proposal_at_burn_height_one_block_ahead() {
+    let (_stacks_client, _signer_db, _block_pk, mut view, mut block) =
+        setup_test_environment("check_proposal_at_burn_height_one_block_ahead");
+    let block_sk = StacksPrivateKey::from_seed(&[0, 1]);
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    block.header.sign_miner(&block_sk).unwrap();
+    let ahead = view.cur_sortition.burn_block_height + 1;
+
+    // A burn block without a sortition leaves the view's current miner in charge
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, ahead),
+        ProposalVerdict::Accept
+    );
+
+    // The last sortition's miner may not propose while the current miner is valid
+    block.header.consensus_hash = view.last_sortition.as_ref().unwrap().consensus_hash;
+    block.header.sign_miner(&block_sk).unwrap();
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, ahead),
+        ProposalVerdict::Reject(RejectCode::SortitionViewMismatch)
+    );
+
+    // A sortition the view does not know about
+    block.header.consensus_hash = ConsensusHash([15; 20]);
+    block.header.sign_miner(&block_sk).unwrap();
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, ahead),
+        ProposalVerdict::Reject(RejectCode::SortitionViewMismatch)
+    );
+
+    // A block signed by a key other than the sortition winner's
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    block
+        .header
+        .sign_miner(&StacksPrivateKey::from_seed(&[2, 3]))
+        .unwrap();
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, ahead),
+        ProposalVerdict::Reject(RejectCode::SortitionViewMismatch)
+    );
+
+    // Too far ahead of the view to be evaluated at all
+    view.config.max_burn_height_ahead = 0;
+    assert_eq!(
+        view.check_proposal_at_burn_height(&block, ahead),
+        ProposalVerdict::Defer
+    );
+}
+
+#[test]
+fn evaluate_block_proposal_empty_consensus_hash() {
+    let (_stacks_client, _signer_db, _block_pk, mut view, mut block) =
+        setup_test_environment("evaluate_block_proposal_empty_consensus_hash");
+    // the test environment's last sortition has an empty consensus hash
+    view.last_sortition = None;
+    block.header.consensus_hash = ConsensusHash([0; 20]);
+    block
+        .header
+        .sign_miner(&StacksPrivateKey::from_seed(&[0, 1]))
+        .unwrap();
+
+    let BlockProposalDecision::Reject(reject_code, reason) =
+        evaluate_block_proposal(&view, &block, &view.config)
+    else {
+        panic!("Expected a block with an empty consensus hash to be rejected");
+    };
+    assert_eq!(reject_code, RejectCode::SortitionViewMismatch);
+    assert!(reason.contains("neither the current nor the last sortition"));
+}
+
+#[test]
+fn evaluate_block_proposal_matching_view() {
+    let (_stacks_client, _signer_db, _block_pk, view, mut block) =
+        setup_test_environment("evaluate_block_proposal_matching_view");
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    block
+        .header
+        .sign_miner(&StacksPrivateKey::from_seed(&[0, 1]))
+        .unwrap();
+
+    assert_eq!(
+        evaluate_block_proposal(&view, &block, &view.config),
+        BlockProposalDecision::Accept
+    );
+
+    // The same block is rejected by a config with a lower size limit
+    let mut config = view.config.clone();
+    config.max_block_bytes = Some(1);
+    assert!(matches!(
+        evaluate_block_proposal(&view, &block, &config),
+        BlockProposalDecision::Reject(RejectCode::BlockTooLarge, _)
+    ));
+}
+
 fn reorg_timing_testing(
     test_name: &str,
     first_proposal_burn_block_timing_secs: u64,
@@ -385,6 +533,43 @@ fn check_proposal_tenure_extend_invalid_conditions() {
         .unwrap());
 }
 
+#[test]
+fn check_block_kind_mask() {
+    let (_stacks_client, _signer_db, _block_pk, _view, mut block) =
+        setup_test_environment("block_kind_mask");
+    let tenure_change_tx = make_tenure_change_tx(make_tenure_change_payload());
+    let mut coinbase_tx = tenure_change_tx.clone();
+    coinbase_tx.payload =
+        TransactionPayload::Coinbase(CoinbasePayload([0; 32]), None, Some(VRFProof::empty()));
+    let mut user_tx = tenure_change_tx.clone();
+    user_tx.payload = TransactionPayload::TokenTransfer(
+        StacksAddress::burn_address(false).into(),
+        1,
+        TokenTransferMemo([0; 34]),
+    );
+
+    block.txs = vec![];
+    assert_eq!(BlockKind::of_block(&block), BlockKind::Empty);
+    block.txs = vec![tenure_change_tx.clone(), coinbase_tx];
+    assert_eq!(BlockKind::of_block(&block), BlockKind::Empty);
+    block.txs.push(user_tx.clone());
+    assert_eq!(BlockKind::of_block(&block), BlockKind::TenureChange);
+    block.txs = vec![user_tx];
+    assert_eq!(BlockKind::of_block(&block), BlockKind::UserTransactions);
+
+    let mask = BlockKindMask::ALL.without(BlockKind::Empty);
+    assert!(!mask.contains(BlockKind::Empty));
+    assert!(mask.contains(BlockKind::TenureChange));
+    assert!(mask.contains(BlockKind::UserTransactions));
+    assert_eq!(
+        mask,
+        [BlockKind::TenureChange, BlockKind::UserTransactions]
+            .into_iter()
+            .collect()
+    );
+    assert_eq!(BlockKindMask::default(), BlockKindMask::ALL);
+}
+
 #[test]
 fn check_block_proposal_timeout() {
     let (stacks_client, mut signer_db, block_pk, mut view, mut curr_sortition_block) =
@@ -445,6 +630,110 @@ fn check_block_proposal_timeout() {
         .unwrap());
 }
 
+#[test]
+fn check_valid_miners() {
+    let (stacks_client, mut signer_db, block_pk, mut view, mut block) =
+        setup_test_environment("valid_miners");
+    let other_pk = StacksPublicKey::from_private(&StacksPrivateKey::from_seed(&[2, 3]));
+
+    // Miner keys are not known until they propose
+    assert!(view.valid_miners().is_empty());
+
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    view.check_proposal(&stacks_client, &mut signer_db, &block, &block_pk, false)
+        .unwrap();
+    assert_eq!(view.cur_sortition.miner_pubkey, Some(block_pk));
+    assert_eq!(view.valid_miners(), vec![block_pk]);
+
+    // An invalidated miner is excluded
+    let last_sortition = view.last_sortition.as_mut().unwrap();
+    last_sortition.miner_pubkey = Some(other_pk);
+    last_sortition.miner_status = SortitionMinerStatus::InvalidatedBeforeFirstBlock;
+    assert_eq!(view.valid_miners(), vec![block_pk]);
+
+    view.last_sortition.as_mut().unwrap().miner_status = SortitionMinerStatus::Valid;
+    assert_eq!(view.valid_miners(), vec![block_pk, other_pk]);
+
+    view.cur_sortition.miner_status = SortitionMinerStatus::InvalidatedAfterFirstBlock;
+    assert_eq!(view.valid_miners(), vec![other_pk]);
+}
+
+#[test]
+fn check_commit_confirmations() {
+    let (stacks_client, _signer_db, block_pk, mut view, mut block) =
+        setup_test_environment("commit_confirmations");
+    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    let sortition_height = view.cur_sortition.burn_block_height;
+
+    // Disabled by default
+    assert!(view
+        .check_commit_confirmations(&stacks_client, &block)
+        .unwrap());
+
+    // Only the first block of a tenure is checked
+    view.config.require_commit_confirmations = 3;
+    assert!(view
+        .check_commit_confirmations(&stacks_client, &block)
+        .unwrap());
+
+    let tenure_change = |cause| {
+        StacksTransaction::new(
+            TransactionVersion::Testnet,
+            TransactionAuth::Standard(TransactionSpendingCondition::new_initial_sighash()),
+            TransactionPayload::TenureChange(TenureChangePayload {
+                tenure_consensus_hash: view.cur_sortition.consensus_hash,
+                prev_tenure_consensus_hash: view.cur_sortition.parent_tenure_id,
+                burn_view_consensus_hash: view.cur_sortition.consensus_hash,
+                previous_tenure_end: block.header.parent_block_id,
+                previous_tenure_blocks: 10,
+                cause,
+                pubkey_hash: Hash160::from_node_public_key(&block_pk),
+            }),
+        )
+    };
+
+    // A tenure extend does not start a tenure, so its commit is not checked (the stacks client
+    // is not connected to a node, so it would fail if it was)
+    let mut extend_block = block.clone();
+    extend_block
+        .txs
+        .push(tenure_change(TenureChangeCause::Extended));
+    assert!(view
+        .check_commit_confirmations(&stacks_client, &extend_block)
+        .unwrap());
+
+    block.txs.push(tenure_change(TenureChangeCause::BlockFound));
+    block.txs.push(StacksTransaction::new(
+        TransactionVersion::Testnet,
+        TransactionAuth::Standard(TransactionSpendingCondition::new_initial_sighash()),
+        TransactionPayload::Coinbase(CoinbasePayload([0; 32]), None, Some(VRFProof::empty())),
+    ));
+
+    // The commit has only been confirmed by its own burn block and one more
+    let MockServerClient {
+        server,
+        client,
+        config,
+    } = MockServerClient::new();
+    let h = std::thread::spawn(move || {
+        let result = view.check_commit_confirmations(&client, &block);
+        (view, client, block, result)
+    });
+    let (response, _) =
+        crate::client::tests::build_get_peer_info_response(Some(sortition_height + 1), None);
+    crate::client::tests::write_response(server, response.as_bytes());
+    let (view, client, block, result) = h.join().unwrap();
+    assert!(!result.unwrap());
+
+    // One more burn block gives the commit enough confirmations
+    let server = crate::client::tests::mock_server_from_config(&config);
+    let h = std::thread::spawn(move || view.check_commit_confirmations(&client, &block));
+    let (response, _) =
+        crate::client::tests::build_get_peer_info_response(Some(sortition_height + 2), None);
+    crate::client::tests::write_response(server, response.as_bytes());
+    assert!(h.join().unwrap().unwrap());
+}
+
 #[test]
 fn check_sortition_timeout() {
     let signer_db_dir = "/tmp/stacks-node-tests/signer-units/";
@@ -468,6 +757,7 @@ fn check_sortition_timeout() {
         miner_status: SortitionMinerStatus::Valid,
         burn_header_timestamp: 2,
         burn_block_hash: BurnchainHeaderHash([1; 32]),
+        burn_block_height: 1,
     };
     // Ensure we have a burn height to compare against
     let burn_hash = sortition.burn_block_hash;
@@ -476,20 +766,21 @@ fn check_sortition_timeout() {
     signer_db
         .insert_burn_block(&burn_hash, burn_height, &received_time)
         .unwrap();
+    let clock = SignerClock::default();
 
     std::thread::sleep(Duration::from_secs(1));
     // We have not yet timed out
     assert!(!sortition
-        .is_timed_out(Duration::from_secs(10), &signer_db)
+        .is_timed_out(Duration::from_secs(10), &clock, &signer_db)
         .unwrap());
     // We are a valid sortition, have an empty tenure, and have now timed out
     assert!(sortition
-        .is_timed_out(Duration::from_secs(1), &signer_db)
+        .is_timed_out(Duration::from_secs(1), &clock, &signer_db)
         .unwrap());
     // This will not be marked as timed out as the status is no longer valid
     sortition.miner_status = SortitionMinerStatus::InvalidatedAfterFirstBlock;
     assert!(!sortition
-        .is_timed_out(Duration::from_secs(1), &signer_db)
+        .is_timed_out(Duration::from_secs(1), &clock, &signer_db)
         .unwrap());
 
     // Revert the status to continue other checks
@@ -523,7 +814,117 @@ fn check_sortition_timeout() {
 
     // This will no longer be timed out as we have a non-empty tenure
     assert!(!sortition
-        .is_timed_out(Duration::from_secs(1), &signer_db)
+        .is_timed_out(Duration::from_secs(1), &clock, &signer_db)
+        .unwrap());
+}
+
+#[test]
+fn check_sortition_timeout_with_clock_offset() {
+    let signer_db_dir = "/tmp/stacks-node-tests/signer-units/";
+    let signer_db_path = format!(
+        "{signer_db_dir}/sortition_timeout_clock_offset.{}.sqlite",
+        get_epoch_time_secs()
+    );
+    fs::create_dir_all(signer_db_dir).unwrap();
+    let mut signer_db = SignerDb::new(signer_db_path).unwrap();
+
+    let block_sk = StacksPrivateKey::from_seed(&[0, 1]);
+    let block_pk = StacksPublicKey::from_private(&block_sk);
+    let block_pkh = Hash160::from_node_public_key(&block_pk);
+
+    let sortition = SortitionState {
+        miner_pkh: block_pkh,
+        miner_pubkey: None,
+        prior_sortition: ConsensusHash([0; 20]),
+        parent_tenure_id: ConsensusHash([0; 20]),
+        consensus_hash: ConsensusHash([2; 20]),
+        miner_status: SortitionMinerStatus::Valid,
+        burn_header_timestamp: 2,
+        burn_block_hash: BurnchainHeaderHash([2; 32]),
+        burn_block_height: 1,
+    };
+    signer_db
+        .insert_burn_block(&sortition.burn_block_hash, 1, &SystemTime::now())
+        .unwrap();
+
+    let skewed_pk = StacksPublicKey::from_private(&StacksPrivateKey::random());
+    let skewed_clock = SignerClock::new(skewed_pk);
+    let clock = SignerClock::new(StacksPublicKey::from_private(&StacksPrivateKey::random()));
+
+    // Neither clock has timed out yet
+    assert!(!sortition
+        .is_timed_out(Duration::from_secs(30), &clock, &signer_db)
+        .unwrap());
+    assert!(!sortition
+        .is_timed_out(Duration::from_secs(30), &skewed_clock, &signer_db)
+        .unwrap());
+
+    // Skew one signer's clock forward past the timeout
+    TEST_SIGNER_CLOCK_OFFSET.set([(skewed_pk, Duration::from_secs(60))].into());
+    assert!(skewed_clock.epoch_time_secs() >= get_epoch_time_secs() + 60);
+    assert!(sortition
+        .is_timed_out(Duration::from_secs(30), &skewed_clock, &signer_db)
+        .unwrap());
+    // Other signers are unaffected
+    assert!(!sortition
+        .is_timed_out(Duration::from_secs(30), &clock, &signer_db)
+        .unwrap());
+
+    TEST_SIGNER_CLOCK_OFFSET.set(Default::default());
+    assert!(!sortition
+        .is_timed_out(Duration::from_secs(30), &skewed_clock, &signer_db)
+        .unwrap());
+}
+
+#[test]
+fn check_sortition_timeout_after_burnchain_reorg() {
+    let (_stacks_client, mut signer_db, _block_pk, mut view, _block) =
+        setup_test_environment("sortition_timeout_after_burnchain_reorg");
+    let clock = SignerClock::default();
+
+    // The sortition's burn block arrived long enough ago for its miner to have timed out
+    let sortition = &view.cur_sortition;
+    let received_time = SystemTime::now() - Duration::from_secs(60);
+    signer_db
+        .insert_burn_block(
+            &sortition.burn_block_hash,
+            sortition.burn_block_height,
+            &received_time,
+        )
+        .unwrap();
+    assert!(sortition
+        .is_timed_out(Duration::from_secs(30), &clock, &signer_db)
+        .unwrap());
+    assert!(!view.in_burnchain_reorg_grace_period(&signer_db).unwrap());
+
+    // A burn block at the same height is a reorg, which restarts the timeout clock
+    let reorg_burn_hash = BurnchainHeaderHash([2; 32]);
+    assert!(signer_db
+        .is_burnchain_reorg(&reorg_burn_hash, sortition.burn_block_height)
+        .unwrap());
+    signer_db
+        .insert_burnchain_reorg(
+            &reorg_burn_hash,
+            sortition.burn_block_height,
+            &SystemTime::now(),
+        )
+        .unwrap();
+    let sortition = &view.cur_sortition;
+    assert!(!sortition
+        .is_timed_out(Duration::from_secs(30), &clock, &signer_db)
+        .unwrap());
+
+    // Timeouts are suppressed until the grace period has passed
+    view.config.burnchain_reorg_grace_period = Duration::from_secs(60);
+    assert!(view.in_burnchain_reorg_grace_period(&signer_db).unwrap());
+    view.config.burnchain_reorg_grace_period = Duration::from_secs(0);
+    assert!(!view.in_burnchain_reorg_grace_period(&signer_db).unwrap());
+
+    // The miner times out if it still does not propose a block once the clock runs out again
+    std::thread::sleep(Duration::from_secs(2));
+    assert!(view
+        .cur_sortition
+        .is_timed_out(Duration::from_secs(1), &clock, &signer_db)
         .unwrap());
 }
 
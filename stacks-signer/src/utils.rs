@@ -13,8 +13,16 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use std::collections::HashSet;
+
+use blockstack_lib::chainstate::stacks::boot::NakamotoSignerEntry;
+use clarity::util::hash::to_hex;
+use clarity::util::secp256k1::{MessageSignature, Secp256k1PublicKey};
 use clarity::vm::types::QualifiedContractIdentifier;
 use libsigner::{SignerSession, StackerDBSession};
+use slog::slog_debug;
+use stacks_common::debug;
+use stacks_common::types::PublicKey;
 
 /// Create a new stacker db session
 pub fn stackerdb_session(host: &str, contract: QualifiedContractIdentifier) -> StackerDBSession {
@@ -22,3 +30,195 @@ pub fn stackerdb_session(host: &str, contract: QualifiedContractIdentifier) -> S
     session.connect(host.to_string(), contract).unwrap();
     session
 }
+
+/// Errors from verifying a set of signatures against a reward set
+#[derive(thiserror::Error, Debug, PartialEq)]
+pub enum VerifyError {
+    /// The reward set contains the same signing key more than once
+    #[error("Duplicate signing key in reward set: {0}")]
+    DuplicateSigningKey(String),
+    /// A reward set signing key is not a valid public key
+    #[error("Invalid signing key in reward set: {0}")]
+    InvalidSigningKey(String),
+    /// Some signatures did not verify against any remaining signer in the reward set.
+    /// Contains the indexes of those signatures.
+    #[error("Signatures at indexes {0:?} do not map to a signer")]
+    UnmappedSignatures(Vec<usize>),
+    /// The signatures verified, but their signers do not carry enough weight
+    #[error("Signed weight {signed_weight} is below the threshold of {threshold_weight}")]
+    ThresholdNotMet {
+        /// The total weight of the signers whose signatures verified
+        signed_weight: u64,
+        /// The weight required
+        threshold_weight: u64,
+    },
+}
+
+/// Verify that `signatures` over `message` come from signers in `reward_set` carrying at least
+/// `threshold_weight` in total.
+///
+/// Signatures must be ordered by their signer's position in the reward set, as they are in a
+/// Nakamoto block header. Signers that did not sign are skipped over.
+pub fn verify_threshold_signatures(
+    message: &[u8],
+    signatures: &[MessageSignature],
+    reward_set: &[NakamotoSignerEntry],
+    threshold_weight: u64,
+) -> Result<(), VerifyError> {
+    let mut signing_keys = HashSet::new();
+    let mut public_keys = Vec::with_capacity(reward_set.len());
+    for signer in reward_set {
+        if !signing_keys.insert(signer.signing_key) {
+            return Err(VerifyError::DuplicateSigningKey(to_hex(
+                &signer.signing_key,
+            )));
+        }
+        let public_key = Secp256k1PublicKey::from_slice(&signer.signing_key)
+            .map_err(|_| VerifyError::InvalidSigningKey(to_hex(&signer.signing_key)))?;
+        public_keys.push(public_key);
+    }
+
+    let mut signer_index = 0;
+    let mut signed_weight = 0u64;
+    let mut unmapped = vec![];
+    for (signature_index, signature) in signatures.iter().enumerate() {
+        let matched = public_keys
+            .iter()
+            .enumerate()
+            .skip(signer_index)
+            .find(|(_, public_key)| public_key.verify(message, signature).unwrap_or(false))
+            .map(|(index, _)| index);
+        let Some(index) = matched else {
+            debug!(
+                "Failed to map signature to a signer";
+                "signature_index" => signature_index,
+                "signer_index" => signer_index,
+            );
+            unmapped.push(signature_index);
+            continue;
+        };
+        signed_weight = signed_weight.saturating_add(u64::from(reward_set[index].weight));
+        signer_index = index + 1;
+    }
+
+    if !unmapped.is_empty() {
+        return Err(VerifyError::UnmappedSignatures(unmapped));
+    }
+    if signed_weight < threshold_weight {
+        return Err(VerifyError::ThresholdNotMet {
+            signed_weight,
+            threshold_weight,
+        });
+    }
+    Ok(())
+}
+
+#[cfg(test)]
+mod tests {
+    use clarity::util::secp256k1::Secp256k1PrivateKey;
+    use stacks_common::types::PrivateKey;
+
+    use super::*;
+
+    /// Build a reward set of `weights.len()` signers, returning it with the signers' private keys
+    fn reward_set(weights: &[u32]) -> (Vec<NakamotoSignerEntry>, Vec<Secp256k1PrivateKey>) {
+        let private_keys: Vec<_> = weights
+            .iter()
+            .map(|_| Secp256k1PrivateKey::random())
+            .collect();
+        let signers = private_keys
+            .iter()
+            .zip(weights)
+            .map(|(private_key, weight)| {
+                let signing_key = Secp256k1PublicKey::from_private(private_key)
+                    .to_bytes_compressed()
+                    .try_into()
+                    .unwrap();
+                NakamotoSignerEntry {
+                    signing_key,
+                    stacked_amt: 0,
+                    weight: *weight,
+                }
+            })
+            .collect();
+        (signers, private_keys)
+    }
+
+    fn sign(message: &[u8], private_keys: &[&Secp256k1PrivateKey]) -> Vec<MessageSignature> {
+        private_keys
+            .iter()
+            .map(|private_key| private_key.sign(message).unwrap())
+            .collect()
+    }
+
+    #[test]
+    fn verify_exact_threshold() {
+        let message = [1u8; 32];
+        let (signers, keys) = reward_set(&[3, 4, 3]);
+        // signer 1 did not sign
+        let signatures = sign(&message, &[&keys[0], &keys[2]]);
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 6),
+            Ok(())
+        );
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 7),
+            Err(VerifyError::ThresholdNotMet {
+                signed_weight: 6,
+                threshold_weight: 7
+            })
+        );
+    }
+
+    #[test]
+    fn verify_over_threshold() {
+        let message = [2u8; 32];
+        let (signers, keys) = reward_set(&[1, 1, 1, 1, 1]);
+        let signatures = sign(
+            &message,
+            &[&keys[0], &keys[1], &keys[2], &keys[3], &keys[4]],
+        );
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 4),
+            Ok(())
+        );
+    }
+
+    #[test]
+    fn verify_invalid_signature() {
+        let message = [3u8; 32];
+        let (signers, keys) = reward_set(&[1, 1, 1, 1]);
+        let outsider = Secp256k1PrivateKey::random();
+        let mut signatures = sign(&message, &[&keys[0], &outsider, &keys[2], &keys[3]]);
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 3),
+            Err(VerifyError::UnmappedSignatures(vec![1]))
+        );
+
+        // a signature over a different message doesn't map either
+        signatures[1] = keys[1].sign(&[4u8; 32]).unwrap();
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 3),
+            Err(VerifyError::UnmappedSignatures(vec![1]))
+        );
+
+        // signatures out of reward set order don't map
+        let signatures = sign(&message, &[&keys[2], &keys[0]]);
+        assert_eq!(
+            verify_threshold_signatures(&message, &signatures, &signers, 1),
+            Err(VerifyError::UnmappedSignatures(vec![1]))
+        );
+    }
+
+    #[test]
+    fn verify_duplicate_signing_key() {
+        let message = [5u8; 32];
+        let (mut signers, keys) = reward_set(&[1, 1]);
+        signers[1].signing_key = signers[0].signing_key;
+        let signatures = sign(&message, &[&keys[0]]);
+        assert!(matches!(
+            verify_threshold_signatures(&message, &signatures, &signers, 1),
+            Err(VerifyError::DuplicateSigningKey(_))
+        ));
+    }
+}
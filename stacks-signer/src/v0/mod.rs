@@ -21,9 +21,67 @@ pub mod signer;
 /// Test specific functions for the signer module
 pub mod tests;
 
-use libsigner::v0::messages::SignerMessage;
+use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
+use libsigner::v0::messages::{RejectCode, SignerMessage};
+use stacks_common::codec::StacksMessageCodec;
+use stacks_common::types::chainstate::StacksPublicKey;
 
+use crate::chainstate::{ProposalEvalConfig, SortitionsView};
 use crate::v0::signer::Signer;
 
 /// A v0 spawned signer
 pub type SpawnedSigner = crate::SpawnedSigner<Signer, SignerMessage>;
+
+/// A signer's decision on a block proposal, reached before it writes any response to StackerDB
+#[derive(Debug, Clone, PartialEq)]
+pub enum BlockProposalDecision {
+    /// The block passes the signer's checks
+    Accept,
+    /// The block should be rejected, for the given reason
+    Reject(RejectCode, String),
+}
+
+/// Decide on a block proposal using only a sortition view and a proposal config, without a
+/// stacks node or a signer database. This applies the same checks, in the same order, that a
+/// signer applies to a proposal before consulting its database and node: the block must fit in
+/// `config.max_block_bytes`, and must be signed by the miner that the view expects. The miner's
+/// public key is recovered from the block's signature.
+pub fn evaluate_block_proposal(
+    view: &SortitionsView,
+    block: &NakamotoBlock,
+    config: &ProposalEvalConfig,
+) -> BlockProposalDecision {
+    let Some(miner_pubkey) = block.header.recover_miner_pk() else {
+        return BlockProposalDecision::Reject(
+            RejectCode::SortitionViewMismatch,
+            "block has no valid miner signature".into(),
+        );
+    };
+    evaluate_block_proposal_by(view, block, &miner_pubkey, config)
+}
+
+/// Decide on a block proposal from `miner_pubkey` as in `evaluate_block_proposal`. The signer
+/// uses this with the public key of the StackerDB slot the proposal was written to.
+pub(crate) fn evaluate_block_proposal_by(
+    view: &SortitionsView,
+    block: &NakamotoBlock,
+    miner_pubkey: &StacksPublicKey,
+    config: &ProposalEvalConfig,
+) -> BlockProposalDecision {
+    if let Some(max_block_bytes) = config.max_block_bytes {
+        let block_bytes = u64::try_from(block.serialize_to_vec().len()).unwrap_or(u64::MAX);
+        if block_bytes > max_block_bytes {
+            return BlockProposalDecision::Reject(
+                RejectCode::BlockTooLarge,
+                format!("block is {block_bytes} bytes, more than the maximum of {max_block_bytes}"),
+            );
+        }
+    }
+    match view.check_view(block, miner_pubkey) {
+        Ok(_) => BlockProposalDecision::Accept,
+        Err(mismatch) => BlockProposalDecision::Reject(
+            RejectCode::SortitionViewMismatch,
+            mismatch.reason().into(),
+        ),
+    }
+}
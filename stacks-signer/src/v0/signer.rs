@@ -12,7 +12,7 @@
 //
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::fmt::Debug;
 use std::sync::mpsc::Sender;
 use std::time::{Duration, Instant};
@@ -26,22 +26,25 @@ use clarity::types::chainstate::StacksPrivateKey;
 use clarity::types::{PrivateKey, StacksEpochId};
 use clarity::util::hash::{MerkleHashFunc, Sha512Trunc256Sum};
 use clarity::util::secp256k1::Secp256k1PublicKey;
+use clarity::vm::costs::ExecutionCost;
 use libsigner::v0::messages::{
     BlockAccepted, BlockRejection, BlockResponse, MessageSlotID, MockProposal, MockSignature,
     RejectCode, SignerMessage,
 };
 use libsigner::{BlockProposal, SignerEvent};
+use libstackerdb::StackerDBChunkAckData;
 use slog::{slog_debug, slog_error, slog_info, slog_warn};
 use stacks_common::types::chainstate::StacksAddress;
 use stacks_common::util::get_epoch_time_secs;
 use stacks_common::util::secp256k1::MessageSignature;
 use stacks_common::{debug, error, info, warn};
 
-use crate::chainstate::{ProposalEvalConfig, SortitionsView};
+use crate::chainstate::{view_mismatch_reject_code, BlockKind, ProposalEvalConfig, SortitionsView};
 use crate::client::{ClientError, SignerSlotID, StackerDB, StacksClient};
 use crate::config::{SignerConfig, SignerConfigMode};
 use crate::runloop::SignerResult;
 use crate::signerdb::{BlockInfo, BlockState, SignerDb};
+use crate::v0::{evaluate_block_proposal_by, BlockProposalDecision};
 use crate::Signer as SignerTrait;
 
 /// Signer running mode (whether dry-run or real)
@@ -92,6 +95,22 @@ pub struct Signer {
     pub submitted_block_proposal: Option<(Sha512Trunc256Sum, Instant)>,
     /// Maximum age of a block proposal in seconds before it is dropped without processing
     pub block_proposal_max_age_secs: u64,
+    /// The block responses this signer has broadcast since the last burn block, keyed by signer
+    /// signature hash, so they can be re-broadcast on request
+    pub sent_responses: HashMap<Sha512Trunc256Sum, BlockResponse>,
+    /// How many undecodable messages from the same signer slot to tolerate before re-reading
+    /// every signer's latest block response from StackerDB. If not set, undecodable messages
+    /// are skipped.
+    pub undecodable_message_resync_threshold: Option<u32>,
+    /// The number of undecodable messages received from each signer slot since it was last
+    /// re-read
+    pub undecodable_message_counts: HashMap<u32, u32>,
+    /// How many burn blocks the node's burnchain view may lag the bitcoin tip before the signer
+    /// abstains on block proposals
+    pub max_node_burn_lag: u64,
+    /// How many burn blocks the node's burnchain view lagged the bitcoin tip, as of the last
+    /// burn block this signer processed. Cleared on each new burn block.
+    pub node_burn_lag: Option<u64>,
 }
 
 impl std::fmt::Display for SignerMode {
@@ -137,6 +156,8 @@ impl SignerTrait<SignerMessage> for Signer {
             | Some(SignerEvent::NewBurnBlock { .. })
             | Some(SignerEvent::NewBlock { .. })
             | Some(SignerEvent::StatusCheck)
+            | Some(SignerEvent::ReevaluateBlock(_))
+            | Some(SignerEvent::ReloadConfig)
             | None => None,
             Some(SignerEvent::SignerMessages(msg_parity, ..)) => Some(u64::from(*msg_parity) % 2),
         };
@@ -162,16 +183,27 @@ impl SignerTrait<SignerMessage> for Signer {
             debug!("{self}: Signer reward cycle has not yet started. Ignoring event.");
             return;
         }
+        // Gather any signatures relayed to us through the file relay directory
+        for message in self.stackerdb.take_relayed_messages::<SignerMessage>() {
+            let SignerMessage::BlockResponse(block_response) = message else {
+                continue;
+            };
+            debug!("{self}: Received a relayed block response: {block_response:?}");
+            self.handle_block_response(stacks_client, &block_response);
+        }
         match event {
             SignerEvent::BlockValidationResponse(block_validate_response) => {
                 debug!("{self}: Received a block proposal result from the stacks node...");
                 self.handle_block_validate_response(stacks_client, block_validate_response)
             }
-            SignerEvent::SignerMessages(_signer_set, messages) => {
+            SignerEvent::SignerMessages(_signer_set, messages, undecodable_slot_ids) => {
                 debug!(
                     "{self}: Received {} messages from the other signers",
                     messages.len()
                 );
+                for slot_id in undecodable_slot_ids {
+                    self.handle_undecodable_message(stacks_client, *slot_id);
+                }
                 // try and gather signatures
                 for message in messages {
                     let SignerMessage::BlockResponse(block_response) = message else {
@@ -197,6 +229,7 @@ impl SignerTrait<SignerMessage> for Signer {
                                 sortition_state,
                                 block_proposal,
                                 miner_pubkey,
+                                current_reward_cycle,
                             );
                         }
                         SignerMessage::BlockPushed(b) => {
@@ -232,6 +265,11 @@ impl SignerTrait<SignerMessage> for Signer {
                                 self.mock_sign(mock_proposal.clone());
                             }
                         }
+                        SignerMessage::ResponseRequest {
+                            signer_signature_hash,
+                        } => {
+                            self.handle_response_request(signer_signature_hash);
+                        }
                         _ => {}
                     }
                 }
@@ -239,12 +277,40 @@ impl SignerTrait<SignerMessage> for Signer {
             SignerEvent::StatusCheck => {
                 debug!("{self}: Received a status check event.");
             }
+            SignerEvent::ReevaluateBlock(signer_signature_hash) => {
+                self.handle_reevaluate_block(stacks_client, signer_signature_hash);
+            }
+            SignerEvent::ReloadConfig => {
+                debug!("{self}: Received a reload config event.");
+            }
             SignerEvent::NewBurnBlock {
                 burn_height,
                 burn_header_hash,
                 received_time,
             } => {
                 info!("{self}: Received a new burn block event for block height {burn_height}");
+                self.node_burn_lag = None;
+                self.sent_responses.clear();
+                let is_reorg = self
+                    .signer_db
+                    .is_burnchain_reorg(burn_header_hash, *burn_height)
+                    .unwrap_or_else(|e| {
+                        warn!("{self}: Failed to check burn block for a burnchain reorg: {e:?}");
+                        false
+                    });
+                if is_reorg {
+                    info!(
+                        "{self}: Burnchain reorg detected, restarting the block proposal timeout";
+                        "burn_header_hash" => %burn_header_hash,
+                        "burn_height" => burn_height,
+                        "burnchain_reorg_grace_period" => ?self.proposal_config.burnchain_reorg_grace_period,
+                    );
+                    self.signer_db
+                        .insert_burnchain_reorg(burn_header_hash, *burn_height, received_time)
+                        .unwrap_or_else(|e| {
+                            warn!("{self}: Failed to record burnchain reorg: {e:?}");
+                        });
+                }
                 self.signer_db
                     .insert_burn_block(burn_header_hash, *burn_height, received_time)
                     .unwrap_or_else(|e| {
@@ -297,6 +363,15 @@ impl SignerTrait<SignerMessage> for Signer {
                 true
             })
     }
+
+    fn update_proposal_timeouts(
+        &mut self,
+        first_proposal_burn_block_timing: Duration,
+        block_proposal_timeout: Duration,
+    ) {
+        self.proposal_config.first_proposal_burn_block_timing = first_proposal_burn_block_timing;
+        self.proposal_config.block_proposal_timeout = block_proposal_timeout;
+    }
 }
 
 impl From<SignerConfig> for Signer {
@@ -327,6 +402,12 @@ impl From<SignerConfig> for Signer {
             submitted_block_proposal: None,
             block_proposal_validation_timeout: signer_config.block_proposal_validation_timeout,
             block_proposal_max_age_secs: signer_config.block_proposal_max_age_secs,
+            sent_responses: HashMap::new(),
+            undecodable_message_resync_threshold: signer_config
+                .undecodable_message_resync_threshold,
+            undecodable_message_counts: HashMap::new(),
+            max_node_burn_lag: signer_config.max_node_burn_lag,
+            node_burn_lag: None,
         }
     }
 }
@@ -347,6 +428,43 @@ impl Signer {
         Some(response)
     }
 
+    /// Broadcast a block response to the .signers contract for miners, remembering it so it
+    /// can be re-broadcast if a miner requests it
+    fn send_block_response(
+        &mut self,
+        block_response: BlockResponse,
+    ) -> Result<StackerDBChunkAckData, ClientError> {
+        self.sent_responses.insert(
+            block_response.signer_signature_hash(),
+            block_response.clone(),
+        );
+        self.stackerdb
+            .send_and_relay_message_with_retry::<SignerMessage>(block_response.into())
+    }
+
+    /// Handle a miner's request to re-broadcast our response to a block.
+    /// The stored response is re-sent unchanged, at a new slot version.
+    fn handle_response_request(&mut self, signer_signature_hash: &Sha512Trunc256Sum) {
+        let Some(block_response) = self.sent_responses.get(signer_signature_hash).cloned() else {
+            debug!("{self}: Received a response request for a block we have not responded to. Ignoring.";
+                "signer_sighash" => %signer_signature_hash,
+            );
+            return;
+        };
+        info!("{self}: Re-broadcasting block response on request: {block_response:?}");
+        match self
+            .stackerdb
+            .send_and_relay_message_with_retry::<SignerMessage>(block_response.into())
+        {
+            Err(e) => warn!("{self}: Failed to re-broadcast block response to stacker-db: {e:?}"),
+            Ok(ack) if !ack.accepted => warn!(
+                "{self}: Re-broadcast block response not accepted by stacker-db: {:?}",
+                ack.reason
+            ),
+            Ok(_) => debug!("{self}: Re-broadcast block response accepted by stacker-db"),
+        }
+    }
+
     /// Create a block acceptance response for a block
     pub fn create_block_acceptance(&self, block: &NakamotoBlock) -> BlockResponse {
         let signature = self
@@ -385,13 +503,130 @@ impl Signer {
             ),
         )
     }
-    /// Check if block should be rejected based on sortition state
+
+    /// Check if a block proposal's burn height is too far ahead of this signer's burn view for the
+    /// signer to evaluate it. Such proposals are deferred: the signer neither accepts nor rejects
+    /// them, and does not store them, so the block is evaluated if it is proposed again.
+    fn is_proposal_too_far_ahead(&self, block_proposal: &BlockProposal) -> bool {
+        let burn_view_height = match self.signer_db.get_last_burn_block_height() {
+            Ok(Some(height)) => height,
+            Ok(None) => return false,
+            Err(e) => {
+                warn!("{self}: Failed to get the last burn block height: {e:?}");
+                return false;
+            }
+        };
+        let blocks_ahead = block_proposal.burn_height.saturating_sub(burn_view_height);
+        if blocks_ahead <= self.proposal_config.max_burn_height_ahead {
+            return false;
+        }
+        info!(
+            "{self}: Deferring a block proposal that is too far ahead of our burn view";
+            "signer_sighash" => %block_proposal.block.header.signer_signature_hash(),
+            "block_id" => %block_proposal.block.block_id(),
+            "burn_height" => block_proposal.burn_height,
+            "burn_view_height" => burn_view_height,
+            "max_burn_height_ahead" => self.proposal_config.max_burn_height_ahead,
+        );
+        crate::monitoring::actions::increment_block_proposals_deferred_ahead();
+        true
+    }
+
+    /// Check if a block proposal claims a reward cycle that has not started yet.  No signer
+    /// instance knows the signer set for such a cycle, so the proposal is ignored without being
+    /// validated.  Only the signer for the current reward cycle counts these proposals.
+    fn is_proposal_from_future_cycle(
+        &self,
+        block_proposal: &BlockProposal,
+        current_reward_cycle: u64,
+    ) -> bool {
+        if block_proposal.reward_cycle <= current_reward_cycle {
+            return false;
+        }
+        if self.reward_cycle == current_reward_cycle {
+            warn!(
+                "{self}: Ignoring a block proposal for a future reward cycle";
+                "signer_sighash" => %block_proposal.block.header.signer_signature_hash(),
+                "block_id" => %block_proposal.block.block_id(),
+                "proposal_reward_cycle" => block_proposal.reward_cycle,
+                "current_reward_cycle" => current_reward_cycle,
+            );
+            crate::monitoring::actions::increment_future_cycle_proposals();
+        }
+        true
+    }
+
+    /// Check if this signer's signing policy excludes the kind of block proposed. The signer
+    /// abstains on such proposals: it neither accepts nor rejects them.
+    fn abstains_on_block_kind(&self, block: &NakamotoBlock) -> bool {
+        let kind = BlockKind::of_block(block);
+        if self.proposal_config.sign_block_kinds.contains(kind) {
+            return false;
+        }
+        info!(
+            "{self}: Abstaining on a block proposal whose kind we do not sign";
+            "signer_sighash" => %block.header.signer_signature_hash(),
+            "block_id" => %block.block_id(),
+            "block_kind" => ?kind,
+        );
+        true
+    }
+
+    /// Check if the node's burnchain view lags the bitcoin tip by more than `max_node_burn_lag`
+    /// blocks. The node's view of the chain is stale until it catches up, so the signer abstains
+    /// on the block. The lag is only queried from the node once per burn block.
+    fn node_burn_view_lags(&mut self, stacks_client: &StacksClient, block: &NakamotoBlock) -> bool {
+        if self.max_node_burn_lag == u64::MAX {
+            return false;
+        }
+        let node_burn_lag = match self.node_burn_lag {
+            Some(node_burn_lag) => node_burn_lag,
+            None => match stacks_client.get_node_burn_lag() {
+                Ok(node_burn_lag) => {
+                    self.node_burn_lag = Some(node_burn_lag);
+                    node_burn_lag
+                }
+                Err(e) => {
+                    warn!("{self}: Failed to get the node's burnchain sync status: {e:?}");
+                    return false;
+                }
+            },
+        };
+        #[cfg(any(test, feature = "testing"))]
+        let node_burn_lag = self.test_node_burn_lag().unwrap_or(node_burn_lag);
+        if node_burn_lag <= self.max_node_burn_lag {
+            return false;
+        }
+        info!(
+            "{self}: Abstaining on a block proposal because the node's burnchain view is behind the bitcoin tip";
+            "signer_sighash" => %block.header.signer_signature_hash(),
+            "block_id" => %block.block_id(),
+            "node_burn_lag" => node_burn_lag,
+            "max_node_burn_lag" => self.max_node_burn_lag,
+        );
+        true
+    }
+
+    /// Choose the reject code for a block proposal that does not match this signer's sortition
+    /// view, taking the last burn block this signer processed as its burn view.
+    fn sortition_view_reject_code(&self, proposal_burn_height: u64) -> RejectCode {
+        let burn_view_height = self
+            .signer_db
+            .get_last_burn_block_height()
+            .inspect_err(|e| warn!("{self}: Failed to get the last burn block height: {e:?}"))
+            .ok()
+            .flatten();
+        view_mismatch_reject_code(proposal_burn_height, burn_view_height)
+    }
+
+    /// Check if block should be rejected based on its size or on sortition state
     /// Will return a BlockResponse::Rejection if the block is invalid, none otherwise.
     fn check_block_against_sortition_state(
         &mut self,
         stacks_client: &StacksClient,
         sortition_state: &mut Option<SortitionsView>,
         block: &NakamotoBlock,
+        proposal_burn_height: u64,
         miner_pubkey: &Secp256k1PublicKey,
     ) -> Option<BlockResponse> {
         let signer_signature_hash = block.header.signer_signature_hash();
@@ -410,43 +645,97 @@ impl Signer {
                     .ok();
         }
 
-        // Check if proposal can be rejected now if not valid against sortition view
-        if let Some(sortition_state) = sortition_state {
-            match sortition_state.check_proposal(
-                stacks_client,
-                &mut self.signer_db,
-                block,
-                miner_pubkey,
-                true,
-            ) {
-                // Error validating block
+        let Some(sortition_state) = sortition_state else {
+            warn!(
+                "{self}: Cannot validate block, no sortition view";
+                "signer_sighash" => %signer_signature_hash,
+                "block_id" => %block_id,
+            );
+            return Some(self.create_block_rejection(RejectCode::NoSortitionView, block));
+        };
+        if let Err(e) = sortition_state.update_view_for_proposal(
+            stacks_client,
+            &mut self.signer_db,
+            block,
+            miner_pubkey,
+            true,
+        ) {
+            warn!(
+                "{self}: Error checking block proposal: {e:?}";
+                "signer_sighash" => %signer_signature_hash,
+                "block_id" => %block_id,
+            );
+            return Some(self.create_block_rejection(RejectCode::ConnectivityIssues, block));
+        }
+
+        // Check if proposal can be rejected now if it is too large or not valid against sortition view
+        if let BlockProposalDecision::Reject(reject_code, reason) =
+            evaluate_block_proposal_by(sortition_state, block, miner_pubkey, &self.proposal_config)
+        {
+            let reject_code = match reject_code {
+                RejectCode::SortitionViewMismatch => {
+                    self.sortition_view_reject_code(proposal_burn_height)
+                }
+                reject_code => reject_code,
+            };
+            warn!(
+                "{self}: Block proposal invalid";
+                "signer_sighash" => %signer_signature_hash,
+                "block_id" => %block_id,
+                "proposal_burn_height" => proposal_burn_height,
+                "reason" => reason,
+                "reject_code" => ?reject_code,
+            );
+            return Some(self.create_block_rejection(reject_code, block));
+        }
+
+        match sortition_state.check_proposal_against_chainstate(
+            stacks_client,
+            &mut self.signer_db,
+            block,
+            miner_pubkey,
+        ) {
+            // Error validating block
+            Err(e) => {
+                warn!(
+                    "{self}: Error checking block proposal: {e:?}";
+                    "signer_sighash" => %signer_signature_hash,
+                    "block_id" => %block_id,
+                );
+                Some(self.create_block_rejection(RejectCode::ConnectivityIssues, block))
+            }
+            // Block proposal is bad
+            Ok(false) => {
+                let reject_code = self.sortition_view_reject_code(proposal_burn_height);
+                warn!(
+                    "{self}: Block proposal invalid";
+                    "signer_sighash" => %signer_signature_hash,
+                    "block_id" => %block_id,
+                    "proposal_burn_height" => proposal_burn_height,
+                    "reject_code" => ?reject_code,
+                );
+                Some(self.create_block_rejection(reject_code, block))
+            }
+            // Block proposal passed check, still don't know if valid
+            Ok(true) => match sortition_state.check_commit_confirmations(stacks_client, block) {
                 Err(e) => {
                     warn!(
-                        "{self}: Error checking block proposal: {e:?}";
+                        "{self}: Error checking block-commit confirmations: {e:?}";
                         "signer_sighash" => %signer_signature_hash,
                         "block_id" => %block_id,
                     );
                     Some(self.create_block_rejection(RejectCode::ConnectivityIssues, block))
                 }
-                // Block proposal is bad
                 Ok(false) => {
                     warn!(
-                        "{self}: Block proposal invalid";
+                        "{self}: Block proposal starts a tenure whose block-commit is not yet sufficiently confirmed";
                         "signer_sighash" => %signer_signature_hash,
                         "block_id" => %block_id,
                     );
-                    Some(self.create_block_rejection(RejectCode::SortitionViewMismatch, block))
+                    Some(self.create_block_rejection(RejectCode::CommitNotConfirmed, block))
                 }
-                // Block proposal passed check, still don't know if valid
                 Ok(true) => None,
-            }
-        } else {
-            warn!(
-                "{self}: Cannot validate block, no sortition view";
-                "signer_sighash" => %signer_signature_hash,
-                "block_id" => %block_id,
-            );
-            Some(self.create_block_rejection(RejectCode::NoSortitionView, block))
+            },
         }
     }
 
@@ -457,8 +746,12 @@ impl Signer {
         sortition_state: &mut Option<SortitionsView>,
         block_proposal: &BlockProposal,
         miner_pubkey: &Secp256k1PublicKey,
+        current_reward_cycle: u64,
     ) {
         debug!("{self}: Received a block proposal: {block_proposal:?}");
+        if self.is_proposal_from_future_cycle(block_proposal, current_reward_cycle) {
+            return;
+        }
         if block_proposal.reward_cycle != self.reward_cycle {
             // We are not signing for this reward cycle. Ignore the block.
             debug!(
@@ -486,6 +779,14 @@ impl Signer {
             return;
         }
 
+        if self.is_proposal_too_far_ahead(block_proposal) {
+            return;
+        }
+
+        if self.abstains_on_block_kind(&block_proposal.block) {
+            return;
+        }
+
         // TODO: should add a check to ignore an old burn block height if we know its outdated. Would require us to store the burn block height we last saw on the side.
         //  the signer needs to be able to determine whether or not the block they're about to sign would conflict with an already-signed Stacks block
         let signer_signature_hash = block_proposal.block.header.signer_signature_hash();
@@ -498,13 +799,19 @@ impl Signer {
                 );
                 return;
             };
+            if block_info.signed_self.is_some() {
+                // We signed this block before (perhaps before a restart), so repeat our earlier
+                // response rather than validating and signing it anew.
+                debug!("{self}: Received a block proposal for a block we already signed. Not re-signing it.";
+                    "signer_sighash" => %signer_signature_hash,
+                    "block_id" => %block_proposal.block.block_id()
+                );
+                crate::monitoring::actions::increment_resign_skipped();
+            }
             // Submit a proposal response to the .signers contract for miners
             debug!("{self}: Broadcasting a block response to stacks node: {block_response:?}");
             let accepted = matches!(block_response, BlockResponse::Accepted(..));
-            match self
-                .stackerdb
-                .send_message_with_retry::<SignerMessage>(block_response.into())
-            {
+            match self.send_block_response(block_response) {
                 Ok(_) => {
                     crate::monitoring::actions::increment_block_responses_sent(accepted);
                     crate::monitoring::actions::record_block_response_latency(
@@ -518,6 +825,10 @@ impl Signer {
             return;
         }
 
+        if self.node_burn_view_lags(stacks_client, &block_proposal.block) {
+            return;
+        }
+
         info!(
             "{self}: received a block proposal for a new block.";
             "signer_sighash" => %signer_signature_hash,
@@ -546,11 +857,11 @@ impl Signer {
                     .ok();
         }
 
-        // Check if proposal can be rejected now if not valid against sortition view
         let block_response = self.check_block_against_sortition_state(
             stacks_client,
             sortition_state,
             &block_proposal.block,
+            block_proposal.burn_height,
             miner_pubkey,
         );
 
@@ -561,9 +872,7 @@ impl Signer {
         if let Some(block_response) = block_response {
             // We know proposal is invalid. Send rejection message, do not do further validation and do not store it.
             debug!("{self}: Broadcasting a block response to stacks node: {block_response:?}");
-            let res = self
-                .stackerdb
-                .send_message_with_retry::<SignerMessage>(block_response.into());
+            let res = self.send_block_response(block_response);
 
             match res {
                 Err(e) => warn!("{self}: Failed to send block rejection to stacker-db: {e:?}"),
@@ -611,6 +920,44 @@ impl Signer {
         }
     }
 
+    /// Handle a message from another signer's `slot_id` that could not be decoded. It is
+    /// skipped, unless the signer is configured to re-sync and the slot has now sent too many
+    /// undecodable messages. StackerDB only keeps a slot's latest chunk, so re-reading that slot
+    /// would return the same undecodable message; instead, the signer re-syncs by re-reading
+    /// the latest block response from every slot in its reward cycle, in case it missed any
+    /// while its view of StackerDB was out of step.
+    fn handle_undecodable_message(&mut self, stacks_client: &StacksClient, slot_id: u32) {
+        crate::monitoring::actions::increment_undecodable_messages();
+        warn!("{self}: Failed to decode a message from another signer. Skipping it."; "slot_id" => slot_id);
+        let Some(threshold) = self.undecodable_message_resync_threshold else {
+            return;
+        };
+        let count = self.undecodable_message_counts.entry(slot_id).or_default();
+        *count = count.saturating_add(1);
+        if *count < threshold {
+            return;
+        }
+        self.undecodable_message_counts.remove(&slot_id);
+        info!(
+            "{self}: Re-syncing block responses after repeated undecodable messages";
+            "slot_id" => slot_id,
+            "undecodable_message_resync_threshold" => threshold,
+        );
+        let responses = match self.stackerdb.get_all_block_responses(self.reward_cycle) {
+            Ok(responses) => responses,
+            Err(e) => {
+                warn!("{self}: Failed to re-read block responses: {e:?}");
+                return;
+            }
+        };
+        for (_slot_id, message) in responses {
+            let SignerMessage::BlockResponse(block_response) = message else {
+                continue;
+            };
+            self.handle_block_response(stacks_client, &block_response);
+        }
+    }
+
     /// Handle block response messages from a signer
     fn handle_block_response(
         &mut self,
@@ -627,6 +974,76 @@ impl Signer {
         };
     }
 
+    /// The cumulative execution cost of the given block's tenure if it were accepted: the cost
+    /// of the blocks this signer accepted in the tenure since its last tenure change, below the
+    /// block's height, plus the block's own cost. Of several accepted blocks at the same height,
+    /// only the most recently proposed one counts, since it replaced the others.
+    fn tenure_cost_with_block(
+        &self,
+        block: &NakamotoBlock,
+        block_cost: &ExecutionCost,
+    ) -> ExecutionCost {
+        let mut tenure_cost = ExecutionCost::ZERO;
+        if block.get_tenure_change_tx_payload().is_none() {
+            let accepted_blocks = self
+                .signer_db
+                .get_accepted_blocks_in_tenure(&block.header.consensus_hash)
+                .unwrap_or_else(|e| {
+                    warn!("{self}: Failed to load the tenure's accepted blocks: {e:?}");
+                    vec![]
+                });
+            let signer_signature_hash = block.header.signer_signature_hash();
+            let mut blocks_by_height = BTreeMap::new();
+            for block_info in accepted_blocks {
+                if block_info.block.header.chain_length >= block.header.chain_length
+                    || block_info.signer_signature_hash() == signer_signature_hash
+                {
+                    continue;
+                }
+                blocks_by_height.insert(block_info.block.header.chain_length, block_info);
+            }
+            for block_info in blocks_by_height.values() {
+                if block_info.is_tenure_change() {
+                    tenure_cost = ExecutionCost::ZERO;
+                }
+                let Some(cost) = block_info.validation_cost.as_ref() else {
+                    continue;
+                };
+                if tenure_cost.add(cost).is_err() {
+                    return ExecutionCost::max_value();
+                }
+            }
+        }
+        if tenure_cost.add(block_cost).is_err() {
+            tenure_cost = ExecutionCost::max_value();
+        }
+        tenure_cost
+    }
+
+    /// Check a validated block's execution cost against the configured tenure budget.
+    /// Returns a rejection if accepting the block would push its tenure over budget.
+    fn check_block_against_tenure_budget(
+        &self,
+        block: &NakamotoBlock,
+        block_cost: &ExecutionCost,
+    ) -> Option<BlockResponse> {
+        let max_tenure_cost = self.proposal_config.max_tenure_cost.as_ref()?;
+        let tenure_cost = self.tenure_cost_with_block(block, block_cost);
+        if !tenure_cost.exceeds(max_tenure_cost) {
+            return None;
+        }
+        warn!(
+            "{self}: Rejecting block proposal because it would exceed the tenure budget";
+            "signer_sighash" => %block.header.signer_signature_hash(),
+            "block_id" => %block.block_id(),
+            "consensus_hash" => %block.header.consensus_hash,
+            "block_cost" => %block_cost,
+            "tenure_cost" => %tenure_cost,
+            "max_tenure_cost" => %max_tenure_cost,
+        );
+        Some(self.create_block_rejection(RejectCode::TenureBudgetExceeded, block))
+    }
+
     /// WARNING: This is an incomplete check. Do NOT call this function PRIOR to check_proposal or block_proposal validation succeeds.
     ///
     /// Re-verify a block's chain length against the last signed block within signerdb.
@@ -725,8 +1142,12 @@ impl Signer {
             debug!("{self}: Received a block validate response for a block we have not seen before. Ignoring...");
             return None;
         };
+        crate::monitoring::actions::increment_proposals_validated(self.reward_cycle);
         if block_info.is_locally_finalized() {
             debug!("{self}: Received block validation for a block that is already marked as {}. Ignoring...", block_info.state);
+            if block_info.signed_self.is_some() {
+                crate::monitoring::actions::increment_resign_skipped();
+            }
             return None;
         }
 
@@ -740,9 +1161,7 @@ impl Signer {
                 }
             };
             debug!("{self}: Broadcasting a block response to stacks node: {block_response:?}");
-            let res = self
-                .stackerdb
-                .send_message_with_retry::<SignerMessage>(block_response.into());
+            let res = self.send_block_response(block_response);
 
             crate::monitoring::actions::record_block_response_latency(&block_info.block);
 
@@ -758,6 +1177,18 @@ impl Signer {
                 .insert_block(&block_info)
                 .unwrap_or_else(|e| self.handle_insert_block_error(e));
             None
+        } else if let Some(block_response) =
+            self.check_block_against_tenure_budget(&block_info.block, &block_validate_ok.cost)
+        {
+            if let Err(e) = block_info.mark_locally_rejected() {
+                if !block_info.has_reached_consensus() {
+                    warn!("{self}: Failed to mark block as locally rejected: {e:?}");
+                }
+            };
+            self.signer_db
+                .insert_block(&block_info)
+                .unwrap_or_else(|e| self.handle_insert_block_error(e));
+            Some(block_response)
         } else {
             if let Err(e) = block_info.mark_locally_accepted(false) {
                 if !block_info.has_reached_consensus() {
@@ -772,6 +1203,7 @@ impl Signer {
             } else {
                 Some(block_validate_ok.validation_time_ms)
             };
+            block_info.validation_cost = Some(block_validate_ok.cost.clone());
 
             self.signer_db
                 .insert_block(&block_info)
@@ -802,6 +1234,7 @@ impl Signer {
             debug!("{self}: Received a block validate response for a block we have not seen before. Ignoring...");
             return None;
         };
+        crate::monitoring::actions::increment_proposals_validated(self.reward_cycle);
         if block_info.is_locally_finalized() {
             debug!("{self}: Received block validation for a block that is already marked as {}. Ignoring...", block_info.state);
             return None;
@@ -861,10 +1294,7 @@ impl Signer {
                 "{self}: Broadcasting a block response to stacks node: {response:?}";
             );
             let accepted = matches!(response, BlockResponse::Accepted(..));
-            match self
-                .stackerdb
-                .send_message_with_retry::<SignerMessage>(response.into())
-            {
+            match self.send_block_response(response) {
                 Ok(_) => {
                     crate::monitoring::actions::increment_block_responses_sent(accepted);
                     if let Ok(Some(block_info)) = self
@@ -954,9 +1384,7 @@ impl Signer {
             }
         };
         debug!("{self}: Broadcasting a block response to stacks node: {rejection:?}");
-        let res = self
-            .stackerdb
-            .send_message_with_retry::<SignerMessage>(rejection.into());
+        let res = self.send_block_response(rejection);
 
         crate::monitoring::actions::record_block_response_latency(&block_info.block);
 
@@ -1168,6 +1596,15 @@ impl Signer {
             return;
         }
 
+        // later signatures on a block that already reached the threshold do not change the margin
+        // it was reached by
+        if block_info.signed_group.is_none() {
+            crate::monitoring::actions::record_finalization_margin_weight(
+                signature_weight,
+                min_weight,
+            );
+        }
+
         // have enough signatures to broadcast!
         // move block to LOCALLY accepted state.
         // It is only considered globally accepted IFF we receive a new block event confirming it OR see the chain tip of the node advance to it.
@@ -1236,6 +1673,10 @@ impl Signer {
     /// is busy with a previous request.
     fn submit_block_for_validation(&mut self, stacks_client: &StacksClient, block: &NakamotoBlock) {
         let signer_signature_hash = block.header.signer_signature_hash();
+        #[cfg(any(test, feature = "testing"))]
+        if self.test_fail_block_validation_submission(block) {
+            return;
+        }
         match stacks_client.submit_block_for_validation(block.clone()) {
             Ok(_) => {
                 self.submitted_block_proposal = Some((signer_signature_hash, Instant::now()));
@@ -1263,6 +1704,50 @@ impl Signer {
         }
     }
 
+    /// Handle an operator's request to re-evaluate a block, e.g. because we abstained from it
+    /// after failing to reach the node.  Only a block that we have not yet responded to is
+    /// re-submitted to the node for validation; the validation response is then handled as
+    /// usual, so a response for the block is broadcast once the node answers.
+    fn handle_reevaluate_block(
+        &mut self,
+        stacks_client: &StacksClient,
+        signer_signature_hash: &Sha512Trunc256Sum,
+    ) {
+        let Some(block_info) = self.block_lookup_by_reward_cycle(signer_signature_hash) else {
+            debug!("{self}: Asked to re-evaluate a block we have not seen in this reward cycle. Ignoring...";
+                "signer_sighash" => %signer_signature_hash,
+            );
+            return;
+        };
+        if block_info.is_locally_finalized() || block_info.signed_self.is_some() {
+            info!("{self}: Asked to re-evaluate a block that we already responded to. Ignoring...";
+                "signer_sighash" => %signer_signature_hash,
+                "state" => %block_info.state,
+            );
+            return;
+        }
+        if self
+            .submitted_block_proposal
+            .is_some_and(|(proposal_hash, _)| proposal_hash != *signer_signature_hash)
+        {
+            warn!("{self}: Cannot re-evaluate block as we are already waiting for a response for a prior submission. Inserting pending proposal.";
+                "signer_sighash" => %signer_signature_hash,
+            );
+            self.signer_db
+                .insert_pending_block_validation(signer_signature_hash, get_epoch_time_secs())
+                .unwrap_or_else(|e| {
+                    warn!("{self}: Failed to insert pending block validation: {e:?}")
+                });
+            return;
+        }
+        info!("{self}: Re-evaluating block at operator's request";
+            "signer_sighash" => %signer_signature_hash,
+            "block_id" => %block_info.block.block_id(),
+            "block_height" => block_info.block.header.chain_length,
+        );
+        self.submit_block_for_validation(stacks_client, &block_info.block);
+    }
+
     /// Send a mock signature to stackerdb to prove we are still alive
     fn mock_sign(&mut self, mock_proposal: MockProposal) {
         info!("{self}: Mock signing mock proposal: {mock_proposal:?}");
@@ -13,7 +13,9 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use std::collections::HashMap;
 use std::sync::LazyLock;
+use std::time::Duration;
 
 use blockstack_lib::chainstate::nakamoto::NakamotoBlock;
 use libsigner::v0::messages::{BlockResponse, RejectCode};
@@ -45,9 +47,22 @@ pub static TEST_SKIP_BLOCK_BROADCAST: LazyLock<TestFlag<bool>> = LazyLock::new(T
 pub static TEST_STALL_BLOCK_VALIDATION_SUBMISSION: LazyLock<TestFlag<bool>> =
     LazyLock::new(TestFlag::default);
 
+/// A global variable that can be used to fail block validation submissions, as if the node could
+/// not be reached, if the signer's public key is in the provided list
+pub static TEST_FAIL_BLOCK_VALIDATION_SUBMISSION: LazyLock<TestFlag<Vec<StacksPublicKey>>> =
+    LazyLock::new(TestFlag::default);
+
 /// A global variable that can be used to prevent signer cleanup
 pub static TEST_SKIP_SIGNER_CLEANUP: LazyLock<TestFlag<bool>> = LazyLock::new(TestFlag::default);
 
+/// A global variable that can be used to override how many burn blocks the node's burnchain view
+/// appears to lag the bitcoin tip
+pub static TEST_NODE_BURN_LAG: LazyLock<TestFlag<Option<u64>>> = LazyLock::new(TestFlag::default);
+
+/// A global variable that can be used to skew a signer's clock forward by the given offset, keyed by the signer's public key
+pub static TEST_SIGNER_CLOCK_OFFSET: LazyLock<TestFlag<HashMap<StacksPublicKey, Duration>>> =
+    LazyLock::new(TestFlag::default);
+
 impl Signer {
     /// Skip the block broadcast if the TEST_SKIP_BLOCK_BROADCAST flag is set
     pub fn test_skip_block_broadcast(&self, block: &NakamotoBlock) -> bool {
@@ -138,6 +153,30 @@ impl Signer {
         false
     }
 
+    /// Fail the block validation submission if the TEST_FAIL_BLOCK_VALIDATION_SUBMISSION flag is set for the signer's public key
+    pub fn test_fail_block_validation_submission(&self, block: &NakamotoBlock) -> bool {
+        let public_keys = TEST_FAIL_BLOCK_VALIDATION_SUBMISSION.get();
+        if public_keys.contains(
+            &stacks_common::types::chainstate::StacksPublicKey::from_private(&self.private_key),
+        ) {
+            warn!("{self}: Failing block validation submission due to testing directive";
+                "signer_sighash" => %block.header.signer_signature_hash(),
+                "height" => block.header.chain_length,
+            );
+            return true;
+        }
+        false
+    }
+
+    /// Get the node burn lag set by the TEST_NODE_BURN_LAG flag, if any
+    pub fn test_node_burn_lag(&self) -> Option<u64> {
+        let node_burn_lag = TEST_NODE_BURN_LAG.get()?;
+        warn!("{self}: Overriding the node's burn lag due to testing directive";
+            "node_burn_lag" => node_burn_lag,
+        );
+        Some(node_burn_lag)
+    }
+
     /// Stall the block validation submission if the TEST_STALL_BLOCK_VALIDATION_SUBMISSION flag is set
     pub fn test_stall_block_validation_submission(&self) {
         if TEST_STALL_BLOCK_VALIDATION_SUBMISSION.get() {
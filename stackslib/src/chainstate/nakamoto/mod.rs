@@ -969,6 +969,20 @@ impl NakamotoBlockHeader {
         }
     }
 
+    /// Return this header with the given consensus hash (testing only)
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_consensus_hash(mut self, consensus_hash: ConsensusHash) -> NakamotoBlockHeader {
+        self.consensus_hash = consensus_hash;
+        self
+    }
+
+    /// Return this header with the given PoX treatment bitvec (testing only)
+    #[cfg(any(test, feature = "testing"))]
+    pub fn with_pox_treatment(mut self, pox_treatment: BitVec<4000>) -> NakamotoBlockHeader {
+        self.pox_treatment = pox_treatment;
+        self
+    }
+
     /// Make a genesis header (testing only)
     pub fn genesis() -> NakamotoBlockHeader {
         NakamotoBlockHeader {
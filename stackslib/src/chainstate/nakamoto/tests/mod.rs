@@ -208,6 +208,47 @@ fn codec_nakamoto_header() {
     check_codec_and_corruption(&header, &bytes);
 }
 
+#[test]
+fn nakamoto_header_builders() {
+    let consensus_hash = ConsensusHash([0x04; 20]);
+    let pox_treatment = BitVec::ones(8).unwrap();
+    let header = NakamotoBlockHeader::empty()
+        .with_consensus_hash(consensus_hash.clone())
+        .with_pox_treatment(pox_treatment.clone());
+    assert_eq!(header.consensus_hash, consensus_hash);
+    assert_eq!(header.pox_treatment, pox_treatment);
+
+    // The builders produce the same header as setting the fields directly
+    let mut expected = NakamotoBlockHeader::empty();
+    expected.consensus_hash = consensus_hash;
+    expected.pox_treatment = pox_treatment;
+    assert_eq!(header, expected);
+    assert_eq!(
+        header.signer_signature_hash(),
+        expected.signer_signature_hash()
+    );
+
+    // Both fields are committed to by the signer signature hash
+    assert_ne!(
+        header.signer_signature_hash(),
+        NakamotoBlockHeader::empty().signer_signature_hash()
+    );
+    assert_ne!(
+        header.signer_signature_hash(),
+        header
+            .clone()
+            .with_pox_treatment(BitVec::zeros(8).unwrap())
+            .signer_signature_hash()
+    );
+    assert_ne!(
+        header.signer_signature_hash(),
+        header
+            .clone()
+            .with_consensus_hash(ConsensusHash([0x05; 20]))
+            .signer_signature_hash()
+    );
+}
+
 #[test]
 pub fn test_nakamoto_first_tenure_block_syntactic_validation() {
     let private_key = StacksPrivateKey::random();
@@ -60,6 +60,7 @@ use crate::cost_estimates::metrics::{CostMetric, ProportionalDotProduct, UnitMet
 use crate::cost_estimates::{CostEstimator, FeeEstimator, PessimisticEstimator, UnitEstimator};
 use crate::net::atlas::AtlasConfig;
 use crate::net::connection::{ConnectionOptions, DEFAULT_BLOCK_PROPOSAL_MAX_AGE_SECS};
+use crate::net::http::HttpContentType;
 use crate::net::{Neighbor, NeighborAddress, NeighborKey};
 use crate::types::chainstate::BurnchainHeaderHash;
 use crate::types::EpochList;
@@ -2274,10 +2275,14 @@ pub struct ConnectionOptionsFile {
     pub antientropy_public: Option<bool>,
     pub private_neighbors: Option<bool>,
     pub auth_token: Option<String>,
+    pub cors_allowed_headers: Option<Vec<String>>,
     pub antientropy_retry: Option<u64>,
     pub reject_blocks_pushed: Option<bool>,
     pub stackerdb_hint_replicas: Option<String>,
     pub block_proposal_max_age_secs: Option<u64>,
+    pub max_mempool_tx_per_response: Option<u64>,
+    pub max_http_stream_buffer_bytes: Option<u64>,
+    pub default_response_content_type: Option<String>,
 }
 
 impl ConnectionOptionsFile {
@@ -2409,6 +2414,10 @@ impl ConnectionOptionsFile {
             antientropy_public: self.antientropy_public.unwrap_or(true),
             private_neighbors: self.private_neighbors.unwrap_or(false),
             auth_token: self.auth_token,
+            cors_allowed_headers: self
+                .cors_allowed_headers
+                .map(|headers| headers.iter().map(|header| header.to_lowercase()).collect())
+                .unwrap_or(default.cors_allowed_headers),
             antientropy_retry: self.antientropy_retry.unwrap_or(default.antientropy_retry),
             reject_blocks_pushed: self
                 .reject_blocks_pushed
@@ -2429,6 +2438,21 @@ impl ConnectionOptionsFile {
             block_proposal_max_age_secs: self
                 .block_proposal_max_age_secs
                 .unwrap_or(DEFAULT_BLOCK_PROPOSAL_MAX_AGE_SECS),
+            mempool_max_tx_query: self
+                .max_mempool_tx_per_response
+                .unwrap_or(default.mempool_max_tx_query),
+            max_http_stream_buffer_bytes: self
+                .max_http_stream_buffer_bytes
+                .unwrap_or(default.max_http_stream_buffer_bytes),
+            default_response_content_type: self
+                .default_response_content_type
+                .map(|content_type| {
+                    HttpContentType::from_str(&content_type).map_err(|e| {
+                        format!("Failed to decode `default_response_content_type`: {e:?}")
+                    })
+                })
+                .transpose()?
+                .unwrap_or(default.default_response_content_type),
             ..default
         })
     }
@@ -3204,6 +3228,83 @@ mod tests {
         );
     }
 
+    #[test]
+    fn should_load_cors_allowed_headers() {
+        let config = Config::from_config_file(
+            ConfigFile::from_str(
+                r#"
+                [connection_options]
+                cors_allowed_headers = ["Content-Type", "X-Custom"]
+                "#,
+            )
+            .unwrap(),
+            false,
+        )
+        .expect("Expected to be able to parse CORS allowed headers from file");
+
+        assert_eq!(
+            config.connection_options.cors_allowed_headers,
+            vec!["content-type".to_string(), "x-custom".to_string()]
+        );
+    }
+
+    #[test]
+    fn should_load_max_mempool_tx_per_response() {
+        let config = Config::from_config_file(
+            ConfigFile::from_str(
+                r#"
+                [connection_options]
+                max_mempool_tx_per_response = 16
+                "#,
+            )
+            .unwrap(),
+            false,
+        )
+        .expect("Expected to be able to parse max_mempool_tx_per_response from file");
+
+        assert_eq!(config.connection_options.mempool_max_tx_query, 16);
+    }
+
+    #[test]
+    fn should_load_max_http_stream_buffer_bytes() {
+        let config = Config::from_config_file(
+            ConfigFile::from_str(
+                r#"
+                [connection_options]
+                max_http_stream_buffer_bytes = 65536
+                "#,
+            )
+            .unwrap(),
+            false,
+        )
+        .expect("Expected to be able to parse max_http_stream_buffer_bytes from file");
+
+        assert_eq!(
+            config.connection_options.max_http_stream_buffer_bytes,
+            65536
+        );
+    }
+
+    #[test]
+    fn should_load_default_response_content_type() {
+        let config = Config::from_config_file(
+            ConfigFile::from_str(
+                r#"
+                [connection_options]
+                default_response_content_type = "text/plain"
+                "#,
+            )
+            .unwrap(),
+            false,
+        )
+        .expect("Expected to be able to parse default_response_content_type from file");
+
+        assert_eq!(
+            config.connection_options.default_response_content_type,
+            HttpContentType::Text
+        );
+    }
+
     #[test]
     fn should_load_affirmation_map() {
         let affirmation_string = "nnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnnppnnnnnnnnnnnnnnnnnnnnnnnnpppppnnnnnnnnnnnnnnnnnnnnnnnpppppppppppppppnnnnnnnnnnnnnnnnnnnnnnnppppppppppnnnnnnnnnnnnnnnnnnnppppnnnnnnnnnnnnnnnnnnnnnnnppppppppnnnnnnnnnnnnnnnnnnnnnnnppnppnnnnnnnnnnnnnnnnnnnnnnnppppnnnnnnnnnnnnnnnnnnnnnnnnnppppppnnnnnnnnnnnnnnnnnnnnnnnnnppnnnnnnnnnnnnnnnnnnnnnnnnnpppppppnnnnnnnnnnnnnnnnnnnnnnnnnnpnnnnnnnnnnnnnnnnnnnnnnnnnpppnppppppppppppppnnppppnpa";
@@ -336,6 +336,28 @@ pub fn update_outbound_rpc_bandwidth(value: i64) {
     prometheus::OUTBOUND_RPC_BANDWIDTH_GAUGE.add(value);
 }
 
+pub fn increment_http_connection_requests_counter() {
+    #[cfg(feature = "monitoring_prom")]
+    prometheus::HTTP_CONNECTION_REQUESTS_COUNTER.inc();
+}
+
+/// Account for bytes received (`direction` is "in") or sent (`direction` is "out") on an HTTP
+/// connection
+#[allow(unused_variables)]
+pub fn increment_http_connection_bytes_counter(direction: &str, value: i64) {
+    #[cfg(feature = "monitoring_prom")]
+    prometheus::HTTP_CONNECTION_BYTES_COUNTER_VEC
+        .with_label_values(&[direction])
+        .inc_by(value);
+}
+
+/// Account for HTTP response stream buffer bytes being reserved (positive) or released (negative)
+#[allow(unused_variables)]
+pub fn update_http_stream_buffer_bytes(value: i64) {
+    #[cfg(feature = "monitoring_prom")]
+    prometheus::HTTP_STREAM_BUFFER_BYTES_GAUGE.add(value);
+}
+
 #[allow(unused_variables)]
 pub fn increment_msg_counter(name: String) {
     #[cfg(feature = "monitoring_prom")]
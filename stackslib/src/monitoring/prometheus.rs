@@ -206,6 +206,21 @@ lazy_static! {
         &["name"]
     ).unwrap();
 
+    pub static ref HTTP_CONNECTION_REQUESTS_COUNTER: IntCounter = register_int_counter!(opts!(
+        "stacks_node_http_connection_requests_total",
+        "Total number of HTTP requests handled across all HTTP connections"
+    )).unwrap();
+
+    pub static ref HTTP_CONNECTION_BYTES_COUNTER_VEC: IntCounterVec = register_int_counter_vec!(
+        "stacks_node_http_connection_bytes_total",
+        "Total number of bytes transferred across all HTTP connections, by direction",
+        &["direction"]
+    ).unwrap();
+
+    pub static ref HTTP_STREAM_BUFFER_BYTES_GAUGE: IntGauge = register_int_gauge!(opts!(
+        "stacks_node_http_stream_buffer_bytes",
+        "Bytes of chunked-encoding buffer reserved by active HTTP response streams"
+    )).unwrap();
 
     pub static ref STX_MEMPOOL_GC: IntCounter = register_int_counter!(opts!(
         "stacks_node_mempool_gc_count",
@@ -16,6 +16,7 @@
 
 use std::fs::OpenOptions;
 use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
 use std::{fs, io};
 
 use regex::{Captures, Regex};
@@ -28,10 +29,11 @@ use {serde, serde_json};
 
 use crate::chainstate::stacks::db::StacksChainState;
 use crate::chainstate::stacks::{Error as ChainError, StacksBlock};
+use crate::net::http::stream::{DeflateEncodeStream, GzipFileStream};
 use crate::net::http::{
-    parse_bytes, Error, HttpBadRequest, HttpChunkGenerator, HttpContentType, HttpNotFound,
-    HttpRequest, HttpRequestContents, HttpRequestPreamble, HttpResponse, HttpResponseContents,
-    HttpResponsePayload, HttpResponsePreamble, HttpServerError, HttpVersion,
+    parse_bytes, Error, HttpBadRequest, HttpChunkGenerator, HttpContentEncoding, HttpContentType,
+    HttpNotFound, HttpRequest, HttpRequestContents, HttpRequestPreamble, HttpResponse,
+    HttpResponseContents, HttpResponsePayload, HttpResponsePreamble, HttpServerError, HttpVersion,
 };
 use crate::net::httpcore::{
     HttpRequestContentsExtensions, RPCRequestHandler, StacksHttp, StacksHttpRequest,
@@ -78,6 +80,16 @@ impl StacksBlockStream {
             blocks_path,
         })
     }
+
+    /// Get the path to a gzip-compressed copy of this block, if one has been stored alongside
+    /// the block file.
+    pub fn precompressed_path(&self) -> Option<PathBuf> {
+        let block_path =
+            StacksChainState::get_index_block_path(&self.blocks_path, &self.index_block_hash)
+                .ok()?;
+        let gzip_path = PathBuf::from(format!("{block_path}.gz"));
+        gzip_path.is_file().then_some(gzip_path)
+    }
 }
 
 /// Decode the HTTP request
@@ -168,7 +180,7 @@ impl RPCRequestHandler for RPCBlocksRequestHandler {
             }
         };
 
-        let resp_preamble = HttpResponsePreamble::from_http_request_preamble(
+        let mut resp_preamble = HttpResponsePreamble::from_http_request_preamble(
             &preamble,
             200,
             "OK",
@@ -176,6 +188,35 @@ impl RPCRequestHandler for RPCBlocksRequestHandler {
             HttpContentType::Bytes,
         );
 
+        // If the block is stored compressed, then serve the compressed bytes directly to clients
+        // that accept gzip, and decompress them on the fly for everyone else.  Clients that only
+        // accept deflate get the decompressed bytes deflated again.
+        if let Some(gzip_path) = stream.precompressed_path() {
+            let gzip_stream = GzipFileStream::new(
+                gzip_path,
+                preamble.accepts_encoding(HttpContentEncoding::Gzip.as_str()),
+            );
+            resp_preamble.add_header("Vary".into(), "Accept-Encoding".into());
+            if let Some(encoding) = gzip_stream.content_encoding() {
+                resp_preamble.add_header("Content-Encoding".into(), encoding.to_string());
+            } else if preamble.accepts_encoding(HttpContentEncoding::Deflate.as_str()) {
+                resp_preamble.add_header(
+                    "Content-Encoding".into(),
+                    HttpContentEncoding::Deflate.to_string(),
+                );
+                return Ok((
+                    resp_preamble,
+                    HttpResponseContents::from_stream(Box::new(DeflateEncodeStream::new(
+                        Box::new(gzip_stream),
+                    ))),
+                ));
+            }
+            return Ok((
+                resp_preamble,
+                HttpResponseContents::from_stream(Box::new(gzip_stream)),
+            ));
+        }
+
         Ok((
             resp_preamble,
             HttpResponseContents::from_stream(Box::new(stream)),
@@ -29,6 +29,10 @@ use {serde, serde_json};
 use crate::chainstate::nakamoto::{NakamotoBlock, NakamotoChainState, NakamotoStagingBlocksConn};
 use crate::chainstate::stacks::db::StacksChainState;
 use crate::chainstate::stacks::Error as ChainError;
+#[cfg(feature = "zstd_encoding")]
+use crate::net::http::stream::ZstdEncodeStream;
+#[cfg(feature = "zstd_encoding")]
+use crate::net::http::HttpContentEncoding;
 use crate::net::http::{
     parse_bytes, Error, HttpBadRequest, HttpChunkGenerator, HttpContentType, HttpNotFound,
     HttpRequest, HttpRequestContents, HttpRequestPreamble, HttpResponse, HttpResponseContents,
@@ -211,7 +215,8 @@ impl RPCRequestHandler for RPCNakamotoBlockRequestHandler {
             }
         };
 
-        let resp_preamble = HttpResponsePreamble::from_http_request_preamble(
+        #[allow(unused_mut)]
+        let mut resp_preamble = HttpResponsePreamble::from_http_request_preamble(
             &preamble,
             200,
             "OK",
@@ -219,6 +224,31 @@ impl RPCRequestHandler for RPCNakamotoBlockRequestHandler {
             HttpContentType::Bytes,
         );
 
+        // Compress the block for clients that accept zstd
+        #[cfg(feature = "zstd_encoding")]
+        if preamble.accepts_encoding(HttpContentEncoding::Zstd.as_str()) {
+            resp_preamble.add_header("Vary".into(), "Accept-Encoding".into());
+            match ZstdEncodeStream::new(Box::new(stream)) {
+                Ok(zstd_stream) => {
+                    resp_preamble.add_header(
+                        "Content-Encoding".into(),
+                        HttpContentEncoding::Zstd.to_string(),
+                    );
+                    return Ok((
+                        resp_preamble,
+                        HttpResponseContents::from_stream(Box::new(zstd_stream)),
+                    ));
+                }
+                Err(e) => {
+                    let msg = format!("Failed to start zstd stream for block {block_id}: {e:?}\n");
+                    warn!("{}", &msg);
+                    return StacksHttpResponse::new_error(&preamble, &HttpServerError::new(msg))
+                        .try_into_contents()
+                        .map_err(NetError::from);
+                }
+            }
+        }
+
         Ok((
             resp_preamble,
             HttpResponseContents::from_stream(Box::new(stream)),
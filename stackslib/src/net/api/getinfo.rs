@@ -100,6 +100,11 @@ pub struct RPCPeerInfoData {
     #[serde(default)]
     #[serde(skip_serializing_if = "Option::is_none")]
     pub stackerdbs: Option<Vec<String>>,
+    /// Height of the highest burnchain block header the node has downloaded.  The node has
+    /// processed burnchain blocks up to `burn_block_height`.
+    #[serde(default)]
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub burn_headers_height: Option<u64>,
 }
 
 impl RPCPeerInfoData {
@@ -168,6 +173,7 @@ impl RPCPeerInfoData {
                     .collect(),
             ),
             tenure_height: coinbase_height,
+            burn_headers_height: Some(network.burnchain_headers_height),
         }
     }
 }
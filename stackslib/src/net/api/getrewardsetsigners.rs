@@ -0,0 +1,298 @@
+// Copyright (C) 2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use regex::{Captures, Regex};
+use serde_json::json;
+use stacks_common::types::net::PeerHost;
+use url::form_urlencoded;
+
+use crate::chainstate::stacks::boot::NakamotoSignerEntry;
+use crate::net::api::getstackers::GetStackersResponse;
+use crate::net::http::{
+    parse_json, Error, HttpBadRequest, HttpContentType, HttpNotFound, HttpRequest,
+    HttpRequestContents, HttpRequestPreamble, HttpResponse, HttpResponseContents,
+    HttpResponsePayload, HttpResponsePreamble,
+};
+use crate::net::httpcore::{
+    HttpPreambleExtensions, HttpRequestContentsExtensions, RPCRequestHandler, StacksHttpRequest,
+    StacksHttpResponse,
+};
+use crate::net::{Error as NetError, StacksNodeState, TipRequest};
+
+/// The most reward set signers served in a single page, and the page size if the client does
+/// not ask for one
+pub const MAX_REWARD_SET_SIGNERS_PAGE_SIZE: u64 = 100;
+
+#[derive(Clone, Default)]
+pub struct GetRewardSetSignersRequestHandler {
+    pub cycle_number: Option<u64>,
+    pub page: Option<u64>,
+    pub page_size: Option<u64>,
+}
+
+impl GetRewardSetSignersRequestHandler {
+    /// Parse an optional numeric query argument
+    fn parse_query_arg(contents: &HttpRequestContents, key: &str) -> Result<Option<u64>, Error> {
+        contents
+            .get_query_arg(key)
+            .map(|value| value.parse::<u64>())
+            .transpose()
+            .map_err(|e| Error::DecodeError(format!("Failed to parse {key}= query parameter: {e}")))
+    }
+
+    /// The path of the given page of the reward set signers for `cycle_number`
+    fn page_path(cycle_number: u64, page: u64, page_size: u64) -> String {
+        format!("/v3/stacker_set/{cycle_number}/signers?page={page}&page_size={page_size}")
+    }
+}
+
+/// Decode the HTTP request
+impl HttpRequest for GetRewardSetSignersRequestHandler {
+    fn verb(&self) -> &'static str {
+        "GET"
+    }
+
+    fn path_regex(&self) -> Regex {
+        Regex::new(r#"^/v3/stacker_set/(?P<cycle_num>[0-9]{1,10})/signers$"#).unwrap()
+    }
+
+    fn metrics_identifier(&self) -> &str {
+        "/v3/stacker_set/:cycle_num/signers"
+    }
+
+    /// Try to decode this request.
+    /// The page and page size are optional query arguments.
+    fn try_parse_request(
+        &mut self,
+        preamble: &HttpRequestPreamble,
+        captures: &Captures,
+        query: Option<&str>,
+        _body: &[u8],
+    ) -> Result<HttpRequestContents, Error> {
+        if preamble.get_content_length() != 0 {
+            return Err(Error::DecodeError(
+                "Invalid Http request: expected 0-length body".into(),
+            ));
+        }
+
+        let Some(cycle_num_str) = captures.name("cycle_num") else {
+            return Err(Error::DecodeError(
+                "Missing in request path: `cycle_num`".into(),
+            ));
+        };
+        let cycle_num = cycle_num_str
+            .as_str()
+            .parse::<u64>()
+            .map_err(|e| Error::DecodeError(format!("Failed to parse cycle number: {e}")))?;
+
+        let contents = HttpRequestContents::new().query_string(query);
+        let page = Self::parse_query_arg(&contents, "page")?;
+        let page_size = Self::parse_query_arg(&contents, "page_size")?;
+        if page_size == Some(0) {
+            return Err(Error::DecodeError(
+                "Invalid page_size= query parameter: must be positive".into(),
+            ));
+        }
+
+        self.cycle_number = Some(cycle_num);
+        self.page = page;
+        self.page_size = page_size;
+
+        Ok(contents)
+    }
+}
+
+impl RPCRequestHandler for GetRewardSetSignersRequestHandler {
+    /// Reset internal state
+    fn restart(&mut self) {
+        self.cycle_number = None;
+        self.page = None;
+        self.page_size = None;
+    }
+
+    /// Make the response
+    fn try_handle_request(
+        &mut self,
+        preamble: HttpRequestPreamble,
+        contents: HttpRequestContents,
+        node: &mut StacksNodeState,
+    ) -> Result<(HttpResponsePreamble, HttpResponseContents), NetError> {
+        let tip = match node.load_stacks_chain_tip(&preamble, &contents) {
+            Ok(tip) => tip,
+            Err(error_resp) => {
+                return error_resp.try_into_contents().map_err(NetError::from);
+            }
+        };
+        let Some(cycle_number) = self.cycle_number.take() else {
+            return StacksHttpResponse::new_error(
+                &preamble,
+                &HttpBadRequest::new_json(json!({
+                    "response": "error",
+                    "err_msg": "Failed to read cycle number in request"
+                })),
+            )
+            .try_into_contents()
+            .map_err(NetError::from);
+        };
+        let page = self.page.take().unwrap_or(0);
+        let page_size = self
+            .page_size
+            .take()
+            .unwrap_or(MAX_REWARD_SET_SIGNERS_PAGE_SIZE)
+            .min(MAX_REWARD_SET_SIGNERS_PAGE_SIZE);
+
+        let stacker_response =
+            node.with_node_state(|network, sortdb, chainstate, _mempool, _rpc_args| {
+                GetStackersResponse::load(
+                    sortdb,
+                    chainstate,
+                    &tip,
+                    network.get_burnchain(),
+                    cycle_number,
+                )
+            });
+
+        let response = match stacker_response {
+            Ok(response) => response,
+            Err(error) => {
+                return StacksHttpResponse::new_error(
+                    &preamble,
+                    &HttpBadRequest::new_json(json!({
+                        "response": "error",
+                        "err_type": error.error_type_string(),
+                        "err_msg": error.to_string()})),
+                )
+                .try_into_contents()
+                .map_err(NetError::from)
+            }
+        };
+
+        let Some(mut signers) = response.stacker_set.signers else {
+            return StacksHttpResponse::new_error(
+                &preamble,
+                &HttpNotFound::new(format!(
+                    "Reward set for cycle {cycle_number} has no signers\n"
+                )),
+            )
+            .try_into_contents()
+            .map_err(NetError::from);
+        };
+
+        let start = usize::try_from(page.saturating_mul(page_size)).unwrap_or(usize::MAX);
+        let end =
+            usize::try_from(page.saturating_add(1).saturating_mul(page_size)).unwrap_or(usize::MAX);
+        let has_next_page = signers.len() > end;
+        let page_signers: Vec<_> = signers
+            .drain(start.min(signers.len())..end.min(signers.len()))
+            .collect();
+
+        let mut resp_preamble = HttpResponsePreamble::from_http_request_preamble(
+            &preamble,
+            200,
+            "OK",
+            None,
+            HttpContentType::JSON,
+        );
+        resp_preamble.set_canonical_stacks_tip_height(Some(node.canonical_stacks_tip_height()));
+        if has_next_page {
+            resp_preamble.add_header(
+                "Link".into(),
+                format!(
+                    "<{}>; rel=\"next\"",
+                    Self::page_path(cycle_number, page.saturating_add(1), page_size)
+                ),
+            );
+        }
+
+        Ok((
+            resp_preamble,
+            HttpResponseContents::from_json_stream(page_signers),
+        ))
+    }
+}
+
+/// Decode the HTTP response
+impl HttpResponse for GetRewardSetSignersRequestHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        let signers: Vec<NakamotoSignerEntry> = parse_json(preamble, body)?;
+        Ok(HttpResponsePayload::try_from_json(signers)?)
+    }
+}
+
+impl StacksHttpRequest {
+    /// Make a new request for one page of the reward set signers for `cycle_num`.
+    /// If `page_size` is not given, the server's maximum page size is used.
+    pub fn new_get_reward_set_signers(
+        host: PeerHost,
+        cycle_num: u64,
+        page: u64,
+        page_size: Option<u64>,
+        tip_req: TipRequest,
+    ) -> StacksHttpRequest {
+        let mut contents = HttpRequestContents::new()
+            .for_tip(tip_req)
+            .query_arg("page".into(), page.to_string());
+        if let Some(page_size) = page_size {
+            contents = contents.query_arg("page_size".into(), page_size.to_string());
+        }
+        StacksHttpRequest::new_for_peer(
+            host,
+            "GET".into(),
+            format!("/v3/stacker_set/{cycle_num}/signers"),
+            contents,
+        )
+        .expect("FATAL: failed to construct request from infallible data")
+    }
+}
+
+impl StacksHttpResponse {
+    /// Decode a page of reward set signers, along with the number of the next page if the
+    /// response's `Link` header points to one
+    pub fn decode_reward_set_signers(
+        self,
+    ) -> Result<(Vec<NakamotoSignerEntry>, Option<u64>), NetError> {
+        let next_page = self
+            .preamble()
+            .get_header("link".into())
+            .and_then(|link| parse_next_page(&link));
+        let contents = self.get_http_payload_ok()?;
+        let response_json: serde_json::Value = contents.try_into()?;
+        let signers: Vec<NakamotoSignerEntry> = serde_json::from_value(response_json)
+            .map_err(|_e| Error::DecodeError("Failed to decode JSON".to_string()))?;
+        Ok((signers, next_page))
+    }
+}
+
+/// Get the `page` query argument of the `rel="next"` link in a `Link` header value
+pub fn parse_next_page(link: &str) -> Option<u64> {
+    link.split(',').find_map(|link_value| {
+        let (target, params) = link_value.split_once(';')?;
+        if !params
+            .split(';')
+            .any(|param| param.trim() == "rel=\"next\"" || param.trim() == "rel=next")
+        {
+            return None;
+        }
+        let target = target.trim().strip_prefix('<')?.strip_suffix('>')?;
+        let (_, query) = target.split_once('?')?;
+        form_urlencoded::parse(query.as_bytes())
+            .find(|(key, _)| key == "page")
+            .and_then(|(_, value)| value.parse::<u64>().ok())
+    })
+}
@@ -28,9 +28,9 @@ use crate::chainstate::stacks::db::StacksChainState;
 use crate::chainstate::stacks::Error as ChainError;
 use crate::core::mempool::MemPoolDB;
 use crate::net::http::{
-    parse_json, Error, HttpBadRequest, HttpNotFound, HttpRequest, HttpRequestContents,
-    HttpRequestPreamble, HttpResponse, HttpResponseContents, HttpResponsePayload,
-    HttpResponsePreamble, HttpServerError,
+    parse_json, Error, HttpBadRequest, HttpContentEncoding, HttpNotFound, HttpRequest,
+    HttpRequestContents, HttpRequestPreamble, HttpResponse, HttpResponseContents,
+    HttpResponsePayload, HttpResponsePreamble, HttpServerError,
 };
 use crate::net::httpcore::{
     HttpPreambleExtensions, HttpRequestContentsExtensions, RPCRequestHandler, StacksHttp,
@@ -210,9 +210,19 @@ impl RPCRequestHandler for GetStackersRequestHandler {
             }
         };
 
+        let accepts_gzip = preamble.accepts_encoding(HttpContentEncoding::Gzip.as_str());
         let mut preamble = HttpResponsePreamble::ok_json(&preamble);
         preamble.set_canonical_stacks_tip_height(Some(node.canonical_stacks_tip_height()));
-        let body = HttpResponseContents::try_from_json(&response)?;
+        // The reward set can be large, so compress it for clients that accept gzip
+        preamble.add_header("Vary".into(), "Accept-Encoding".into());
+        let body = if accepts_gzip {
+            HttpResponseContents::from_ram_compressed(
+                serde_json::to_vec(&response).map_err(Error::from)?,
+                HttpContentEncoding::Gzip,
+            )?
+        } else {
+            HttpResponseContents::try_from_json(&response)?
+        };
         Ok((preamble, body))
     }
 }
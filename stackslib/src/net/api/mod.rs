@@ -59,6 +59,7 @@ pub mod getmicroblocks_indexed;
 pub mod getmicroblocks_unconfirmed;
 pub mod getneighbors;
 pub mod getpoxinfo;
+pub mod getrewardsetsigners;
 pub mod getsigner;
 pub mod getsortition;
 pub mod getstackerdbchunk;
@@ -70,6 +71,7 @@ pub mod gettenureinfo;
 pub mod gettenuretip;
 pub mod gettransaction_unconfirmed;
 pub mod liststackerdbreplicas;
+pub mod options;
 pub mod postblock;
 pub mod postblock_proposal;
 #[warn(unused_imports)]
@@ -126,6 +128,9 @@ impl StacksHttp {
             getstackerdbmetadata::RPCGetStackerDBMetadataRequestHandler::new(),
         );
         self.register_rpc_endpoint(getstackers::GetStackersRequestHandler::default());
+        self.register_rpc_endpoint(
+            getrewardsetsigners::GetRewardSetSignersRequestHandler::default(),
+        );
         self.register_rpc_endpoint(getsortition::GetSortitionHandler::new());
         self.register_rpc_endpoint(gettenure::RPCNakamotoTenureRequestHandler::new());
         self.register_rpc_endpoint(gettenureinfo::RPCNakamotoTenureInfoRequestHandler::new());
@@ -138,6 +143,9 @@ impl StacksHttp {
         self.register_rpc_endpoint(
             liststackerdbreplicas::RPCListStackerDBReplicasRequestHandler::new(),
         );
+        self.register_rpc_endpoint(options::RPCOptionsRequestHandler::new(
+            self.cors_allowed_headers.clone(),
+        ));
         self.register_rpc_endpoint(postblock::RPCPostBlockRequestHandler::new());
         self.register_rpc_endpoint(postblock_proposal::RPCBlockProposalRequestHandler::new(
             self.auth_token.clone(),
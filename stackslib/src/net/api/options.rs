@@ -0,0 +1,173 @@
+// Copyright (C) 2013-2020 Blockstack PBC, a public benefit corporation
+// Copyright (C) 2020-2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use regex::{Captures, Regex};
+use stacks_common::types::net::PeerHost;
+
+use crate::net::http::{
+    Error, HttpContentType, HttpRequest, HttpRequestContents, HttpRequestPreamble, HttpResponse,
+    HttpResponseContents, HttpResponsePayload, HttpResponsePreamble,
+};
+use crate::net::httpcore::{RPCRequestHandler, StacksHttpRequest};
+use crate::net::{Error as NetError, StacksNodeState};
+
+/// Answers CORS preflight (`OPTIONS`) requests for any path
+#[derive(Clone)]
+pub struct RPCOptionsRequestHandler {
+    /// Lowercase header names that cross-origin requests may use.  `*` allows any header.
+    pub allowed_headers: Vec<String>,
+    /// The value of the request's `Access-Control-Request-Headers` header, if given
+    pub requested_headers: Option<String>,
+}
+
+impl RPCOptionsRequestHandler {
+    pub fn new(allowed_headers: Vec<String>) -> Self {
+        Self {
+            allowed_headers,
+            requested_headers: None,
+        }
+    }
+
+    /// Work out the `Access-Control-Allow-Headers` value for a preflight request.
+    /// Each header named in `requested_headers` is reflected if it is in `allowed_headers` (or if
+    /// `allowed_headers` contains `*`), and the others are dropped.  Without any requested headers,
+    /// the allowed headers themselves are listed.
+    pub fn allow_headers_value(
+        requested_headers: Option<&str>,
+        allowed_headers: &[String],
+    ) -> String {
+        let allow_any = allowed_headers.iter().any(|header| header == "*");
+        let Some(requested_headers) = requested_headers else {
+            let listed: Vec<_> = allowed_headers
+                .iter()
+                .filter(|header| *header != "*")
+                .map(String::as_str)
+                .collect();
+            return listed.join(", ");
+        };
+        let mut reflected: Vec<String> = vec![];
+        for header in requested_headers.split(',') {
+            let header = header.trim().to_lowercase();
+            if header.is_empty() || reflected.contains(&header) {
+                continue;
+            }
+            if allow_any || allowed_headers.contains(&header) {
+                reflected.push(header);
+            }
+        }
+        reflected.join(", ")
+    }
+}
+
+/// Decode the HTTP request
+impl HttpRequest for RPCOptionsRequestHandler {
+    fn verb(&self) -> &'static str {
+        "OPTIONS"
+    }
+
+    fn path_regex(&self) -> Regex {
+        Regex::new(r#"^/.*$"#).unwrap()
+    }
+
+    fn metrics_identifier(&self) -> &str {
+        "OPTIONS"
+    }
+
+    /// Try to decode this request.
+    /// The only thing to load is the list of headers the client wants to use.
+    fn try_parse_request(
+        &mut self,
+        preamble: &HttpRequestPreamble,
+        _captures: &Captures,
+        query: Option<&str>,
+        _body: &[u8],
+    ) -> Result<HttpRequestContents, Error> {
+        if preamble.get_content_length() != 0 {
+            return Err(Error::DecodeError(
+                "Invalid Http request: expected 0-length body".to_string(),
+            ));
+        }
+        self.requested_headers = preamble
+            .headers
+            .get("access-control-request-headers")
+            .cloned();
+        Ok(HttpRequestContents::new().query_string(query))
+    }
+}
+
+impl RPCRequestHandler for RPCOptionsRequestHandler {
+    /// Reset internal state
+    fn restart(&mut self) {
+        self.requested_headers = None;
+    }
+
+    /// Make the response
+    fn try_handle_request(
+        &mut self,
+        preamble: HttpRequestPreamble,
+        _contents: HttpRequestContents,
+        _node: &mut StacksNodeState,
+    ) -> Result<(HttpResponsePreamble, HttpResponseContents), NetError> {
+        let allow_headers = Self::allow_headers_value(
+            self.requested_headers.take().as_deref(),
+            &self.allowed_headers,
+        );
+        let mut preamble = HttpResponsePreamble::from_http_request_preamble(
+            &preamble,
+            200,
+            "OK",
+            Some(0),
+            HttpContentType::Text,
+        );
+        preamble.add_header("Access-Control-Allow-Headers".to_string(), allow_headers);
+        Ok((preamble, HttpResponseContents::from_ram(vec![])))
+    }
+}
+
+/// Decode the HTTP response
+impl HttpResponse for RPCOptionsRequestHandler {
+    fn try_parse_response(
+        &self,
+        _preamble: &HttpResponsePreamble,
+        _body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        Ok(HttpResponsePayload::Empty)
+    }
+}
+
+impl StacksHttpRequest {
+    /// Make a new CORS preflight request for `path`, asking to use `requested_headers`
+    pub fn new_options(
+        host: PeerHost,
+        path: String,
+        requested_headers: Option<&str>,
+    ) -> StacksHttpRequest {
+        let mut request = StacksHttpRequest::new_for_peer(
+            host,
+            "OPTIONS".into(),
+            path,
+            HttpRequestContents::new(),
+        )
+        .expect("FATAL: failed to construct request from infallible data");
+        if let Some(requested_headers) = requested_headers {
+            request.add_header(
+                "Access-Control-Request-Headers".into(),
+                requested_headers.into(),
+            );
+        }
+        request
+    }
+}
@@ -841,4 +841,19 @@ impl HttpResponse for RPCBlockProposalRequestHandler {
         let response: BlockProposalResponse = parse_json(preamble, body)?;
         HttpResponsePayload::try_from_json(response)
     }
+
+    fn allowed_status_codes(&self) -> Option<&'static [u16]> {
+        Some(&[200, 202])
+    }
+}
+
+impl StacksHttpResponse {
+    /// Decode the result of a block proposal validation, as sent to event observers
+    pub fn decode_block_validate_response(self) -> Result<BlockValidateResponse, NetError> {
+        let contents = self.get_http_payload_ok()?;
+        let response_json: serde_json::Value = contents.try_into()?;
+        let response: BlockValidateResponse = serde_json::from_value(response_json)
+            .map_err(|_e| Error::DecodeError("Failed to decode JSON".to_string()))?;
+        Ok(response)
+    }
 }
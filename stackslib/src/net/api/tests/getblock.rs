@@ -34,7 +34,7 @@ use crate::chainstate::stacks::{
 use crate::net::api::getblock::StacksBlockStream;
 use crate::net::api::*;
 use crate::net::connection::ConnectionOptions;
-use crate::net::http::HttpChunkGenerator;
+use crate::net::http::{HttpChunkGenerator, HttpStreamBufferPool};
 use crate::net::httpcore::{
     HttpPreambleExtensions, HttpRequestContentsExtensions, RPCRequestHandler, StacksHttp,
     StacksHttpRequest,
@@ -186,3 +186,73 @@ fn test_stream_blocks() {
     let staging_block = StacksBlock::consensus_deserialize(&mut &all_block_bytes[..]).unwrap();
     assert_eq!(staging_block, block);
 }
+
+#[test]
+fn test_stream_buffer_limit() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+
+    let mut rpc_test = TestRPC::setup(function_name!());
+    let stacks_chain_tip = rpc_test.canonical_tip.clone();
+
+    // room for exactly one block stream's encoder buffer
+    let stream_buffers = HttpStreamBufferPool::new(32);
+    rpc_test
+        .convo_2
+        .set_stream_buffer_pool(stream_buffers.clone());
+
+    // some other stream holds all of the buffer space
+    let reservation = stream_buffers.try_reserve(32).unwrap();
+    assert!(stream_buffers.try_reserve(1).is_none());
+
+    let requests = vec![
+        StacksHttpRequest::new_getblock(addr.into(), stacks_chain_tip.clone()),
+        StacksHttpRequest::new_getinfo(addr.into(), None),
+    ];
+    let mut responses = rpc_test.run(requests);
+
+    // no room to stream the block
+    let response = responses.remove(0);
+    let (preamble, _body) = response.destruct();
+    assert_eq!(preamble.status_code, 503);
+
+    // responses held in RAM are unaffected
+    let response = responses.remove(0);
+    response.decode_peer_info().unwrap();
+
+    // releasing the space makes it available again
+    drop(reservation);
+    assert_eq!(stream_buffers.reserved_bytes(), 0);
+    assert!(stream_buffers.try_reserve(32).is_some());
+}
+
+#[test]
+fn test_stream_buffer_released() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+
+    let mut rpc_test = TestRPC::setup(function_name!());
+    let stacks_chain_tip = rpc_test.canonical_tip.clone();
+    let consensus_hash = rpc_test.consensus_hash.clone();
+
+    // room for exactly one block stream's encoder buffer
+    let stream_buffers = HttpStreamBufferPool::new(32);
+    rpc_test
+        .convo_2
+        .set_stream_buffer_pool(stream_buffers.clone());
+
+    // each stream gives its buffer space back once it has been sent, so the second stream fits
+    let requests = vec![
+        StacksHttpRequest::new_getblock(addr.into(), stacks_chain_tip.clone()),
+        StacksHttpRequest::new_getblock(addr.into(), stacks_chain_tip.clone()),
+    ];
+    let responses = rpc_test.run(requests);
+
+    assert_eq!(responses.len(), 2);
+    for response in responses {
+        let resp = response.decode_block().unwrap();
+        assert_eq!(
+            StacksBlockHeader::make_index_block_hash(&consensus_hash, &resp.block_hash()),
+            stacks_chain_tip
+        );
+    }
+    assert_eq!(stream_buffers.reserved_bytes(), 0);
+}
@@ -185,3 +185,82 @@ fn test_stream_nakamoto_blocks() {
     let staging_block = NakamotoBlock::consensus_deserialize(&mut &all_block_bytes[..]).unwrap();
     assert_eq!(staging_block.header.block_id(), nakamoto_tip_block_id);
 }
+
+#[cfg(feature = "zstd_encoding")]
+#[test]
+fn test_stream_nakamoto_blocks_zstd() {
+    use crate::net::http::stream::ZstdEncodeStream;
+    use crate::net::http::{
+        decode_content_encoding, Error as HttpError, HttpContentEncoding, HttpContentType,
+        HttpResponsePreamble, HttpVersion,
+    };
+
+    let test_observer = TestEventObserver::new();
+    let bitvecs = vec![vec![
+        true, true, true, true, true, true, true, true, true, true,
+    ]];
+
+    let mut peer = make_nakamoto_peer_from_invs(function_name!(), &test_observer, 10, 3, bitvecs);
+
+    let nakamoto_tip = {
+        let sortdb = peer.sortdb.take().unwrap();
+        let tip = SortitionDB::get_canonical_burn_chain_tip(sortdb.conn()).unwrap();
+        let ih = sortdb.index_handle(&tip.sortition_id);
+        let nakamoto_tip = ih.get_nakamoto_tip().unwrap().unwrap();
+        peer.sortdb = Some(sortdb);
+        nakamoto_tip
+    };
+
+    let nakamoto_tip_block_id = StacksBlockId::new(&nakamoto_tip.0, &nakamoto_tip.1);
+    let (nakamoto_block, _) = peer
+        .chainstate()
+        .nakamoto_blocks_db()
+        .get_nakamoto_block(&nakamoto_tip_block_id)
+        .unwrap()
+        .unwrap();
+    let block_bytes = nakamoto_block.serialize_to_vec();
+
+    let stream = NakamotoBlockStream::new(
+        peer.chainstate(),
+        nakamoto_tip_block_id.clone(),
+        nakamoto_tip.0.clone(),
+        nakamoto_block.header.parent_block_id.clone(),
+    )
+    .unwrap();
+    let mut zstd_stream = ZstdEncodeStream::new(Box::new(stream)).unwrap();
+    let mut compressed = vec![];
+    loop {
+        let mut next_bytes = zstd_stream.generate_next_chunk().unwrap();
+        if next_bytes.is_empty() {
+            break;
+        }
+        compressed.append(&mut next_bytes);
+    }
+    assert_eq!(zstd_stream.total_bytes, compressed.len() as u64);
+
+    let mut preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(compressed.len() as u32),
+        HttpContentType::Bytes,
+        true,
+    );
+    preamble.add_header(
+        "Content-Encoding".into(),
+        HttpContentEncoding::Zstd.to_string(),
+    );
+
+    // the decompressed body decodes to the same block
+    let decoded =
+        decode_content_encoding(&preamble, &compressed, block_bytes.len() as u64).unwrap();
+    assert_eq!(decoded.as_ref(), block_bytes.as_slice());
+    let decoded_block = NakamotoBlock::consensus_deserialize(&mut decoded.as_ref()).unwrap();
+    assert_eq!(decoded_block, nakamoto_block);
+
+    // bodies that decompress to more than the cap are rejected
+    match decode_content_encoding(&preamble, &compressed, block_bytes.len() as u64 - 1) {
+        Err(HttpError::DecodeError(msg)) => assert!(msg.contains("longer than"), "{msg}"),
+        res => panic!("Expected DecodeError, got {res:?}"),
+    }
+}
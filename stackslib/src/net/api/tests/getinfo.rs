@@ -28,6 +28,7 @@ use super::test_rpc;
 use crate::net::api::getinfo::RPCPeerInfoData;
 use crate::net::api::*;
 use crate::net::connection::ConnectionOptions;
+use crate::net::http::{HttpContentType, HttpResponsePayload};
 use crate::net::httpcore::{
     HttpPreambleExtensions, HttpRequestContentsExtensions, RPCRequestHandler, StacksHttp,
     StacksHttpRequest,
@@ -105,3 +106,115 @@ fn test_try_make_response() {
 
     assert_eq!(resp.tenure_height, 1);
 }
+
+#[test]
+fn test_try_make_response_expectation_failed() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+
+    let mut requests = vec![];
+
+    // an expectation we cannot meet
+    let mut request = StacksHttpRequest::new_getinfo(addr.into(), Some(123));
+    request.add_header("Expect".into(), "200-ok".into());
+    requests.push(request);
+
+    // the one expectation we can meet
+    let mut request = StacksHttpRequest::new_getinfo(addr.into(), Some(123));
+    request.add_header("Expect".into(), "100-continue".into());
+    requests.push(request);
+
+    let mut responses = test_rpc(function_name!(), requests);
+
+    let response = responses.remove(0);
+    debug!(
+        "Response:\n{}\n",
+        std::str::from_utf8(&response.try_serialize().unwrap()).unwrap()
+    );
+    assert_eq!(response.preamble().status_code, 417);
+    let (preamble, payload) = response.destruct();
+    assert_eq!(preamble.content_type, HttpContentType::JSON);
+    let HttpResponsePayload::JSON(body) = payload else {
+        panic!("Expected a JSON error body, got {payload:?}");
+    };
+    assert_eq!(body["error"], "Unsupported expectation '200-ok'");
+
+    let response = responses.remove(0);
+    assert_eq!(response.preamble().status_code, 200);
+    response.decode_peer_info().unwrap();
+}
+
+/// Read the current value of an HTTP connection counter from the global Prometheus registry
+#[cfg(feature = "monitoring_prom")]
+fn get_http_connection_counter(name: &str, direction: Option<&str>) -> u64 {
+    ::prometheus::gather()
+        .iter()
+        .filter(|family| family.get_name() == name)
+        .flat_map(|family| family.get_metric())
+        .filter(|metric| {
+            direction.map_or(true, |direction| {
+                metric
+                    .get_label()
+                    .iter()
+                    .any(|label| label.get_name() == "direction" && label.get_value() == direction)
+            })
+        })
+        .map(|metric| metric.get_counter().get_value() as u64)
+        .sum()
+}
+
+#[test]
+#[cfg(feature = "monitoring_prom")]
+fn test_http_connection_metrics() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+
+    let requests = vec![
+        StacksHttpRequest::new_getinfo(addr.into(), Some(123)),
+        StacksHttpRequest::new_getinfo(addr.into(), Some(123)),
+    ];
+
+    let rpc_test = super::TestRPC::setup(function_name!());
+
+    let requests_before =
+        get_http_connection_counter("stacks_node_http_connection_requests_total", None);
+    let bytes_in_before =
+        get_http_connection_counter("stacks_node_http_connection_bytes_total", Some("in"));
+    let bytes_out_before =
+        get_http_connection_counter("stacks_node_http_connection_bytes_total", Some("out"));
+
+    let (responses, client_convo, server_convo) = rpc_test.run_with_convos(requests, None);
+    assert_eq!(responses.len(), 2);
+    for response in responses {
+        response.decode_peer_info().unwrap();
+    }
+
+    // both requests went over the same connection, so each side saw what the other sent
+    assert!(client_convo.get_total_bytes_sent() > 0);
+    assert!(server_convo.get_total_bytes_sent() > 0);
+    assert_eq!(
+        client_convo.get_total_bytes_sent(),
+        server_convo.get_total_bytes_recv()
+    );
+    assert_eq!(
+        server_convo.get_total_bytes_sent(),
+        client_convo.get_total_bytes_recv()
+    );
+
+    // other tests share the global registry, so the counters may have moved by more than this
+    // connection accounts for
+    let requests_after =
+        get_http_connection_counter("stacks_node_http_connection_requests_total", None);
+    let bytes_in_after =
+        get_http_connection_counter("stacks_node_http_connection_bytes_total", Some("in"));
+    let bytes_out_after =
+        get_http_connection_counter("stacks_node_http_connection_bytes_total", Some("out"));
+
+    assert!(requests_after - requests_before >= 2);
+    assert!(
+        bytes_in_after - bytes_in_before
+            >= client_convo.get_total_bytes_recv() + server_convo.get_total_bytes_recv()
+    );
+    assert!(
+        bytes_out_after - bytes_out_before
+            >= client_convo.get_total_bytes_sent() + server_convo.get_total_bytes_sent()
+    );
+}
@@ -0,0 +1,146 @@
+// Copyright (C) 2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::collections::BTreeMap;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use stacks_common::types::net::PeerHost;
+
+use crate::chainstate::stacks::boot::NakamotoSignerEntry;
+use crate::net::api::getrewardsetsigners::{parse_next_page, GetRewardSetSignersRequestHandler};
+use crate::net::connection::ConnectionOptions;
+use crate::net::http::stream::JsonStream;
+use crate::net::http::{Error as HttpError, HttpChunkGenerator, HttpRequestPreamble, HttpVersion};
+use crate::net::httpcore::{RPCRequestHandler, StacksHttp};
+use crate::net::Error as NetError;
+
+fn make_preamble(path_and_query: &str) -> HttpRequestPreamble {
+    HttpRequestPreamble {
+        version: HttpVersion::Http11,
+        verb: "GET".into(),
+        path_and_query_str: path_and_query.into(),
+        host: PeerHost::DNS("localhost".into(), 0),
+        content_type: None,
+        content_length: Some(0),
+        keep_alive: false,
+        headers: BTreeMap::new(),
+    }
+}
+
+fn make_signer_entry(i: u8) -> NakamotoSignerEntry {
+    NakamotoSignerEntry {
+        signing_key: [i; 33],
+        stacked_amt: u128::from(i) * 1_000,
+        weight: u32::from(i),
+    }
+}
+
+#[test]
+fn test_try_parse_request() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+    let http = StacksHttp::new(addr.clone(), &ConnectionOptions::default());
+
+    let mut handler = GetRewardSetSignersRequestHandler::default();
+    let mut bad_content_length_preamble = make_preamble("/v3/stacker_set/5/signers");
+    bad_content_length_preamble.content_length = Some(1);
+    let tests = vec![
+        (
+            make_preamble("/v3/stacker_set/5/signers"),
+            Ok((Some(5), None, None)),
+        ),
+        (
+            make_preamble("/v3/stacker_set/5/signers?page=3&page_size=2"),
+            Ok((Some(5), Some(3), Some(2))),
+        ),
+        (
+            make_preamble("/v3/stacker_set/5/signers?page=foo"),
+            Err(HttpError::DecodeError(
+                "Failed to parse page= query parameter: invalid digit found in string".into(),
+            )
+            .into()),
+        ),
+        (
+            make_preamble("/v3/stacker_set/5/signers?page_size=0"),
+            Err(HttpError::DecodeError(
+                "Invalid page_size= query parameter: must be positive".into(),
+            )
+            .into()),
+        ),
+        (
+            make_preamble("/v3/stacker_set/foo/signers"),
+            Err(NetError::NotFoundError),
+        ),
+        (
+            bad_content_length_preamble,
+            Err(
+                HttpError::DecodeError("Invalid Http request: expected 0-length body".into())
+                    .into(),
+            ),
+        ),
+    ];
+
+    for (inp, expected_result) in tests.into_iter() {
+        handler.restart();
+        let parsed_request = http.handle_try_parse_request(&mut handler, &inp, &[]);
+        match expected_result {
+            Ok((cycle_number, page, page_size)) => {
+                assert!(parsed_request.is_ok());
+                assert_eq!(handler.cycle_number, cycle_number);
+                assert_eq!(handler.page, page);
+                assert_eq!(handler.page_size, page_size);
+            }
+            Err(e) => {
+                assert_eq!(e, parsed_request.unwrap_err());
+            }
+        }
+    }
+}
+
+#[test]
+fn test_parse_next_page() {
+    assert_eq!(
+        parse_next_page("</v3/stacker_set/5/signers?page=2&page_size=10>; rel=\"next\""),
+        Some(2)
+    );
+    assert_eq!(
+        parse_next_page(
+            "</v3/stacker_set/5/signers?page=0&page_size=10>; rel=\"first\", </v3/stacker_set/5/signers?page_size=10&page=4>; rel=next"
+        ),
+        Some(4)
+    );
+    assert_eq!(
+        parse_next_page("</v3/stacker_set/5/signers?page=0>; rel=\"prev\""),
+        None
+    );
+    assert_eq!(parse_next_page("garbage"), None);
+}
+
+#[test]
+fn test_stream_reward_set_signers() {
+    for num_signers in [0, 1, 3] {
+        let signers: Vec<_> = (0..num_signers).map(make_signer_entry).collect();
+        let mut stream = JsonStream::new(signers.clone());
+        let mut body = vec![];
+        loop {
+            let chunk = stream.generate_next_chunk().unwrap();
+            if chunk.is_empty() {
+                break;
+            }
+            body.extend_from_slice(&chunk);
+        }
+        let decoded: Vec<NakamotoSignerEntry> = serde_json::from_slice(&body).unwrap();
+        assert_eq!(decoded, signers);
+    }
+}
@@ -14,12 +14,17 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::net::SocketAddr;
+use std::io;
+use std::net::{IpAddr, Ipv4Addr, SocketAddr, TcpListener};
+use std::sync::mpsc::channel;
 use std::sync::{Arc, Mutex};
+use std::thread;
+use std::time::Duration;
 
 use clarity::vm::costs::ExecutionCost;
 use clarity::vm::types::{QualifiedContractIdentifier, StacksAddressExtensions};
 use libstackerdb::SlotMetadata;
+use regex::{Captures, Regex};
 use stacks_common::address::{AddressHashMode, C32_ADDRESS_VERSION_TESTNET_SINGLESIG};
 use stacks_common::codec::StacksMessageCodec;
 use stacks_common::types::chainstate::{
@@ -43,15 +48,23 @@ use crate::chainstate::stacks::{
 };
 use crate::core::MemPoolDB;
 use crate::net::api::{prefix_hex, prefix_opt_hex};
+use crate::net::connection::ConnectionOptions;
 use crate::net::db::PeerDB;
-use crate::net::httpcore::{StacksHttpRequest, StacksHttpResponse};
+use crate::net::http::{
+    parse_json, Error as HttpError, HttpContentType, HttpRequest, HttpRequestContents,
+    HttpRequestPreamble, HttpResponse, HttpResponseContents, HttpResponsePayload,
+    HttpResponsePreamble,
+};
+use crate::net::httpcore::{
+    send_http_request, RPCRequestHandler, StacksHttpRequest, StacksHttpResponse,
+};
 use crate::net::relay::Relayer;
 use crate::net::rpc::ConversationHttp;
 use crate::net::test::{RPCHandlerArgsType, TestEventObserver, TestPeer, TestPeerConfig};
 use crate::net::tests::inv::nakamoto::make_nakamoto_peers_from_invs_ext;
 use crate::net::{
-    Attachment, AttachmentInstance, MemPoolEventDispatcher, RPCHandlerArgs, StackerDBConfig,
-    StacksNodeState, UrlString,
+    Attachment, AttachmentInstance, Error as NetError, MemPoolEventDispatcher, RPCHandlerArgs,
+    StackerDBConfig, StacksNodeState, UrlString,
 };
 
 mod callreadonly;
@@ -77,6 +90,7 @@ mod getmicroblocks_indexed;
 mod getmicroblocks_unconfirmed;
 mod getneighbors;
 mod getpoxinfo;
+mod getrewardsetsigners;
 mod getsigner;
 mod getsortition;
 mod getstackerdbchunk;
@@ -87,6 +101,7 @@ mod gettenureinfo;
 mod gettenuretip;
 mod gettransaction_unconfirmed;
 mod liststackerdbreplicas;
+mod options;
 mod postblock;
 mod postblock_proposal;
 mod postblock_v3;
@@ -965,6 +980,17 @@ impl<'a> TestRPC<'a> {
         requests: Vec<StacksHttpRequest>,
         event_observer: Option<&dyn MemPoolEventDispatcher>,
     ) -> Vec<StacksHttpResponse> {
+        self.run_with_convos(requests, event_observer).0
+    }
+
+    /// Run zero or more HTTP requests on this setup RPC test harness, all on the same connection.
+    /// Return the list of responses, as well as the client (first) and server (second)
+    /// conversations they were exchanged on.
+    pub fn run_with_convos(
+        self,
+        requests: Vec<StacksHttpRequest>,
+        event_observer: Option<&dyn MemPoolEventDispatcher>,
+    ) -> (Vec<StacksHttpResponse>, ConversationHttp, ConversationHttp) {
         let mut peer_1 = self.peer_1;
         let mut peer_2 = self.peer_2;
         let peer_1_indexer = self.peer_1_indexer;
@@ -1144,7 +1170,7 @@ impl<'a> TestRPC<'a> {
             responses.push(resp);
         }
 
-        return responses;
+        (responses, convo_1, convo_2)
     }
 }
 
@@ -1253,3 +1279,223 @@ fn prefixed_hex_serialization() {
         assert_eq!(hex_str, format!("\"0x{}\"", to_hex(&inp.0)));
     }
 }
+
+/// Test handler for `GET /test/untyped-json`, which answers with a raw JSON string body but
+/// leaves the response's content type to the server
+#[derive(Clone)]
+struct RPCUntypedJSONRequestHandler {}
+
+impl HttpRequest for RPCUntypedJSONRequestHandler {
+    fn verb(&self) -> &'static str {
+        "GET"
+    }
+
+    fn path_regex(&self) -> Regex {
+        Regex::new(r#"^/test/untyped-json$"#).unwrap()
+    }
+
+    fn metrics_identifier(&self) -> &str {
+        "/test/untyped-json"
+    }
+
+    fn try_parse_request(
+        &mut self,
+        _preamble: &HttpRequestPreamble,
+        _captures: &Captures,
+        query: Option<&str>,
+        _body: &[u8],
+    ) -> Result<HttpRequestContents, HttpError> {
+        Ok(HttpRequestContents::new().query_string(query))
+    }
+}
+
+impl RPCRequestHandler for RPCUntypedJSONRequestHandler {
+    fn restart(&mut self) {}
+
+    fn try_handle_request(
+        &mut self,
+        preamble: HttpRequestPreamble,
+        _contents: HttpRequestContents,
+        _node: &mut StacksNodeState,
+    ) -> Result<(HttpResponsePreamble, HttpResponseContents), NetError> {
+        let preamble =
+            HttpResponsePreamble::from_http_request_preamble_untyped(&preamble, 200, "OK", None);
+        let body = HttpResponseContents::from_ram(r#"{"untyped":true}"#.as_bytes().to_vec());
+        Ok((preamble, body))
+    }
+}
+
+impl HttpResponse for RPCUntypedJSONRequestHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, HttpError> {
+        let value: serde_json::Value = parse_json(preamble, body)?;
+        Ok(HttpResponsePayload::JSON(value))
+    }
+}
+
+#[test]
+fn test_untyped_response_gets_default_content_type() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+    assert_eq!(
+        ConnectionOptions::default().default_response_content_type,
+        HttpContentType::JSON
+    );
+
+    let mut rpc_test = TestRPC::setup(function_name!());
+    rpc_test
+        .convo_1
+        .register_rpc_endpoint(RPCUntypedJSONRequestHandler {});
+    rpc_test
+        .convo_2
+        .register_rpc_endpoint(RPCUntypedJSONRequestHandler {});
+
+    let request = StacksHttpRequest::new_for_peer(
+        addr.into(),
+        "GET".into(),
+        "/test/untyped-json".into(),
+        HttpRequestContents::new(),
+    )
+    .unwrap();
+    let mut responses = rpc_test.run(vec![request]);
+
+    let (preamble, payload) = responses.remove(0).destruct();
+    assert_eq!(preamble.status_code, 200);
+    assert_eq!(preamble.content_type, HttpContentType::JSON);
+    assert_eq!(
+        payload,
+        HttpResponsePayload::JSON(serde_json::json!({ "untyped": true }))
+    );
+}
+
+/// Convert an io::Error from `send_http_request()` to an upstream into a NetError that an RPC
+/// handler can return.  Timeouts become `NetError::RecvTimeout`, which is reported to the client
+/// as a 504.
+fn upstream_io_error(e: io::Error) -> NetError {
+    match e.kind() {
+        io::ErrorKind::WouldBlock | io::ErrorKind::TimedOut => NetError::RecvTimeout,
+        _ => NetError::ReadError(e),
+    }
+}
+
+/// Test handler for `GET /test/upstream`, which answers with the `/v2/info` response of an
+/// upstream node
+#[derive(Clone)]
+struct RPCUpstreamRequestHandler {
+    upstream_port: u16,
+    upstream_timeout: Duration,
+}
+
+impl HttpRequest for RPCUpstreamRequestHandler {
+    fn verb(&self) -> &'static str {
+        "GET"
+    }
+
+    fn path_regex(&self) -> Regex {
+        Regex::new(r#"^/test/upstream$"#).unwrap()
+    }
+
+    fn metrics_identifier(&self) -> &str {
+        "/test/upstream"
+    }
+
+    fn try_parse_request(
+        &mut self,
+        _preamble: &HttpRequestPreamble,
+        _captures: &Captures,
+        query: Option<&str>,
+        _body: &[u8],
+    ) -> Result<HttpRequestContents, HttpError> {
+        Ok(HttpRequestContents::new().query_string(query))
+    }
+}
+
+impl RPCRequestHandler for RPCUpstreamRequestHandler {
+    fn restart(&mut self) {}
+
+    fn try_handle_request(
+        &mut self,
+        preamble: HttpRequestPreamble,
+        _contents: HttpRequestContents,
+        _node: &mut StacksNodeState,
+    ) -> Result<(HttpResponsePreamble, HttpResponseContents), NetError> {
+        let upstream_request = StacksHttpRequest::new_for_peer(
+            format!("127.0.0.1:{}", self.upstream_port).parse().unwrap(),
+            "GET".into(),
+            "/v2/info".into(),
+            HttpRequestContents::new(),
+        )?;
+        let upstream_response = send_http_request(
+            "127.0.0.1",
+            self.upstream_port,
+            upstream_request,
+            self.upstream_timeout,
+        )
+        .map_err(upstream_io_error)?;
+        let HttpResponsePayload::JSON(info) = upstream_response.get_http_payload_ok()? else {
+            return Err(NetError::DeserializeError(
+                "Upstream did not answer with JSON".to_string(),
+            ));
+        };
+        let preamble = HttpResponsePreamble::ok_json(&preamble);
+        let body = HttpResponseContents::try_from_json(&info)?;
+        Ok((preamble, body))
+    }
+}
+
+impl HttpResponse for RPCUpstreamRequestHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, HttpError> {
+        let value: serde_json::Value = parse_json(preamble, body)?;
+        Ok(HttpResponsePayload::JSON(value))
+    }
+}
+
+#[test]
+fn test_upstream_timeout_is_gateway_timeout() {
+    // an upstream that accepts a connection but never responds
+    let upstream = TcpListener::bind("127.0.0.1:0").expect("Failed to bind upstream listener");
+    let upstream_port = upstream.local_addr().unwrap().port();
+    let (upstream_done_tx, upstream_done_rx) = channel::<()>();
+    thread::spawn(move || {
+        let (_stream, _addr) = upstream.accept().unwrap();
+        // hold the connection open until the test is done
+        let _ = upstream_done_rx.recv();
+    });
+
+    let handler = RPCUpstreamRequestHandler {
+        upstream_port,
+        upstream_timeout: Duration::from_secs(2),
+    };
+    let mut rpc_test = TestRPC::setup(function_name!());
+    rpc_test.convo_1.register_rpc_endpoint(handler.clone());
+    rpc_test.convo_2.register_rpc_endpoint(handler);
+
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+    let request = StacksHttpRequest::new_for_peer(
+        addr.into(),
+        "GET".into(),
+        "/test/upstream".into(),
+        HttpRequestContents::new(),
+    )
+    .unwrap();
+    let mut responses = rpc_test.run(vec![request]);
+
+    let (preamble, payload) = responses.remove(0).destruct();
+    assert_eq!(preamble.status_code, 504);
+    assert_eq!(preamble.content_type, HttpContentType::JSON);
+    let HttpResponsePayload::JSON(body) = payload else {
+        panic!("Expected a JSON error body, got {payload:?}");
+    };
+    assert!(body["error"]
+        .as_str()
+        .unwrap()
+        .contains("Timed out waiting for upstream"));
+
+    upstream_done_tx.send(()).unwrap();
+}
@@ -0,0 +1,151 @@
+// Copyright (C) 2013-2020 Blockstack PBC, a public benefit corporation
+// Copyright (C) 2020-2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::net::{IpAddr, Ipv4Addr, SocketAddr};
+
+use super::test_rpc;
+use crate::net::api::options::RPCOptionsRequestHandler;
+use crate::net::connection::ConnectionOptions;
+use crate::net::httpcore::{StacksHttp, StacksHttpRequest};
+use crate::net::ProtocolFamily;
+
+#[test]
+fn test_try_parse_request() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+    let mut http = StacksHttp::new(addr.clone(), &ConnectionOptions::default());
+
+    let request = StacksHttpRequest::new_options(
+        addr.into(),
+        "/v2/info".into(),
+        Some("x-custom, authorization"),
+    );
+    let bytes = request.try_serialize().unwrap();
+
+    debug!("Request:\n{}\n", std::str::from_utf8(&bytes).unwrap());
+
+    let (parsed_preamble, offset) = http.read_preamble(&bytes).unwrap();
+    let mut handler = RPCOptionsRequestHandler::new(vec!["authorization".into()]);
+    let mut parsed_request = http
+        .handle_try_parse_request(
+            &mut handler,
+            &parsed_preamble.expect_request(),
+            &bytes[offset..],
+        )
+        .unwrap();
+    assert_eq!(
+        handler.requested_headers.as_deref(),
+        Some("x-custom, authorization")
+    );
+
+    // parsed request consumes headers that would not be in a constructed request
+    parsed_request.clear_headers();
+    parsed_request.add_header(
+        "Access-Control-Request-Headers".into(),
+        "x-custom, authorization".into(),
+    );
+    let (preamble, _contents) = parsed_request.destruct();
+    assert_eq!(&preamble, request.preamble());
+}
+
+#[test]
+fn test_allow_headers_value() {
+    let allowed: Vec<String> = vec!["content-type".into(), "authorization".into()];
+    let tests = [
+        // only the allowed subset is reflected, in the order requested
+        (Some("x-custom, authorization"), &allowed, "authorization"),
+        (
+            Some("Authorization,Content-Type , authorization"),
+            &allowed,
+            "authorization, content-type",
+        ),
+        (Some("x-custom"), &allowed, ""),
+        // without requested headers, the allowlist itself is listed
+        (None, &allowed, "content-type, authorization"),
+    ];
+    for (requested, allowed, expected) in tests {
+        assert_eq!(
+            RPCOptionsRequestHandler::allow_headers_value(requested, allowed),
+            expected,
+            "requested = {requested:?}"
+        );
+    }
+
+    // a wildcard allows every requested header
+    let allow_any: Vec<String> = vec!["*".into(), "origin".into()];
+    assert_eq!(
+        RPCOptionsRequestHandler::allow_headers_value(Some("x-custom, authorization"), &allow_any),
+        "x-custom, authorization"
+    );
+    assert_eq!(
+        RPCOptionsRequestHandler::allow_headers_value(None, &allow_any),
+        "origin"
+    );
+}
+
+#[test]
+fn test_try_make_response() {
+    let addr = SocketAddr::new(IpAddr::V4(Ipv4Addr::new(127, 0, 0, 1)), 33333);
+
+    let mut requests = vec![];
+    requests.push(StacksHttpRequest::new_options(
+        addr.into(),
+        "/v2/info".into(),
+        Some("x-custom, authorization"),
+    ));
+    requests.push(StacksHttpRequest::new_options(
+        addr.into(),
+        "/v3/block_proposal".into(),
+        None,
+    ));
+
+    let mut responses = test_rpc(function_name!(), requests);
+    assert_eq!(responses.len(), 2);
+
+    // the default policy allows `authorization`, but not `x-custom`
+    let response = responses.remove(0);
+    let response_txt = String::from_utf8(response.try_serialize().unwrap()).unwrap();
+    debug!("Response:\n{response_txt}\n");
+    assert_eq!(response.preamble().status_code, 200);
+    assert_eq!(
+        response
+            .preamble()
+            .headers
+            .get("access-control-allow-headers")
+            .map(String::as_str),
+        Some("authorization")
+    );
+    assert_eq!(
+        response
+            .preamble()
+            .headers
+            .get("access-control-allow-origin")
+            .map(String::as_str),
+        Some("*")
+    );
+    assert!(!response_txt.contains("origin, content-type"));
+
+    // without requested headers, the whole default policy is listed
+    let response = responses.remove(0);
+    assert_eq!(response.preamble().status_code, 200);
+    assert_eq!(
+        response
+            .preamble()
+            .headers
+            .get("access-control-allow-headers")
+            .map(String::as_str),
+        Some("origin, content-type, authorization")
+    );
+}
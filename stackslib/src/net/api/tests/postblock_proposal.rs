@@ -23,6 +23,7 @@ use clarity::types::chainstate::{StacksPrivateKey, TrieHash};
 use clarity::util::secp256k1::MessageSignature;
 use clarity::util::vrf::VRFProof;
 use clarity::vm::ast::ASTRules;
+use clarity::vm::costs::ExecutionCost;
 use clarity::vm::types::{PrincipalData, QualifiedContractIdentifier, StacksAddressExtensions};
 use clarity::vm::{ClarityName, ContractName, Value};
 use mempool::{MemPoolDB, MemPoolEventDispatcher, ProposalCallbackReceiver};
@@ -49,8 +50,10 @@ use crate::chainstate::stacks::{
 use crate::core::BLOCK_LIMIT_MAINNET_21;
 use crate::net::api::*;
 use crate::net::connection::ConnectionOptions;
+use crate::net::http::{HttpResponsePayload, HttpResponsePreamble, HttpVersion};
 use crate::net::httpcore::{
     HttpRequestContentsExtensions, RPCRequestHandler, StacksHttp, StacksHttpRequest,
+    StacksHttpResponse,
 };
 use crate::net::relay::Relayer;
 use crate::net::test::TestEventObserver;
@@ -490,3 +493,66 @@ fn test_try_make_response() {
         }
     }
 }
+
+#[test]
+fn test_decode_block_validate_response() {
+    let decode = |body: &str| {
+        StacksHttpResponse::new(
+            HttpResponsePreamble::raw_ok_json(HttpVersion::Http11, false),
+            HttpResponsePayload::JSON(serde_json::from_str(body).unwrap()),
+        )
+        .decode_block_validate_response()
+    };
+    let signer_signature_hash = Sha512Trunc256Sum([0x11; 32]);
+
+    let ok = decode(&format!(
+        r#"{{
+            "result": "Ok",
+            "signer_signature_hash": "{signer_signature_hash}",
+            "cost": {{"write_length": 1, "write_count": 2, "read_length": 3, "read_count": 4, "runtime": 5}},
+            "size": 180,
+            "validation_time_ms": 42
+        }}"#
+    ))
+    .unwrap();
+    assert_eq!(
+        ok,
+        postblock_proposal::BlockValidateResponse::Ok(postblock_proposal::BlockValidateOk {
+            signer_signature_hash,
+            cost: ExecutionCost {
+                write_length: 1,
+                write_count: 2,
+                read_length: 3,
+                read_count: 4,
+                runtime: 5,
+            },
+            size: 180,
+            validation_time_ms: 42,
+        })
+    );
+    assert_eq!(ok.signer_signature_hash(), signer_signature_hash);
+
+    let reject = decode(&format!(
+        r#"{{
+            "result": "Reject",
+            "signer_signature_hash": "{signer_signature_hash}",
+            "reason": "Block timestamp is too far into the future",
+            "reason_code": "InvalidBlock"
+        }}"#
+    ))
+    .unwrap();
+    assert_eq!(
+        reject,
+        postblock_proposal::BlockValidateResponse::Reject(
+            postblock_proposal::BlockValidateReject {
+                signer_signature_hash,
+                reason: "Block timestamp is too far into the future".into(),
+                reason_code: ValidateRejectCode::InvalidBlock,
+            }
+        )
+    );
+    assert_eq!(reject.signer_signature_hash(), signer_signature_hash);
+
+    // an unknown result is not a validation response
+    decode(r#"{"result": "Accepted", "message": "Block proposal is processing"}"#).unwrap_err();
+}
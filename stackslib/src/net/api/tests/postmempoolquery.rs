@@ -346,6 +346,115 @@ fn test_stream_mempool_txs() {
     }
 }
 
+#[test]
+fn test_stream_mempool_txs_capped() {
+    let mut chainstate = instantiate_chainstate(false, 0x80000000, function_name!());
+    let chainstate_path = chainstate_path(function_name!());
+    let mut mempool = MemPoolDB::open_test(false, 0x80000000, &chainstate_path).unwrap();
+
+    let addr = StacksAddress::new(1, Hash160([0xff; 20])).unwrap();
+    let mut txs = vec![];
+    let block_height = 10;
+
+    let mut mempool_tx = mempool.tx_begin().unwrap();
+    for _ in 0..10 {
+        let pk = StacksPrivateKey::random();
+        let mut tx = StacksTransaction {
+            version: TransactionVersion::Testnet,
+            chain_id: 0x80000000,
+            auth: TransactionAuth::from_p2pkh(&pk).unwrap(),
+            anchor_mode: TransactionAnchorMode::Any,
+            post_condition_mode: TransactionPostConditionMode::Allow,
+            post_conditions: vec![],
+            payload: TransactionPayload::TokenTransfer(
+                addr.to_account_principal(),
+                123,
+                TokenTransferMemo([0u8; 34]),
+            ),
+        };
+        tx.set_tx_fee(1000);
+        tx.set_origin_nonce(0);
+
+        let txid = tx.txid();
+        let tx_bytes = tx.serialize_to_vec();
+        let origin_addr = tx.origin_address();
+        let origin_nonce = tx.get_origin_nonce();
+        let sponsor_addr = tx.sponsor_address().unwrap_or(origin_addr.clone());
+        let sponsor_nonce = tx.get_sponsor_nonce().unwrap_or(origin_nonce);
+        let tx_fee = tx.get_tx_fee();
+
+        MemPoolDB::try_add_tx(
+            &mut mempool_tx,
+            &mut chainstate,
+            &ConsensusHash([0x1 + (block_height as u8); 20]),
+            &BlockHeaderHash([0x2 + (block_height as u8); 32]),
+            false, // don't resolve the above chain tip since it doesn't exist
+            txid,
+            tx_bytes,
+            tx_fee,
+            block_height,
+            &origin_addr,
+            origin_nonce,
+            &sponsor_addr,
+            sponsor_nonce,
+            None,
+        )
+        .unwrap();
+
+        txs.push(tx);
+    }
+    mempool_tx.commit().unwrap();
+
+    // serve at most 3 transactions per response, as with `max_mempool_tx_per_response = 3`
+    let max_txs = 3;
+    let mut page_id = Txid([0u8; 32]);
+    let mut decoded_txs = vec![];
+    let mut num_pages = 0;
+    loop {
+        let mut tx_stream_data = StacksMemPoolStream::new(
+            mempool.reopen(false).unwrap(),
+            MemPoolSyncData::TxTags([0u8; 32], vec![]),
+            max_txs,
+            block_height,
+            Some(page_id),
+        );
+
+        let mut buf = vec![];
+        loop {
+            let chunk = tx_stream_data.generate_next_chunk().unwrap();
+            if chunk.is_empty() {
+                break;
+            }
+            buf.extend_from_slice(&chunk[..]);
+        }
+
+        let (mut next_txs, next_page) = decode_tx_stream(&mut &buf[..]).unwrap();
+        num_pages += 1;
+
+        // never more than the cap in a single response
+        assert!(next_txs.len() as u64 <= max_txs);
+        let truncated = next_txs.len() as u64 == max_txs;
+        decoded_txs.append(&mut next_txs);
+
+        if decoded_txs.len() < txs.len() {
+            // a truncated response must tell the client where to resume
+            assert!(truncated);
+            page_id = next_page.expect("truncated response did not include a next page ID");
+        } else if let Some(next_page) = next_page {
+            page_id = next_page;
+        } else {
+            break;
+        }
+    }
+
+    assert!(num_pages >= 4);
+
+    let tx_set: HashSet<_> = txs.iter().map(|tx| tx.txid()).collect();
+    let decoded_set: HashSet<_> = decoded_txs.iter().map(|tx| tx.txid()).collect();
+    assert_eq!(decoded_txs.len(), txs.len());
+    assert_eq!(tx_set, decoded_set);
+}
+
 #[test]
 fn test_decode_tx_stream() {
     let addr = StacksAddress::new(1, Hash160([0xff; 20])).unwrap();
@@ -37,6 +37,7 @@ use crate::core::mempool::MAX_BLOOM_COUNTER_TXS;
 use crate::monitoring::{update_inbound_bandwidth, update_outbound_bandwidth};
 use crate::net::codec::*;
 use crate::net::download::BLOCK_DOWNLOAD_INTERVAL;
+use crate::net::http::{HttpContentType, DEFAULT_CORS_ALLOWED_HEADERS};
 use crate::net::inv::{INV_REWARD_CYCLES, INV_SYNC_INTERVAL};
 use crate::net::neighbors::{
     MAX_NEIGHBOR_AGE, NEIGHBOR_REQUEST_TIMEOUT, NEIGHBOR_WALK_INTERVAL, NUM_INITIAL_WALKS,
@@ -417,8 +418,15 @@ pub struct ConnectionOptions {
     pub max_buffered_stackerdb_chunks: u64,
     /// how often to query a remote peer for its mempool, in seconds
     pub mempool_sync_interval: u64,
-    /// how many transactions to ask for in a mempool query
+    /// how many transactions to ask for in a mempool query, and the most we will serve in a
+    /// single mempool query response.  A truncated response ends with the page ID at which the
+    /// client should resume.
     pub mempool_max_tx_query: u64,
+    /// maximum number of bytes of chunked-encoding buffer that all streamed HTTP responses may
+    /// hold at once.  Requests that would exceed this get a 503.
+    pub max_http_stream_buffer_bytes: u64,
+    /// content type of HTTP responses whose handler does not set one
+    pub default_response_content_type: HttpContentType,
     /// how long a mempool sync is allowed to take, in total, before timing out
     pub mempool_sync_timeout: u64,
     /// socket read buffer size
@@ -438,6 +446,9 @@ pub struct ConnectionOptions {
     pub nakamoto_unconfirmed_downloader_interval_ms: u128,
     /// The authorization token to enable privileged RPC endpoints
     pub auth_token: Option<String>,
+    /// Lowercase header names that cross-origin requests may use, as answered to CORS preflight
+    /// requests.  `*` allows any header.
+    pub cors_allowed_headers: Vec<String>,
     /// The maximum age in seconds of a block that can be validated by the block proposal endpoint
     pub block_proposal_max_age_secs: u64,
     /// StackerDB replicas to talk to for a particular smart contract
@@ -565,6 +576,8 @@ impl std::default::Default for ConnectionOptions {
             max_buffered_stackerdb_chunks: 4096,
             mempool_sync_interval: 30, // number of seconds in-between mempool sync
             mempool_max_tx_query: 128, // maximum number of transactions to visit per mempool query
+            max_http_stream_buffer_bytes: 256 * 1024 * 1024, // at most 256 MiB of stream buffers
+            default_response_content_type: HttpContentType::JSON,
             mempool_sync_timeout: 180, // how long a mempool sync can go for (3 minutes)
             socket_recv_buffer_size: 131072, // Linux default
             socket_send_buffer_size: 16384, // Linux default
@@ -574,6 +587,10 @@ impl std::default::Default for ConnectionOptions {
             nakamoto_inv_sync_burst_interval_ms: 1_000, // wait 1 second after a sortition before running inventory sync
             nakamoto_unconfirmed_downloader_interval_ms: 5_000, // run unconfirmed downloader once every 5 seconds
             auth_token: None,
+            cors_allowed_headers: DEFAULT_CORS_ALLOWED_HEADERS
+                .iter()
+                .map(|header| header.to_string())
+                .collect(),
             block_proposal_max_age_secs: DEFAULT_BLOCK_PROPOSAL_MAX_AGE_SECS,
             stackerdb_hint_replicas: HashMap::new(),
 
@@ -14,6 +14,7 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
+use std::collections::BTreeMap;
 use std::io::Read;
 use std::net::SocketAddr;
 use std::str::FromStr;
@@ -42,11 +43,16 @@ pub enum HttpReservedHeader {
     ContentLength(u32),
     ContentType(HttpContentType),
     Host(PeerHost),
+    Location(String),
+    CacheControl(String),
 }
 
 impl HttpReservedHeader {
     pub fn is_reserved(header: &str) -> bool {
-        matches!(header, "content-length" | "content-type" | "host")
+        matches!(
+            header,
+            "content-length" | "content-type" | "host" | "location" | "cache-control"
+        )
     }
 
     pub fn try_from_str(header: &str, value: &str) -> Option<HttpReservedHeader> {
@@ -64,6 +70,11 @@ impl HttpReservedHeader {
                 Ok(ph) => Some(HttpReservedHeader::Host(ph)),
                 Err(_) => None,
             },
+            // a location with control characters (e.g. CR/LF) could split the header
+            "location" if !value.chars().any(|c| c.is_control()) => {
+                Some(HttpReservedHeader::Location(value.to_string()))
+            }
+            "cache-control" => Some(HttpReservedHeader::CacheControl(value.to_string())),
             _ => None,
         }
     }
@@ -73,6 +84,99 @@ impl HttpReservedHeader {
 pub const HTTP_PREAMBLE_MAX_ENCODED_SIZE: u32 = 4096;
 /// Maximum number of headers in an HTTP request or response
 pub const HTTP_PREAMBLE_MAX_NUM_HEADERS: usize = 64;
+/// Maximum length of a single HTTP header name
+pub const HTTP_PREAMBLE_MAX_HEADER_NAME_LEN: usize = 256;
+
+/// HTTP headers, keyed by lowercase name.  A header may be given several times (e.g.
+/// `Set-Cookie`), so each name maps to all of its values, in the order they were given.
+#[derive(Debug, Clone, PartialEq, Default)]
+pub struct HttpHeaderMap {
+    headers: BTreeMap<String, Vec<String>>,
+}
+
+impl HttpHeaderMap {
+    pub fn new() -> HttpHeaderMap {
+        HttpHeaderMap {
+            headers: BTreeMap::new(),
+        }
+    }
+
+    /// Get the first value of a header
+    pub fn get(&self, key: &str) -> Option<&String> {
+        self.headers.get(key).and_then(|values| values.first())
+    }
+
+    /// Get all values of a header, in the order they were given
+    pub fn get_all(&self, key: &str) -> &[String] {
+        self.headers
+            .get(key)
+            .map(|values| values.as_slice())
+            .unwrap_or(&[])
+    }
+
+    /// Set a header, replacing any values it already has
+    pub fn insert(&mut self, key: String, value: String) {
+        self.headers.insert(key, vec![value]);
+    }
+
+    /// Add a value to a header, after any values it already has
+    pub fn append(&mut self, key: String, value: String) {
+        self.headers.entry(key).or_default().push(value);
+    }
+
+    /// Remove a header, returning its values if it was present
+    pub fn remove(&mut self, key: &str) -> Option<Vec<String>> {
+        self.headers.remove(key)
+    }
+
+    pub fn contains_key(&self, key: &str) -> bool {
+        self.headers.contains_key(key)
+    }
+
+    /// Number of distinct headers
+    pub fn len(&self) -> usize {
+        self.headers.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.headers.is_empty()
+    }
+
+    pub fn clear(&mut self) {
+        self.headers.clear();
+    }
+
+    /// Iterate over every (name, value) pair, with the values of a repeated header in the order
+    /// they were given
+    pub fn iter(&self) -> impl Iterator<Item = (&String, &String)> {
+        self.headers
+            .iter()
+            .flat_map(|(key, values)| values.iter().map(move |value| (key, value)))
+    }
+}
+
+/// Strip the optional whitespace (spaces and horizontal tabs) that may surround an HTTP header
+/// value.  Whitespace within the value is preserved.
+pub fn trim_header_value(value: &str) -> &str {
+    value.trim_matches(|c| c == ' ' || c == '\t')
+}
+
+/// Decode the value of a `Connection:` header into whether or not to keep the connection alive.
+/// The value is a comma-separated list of tokens.  A `close` token takes precedence over a
+/// `keep-alive` token, and all other tokens (e.g. `Upgrade`) are ignored.
+/// Returns `default_keep_alive` (the HTTP version's default) if there is neither a `close` nor a
+/// `keep-alive` token.
+pub fn parse_connection_header(value: &str, default_keep_alive: bool) -> bool {
+    let mut keep_alive = default_keep_alive;
+    for token in value.split(',') {
+        match token.trim().to_lowercase().as_str() {
+            "close" => return false,
+            "keep-alive" => keep_alive = true,
+            _ => {}
+        }
+    }
+    keep_alive
+}
 
 /// Helper function to parse a SIP-003 bytestream.  The first 4 bytes are a big-endian length prefix
 pub fn parse_bytestream<R: Read, T: StacksMessageCodec>(
@@ -135,6 +239,30 @@ pub fn parse_raw_bytes(
     }
 }
 
+/// How to handle invalid UTF-8 in a `text/plain` body
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum TextDecodeMode {
+    /// Fail to decode the body if it is not valid UTF-8
+    Strict,
+    /// Replace invalid UTF-8 sequences with U+FFFD
+    Lossy,
+}
+
+/// Helper function to read `text/plain` content, decoding it according to `mode`
+pub fn parse_text(
+    preamble: &HttpResponsePreamble,
+    body: &[u8],
+    max_len: u64,
+    mode: TextDecodeMode,
+) -> Result<String, Error> {
+    let text_bytes = parse_raw_bytes(preamble, body, max_len, HttpContentType::Text)?;
+    match mode {
+        TextDecodeMode::Strict => String::from_utf8(text_bytes)
+            .map_err(|e| Error::DecodeError(format!("Invalid UTF-8 in text body: {e}"))),
+        TextDecodeMode::Lossy => Ok(String::from_utf8_lossy(&text_bytes).into_owned()),
+    }
+}
+
 /// Helper function to read `application/octet-stream` content
 pub fn parse_bytes(
     preamble: &HttpResponsePreamble,
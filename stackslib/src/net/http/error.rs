@@ -17,6 +17,7 @@
 use std::io;
 use std::io::Read;
 
+use serde::{Deserialize, Serialize};
 use serde_json;
 use stacks_common::codec::MAX_MESSAGE_LEN;
 use stacks_common::util::retry::BoundReader;
@@ -63,6 +64,42 @@ pub fn try_parse_error_response(
     }
 }
 
+/// The canonical JSON body of an HTTP error response, e.g.
+/// `{"error": "No such block", "reason": "Block is not stored", "code": 404}`
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HttpErrorPayload {
+    /// What went wrong
+    pub error: String,
+    /// Why it went wrong, if known
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub reason: Option<String>,
+    /// The HTTP status code of the response
+    pub code: u16,
+}
+
+impl HttpErrorPayload {
+    pub fn new(code: u16, error: String, reason: Option<String>) -> Self {
+        Self {
+            error,
+            reason,
+            code,
+        }
+    }
+
+    /// Decode the body of an error response.
+    /// Returns None if the response is not an error, is not JSON, or does not hold an
+    /// `HttpErrorPayload`.
+    pub fn try_from_response(preamble: &HttpResponsePreamble, body: &[u8]) -> Option<Self> {
+        if preamble.status_code < 400 || preamble.content_type != HttpContentType::JSON {
+            return None;
+        }
+        let mut ioc = io::Cursor::new(body);
+        let mut bound_fd =
+            BoundReader::from_reader(&mut ioc, body.len().min(MAX_MESSAGE_LEN as usize) as u64);
+        serde_json::from_reader(&mut bound_fd).ok()
+    }
+}
+
 /// Decode an HTTP status code into a reason
 pub fn http_reason(code: u16) -> &'static str {
     match code {
@@ -83,6 +120,8 @@ pub fn http_reason(code: u16) -> &'static str {
         304 => "Not Modified",
         305 => "Use Proxy",
         307 => "Temporary Redirect",
+        // from RFC 7538
+        308 => "Permanent Redirect",
         400 => "Bad Request",
         401 => "Unauthorized",
         402 => "Payment Required",
@@ -120,6 +159,12 @@ pub trait HttpErrorResponse {
         preamble: &HttpResponsePreamble,
         body: &[u8],
     ) -> Result<HttpResponsePayload, Error>;
+
+    /// Extra headers to send with the error response.
+    /// By default, there are none.
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
 }
 
 pub fn http_error_from_code_and_text(code: u16, message: String) -> Box<dyn HttpErrorResponse> {
@@ -129,8 +174,12 @@ pub fn http_error_from_code_and_text(code: u16, message: String) -> Box<dyn Http
         402 => Box::new(HttpPaymentRequired::new(message)),
         403 => Box::new(HttpForbidden::new(message)),
         404 => Box::new(HttpNotFound::new(message)),
+        416 => Box::new(HttpRangeNotSatisfiable::new(message, None)),
+        417 => Box::new(HttpExpectationFailed::new(message)),
         500 => Box::new(HttpServerError::new(message)),
         503 => Box::new(HttpServiceUnavailable::new(message)),
+        504 => Box::new(HttpGatewayTimeout::new(message)),
+        505 => Box::new(HttpVersionNotSupported::new(message)),
         _ => Box::new(HttpError::new(code, message)),
     }
 }
@@ -346,6 +395,159 @@ impl HttpErrorResponse for HttpServiceUnavailable {
     }
 }
 
+/// HTTP 405
+pub struct HttpMethodNotAllowed {
+    error_text: String,
+    /// the methods that the resource does support, for the `Allow` header
+    allowed_methods: Vec<String>,
+}
+
+impl HttpMethodNotAllowed {
+    pub fn new(error_text: String, allowed_methods: Vec<String>) -> Self {
+        Self {
+            error_text,
+            allowed_methods,
+        }
+    }
+}
+
+impl HttpErrorResponse for HttpMethodNotAllowed {
+    fn code(&self) -> u16 {
+        405
+    }
+    fn payload(&self) -> HttpResponsePayload {
+        HttpResponsePayload::Text(self.error_text.clone())
+    }
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        try_parse_error_response(preamble.status_code, preamble.content_type, body)
+    }
+    fn headers(&self) -> Vec<(String, String)> {
+        vec![("Allow".to_string(), self.allowed_methods.join(", "))]
+    }
+}
+
+/// HTTP 416
+pub struct HttpRangeNotSatisfiable {
+    error_text: String,
+    /// length of the response body the range was checked against, for the `Content-Range` header
+    total_len: Option<u64>,
+}
+
+impl HttpRangeNotSatisfiable {
+    pub fn new(error_text: String, total_len: Option<u64>) -> Self {
+        Self {
+            error_text,
+            total_len,
+        }
+    }
+}
+
+impl HttpErrorResponse for HttpRangeNotSatisfiable {
+    fn code(&self) -> u16 {
+        416
+    }
+    fn payload(&self) -> HttpResponsePayload {
+        HttpResponsePayload::Text(self.error_text.clone())
+    }
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        try_parse_error_response(preamble.status_code, preamble.content_type, body)
+    }
+    fn headers(&self) -> Vec<(String, String)> {
+        self.total_len
+            .map(|total_len| vec![("Content-Range".to_string(), format!("bytes */{total_len}"))])
+            .unwrap_or_default()
+    }
+}
+
+/// HTTP 417
+pub struct HttpExpectationFailed {
+    error_text: String,
+}
+
+impl HttpExpectationFailed {
+    pub fn new(error_text: String) -> Self {
+        Self { error_text }
+    }
+}
+
+impl HttpErrorResponse for HttpExpectationFailed {
+    fn code(&self) -> u16 {
+        417
+    }
+    fn payload(&self) -> HttpResponsePayload {
+        HttpResponsePayload::JSON(serde_json::json!({ "error": self.error_text }))
+    }
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        try_parse_error_response(preamble.status_code, preamble.content_type, body)
+    }
+}
+
+/// HTTP 504
+pub struct HttpGatewayTimeout {
+    error_text: String,
+}
+
+impl HttpGatewayTimeout {
+    pub fn new(error_text: String) -> Self {
+        Self { error_text }
+    }
+}
+
+impl HttpErrorResponse for HttpGatewayTimeout {
+    fn code(&self) -> u16 {
+        504
+    }
+    fn payload(&self) -> HttpResponsePayload {
+        HttpResponsePayload::JSON(serde_json::json!({ "error": self.error_text }))
+    }
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        try_parse_error_response(preamble.status_code, preamble.content_type, body)
+    }
+}
+
+/// HTTP 505
+pub struct HttpVersionNotSupported {
+    error_text: String,
+}
+
+impl HttpVersionNotSupported {
+    pub fn new(error_text: String) -> Self {
+        Self { error_text }
+    }
+}
+
+impl HttpErrorResponse for HttpVersionNotSupported {
+    fn code(&self) -> u16 {
+        505
+    }
+    fn payload(&self) -> HttpResponsePayload {
+        HttpResponsePayload::JSON(serde_json::json!({ "error": self.error_text }))
+    }
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        try_parse_error_response(preamble.status_code, preamble.content_type, body)
+    }
+}
+
 /// Catch-all for any other HTTP error response
 pub struct HttpError {
     error: u16,
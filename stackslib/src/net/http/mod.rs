@@ -16,6 +16,7 @@
 
 pub mod common;
 pub mod error;
+pub mod multipart;
 pub mod request;
 pub mod response;
 pub mod stream;
@@ -23,8 +24,9 @@ pub mod stream;
 #[cfg(test)]
 mod tests;
 
-use std::collections::BTreeMap;
+use std::collections::HashSet;
 use std::io::Write;
+use std::path::Path;
 use std::str::FromStr;
 use std::{fmt, io};
 
@@ -33,19 +35,25 @@ use serde_json;
 use stacks_common::codec::Error as CodecError;
 
 pub use crate::net::http::common::{
-    parse_bytes, parse_bytestream, parse_json, HttpReservedHeader, HttpVersion,
-    HTTP_PREAMBLE_MAX_NUM_HEADERS,
+    parse_bytes, parse_bytestream, parse_json, parse_text, HttpHeaderMap, HttpReservedHeader,
+    HttpVersion, TextDecodeMode, HTTP_PREAMBLE_MAX_NUM_HEADERS,
 };
 pub use crate::net::http::error::{
-    http_error_from_code_and_text, http_reason, HttpBadRequest, HttpError, HttpErrorResponse,
-    HttpForbidden, HttpNotFound, HttpPaymentRequired, HttpServerError, HttpServiceUnavailable,
-    HttpUnauthorized,
+    http_error_from_code_and_text, http_reason, HttpBadRequest, HttpError, HttpErrorPayload,
+    HttpErrorResponse, HttpExpectationFailed, HttpForbidden, HttpGatewayTimeout,
+    HttpMethodNotAllowed, HttpNotFound, HttpPaymentRequired, HttpRangeNotSatisfiable,
+    HttpServerError, HttpServiceUnavailable, HttpUnauthorized, HttpVersionNotSupported,
+};
+pub use crate::net::http::multipart::{
+    decode_multipart, encode_multipart, parse_multipart, MultipartBoundary, MultipartPart,
 };
 pub use crate::net::http::request::{
-    HttpRequest, HttpRequestContents, HttpRequestPayload, HttpRequestPreamble,
+    HttpByteRange, HttpRequest, HttpRequestContents, HttpRequestPayload, HttpRequestPreamble,
 };
 pub use crate::net::http::response::{
-    HttpResponse, HttpResponseContents, HttpResponsePayload, HttpResponsePreamble,
+    decode_content_encoding, read_response_body, try_decode_response, HttpResponse,
+    HttpResponseContents, HttpResponsePayload, HttpResponsePreamble, HttpStreamBufferPool,
+    HttpStreamBufferReservation, DEFAULT_CORS_ALLOWED_HEADERS, HTTP_DEFAULT_CHARSET,
 };
 pub use crate::net::http::stream::HttpChunkGenerator;
 
@@ -67,6 +75,20 @@ pub enum Error {
     Http(u16, String),
     /// Application error
     AppError(String),
+    /// The peer responded with a status code the response handler does not accept
+    UnexpectedStatus { got: u16, allowed: Vec<u16> },
+    /// The request used a method that the server refuses to serve
+    MethodNotAllowed {
+        method: String,
+        allowed: Vec<String>,
+    },
+    /// The request used an HTTP version that the server does not support
+    VersionNotSupported(String),
+    /// The request's `Range` header is malformed, or asks for bytes outside the `total_len`-byte
+    /// response body
+    RangeNotSatisfiable { total_len: u64, reason: String },
+    /// The peer answered with an error response whose body is an `HttpErrorPayload`
+    HttpErrorResponse(HttpErrorPayload),
 }
 
 impl fmt::Display for Error {
@@ -80,6 +102,30 @@ impl fmt::Display for Error {
             Error::UnderflowError(msg) => write!(f, "{}", msg),
             Error::Http(code, msg) => write!(f, "code={}, msg={}", code, msg),
             Error::AppError(msg) => write!(f, "{}", &msg),
+            Error::UnexpectedStatus { got, allowed } => write!(
+                f,
+                "Unexpected HTTP status {}; expected one of {:?}",
+                got, allowed
+            ),
+            Error::MethodNotAllowed { method, allowed } => write!(
+                f,
+                "Method {} not allowed; allowed methods are {:?}",
+                method, allowed
+            ),
+            Error::VersionNotSupported(version) => {
+                write!(f, "HTTP version {} not supported", version)
+            }
+            Error::RangeNotSatisfiable { total_len, reason } => {
+                write!(f, "{} (body is {} bytes)", reason, total_len)
+            }
+            Error::HttpErrorResponse(payload) => match &payload.reason {
+                Some(reason) => write!(
+                    f,
+                    "code={}, error={}, reason={}",
+                    payload.code, payload.error, reason
+                ),
+                None => write!(f, "code={}, error={}", payload.code, payload.error),
+            },
         }
     }
 }
@@ -95,6 +141,11 @@ impl std::error::Error for Error {
             Error::UnderflowError(_) => None,
             Error::Http(..) => None,
             Error::AppError(_) => None,
+            Error::UnexpectedStatus { .. } => None,
+            Error::MethodNotAllowed { .. } => None,
+            Error::VersionNotSupported(_) => None,
+            Error::RangeNotSatisfiable { .. } => None,
+            Error::HttpErrorResponse(_) => None,
         }
     }
 }
@@ -143,6 +194,23 @@ impl Error {
                 "Unhandled application error: {:?}",
                 &x
             ))),
+            Error::UnexpectedStatus { got, allowed } => Box::new(HttpServerError::new(format!(
+                "Unexpected HTTP status {}; expected one of {:?}",
+                got, allowed
+            ))),
+            Error::MethodNotAllowed { method, allowed } => Box::new(HttpMethodNotAllowed::new(
+                format!("Method {} not allowed\n", method),
+                allowed,
+            )),
+            Error::VersionNotSupported(version) => Box::new(HttpVersionNotSupported::new(format!(
+                "HTTP version {version} is not supported"
+            ))),
+            Error::RangeNotSatisfiable { total_len, reason } => {
+                Box::new(HttpRangeNotSatisfiable::new(reason, Some(total_len)))
+            }
+            Error::HttpErrorResponse(payload) => {
+                http_error_from_code_and_text(payload.code, payload.error)
+            }
         }
     }
 }
@@ -153,11 +221,20 @@ pub enum HttpContentType {
     Bytes,
     Text,
     JSON,
+    /// `application/gzip`
+    Gzip,
+    /// `multipart/mixed`, with the boundary between its parts
+    Multipart(MultipartBoundary),
 }
 
 impl fmt::Display for HttpContentType {
     fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
-        write!(f, "{}", self.as_str())
+        match self {
+            HttpContentType::Multipart(boundary) => {
+                write!(f, "{}; boundary={}", self.as_str(), boundary)
+            }
+            _ => write!(f, "{}", self.as_str()),
+        }
     }
 }
 
@@ -167,8 +244,30 @@ impl HttpContentType {
             HttpContentType::Bytes => "application/octet-stream",
             HttpContentType::Text => "text/plain",
             HttpContentType::JSON => "application/json",
+            HttpContentType::Gzip => "application/gzip",
+            HttpContentType::Multipart(_) => "multipart/mixed",
+        }
+    }
+
+    /// Infer the content type of a file served from `path` from its extension.  Unknown
+    /// extensions (and `.bin`) are served as `application/octet-stream`.
+    pub fn from_path(path: &Path) -> HttpContentType {
+        let extension = path
+            .extension()
+            .and_then(|extension| extension.to_str())
+            .map(|extension| extension.to_ascii_lowercase());
+        match extension.as_deref() {
+            Some("json") => HttpContentType::JSON,
+            Some("txt") => HttpContentType::Text,
+            Some("gz") => HttpContentType::Gzip,
+            _ => HttpContentType::Bytes,
         }
     }
+
+    /// Is this a text-family content type, whose charset should be advertised?
+    pub fn is_text(&self) -> bool {
+        matches!(self, HttpContentType::Text | HttpContentType::JSON)
+    }
 }
 
 impl FromStr for HttpContentType {
@@ -176,12 +275,35 @@ impl FromStr for HttpContentType {
 
     fn from_str(header: &str) -> Result<HttpContentType, CodecError> {
         let s = header.to_string().to_lowercase();
-        if s == "application/octet-stream" {
+        // parameters (e.g. `; charset=utf-8`) follow the MIME type
+        let mut parts = s.split(';');
+        let mime_type = parts.next().unwrap_or("").trim();
+        if mime_type == "application/octet-stream" {
             Ok(HttpContentType::Bytes)
-        } else if s == "text/plain" || s.starts_with("text/plain;") {
+        } else if mime_type == "text/plain" {
             Ok(HttpContentType::Text)
-        } else if s == "application/json" || s.starts_with("application/json;") {
+        } else if mime_type == "application/json" {
             Ok(HttpContentType::JSON)
+        } else if mime_type == "application/gzip" {
+            Ok(HttpContentType::Gzip)
+        } else if mime_type == "multipart/mixed" {
+            // the boundary is case-sensitive, so take it from the original header
+            let boundary = header
+                .split(';')
+                .skip(1)
+                .filter_map(|param| {
+                    let (name, value) = param.trim().split_once('=')?;
+                    name.trim()
+                        .eq_ignore_ascii_case("boundary")
+                        .then_some(value.trim())
+                })
+                .next()
+                .ok_or_else(|| {
+                    CodecError::DeserializeError(format!("Missing multipart boundary: {header}"))
+                })?;
+            Ok(HttpContentType::Multipart(MultipartBoundary::from_str(
+                boundary.trim_matches('"'),
+            )?))
         } else {
             Err(CodecError::DeserializeError(format!(
                 "Unsupported HTTP content type: {header}"
@@ -190,12 +312,198 @@ impl FromStr for HttpContentType {
     }
 }
 
+/// Content-codings a response body can be compressed with before it is sent
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum HttpContentEncoding {
+    /// `gzip`
+    Gzip,
+    /// `deflate` (zlib-wrapped, per RFC 1950)
+    Deflate,
+    /// `zstd`
+    #[cfg(feature = "zstd_encoding")]
+    Zstd,
+}
+
+impl fmt::Display for HttpContentEncoding {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl HttpContentEncoding {
+    /// The `Content-Encoding` value for this coding
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            HttpContentEncoding::Gzip => "gzip",
+            HttpContentEncoding::Deflate => "deflate",
+            #[cfg(feature = "zstd_encoding")]
+            HttpContentEncoding::Zstd => "zstd",
+        }
+    }
+}
+
+/// Content types a metrics endpoint can serve, negotiated via the request's `Accept` header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub enum MetricsContentType {
+    /// Prometheus text exposition format
+    Prometheus,
+    /// OpenMetrics text format
+    OpenMetrics,
+}
+
+impl fmt::Display for MetricsContentType {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl MetricsContentType {
+    pub fn as_str(&self) -> &'static str {
+        match *self {
+            MetricsContentType::Prometheus => "text/plain; version=0.0.4",
+            MetricsContentType::OpenMetrics => {
+                "application/openmetrics-text; version=1.0.0; charset=utf-8"
+            }
+        }
+    }
+
+    /// Pick the content type to serve for the given `Accept` header value.
+    /// OpenMetrics is only served if the client weights it strictly higher than plain text;
+    /// otherwise (including when there is no `Accept` header) the Prometheus format is used.
+    pub fn from_accept_header(accept: Option<&str>) -> MetricsContentType {
+        let Some(accept) = accept else {
+            return MetricsContentType::Prometheus;
+        };
+        let mut openmetrics_q = 0.0f64;
+        let mut text_q = 0.0f64;
+        for media_range in accept.split(',') {
+            let mut parts = media_range.split(';').map(|part| part.trim());
+            let media_type = parts.next().unwrap_or("").to_lowercase();
+            let q = parts
+                .filter_map(|param| param.strip_prefix("q="))
+                .find_map(|q| q.parse::<f64>().ok())
+                .unwrap_or(1.0);
+            match media_type.as_str() {
+                "application/openmetrics-text" => openmetrics_q = openmetrics_q.max(q),
+                "text/plain" | "text/*" | "*/*" => text_q = text_q.max(q),
+                _ => {}
+            }
+        }
+        if openmetrics_q > text_q {
+            MetricsContentType::OpenMetrics
+        } else {
+            MetricsContentType::Prometheus
+        }
+    }
+
+    /// Convert a body in the Prometheus text exposition format into this content type.
+    /// For OpenMetrics, counter families are named without their `_total` suffix (which their
+    /// samples must carry), `untyped` families become `unknown`, quotes in help text are
+    /// escaped, timestamps are converted from milliseconds to seconds, blank lines and comments
+    /// are dropped, and the body is terminated with an `# EOF` line.
+    pub fn format_body(&self, body: String) -> String {
+        match *self {
+            MetricsContentType::Prometheus => body,
+            MetricsContentType::OpenMetrics => Self::prometheus_to_openmetrics(&body),
+        }
+    }
+
+    fn prometheus_to_openmetrics(body: &str) -> String {
+        let mut counters = HashSet::new();
+        for line in body.lines() {
+            if let Some((name, "counter")) = line
+                .strip_prefix("# TYPE ")
+                .and_then(|rest| rest.trim().split_once(' '))
+            {
+                counters.insert(name.to_string());
+            }
+        }
+        // a counter's family name drops the `_total` suffix of its samples
+        let family_name = |name: &str| -> String {
+            if counters.contains(name) {
+                name.strip_suffix("_total").unwrap_or(name).to_string()
+            } else {
+                name.to_string()
+            }
+        };
+
+        let mut out = String::with_capacity(body.len() + 6);
+        for line in body.lines() {
+            if line.trim().is_empty() {
+                continue;
+            }
+            if let Some(rest) = line.strip_prefix("# HELP ") {
+                let (name, help) = rest.split_once(' ').unwrap_or((rest, ""));
+                out.push_str(&format!(
+                    "# HELP {} {}\n",
+                    family_name(name),
+                    help.replace('"', "\\\"")
+                ));
+            } else if let Some(rest) = line.strip_prefix("# TYPE ") {
+                let (name, metric_type) = rest.split_once(' ').unwrap_or((rest, "untyped"));
+                let metric_type = match metric_type.trim() {
+                    "untyped" => "unknown",
+                    metric_type => metric_type,
+                };
+                out.push_str(&format!("# TYPE {} {metric_type}\n", family_name(name)));
+            } else if line.starts_with('#') {
+                continue;
+            } else {
+                out.push_str(&Self::openmetrics_sample(line, &counters));
+                out.push('\n');
+            }
+        }
+        out.push_str("# EOF\n");
+        out
+    }
+
+    /// Convert one Prometheus sample line, `name{labels} value [timestamp_ms]`, to OpenMetrics
+    fn openmetrics_sample(line: &str, counters: &HashSet<String>) -> String {
+        let name_end = line.find(['{', ' ']).unwrap_or(line.len());
+        let (name, rest) = line.split_at(name_end);
+        // find the end of the labels, skipping over quoted label values
+        let mut labels_end = 0;
+        if rest.starts_with('{') {
+            let mut in_quotes = false;
+            let mut escaped = false;
+            for (i, c) in rest.char_indices() {
+                match c {
+                    _ if escaped => escaped = false,
+                    '\\' => escaped = true,
+                    '"' => in_quotes = !in_quotes,
+                    '}' if !in_quotes => {
+                        labels_end = i + 1;
+                        break;
+                    }
+                    _ => {}
+                }
+            }
+        }
+        let (labels, value_and_timestamp) = rest.split_at(labels_end);
+        let name = if counters.contains(name) && !name.ends_with("_total") {
+            format!("{name}_total")
+        } else {
+            name.to_string()
+        };
+        let mut fields = value_and_timestamp.split_whitespace();
+        let value = fields.next().unwrap_or("");
+        match fields.next().map(|ts| ts.parse::<i64>()) {
+            Some(Ok(timestamp_ms)) => format!(
+                "{name}{labels} {value} {}.{:03}",
+                timestamp_ms.div_euclid(1000),
+                timestamp_ms.rem_euclid(1000)
+            ),
+            Some(Err(_)) | None => format!("{name}{labels} {value}"),
+        }
+    }
+}
+
 /// Write out a set of HTTP headers to the given Write implementation
-pub fn write_headers<W: Write>(
+pub fn write_headers<'a, W: Write>(
     fd: &mut W,
-    headers: &BTreeMap<String, String>,
+    headers: impl IntoIterator<Item = (&'a String, &'a String)>,
 ) -> Result<(), CodecError> {
-    for (key, value) in headers.iter() {
+    for (key, value) in headers {
         fd.write_all(key.as_str().as_bytes())
             .map_err(CodecError::WriteError)?;
         fd.write_all(": ".as_bytes())
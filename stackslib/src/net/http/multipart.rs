@@ -0,0 +1,298 @@
+// Copyright (C) 2013-2020 Blockstack PBC, a public benefit corporation
+// Copyright (C) 2020-2025 Stacks Open Internet Foundation
+//
+// This program is free software: you can redistribute it and/or modify
+// it under the terms of the GNU General Public License as published by
+// the Free Software Foundation, either version 3 of the License, or
+// (at your option) any later version.
+//
+// This program is distributed in the hope that it will be useful,
+// but WITHOUT ANY WARRANTY; without even the implied warranty of
+// MERCHANTABILITY or FITNESS FOR A PARTICULAR PURPOSE.  See the
+// GNU General Public License for more details.
+//
+// You should have received a copy of the GNU General Public License
+// along with this program.  If not, see <http://www.gnu.org/licenses/>.
+
+use std::fmt;
+use std::str::FromStr;
+
+use stacks_common::codec::Error as CodecError;
+use stacks_common::util::hash::{to_hex, Sha512Trunc256Sum};
+
+use crate::net::http::{Error, HttpContentType, HttpResponsePreamble};
+
+/// The longest multipart boundary allowed by RFC 2046
+const MULTIPART_BOUNDARY_MAX_LEN: usize = 70;
+
+/// Boundary between the parts of a `multipart/mixed` HTTP body.  Any boundary allowed by
+/// RFC 2046 is accepted: 1 to 70 characters from its `bchars` set, not ending in a space.
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct MultipartBoundary {
+    /// The boundary's characters, followed by zeros
+    bytes: [u8; MULTIPART_BOUNDARY_MAX_LEN],
+    /// How many of `bytes` make up the boundary
+    len: u8,
+}
+
+/// Formats the boundary as a `boundary` parameter value, quoting it if it holds characters
+/// that cannot appear in an unquoted parameter
+impl fmt::Display for MultipartBoundary {
+    fn fmt(&self, f: &mut fmt::Formatter) -> fmt::Result {
+        let boundary = self.as_str();
+        if boundary.bytes().any(|c| b"'()+,/:=? ".contains(&c)) {
+            write!(f, "\"{boundary}\"")
+        } else {
+            write!(f, "{boundary}")
+        }
+    }
+}
+
+impl FromStr for MultipartBoundary {
+    type Err = CodecError;
+
+    fn from_str(s: &str) -> Result<MultipartBoundary, CodecError> {
+        if s.is_empty() || s.len() > MULTIPART_BOUNDARY_MAX_LEN {
+            return Err(CodecError::DeserializeError(format!(
+                "Invalid multipart boundary length: {s}"
+            )));
+        }
+        if !s.bytes().all(Self::is_bchar) || s.ends_with(' ') {
+            return Err(CodecError::DeserializeError(format!(
+                "Invalid multipart boundary: {s}"
+            )));
+        }
+        let mut bytes = [0u8; MULTIPART_BOUNDARY_MAX_LEN];
+        bytes[..s.len()].copy_from_slice(s.as_bytes());
+        Ok(MultipartBoundary {
+            bytes,
+            len: u8::try_from(s.len()).expect("FATAL: multipart boundary exceeds 70 bytes"),
+        })
+    }
+}
+
+impl MultipartBoundary {
+    /// Is `c` one of RFC 2046's `bchars`?
+    fn is_bchar(c: u8) -> bool {
+        c.is_ascii_alphanumeric() || b"'()+_,-./:=? ".contains(&c)
+    }
+
+    /// The boundary itself, as it appears in the body's delimiter lines
+    pub fn as_str(&self) -> &str {
+        // only ASCII bchars are ever stored
+        std::str::from_utf8(&self.bytes[..usize::from(self.len)])
+            .expect("FATAL: multipart boundary is not ASCII")
+    }
+
+    /// Generate the boundary for a body made up of `parts`.  The boundary is derived from a hash
+    /// of the parts, so while it is not guaranteed to be absent from their data, it is very
+    /// unlikely to occur there by chance.
+    pub fn from_parts(parts: &[MultipartPart]) -> MultipartBoundary {
+        let mut data = vec![];
+        for part in parts.iter() {
+            for field in [
+                part.name.as_bytes(),
+                part.content_type.to_string().as_bytes(),
+                part.data.as_slice(),
+            ] {
+                data.extend_from_slice(&(field.len() as u64).to_be_bytes());
+                data.extend_from_slice(field);
+            }
+        }
+        let hash = Sha512Trunc256Sum::from_data(&data);
+        MultipartBoundary::from_str(&to_hex(&hash.0[0..16]))
+            .expect("FATAL: hex multipart boundary is invalid")
+    }
+
+    /// The delimiter line that precedes each part
+    fn delimiter(&self) -> Vec<u8> {
+        format!("--{}", self.as_str()).into_bytes()
+    }
+}
+
+/// One named part of a `multipart/mixed` HTTP body
+#[derive(Debug, Clone, PartialEq)]
+pub struct MultipartPart {
+    /// Name of the part.  Any `"`, `\r`, `\n`, or `%` in it is percent-encoded on the wire.
+    pub name: String,
+    /// Content type of the part's data
+    pub content_type: HttpContentType,
+    /// The part's data
+    pub data: Vec<u8>,
+}
+
+impl MultipartPart {
+    pub fn new(name: &str, content_type: HttpContentType, data: Vec<u8>) -> MultipartPart {
+        MultipartPart {
+            name: name.to_string(),
+            content_type,
+            data,
+        }
+    }
+}
+
+/// Percent-encode the characters of a part name that would end its quoted
+/// `Content-Disposition` parameter or header line.  `%` is encoded too, so the name decodes
+/// unambiguously.
+fn escape_part_name(name: &str) -> String {
+    let mut escaped = String::with_capacity(name.len());
+    for c in name.chars() {
+        match c {
+            '"' => escaped.push_str("%22"),
+            '\r' => escaped.push_str("%0D"),
+            '\n' => escaped.push_str("%0A"),
+            '%' => escaped.push_str("%25"),
+            c => escaped.push(c),
+        }
+    }
+    escaped
+}
+
+/// Undo `escape_part_name()`.  Other `%` sequences are left as they are.
+fn unescape_part_name(name: &str) -> String {
+    let mut unescaped = String::with_capacity(name.len());
+    let mut rest = name;
+    while let Some(i) = rest.find('%') {
+        unescaped.push_str(&rest[..i]);
+        let decoded = match rest
+            .get(i + 1..i + 3)
+            .map(str::to_ascii_uppercase)
+            .as_deref()
+        {
+            Some("22") => Some('"'),
+            Some("0D") => Some('\r'),
+            Some("0A") => Some('\n'),
+            Some("25") => Some('%'),
+            _ => None,
+        };
+        if let Some(c) = decoded {
+            unescaped.push(c);
+            rest = &rest[i + 3..];
+        } else {
+            unescaped.push('%');
+            rest = &rest[i + 1..];
+        }
+    }
+    unescaped.push_str(rest);
+    unescaped
+}
+
+/// Encode `parts` as a `multipart/mixed` body, delimited by `boundary`
+pub fn encode_multipart(boundary: &MultipartBoundary, parts: &[MultipartPart]) -> Vec<u8> {
+    let delimiter = boundary.delimiter();
+    let mut body = vec![];
+    for part in parts.iter() {
+        body.extend_from_slice(&delimiter);
+        body.extend_from_slice(
+            format!(
+                "\r\nContent-Disposition: form-data; name=\"{}\"\r\nContent-Type: {}\r\n\r\n",
+                escape_part_name(&part.name),
+                &part.content_type
+            )
+            .as_bytes(),
+        );
+        body.extend_from_slice(&part.data);
+        body.extend_from_slice(b"\r\n");
+    }
+    body.extend_from_slice(&delimiter);
+    body.extend_from_slice(b"--\r\n");
+    body
+}
+
+/// Find the first offset of `needle` in `haystack`
+fn find_bytes(haystack: &[u8], needle: &[u8]) -> Option<usize> {
+    haystack
+        .windows(needle.len())
+        .position(|window| window == needle)
+}
+
+/// Decode the headers of a single part
+fn decode_part_headers(headers: &[u8]) -> Result<(String, HttpContentType), Error> {
+    let headers = std::str::from_utf8(headers)
+        .map_err(|_| Error::DecodeError("Multipart part headers are not UTF-8".to_string()))?;
+    let mut name = None;
+    let mut content_type = None;
+    for line in headers.split("\r\n") {
+        let Some((key, value)) = line.split_once(':') else {
+            return Err(Error::DecodeError(format!(
+                "Invalid multipart part header: {line}"
+            )));
+        };
+        let value = value.trim();
+        match key.trim().to_lowercase().as_str() {
+            "content-disposition" => {
+                name = value
+                    .split(';')
+                    .filter_map(|param| param.trim().strip_prefix("name="))
+                    .next()
+                    .map(|param| unescape_part_name(param.trim_matches('"')));
+            }
+            "content-type" => {
+                content_type = Some(HttpContentType::from_str(value)?);
+            }
+            _ => {}
+        }
+    }
+    let name = name.ok_or_else(|| {
+        Error::DecodeError("Multipart part is missing a Content-Disposition name".to_string())
+    })?;
+    Ok((name, content_type.unwrap_or(HttpContentType::Bytes)))
+}
+
+/// Decode a `multipart/mixed` body delimited by `boundary` into its parts
+pub fn decode_multipart(
+    boundary: &MultipartBoundary,
+    body: &[u8],
+) -> Result<Vec<MultipartPart>, Error> {
+    let delimiter = boundary.delimiter();
+    let mut part_delimiter = b"\r\n".to_vec();
+    part_delimiter.extend_from_slice(&delimiter);
+
+    let mut rest = body.strip_prefix(delimiter.as_slice()).ok_or_else(|| {
+        Error::DecodeError("Multipart body does not start with its boundary".to_string())
+    })?;
+    let mut parts = vec![];
+    loop {
+        if rest.starts_with(b"--") {
+            return Ok(parts);
+        }
+        rest = rest
+            .strip_prefix(b"\r\n")
+            .ok_or_else(|| Error::DecodeError("Malformed multipart boundary line".to_string()))?;
+        let headers_end = find_bytes(rest, b"\r\n\r\n").ok_or_else(|| {
+            Error::UnderflowError("Not enough bytes to parse multipart part headers".to_string())
+        })?;
+        let (name, content_type) = decode_part_headers(&rest[..headers_end])?;
+        rest = &rest[headers_end + 4..];
+
+        let data_end = find_bytes(rest, &part_delimiter).ok_or_else(|| {
+            Error::UnderflowError("Not enough bytes to parse multipart part".to_string())
+        })?;
+        parts.push(MultipartPart {
+            name,
+            content_type,
+            data: rest[..data_end].to_vec(),
+        });
+        rest = &rest[data_end + part_delimiter.len()..];
+    }
+}
+
+/// Helper function to decode an HTTP response preamble and its body into the parts of a
+/// `multipart/mixed` body
+pub fn parse_multipart(
+    preamble: &HttpResponsePreamble,
+    body: &[u8],
+    max_len: u64,
+) -> Result<Vec<MultipartPart>, Error> {
+    let HttpContentType::Multipart(boundary) = preamble.content_type else {
+        return Err(Error::DecodeError(
+            "Invalid content-type: expected multipart/mixed".to_string(),
+        ));
+    };
+    if body.len() as u64 > max_len {
+        return Err(Error::DecodeError(
+            "Multipart body is too long to decode".to_string(),
+        ));
+    }
+    decode_multipart(&boundary, body)
+}
@@ -27,13 +27,30 @@ use stacks_common::types::net::PeerHost;
 use url::form_urlencoded;
 
 use crate::net::http::common::{
-    HttpReservedHeader, HTTP_PREAMBLE_MAX_ENCODED_SIZE, HTTP_PREAMBLE_MAX_NUM_HEADERS,
+    parse_connection_header, trim_header_value, HttpReservedHeader, HTTP_PREAMBLE_MAX_ENCODED_SIZE,
+    HTTP_PREAMBLE_MAX_HEADER_NAME_LEN, HTTP_PREAMBLE_MAX_NUM_HEADERS,
 };
 use crate::net::http::{
     default_accept_header, write_headers, Error, HttpContentType, HttpResponseContents,
     HttpResponsePreamble, HttpVersion,
 };
 
+/// An inclusive range of bytes of a response body, as requested in a `Range` header
+#[derive(Debug, Clone, Copy, PartialEq)]
+pub struct HttpByteRange {
+    /// offset of the first byte in the range
+    pub start: u64,
+    /// offset of the last byte in the range
+    pub end: u64,
+}
+
+impl HttpByteRange {
+    /// Number of bytes in the range
+    pub fn len(&self) -> u64 {
+        self.end - self.start + 1
+    }
+}
+
 /// HTTP request preamble.  This captures "control plane" data for an HTTP request, and contains
 /// everything of use to us from the HTTP requests's headers.
 #[derive(Debug, Clone, PartialEq)]
@@ -156,6 +173,16 @@ impl HttpRequestPreamble {
                         self.content_length = Some(len);
                         return;
                     }
+                    HttpReservedHeader::Location(location) => {
+                        // requests don't act on it, but keep it like any other header
+                        self.headers.insert(hdr, location);
+                        return;
+                    }
+                    HttpReservedHeader::CacheControl(cache_control) => {
+                        // requests may carry cache directives too, but we don't act on them
+                        self.headers.insert(hdr, cache_control);
+                        return;
+                    }
                 },
                 None => {
                     return;
@@ -198,6 +225,99 @@ impl HttpRequestPreamble {
         }
     }
 
+    /// Does the client accept the given content-coding (e.g. "gzip"), per its `Accept-Encoding`
+    /// header?  A coding is accepted if it is listed without a `q=0` weight.  If it is not listed
+    /// by name, `*` stands in for it, so an explicit `gzip;q=0` overrides a `*`.
+    pub fn accepts_encoding(&self, encoding: &str) -> bool {
+        let Some(accept_encoding) = self.headers.get("accept-encoding") else {
+            return false;
+        };
+        let mut named = None;
+        let mut wildcard = None;
+        for coding in accept_encoding.split(',') {
+            let mut parts = coding.split(';').map(|part| part.trim());
+            let name = parts.next().unwrap_or("");
+            // a zero weight means "not acceptable"
+            let acceptable = !parts.any(|param| {
+                param
+                    .strip_prefix("q=")
+                    .and_then(|q| q.parse::<f64>().ok())
+                    .is_some_and(|q| q == 0.0)
+            });
+            if name.eq_ignore_ascii_case(encoding) {
+                named = Some(named.unwrap_or(false) || acceptable);
+            } else if name == "*" {
+                wildcard = Some(wildcard.unwrap_or(false) || acceptable);
+            }
+        }
+        named.or(wildcard).unwrap_or(false)
+    }
+
+    /// Get the byte range of a `total_len`-byte response body that this request asks for in its
+    /// `Range` header, if any.  A single `bytes=start-end`, `bytes=start-`, or `bytes=-suffix`
+    /// range is supported, and its end is clamped to the end of the body.
+    /// Returns Err(Error::RangeNotSatisfiable(..)) if the range is malformed or lies outside the
+    /// body.
+    pub fn get_byte_range(&self, total_len: u64) -> Result<Option<HttpByteRange>, Error> {
+        let Some(range) = self.headers.get("range") else {
+            return Ok(None);
+        };
+        let not_satisfiable = |reason: &str| Error::RangeNotSatisfiable {
+            total_len,
+            reason: format!("{reason}: '{range}'"),
+        };
+        let Some(spec) = range.strip_prefix("bytes=") else {
+            return Err(not_satisfiable("Unsupported range unit"));
+        };
+        if spec.contains(',') {
+            return Err(not_satisfiable("Multiple ranges are not supported"));
+        }
+        let Some((start, end)) = spec.trim().split_once('-') else {
+            return Err(not_satisfiable("Malformed range"));
+        };
+        let parse_offset = |offset: &str| {
+            offset
+                .trim()
+                .parse::<u64>()
+                .map_err(|_| not_satisfiable("Malformed range"))
+        };
+        let last = total_len.checked_sub(1);
+        let byte_range = match (start.trim().is_empty(), end.trim().is_empty()) {
+            // bytes=-suffix: the last `suffix` bytes
+            (true, false) => {
+                let suffix = parse_offset(end)?;
+                let last = last.filter(|_| suffix > 0);
+                last.map(|last| HttpByteRange {
+                    start: total_len.saturating_sub(suffix),
+                    end: last,
+                })
+            }
+            // bytes=start-: everything from `start` on
+            (false, true) => {
+                let start = parse_offset(start)?;
+                last.filter(|last| start <= *last)
+                    .map(|last| HttpByteRange { start, end: last })
+            }
+            // bytes=start-end
+            (false, false) => {
+                let start = parse_offset(start)?;
+                let end = parse_offset(end)?;
+                if end < start {
+                    return Err(not_satisfiable("Malformed range"));
+                }
+                last.filter(|last| start <= *last)
+                    .map(|last| HttpByteRange {
+                        start,
+                        end: end.min(last),
+                    })
+            }
+            (true, true) => return Err(not_satisfiable("Malformed range")),
+        };
+        byte_range
+            .map(Some)
+            .ok_or_else(|| not_satisfiable("Range lies outside the body"))
+    }
+
     /// Content-Length for this request.
     /// If there is no valid Content-Length header, then
     /// the Content-Length is 0
@@ -219,6 +339,31 @@ impl HttpRequestPreamble {
     pub fn send<W: Write>(&self, fd: &mut W) -> Result<(), Error> {
         self.consensus_serialize(fd).map_err(|e| e.into())
     }
+
+    /// If `buf` starts with a request line for an HTTP version other than 1.0 or 1.1, then
+    /// return that version (e.g. `HTTP/2.0`).  A request line without a version is taken to be
+    /// an HTTP/0.9 request.  Returns None if `buf` does not start with such a request line.
+    pub fn unsupported_version(buf: &[u8]) -> Option<String> {
+        let line_end = buf.windows(2).position(|window| window == b"\r\n")?;
+        let line = std::str::from_utf8(&buf[..line_end]).ok()?;
+        let mut parts = line.split(' ');
+        let verb = parts.next()?;
+        let path = parts.next()?;
+        if verb.is_empty() || !verb.bytes().all(|b| b.is_ascii_uppercase()) || path.is_empty() {
+            return None;
+        }
+        match (parts.next(), parts.next()) {
+            (None, _) => Some("HTTP/0.9".to_string()),
+            (Some(version), None)
+                if version.starts_with("HTTP/")
+                    && version != "HTTP/1.0"
+                    && version != "HTTP/1.1" =>
+            {
+                Some(version.to_string())
+            }
+            _ => None,
+        }
+    }
 }
 
 /// Read from a stream until we see '\r\n\r\n', with the purpose of reading a HTTP preamble.
@@ -378,6 +523,8 @@ impl StacksMessageCodec for HttpRequestPreamble {
                             "Invalid HTTP header value: not utf-8".to_string(),
                         )
                     })?;
+                    // peers may pad the value with whitespace, which is not part of it
+                    let value = trim_header_value(&value).to_string();
                     if !value.is_ascii() {
                         return Err(CodecError::DeserializeError(
                             "Invalid HTTP request: header value is not ASCII-US".to_string(),
@@ -389,6 +536,12 @@ impl StacksMessageCodec for HttpRequestPreamble {
                         ));
                     }
 
+                    if req.headers[i].name.len() > HTTP_PREAMBLE_MAX_HEADER_NAME_LEN {
+                        return Err(CodecError::DeserializeError(format!(
+                            "Invalid HTTP request: header name is longer than {HTTP_PREAMBLE_MAX_HEADER_NAME_LEN} bytes"
+                        )));
+                    }
+
                     let key = req.headers[i].name.to_string().to_lowercase();
 
                     if seen_headers.contains(&key) {
@@ -406,7 +559,7 @@ impl StacksMessageCodec for HttpRequestPreamble {
                         };
                     } else if key == "content-type" {
                         // parse
-                        let ctype = value.to_lowercase().parse::<HttpContentType>()?;
+                        let ctype = value.parse::<HttpContentType>()?;
                         content_type = Some(ctype);
                     } else if key == "content-length" {
                         // parse
@@ -416,15 +569,7 @@ impl StacksMessageCodec for HttpRequestPreamble {
                         };
                     } else if key == "connection" {
                         // parse
-                        if value.to_lowercase() == "close" {
-                            keep_alive = false;
-                        } else if value.to_lowercase() == "keep-alive" {
-                            keep_alive = true;
-                        } else {
-                            return Err(CodecError::DeserializeError(
-                                "Inavlid HTTP request: invalid Connection: header".to_string(),
-                            ));
-                        }
+                        keep_alive = parse_connection_header(&value, keep_alive);
                     } else {
                         headers.insert(key, value);
                     }
@@ -14,27 +14,43 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::collections::{BTreeMap, HashMap, HashSet};
+use std::borrow::Cow;
+use std::collections::{HashMap, HashSet};
 use std::fmt;
-use std::io::{Read, Write};
+use std::io::{self, Read, Write};
 use std::ops::Deref;
-use std::time::SystemTime;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, SystemTime};
 
-use stacks_common::codec::{Error as CodecError, StacksMessageCodec};
+use flate2::read::{DeflateDecoder, GzDecoder, ZlibDecoder};
+use stacks_common::codec::{Error as CodecError, StacksMessageCodec, MAX_MESSAGE_LEN};
 use stacks_common::deps_common::httparse;
 use stacks_common::util::chunked_encoding::{
-    HttpChunkedTransferWriter, HttpChunkedTransferWriterState,
+    HttpChunkedTransferReaderState, HttpChunkedTransferWriter, HttpChunkedTransferWriterState,
 };
 use stacks_common::util::hash::to_hex;
-use stacks_common::util::pipe::PipeWrite;
 use {serde, serde_json};
 
+use crate::monitoring;
 use crate::net::http::common::{
-    HttpReservedHeader, HTTP_PREAMBLE_MAX_ENCODED_SIZE, HTTP_PREAMBLE_MAX_NUM_HEADERS,
+    parse_connection_header, trim_header_value, HttpHeaderMap, HttpReservedHeader, TextDecodeMode,
+    HTTP_PREAMBLE_MAX_ENCODED_SIZE, HTTP_PREAMBLE_MAX_HEADER_NAME_LEN,
+    HTTP_PREAMBLE_MAX_NUM_HEADERS,
 };
-use crate::net::http::request::{HttpRequestContents, HttpRequestPreamble};
-use crate::net::http::stream::HttpChunkGenerator;
-use crate::net::http::{http_reason, write_headers, Error, HttpContentType, HttpVersion};
+use crate::net::http::multipart::{encode_multipart, MultipartBoundary, MultipartPart};
+use crate::net::http::request::{HttpByteRange, HttpRequestContents, HttpRequestPreamble};
+use crate::net::http::stream::{encode_stream, HttpChunkGenerator, JsonStream, RamChunkStream};
+use crate::net::http::{
+    http_reason, write_headers, Error, HttpContentEncoding, HttpContentType, HttpErrorPayload,
+    HttpVersion,
+};
+
+/// Charset advertised for text-family response bodies by default
+pub const HTTP_DEFAULT_CHARSET: &str = "utf-8";
+
+/// Header names that may be used in cross-origin requests by default
+pub const DEFAULT_CORS_ALLOWED_HEADERS: [&str; 3] = ["origin", "content-type", "authorization"];
 
 /// HTTP response preamble.  This captures all HTTP header information, but in a way that
 /// certain fields that nodes rely on are guaranteed to have correct, sensible values.
@@ -49,18 +65,107 @@ pub struct HttpResponsePreamble {
     pub reason: String,
     /// true if `Connction: keep-alive` is present
     pub keep_alive: bool,
+    /// `timeout` parameter of the `Keep-Alive` header: how many seconds an idle connection is
+    /// kept open.  Only sent if `keep_alive` is true.
+    pub keep_alive_timeout: Option<u64>,
+    /// `max` parameter of the `Keep-Alive` header: how many more requests the connection will
+    /// serve.  Only sent if `keep_alive` is true.
+    pub keep_alive_max: Option<u64>,
     /// Content-Length value, if given.  If it's not given, then the payload will be treated as
     /// chunk-encoded (and it had better have a `Transfer-Encoding: chunked` header)
     pub content_length: Option<u32>,
     /// Content-Type value.
     pub content_type: HttpContentType,
-    /// Other headers we did not use
-    pub headers: BTreeMap<String, String>,
+    /// false if the handler that made this preamble left the content type unset, in which case
+    /// the server sends the response with its configured default content type
+    pub content_type_set: bool,
+    /// Charset advertised alongside text-family content types, if any.  Received responses
+    /// without a charset parameter are taken to be `HTTP_DEFAULT_CHARSET`, and received text
+    /// responses in a charset other than UTF-8 or US-ASCII are rejected.
+    pub charset: Option<String>,
+    /// Location value, if given.  Redirect responses point the client here.
+    pub location: Option<String>,
+    /// Cache-Control value, if given.  Tells clients and proxies whether and for how long they
+    /// may cache the response.
+    pub cache_control: Option<String>,
+    /// Other headers we did not use.  A header may have several values.
+    pub headers: HttpHeaderMap,
+    /// Trailer headers received after a chunk-encoded body.  These are never sent as part of
+    /// the preamble.
+    pub trailers: HttpHeaderMap,
 }
 
 pub struct HttpStreamState {
     encoder_state: Option<HttpChunkedTransferWriterState>,
     generator: Box<dyn HttpChunkGenerator>,
+    /// claim on the server-wide stream buffer budget, released when the stream is dropped
+    reservation: Option<HttpStreamBufferReservation>,
+    /// content-coding the generator compresses the body with, if any
+    content_encoding: Option<HttpContentEncoding>,
+}
+
+/// Server-wide accounting of the memory held by the chunked-encoding buffers of active response
+/// streams.  Clones share the same budget.
+#[derive(Debug, Clone)]
+pub struct HttpStreamBufferPool {
+    /// number of bytes currently reserved by active streams
+    reserved_bytes: Arc<AtomicU64>,
+    /// maximum number of bytes that may be reserved at once
+    max_bytes: u64,
+}
+
+/// A claim on some of an `HttpStreamBufferPool`'s budget.  The bytes are given back to the pool
+/// when this is dropped.
+#[derive(Debug)]
+pub struct HttpStreamBufferReservation {
+    reserved_bytes: Arc<AtomicU64>,
+    num_bytes: u64,
+}
+
+impl HttpStreamBufferPool {
+    pub fn new(max_bytes: u64) -> HttpStreamBufferPool {
+        HttpStreamBufferPool {
+            reserved_bytes: Arc::new(AtomicU64::new(0)),
+            max_bytes,
+        }
+    }
+
+    /// Number of bytes currently reserved by active streams
+    pub fn reserved_bytes(&self) -> u64 {
+        self.reserved_bytes.load(Ordering::SeqCst)
+    }
+
+    /// Maximum number of bytes that may be reserved at once
+    pub fn max_bytes(&self) -> u64 {
+        self.max_bytes
+    }
+
+    /// Reserve `num_bytes` of buffer space.
+    /// Returns None if doing so would exceed the pool's budget.
+    pub fn try_reserve(&self, num_bytes: u64) -> Option<HttpStreamBufferReservation> {
+        self.reserved_bytes
+            .fetch_update(Ordering::SeqCst, Ordering::SeqCst, |reserved| {
+                reserved
+                    .checked_add(num_bytes)
+                    .filter(|total| *total <= self.max_bytes)
+            })
+            .ok()?;
+        monitoring::update_http_stream_buffer_bytes(i64::try_from(num_bytes).unwrap_or(i64::MAX));
+        Some(HttpStreamBufferReservation {
+            reserved_bytes: self.reserved_bytes.clone(),
+            num_bytes,
+        })
+    }
+}
+
+impl Drop for HttpStreamBufferReservation {
+    fn drop(&mut self) {
+        self.reserved_bytes
+            .fetch_sub(self.num_bytes, Ordering::SeqCst);
+        monitoring::update_http_stream_buffer_bytes(
+            -i64::try_from(self.num_bytes).unwrap_or(i64::MAX),
+        );
+    }
 }
 
 /// HTTP response body generated by the request handler.  It implements a means of streaming data from disk
@@ -69,6 +174,12 @@ pub struct HttpStreamState {
 pub enum HttpResponseContents {
     Stream(HttpStreamState),
     RAM(Vec<u8>),
+    /// The body of a response to a HEAD request, which is never sent.  Its length and encoding
+    /// are kept so the response headers are the same as they would be for a GET.
+    Head {
+        content_length: Option<u32>,
+        content_encoding: Option<HttpContentEncoding>,
+    },
 }
 
 impl fmt::Debug for HttpResponseContents {
@@ -76,6 +187,9 @@ impl fmt::Debug for HttpResponseContents {
         match self {
             Self::Stream(..) => write!(f, "HttpResponseContents::Stream(..)"),
             Self::RAM(ref bytes) => write!(f, "HttpResponseContents::RAM({})", to_hex(bytes)),
+            Self::Head { content_length, .. } => {
+                write!(f, "HttpResponseContents::Head({:?})", content_length)
+            }
         }
     }
 }
@@ -87,15 +201,86 @@ impl HttpResponseContents {
         HttpResponseContents::Stream(HttpStreamState {
             generator,
             encoder_state: Some(HttpChunkedTransferWriterState::new(chunk_size)),
+            reservation: None,
+            content_encoding: None,
         })
     }
 
+    /// Make response contents from a byte array that is compressed with `encoding` as it is
+    /// sent.  The body is streamed with chunked transfer encoding, and the response is sent with
+    /// `Content-Encoding` set to `encoding`.
+    /// Returns Err(Error::WriteError(..)) if the compressor could not be set up.
+    pub fn from_ram_compressed(
+        bytes: Vec<u8>,
+        encoding: HttpContentEncoding,
+    ) -> Result<HttpResponseContents, Error> {
+        let generator = encode_stream(Box::new(RamChunkStream::new(bytes)), encoding)
+            .map_err(Error::WriteError)?;
+        let mut contents = Self::from_stream(generator);
+        if let HttpResponseContents::Stream(ref mut inner_stream) = contents {
+            inner_stream.content_encoding = Some(encoding);
+        }
+        Ok(contents)
+    }
+
+    /// The content-coding these contents are compressed with as they are sent, if any
+    pub fn content_encoding(&self) -> Option<HttpContentEncoding> {
+        match self {
+            Self::Stream(inner_stream) => inner_stream.content_encoding,
+            Self::RAM(..) => None,
+            Self::Head {
+                content_encoding, ..
+            } => *content_encoding,
+        }
+    }
+
+    /// Names of the trailer headers sent after the body, if it is streamed
+    pub fn trailer_names(&self) -> Vec<String> {
+        match self {
+            Self::Stream(inner_stream) => inner_stream.generator.trailer_names(),
+            Self::RAM(..) | Self::Head { .. } => vec![],
+        }
+    }
+
+    /// Number of bytes of encoder buffer this response holds while it is being sent, if it is
+    /// streamed
+    pub fn stream_buffer_size(&self) -> Option<u64> {
+        match self {
+            Self::Stream(inner_stream) => {
+                Some(u64::try_from(inner_stream.generator.hint_chunk_size()).unwrap_or(u64::MAX))
+            }
+            Self::RAM(..) | Self::Head { .. } => None,
+        }
+    }
+
+    /// Attach a buffer reservation to this response, so it is released once the response is
+    /// dropped.  Does nothing if this response is not streamed.
+    pub fn set_stream_buffer_reservation(&mut self, reservation: HttpStreamBufferReservation) {
+        if let Self::Stream(inner_stream) = self {
+            inner_stream.reservation = Some(reservation);
+        }
+    }
+
     /// Make response contents from a byte array
     pub fn from_ram(bytes: Vec<u8>) -> HttpResponseContents {
         assert!(bytes.len() < (u32::MAX as usize));
         HttpResponseContents::RAM(bytes)
     }
 
+    /// Make response contents from the bytes in `range` of a byte array
+    pub fn from_ram_range(mut bytes: Vec<u8>, range: &HttpByteRange) -> HttpResponseContents {
+        let end = usize::try_from(range.end)
+            .expect("FATAL: byte range is too long")
+            .saturating_add(1)
+            .min(bytes.len());
+        let start = usize::try_from(range.start)
+            .expect("FATAL: byte range is too long")
+            .min(end);
+        bytes.truncate(end);
+        bytes.drain(..start);
+        Self::from_ram(bytes)
+    }
+
     /// Make response contents from a JSON value
     pub fn try_from_json<T: serde::ser::Serialize>(
         value: &T,
@@ -103,11 +288,35 @@ impl HttpResponseContents {
         Ok(Self::from_ram(serde_json::to_string(value)?.into_bytes()))
     }
 
+    /// Make response contents that stream `value` as JSON.  The value is serialized
+    /// incrementally as the response is sent, rather than up front.
+    pub fn from_json_stream<T: serde::ser::Serialize + Send + 'static>(
+        value: T,
+    ) -> HttpResponseContents {
+        Self::from_stream(Box::new(JsonStream::new(value)))
+    }
+
+    /// Turn these contents into those of a response to a HEAD request: the body is dropped and
+    /// never sent, but `content_length()` and `content_encoding()` still report what it would
+    /// have been.
+    pub fn into_head(self) -> HttpResponseContents {
+        Self::Head {
+            content_length: self.content_length(),
+            content_encoding: self.content_encoding(),
+        }
+    }
+
+    /// Is this the body of a response to a HEAD request?
+    pub fn is_head(&self) -> bool {
+        matches!(self, Self::Head { .. })
+    }
+
     /// Deduce the proper content-length
     pub fn content_length(&self) -> Option<u32> {
         match self {
             Self::Stream(..) => None,
             Self::RAM(data) => Some(data.len() as u32),
+            Self::Head { content_length, .. } => *content_length,
         }
     }
 
@@ -115,7 +324,7 @@ impl HttpResponseContents {
     /// Return Ok(Some(..)) if there is mroe data to send.
     /// Once all data is sent, return Ok(None)
     #[cfg_attr(test, mutants::skip)]
-    pub fn pipe_out(&mut self, fd: &mut PipeWrite) -> Result<u64, Error> {
+    pub fn pipe_out<W: Write>(&mut self, fd: &mut W) -> Result<u64, Error> {
         match self {
             HttpResponseContents::Stream(ref mut inner_stream) => {
                 // write the next chunk
@@ -131,18 +340,38 @@ impl HttpResponseContents {
                 res
             }
             HttpResponseContents::RAM(ref mut buf) => {
-                // dump directly into the pipewrite
+                // dump directly into the pipewrite, keeping whatever it does not accept for the
+                // next call
                 // TODO: zero-copy?
                 if !buf.is_empty() {
-                    fd.write_all(&buf[..]).map_err(Error::WriteError)?;
-                    buf.clear();
+                    let num_written = write_nonblocking(fd, &buf[..]).map_err(Error::WriteError)?;
+                    buf.drain(..num_written);
                 }
                 Ok(buf.len() as u64)
             }
+            // no body is sent in response to a HEAD request
+            HttpResponseContents::Head { .. } => Ok(0),
         }
     }
 }
 
+/// Write as much of `buf` to `fd` as it will accept without blocking.
+/// Returns the number of bytes written, which is less than `buf.len()` if `fd` would block or
+/// stops accepting bytes.
+fn write_nonblocking<W: Write>(fd: &mut W, buf: &[u8]) -> Result<usize, io::Error> {
+    let mut num_written = 0;
+    while num_written < buf.len() {
+        match fd.write(&buf[num_written..]) {
+            Ok(0) => break,
+            Ok(n) => num_written += n,
+            Err(e) if e.kind() == io::ErrorKind::WouldBlock => break,
+            Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+            Err(e) => return Err(e),
+        }
+    }
+    Ok(num_written)
+}
+
 impl From<Vec<u8>> for HttpResponseContents {
     fn from(data: Vec<u8>) -> Self {
         Self::RAM(data)
@@ -163,9 +392,16 @@ impl HttpResponsePreamble {
             status_code,
             reason,
             keep_alive,
+            keep_alive_timeout: None,
+            keep_alive_max: None,
             content_length: content_length_opt,
             content_type,
-            headers: BTreeMap::new(),
+            content_type_set: true,
+            charset: Some(HTTP_DEFAULT_CHARSET.to_string()),
+            location: None,
+            cache_control: None,
+            headers: HttpHeaderMap::new(),
+            trailers: HttpHeaderMap::new(),
         }
     }
 
@@ -186,6 +422,25 @@ impl HttpResponsePreamble {
         )
     }
 
+    /// Make a preamble for a response whose content type is left to the server.  The server sends
+    /// it with `ConnectionOptions::default_response_content_type`.
+    pub fn from_http_request_preamble_untyped(
+        preamble: &HttpRequestPreamble,
+        status: u16,
+        reason: &str,
+        content_len_opt: Option<u32>,
+    ) -> HttpResponsePreamble {
+        let mut res = HttpResponsePreamble::from_http_request_preamble(
+            preamble,
+            status,
+            reason,
+            content_len_opt,
+            HttpContentType::Bytes,
+        );
+        res.content_type_set = false;
+        res
+    }
+
     pub fn success_2xx_json(
         preamble: &HttpRequestPreamble,
         status_code: u16,
@@ -200,6 +455,28 @@ impl HttpResponsePreamble {
         )
     }
 
+    /// Preamble for a 206 response carrying the bytes in `range` of a `total_len`-byte body, as
+    /// described by its `Content-Range` header
+    pub fn partial_content_2xx(
+        preamble: &HttpRequestPreamble,
+        range: &HttpByteRange,
+        total_len: u64,
+        content_type: HttpContentType,
+    ) -> HttpResponsePreamble {
+        let mut res = HttpResponsePreamble::from_http_request_preamble(
+            preamble,
+            206,
+            http_reason(206),
+            Some(u32::try_from(range.len()).expect("FATAL: byte range is too long")),
+            content_type,
+        );
+        res.add_header(
+            "Content-Range".to_string(),
+            format!("bytes {}-{}/{}", range.start, range.end, total_len),
+        );
+        res
+    }
+
     pub fn ok_json(preamble: &HttpRequestPreamble) -> HttpResponsePreamble {
         Self::success_2xx_json(preamble, 200)
     }
@@ -241,6 +518,57 @@ impl HttpResponsePreamble {
         )
     }
 
+    /// Preamble and body for an error response whose body is `payload`
+    pub fn error_json_payload(
+        code: u16,
+        payload: &HttpErrorPayload,
+    ) -> Result<(HttpResponsePreamble, HttpResponseContents), Error> {
+        let contents = HttpResponseContents::try_from_json(payload)?;
+        let mut preamble = Self::error_json(code, http_reason(code));
+        preamble.content_length = contents.content_length();
+        Ok((preamble, contents))
+    }
+
+    /// Preamble for a 504 response with a JSON body, for when an upstream we depend on timed out
+    pub fn gateway_timeout() -> Self {
+        Self::error_json(504, http_reason(504))
+    }
+
+    /// Preamble for a 417 response with a JSON body, for when a request's `Expect` header asks
+    /// for something other than `100-continue`
+    pub fn expectation_failed() -> Self {
+        Self::error_json(417, http_reason(417))
+    }
+
+    /// Preamble for a 505 response with a JSON body, for when a request uses an HTTP version
+    /// other than 1.0 or 1.1
+    pub fn version_not_supported() -> Self {
+        Self::error_json(505, http_reason(505))
+    }
+
+    /// Preamble for a 308 response with an empty body, which tells the client to repeat the same
+    /// request (with the same method and body) against `location`.
+    /// Fails if `location` contains control characters, which would corrupt the header.
+    pub fn permanent_redirect(location: &str) -> Result<Self, Error> {
+        let Some(HttpReservedHeader::Location(location)) =
+            HttpReservedHeader::try_from_str("location", location)
+        else {
+            return Err(Error::AppError(format!(
+                "Invalid Location header value {location:?}"
+            )));
+        };
+        let mut preamble = HttpResponsePreamble::new(
+            HttpVersion::Http11,
+            308,
+            http_reason(308).to_string(),
+            Some(0),
+            HttpContentType::Bytes,
+            true,
+        );
+        preamble.location = Some(location);
+        Ok(preamble)
+    }
+
     pub fn error_text(code: u16, reason: &str, message: &str) -> Self {
         HttpResponsePreamble::new(
             HttpVersion::Http11,
@@ -291,12 +619,21 @@ impl HttpResponsePreamble {
                     }
                     HttpReservedHeader::ContentType(ct) => {
                         self.content_type = ct;
+                        self.content_type_set = true;
                         return;
                     }
                     HttpReservedHeader::Host(..) => {
                         // ignored
                         return;
                     }
+                    HttpReservedHeader::Location(location) => {
+                        self.location = Some(location);
+                        return;
+                    }
+                    HttpReservedHeader::CacheControl(cache_control) => {
+                        self.cache_control = Some(cache_control);
+                        return;
+                    }
                 },
                 None => {
                     return;
@@ -320,7 +657,62 @@ impl HttpResponsePreamble {
         return true;
     }
 
-    /// Get an owned copy of a header if it exists
+    /// Use `content_type` if no content type has been set
+    pub fn set_default_content_type(&mut self, content_type: HttpContentType) {
+        if !self.content_type_set {
+            self.content_type = content_type;
+            self.content_type_set = true;
+        }
+    }
+
+    /// Set the `Cache-Control` header.  If `no_store` is true, the response must not be cached
+    /// at all; otherwise, it may be cached by anyone (`public`), for up to `max_age` seconds if
+    /// given.  Set `immutable` for responses that never change, such as confirmed blocks.
+    pub fn set_cache_control(&mut self, max_age: Option<u64>, immutable: bool, no_store: bool) {
+        let mut directives = vec![];
+        if no_store {
+            directives.push("no-store".to_string());
+        } else {
+            directives.push("public".to_string());
+        }
+        if let Some(max_age) = max_age {
+            directives.push(format!("max-age={max_age}"));
+        }
+        if immutable {
+            directives.push("immutable".to_string());
+        }
+        self.cache_control = Some(directives.join(", "));
+    }
+
+    /// Add a `Server-Timing` header with the duration of each named phase of handling the
+    /// request, in milliseconds (e.g. `db;dur=12.3, serialize;dur=4.5`).  Each call adds another
+    /// `Server-Timing` header; nothing is added if `metrics` is empty.
+    pub fn add_server_timing(&mut self, metrics: Vec<(String, Duration)>) {
+        if metrics.is_empty() {
+            return;
+        }
+        let value = metrics
+            .iter()
+            .map(|(name, dur)| format!("{};dur={:.1}", name, dur.as_secs_f64() * 1000.0))
+            .collect::<Vec<_>>()
+            .join(", ");
+        self.headers.append("server-timing".to_string(), value);
+    }
+
+    /// Get an owned copy of all values of a header, in the order they were given.  Reserved
+    /// headers have at most one value.
+    pub fn get_header_all(&self, key: String) -> Vec<String> {
+        let hdr = key.to_lowercase();
+        match hdr.as_str() {
+            "content-type" | "content-length" | "location" | "cache-control" => {
+                self.get_header(hdr).into_iter().collect()
+            }
+            _ => self.headers.get_all(&hdr).to_vec(),
+        }
+    }
+
+    /// Get an owned copy of a header if it exists.  If the header has several values, then the
+    /// first one is returned.
     pub fn get_header(&self, key: String) -> Option<String> {
         let hdr = key.to_lowercase();
         match hdr.as_str() {
@@ -330,6 +722,12 @@ impl HttpResponsePreamble {
             "content-length" => {
                 return self.content_length.clone().map(|cl| format!("{}", &cl));
             }
+            "location" => {
+                return self.location.clone();
+            }
+            "cache-control" => {
+                return self.cache_control.clone();
+            }
             _ => {
                 return self.headers.get(&hdr).cloned();
             }
@@ -349,16 +747,22 @@ impl HttpResponsePreamble {
 
 /// Get an RFC 7231 date that represents the current time
 fn rfc7231_now() -> String {
-    let now = time::PrimitiveDateTime::from(SystemTime::now());
-    now.format("%a, %b %-d %-Y %-H:%M:%S GMT")
+    rfc7231_date(SystemTime::now())
+}
+
+/// Format `time` as an RFC 7231 HTTP-date, in its preferred IMF-fixdate form
+/// (e.g. `Tue, 15 Nov 1994 08:12:31 GMT`)
+pub(crate) fn rfc7231_date(time: SystemTime) -> String {
+    let time = time::PrimitiveDateTime::from(time);
+    time.format("%a, %d %b %Y %H:%M:%S GMT")
 }
 
 /// Read from a stream until we see '\r\n\r\n', with the purpose of reading an HTTP preamble.
 /// It's gonna be important here that R does some bufferring, since this reads byte by byte.
-/// EOF if we read 0 bytes.
-fn read_to_crlf2<R: Read>(fd: &mut R) -> Result<Vec<u8>, CodecError> {
-    let mut ret = Vec::with_capacity(HTTP_PREAMBLE_MAX_ENCODED_SIZE as usize);
-    while ret.len() < HTTP_PREAMBLE_MAX_ENCODED_SIZE as usize {
+/// EOF if we read 0 bytes.  Reads at most `max_size` bytes.
+fn read_to_crlf2<R: Read>(fd: &mut R, max_size: usize) -> Result<Vec<u8>, CodecError> {
+    let mut ret = Vec::with_capacity(max_size.min(HTTP_PREAMBLE_MAX_ENCODED_SIZE as usize));
+    while ret.len() < max_size {
         let mut b = [0u8];
         fd.read_exact(&mut b).map_err(CodecError::ReadError)?;
         ret.push(b[0]);
@@ -402,8 +806,14 @@ impl StacksMessageCodec for HttpResponsePreamble {
         }
 
         if !self.headers.contains_key("access-control-allow-headers") {
-            fd.write_all("Access-Control-Allow-Headers: origin, content-type\r\n".as_bytes())
-                .map_err(CodecError::WriteError)?;
+            fd.write_all(
+                format!(
+                    "Access-Control-Allow-Headers: {}\r\n",
+                    DEFAULT_CORS_ALLOWED_HEADERS.join(", ")
+                )
+                .as_bytes(),
+            )
+            .map_err(CodecError::WriteError)?;
         }
 
         if !self.headers.contains_key("access-control-allow-methods") {
@@ -416,9 +826,29 @@ impl StacksMessageCodec for HttpResponsePreamble {
             .map_err(CodecError::WriteError)?;
         fd.write_all(self.content_type.to_string().as_bytes())
             .map_err(CodecError::WriteError)?;
+        if let Some(charset) = self
+            .charset
+            .as_ref()
+            .filter(|_| self.content_type.is_text())
+        {
+            fd.write_all(format!("; charset={charset}").as_bytes())
+                .map_err(CodecError::WriteError)?;
+        }
         fd.write_all("\r\n".as_bytes())
             .map_err(CodecError::WriteError)?;
 
+        // location (reserved header)
+        if let Some(location) = self.location.as_ref() {
+            fd.write_all(format!("Location: {location}\r\n").as_bytes())
+                .map_err(CodecError::WriteError)?;
+        }
+
+        // cache-control (reserved header)
+        if let Some(cache_control) = self.cache_control.as_ref() {
+            fd.write_all(format!("Cache-Control: {cache_control}\r\n").as_bytes())
+                .map_err(CodecError::WriteError)?;
+        }
+
         // content-length / transfer-encoding (reserved header)
         match self.content_length {
             Some(len) => {
@@ -454,8 +884,23 @@ impl StacksMessageCodec for HttpResponsePreamble {
             }
         }
 
+        // keep-alive tuning, if any
+        if self.keep_alive {
+            let params: Vec<_> = [
+                ("timeout", self.keep_alive_timeout),
+                ("max", self.keep_alive_max),
+            ]
+            .into_iter()
+            .filter_map(|(name, value)| value.map(|value| format!("{name}={value}")))
+            .collect();
+            if !params.is_empty() {
+                fd.write_all(format!("Keep-Alive: {}\r\n", params.join(", ")).as_bytes())
+                    .map_err(CodecError::WriteError)?;
+            }
+        }
+
         // other headers
-        write_headers(fd, &self.headers)?;
+        write_headers(fd, self.headers.iter())?;
 
         fd.write_all("\r\n".as_bytes())
             .map_err(CodecError::WriteError)?;
@@ -463,11 +908,51 @@ impl StacksMessageCodec for HttpResponsePreamble {
     }
 
     fn consensus_deserialize<R: Read>(fd: &mut R) -> Result<HttpResponsePreamble, CodecError> {
+        Self::consensus_deserialize_with_allowlist(fd, None)
+    }
+}
+
+impl HttpResponsePreamble {
+    /// Decode a response preamble, like `consensus_deserialize`, but fail if its content type is
+    /// not one of `allowed_content_types` (if given).  A missing `Content-Type` header is treated
+    /// as `application/octet-stream`, and multipart types match regardless of their boundary.
+    pub fn consensus_deserialize_with_allowlist<R: Read>(
+        fd: &mut R,
+        allowed_content_types: Option<&[HttpContentType]>,
+    ) -> Result<HttpResponsePreamble, CodecError> {
         // realistically, there won't be more than HTTP_PREAMBLE_MAX_NUM_HEADERS headers
-        let mut headers = [httparse::EMPTY_HEADER; HTTP_PREAMBLE_MAX_NUM_HEADERS];
-        let mut resp = httparse::Response::new(&mut headers);
+        Self::deserialize_with_header_limits(
+            fd,
+            allowed_content_types,
+            HTTP_PREAMBLE_MAX_NUM_HEADERS,
+            HTTP_PREAMBLE_MAX_ENCODED_SIZE,
+        )
+    }
 
-        let buf_read = read_to_crlf2(fd)?;
+    /// Decode a response preamble, like `consensus_deserialize`, but allow up to `max_headers`
+    /// headers in up to `max_size` bytes instead of `HTTP_PREAMBLE_MAX_NUM_HEADERS` headers in
+    /// `HTTP_PREAMBLE_MAX_ENCODED_SIZE` bytes.  This is for responses that pass through reverse
+    /// proxies, which may add many headers of their own.
+    pub fn consensus_deserialize_with_limits<R: Read>(
+        fd: &mut R,
+        max_headers: usize,
+        max_size: u32,
+    ) -> Result<HttpResponsePreamble, CodecError> {
+        Self::deserialize_with_header_limits(fd, None, max_headers, max_size)
+    }
+
+    /// Decode a response preamble of at most `max_size` bytes, with room for up to `max_headers`
+    /// headers
+    fn deserialize_with_header_limits<R: Read>(
+        fd: &mut R,
+        allowed_content_types: Option<&[HttpContentType]>,
+        max_headers: usize,
+        max_size: u32,
+    ) -> Result<HttpResponsePreamble, CodecError> {
+        let buf_read = read_to_crlf2(fd, max_size as usize)?;
+
+        let mut headers = vec![httparse::EMPTY_HEADER; max_headers];
+        let mut resp = httparse::Response::new(&mut headers);
 
         // consume response
         match resp.parse(&buf_read).map_err(|e| {
@@ -497,20 +982,35 @@ impl StacksMessageCodec for HttpResponsePreamble {
                 let status_code = resp.code.ok_or(CodecError::DeserializeError(
                     "No HTTP status code".to_string(),
                 ))?;
+                if !(100..=599).contains(&status_code) {
+                    return Err(CodecError::DeserializeError(format!(
+                        "Invalid HTTP response: status code {status_code} is not between 100 and 599"
+                    )));
+                }
                 let reason = resp
                     .reason
                     .ok_or(CodecError::DeserializeError(
                         "No HTTP status reason".to_string(),
                     ))?
                     .to_string();
+                if reason.trim().is_empty() {
+                    return Err(CodecError::DeserializeError(
+                        "Invalid HTTP response: empty status reason".to_string(),
+                    ));
+                }
 
-                let mut headers: BTreeMap<String, String> = BTreeMap::new();
+                let mut headers = HttpHeaderMap::new();
                 let mut seen_headers: HashSet<String> = HashSet::new();
 
                 let mut content_type = None;
+                let mut charset = None;
+                let mut location = None;
+                let mut cache_control: Option<String> = None;
                 let mut content_length = None;
                 let mut chunked_encoding = false;
                 let mut keep_alive = true;
+                let mut keep_alive_timeout = None;
+                let mut keep_alive_max = None;
 
                 for i in 0..resp.headers.len() {
                     let value =
@@ -519,29 +1019,64 @@ impl StacksMessageCodec for HttpResponsePreamble {
                                 "Invalid HTTP header value: not utf-8".to_string(),
                             )
                         })?;
+                    // peers may pad the value with whitespace, which is not part of it
+                    let value = trim_header_value(&value).to_string();
                     if !value.is_ascii() {
                         return Err(CodecError::DeserializeError(
                             "Invalid HTTP request: header value is not ASCII-US".to_string(),
                         ));
                     }
-                    if value.len() > HTTP_PREAMBLE_MAX_ENCODED_SIZE as usize {
+                    if value.len() > max_size as usize {
                         return Err(CodecError::DeserializeError(
                             "Invalid HTTP request: header value is too big".to_string(),
                         ));
                     }
 
-                    let key = resp.headers[i].name.to_string().to_lowercase();
-
-                    if seen_headers.contains(&key) {
+                    if resp.headers[i].name.len() > HTTP_PREAMBLE_MAX_HEADER_NAME_LEN {
                         return Err(CodecError::DeserializeError(format!(
-                            "Invalid HTTP request: duplicate header \"{}\"",
-                            key
+                            "Invalid HTTP response: header name is longer than {HTTP_PREAMBLE_MAX_HEADER_NAME_LEN} bytes"
                         )));
                     }
-                    seen_headers.insert(key.clone());
+
+                    let key = resp.headers[i].name.to_string().to_lowercase();
+
+                    let is_control_header = matches!(
+                        key.as_str(),
+                        "content-type"
+                            | "content-length"
+                            | "location"
+                            | "connection"
+                            | "transfer-encoding"
+                    );
+                    if is_control_header {
+                        // other headers may be repeated, but these decide how the response is read
+                        if seen_headers.contains(&key) {
+                            return Err(CodecError::DeserializeError(format!(
+                                "Invalid HTTP request: duplicate header \"{}\"",
+                                key
+                            )));
+                        }
+                        seen_headers.insert(key.clone());
+                    }
 
                     if key == "content-type" {
-                        let ctype = value.to_lowercase().parse::<HttpContentType>()?;
+                        // the MIME type and charset are case-insensitive, but a multipart
+                        // boundary is not, so parse the value as given
+                        let ctype = value.parse::<HttpContentType>()?;
+                        charset = value
+                            .to_lowercase()
+                            .split(';')
+                            .filter_map(|param| param.trim().strip_prefix("charset="))
+                            .next()
+                            .map(|param| param.trim_matches('"').to_string());
+                        // text bodies are always decoded as UTF-8 (of which US-ASCII is a subset)
+                        if let Some(charset) = charset.as_ref().filter(|_| ctype.is_text()) {
+                            if !matches!(charset.as_str(), "utf-8" | "utf8" | "us-ascii") {
+                                return Err(CodecError::DeserializeError(format!(
+                                    "Unsupported charset \"{charset}\" for {ctype}"
+                                )));
+                            }
+                        }
                         content_type = Some(ctype);
                     } else if key == "content-length" {
                         let len = value.parse::<u32>().map_err(|_e| {
@@ -550,17 +1085,17 @@ impl StacksMessageCodec for HttpResponsePreamble {
                             )
                         })?;
                         content_length = Some(len);
+                    } else if key == "location" {
+                        location = Some(value);
+                    } else if key == "cache-control" {
+                        // directives may be split across several headers
+                        cache_control = Some(match cache_control {
+                            Some(directives) => format!("{directives}, {value}"),
+                            None => value,
+                        });
                     } else if key == "connection" {
                         // parse
-                        if value.to_lowercase() == "close" {
-                            keep_alive = false;
-                        } else if value.to_lowercase() == "keep-alive" {
-                            keep_alive = true;
-                        } else {
-                            return Err(CodecError::DeserializeError(
-                                "Inavlid HTTP request: invalid Connection: header".to_string(),
-                            ));
-                        }
+                        keep_alive = parse_connection_header(&value, keep_alive);
                     } else if key == "transfer-encoding" {
                         if value.to_lowercase() == "chunked" {
                             chunked_encoding = true;
@@ -570,8 +1105,23 @@ impl StacksMessageCodec for HttpResponsePreamble {
                                 value
                             )));
                         }
+                    } else if key == "keep-alive" {
+                        // the parameters are advisory, so unknown or malformed ones are ignored
+                        for param in value.split(',') {
+                            let Some((name, param_value)) = param.split_once('=') else {
+                                continue;
+                            };
+                            let Ok(param_value) = param_value.trim().parse::<u64>() else {
+                                continue;
+                            };
+                            match name.trim().to_lowercase().as_str() {
+                                "timeout" => keep_alive_timeout = Some(param_value),
+                                "max" => keep_alive_max = Some(param_value),
+                                _ => {}
+                            }
+                        }
                     } else {
-                        headers.insert(key, value);
+                        headers.append(key, value);
                     }
                 }
 
@@ -588,14 +1138,35 @@ impl StacksMessageCodec for HttpResponsePreamble {
                     ));
                 }
 
+                let content_type = content_type.unwrap_or(HttpContentType::Bytes); // per the RFC
+                if let Some(allowed) = allowed_content_types {
+                    if !allowed.iter().any(|ct| {
+                        std::mem::discriminant(ct) == std::mem::discriminant(&content_type)
+                    }) {
+                        let allowed: Vec<_> = allowed.iter().map(|ct| ct.to_string()).collect();
+                        return Err(CodecError::DeserializeError(format!(
+                            "Invalid HTTP response: content type '{}' is not one of [{}]",
+                            content_type,
+                            allowed.join(", ")
+                        )));
+                    }
+                }
+
                 Ok(HttpResponsePreamble {
                     client_http_version,
                     status_code,
                     reason,
                     keep_alive,
-                    content_type: content_type.unwrap_or(HttpContentType::Bytes), // per the RFC
+                    keep_alive_timeout,
+                    keep_alive_max,
+                    content_type,
+                    content_type_set: true,
+                    charset: Some(charset.unwrap_or_else(|| HTTP_DEFAULT_CHARSET.to_string())),
+                    location,
+                    cache_control,
                     content_length,
                     headers,
+                    trailers: HttpHeaderMap::new(),
                 })
             }
         }
@@ -613,6 +1184,8 @@ pub enum HttpResponsePayload {
     Bytes(Vec<u8>),
     /// HTTP body is a UTF-8 String
     Text(String),
+    /// HTTP body is a `multipart/mixed` sequence of named parts
+    Multipart(Vec<MultipartPart>),
 }
 
 impl TryFrom<HttpResponsePayload> for HttpResponseContents {
@@ -627,6 +1200,9 @@ impl TryFrom<HttpResponsePayload> for HttpResponseContents {
             HttpResponsePayload::Text(string) => {
                 Ok(HttpResponseContents::from_ram(string.into_bytes()))
             }
+            HttpResponsePayload::Multipart(parts) => Ok(HttpResponseContents::from_ram(
+                HttpResponsePayload::encode_multipart(&parts),
+            )),
         }
     }
 }
@@ -637,6 +1213,24 @@ impl HttpResponsePayload {
         Ok(Self::JSON(serde_json::to_value(&obj)?))
     }
 
+    /// The content type to send this payload with
+    pub fn content_type(&self) -> HttpContentType {
+        match self {
+            Self::Empty => HttpContentType::Bytes,
+            Self::Bytes(..) => HttpContentType::Bytes,
+            Self::Text(..) => HttpContentType::Text,
+            Self::JSON(..) => HttpContentType::JSON,
+            Self::Multipart(parts) => {
+                HttpContentType::Multipart(MultipartBoundary::from_parts(parts))
+            }
+        }
+    }
+
+    /// Encode a multipart payload's parts, delimited by the boundary reported in its content type
+    fn encode_multipart(parts: &[MultipartPart]) -> Vec<u8> {
+        encode_multipart(&MultipartBoundary::from_parts(parts), parts)
+    }
+
     /// Try to calculate the content length
     pub fn try_content_length(&self) -> Option<u32> {
         match self {
@@ -660,6 +1254,13 @@ impl HttpResponsePayload {
                 }
                 Some(value.len() as u32)
             }
+            Self::Multipart(parts) => {
+                let value_bytes = Self::encode_multipart(parts);
+                if value_bytes.len() > (u32::MAX as usize) {
+                    return None;
+                }
+                Some(value_bytes.len() as u32)
+            }
         }
     }
 
@@ -670,9 +1271,47 @@ impl HttpResponsePayload {
             Self::JSON(value) => serde_json::to_writer(fd, &value).map_err(Error::JsonError),
             Self::Bytes(value) => fd.write_all(value).map_err(Error::WriteError),
             Self::Text(value) => fd.write_all(value.as_bytes()).map_err(Error::WriteError),
+            Self::Multipart(parts) => fd
+                .write_all(&Self::encode_multipart(parts))
+                .map_err(Error::WriteError),
+        }
+    }
+
+    /// Get the encoded bytes of this payload
+    fn to_bytes(&self) -> Result<Cow<'_, [u8]>, Error> {
+        match self {
+            Self::Empty => Ok(Cow::Borrowed(&[])),
+            Self::JSON(value) => Ok(Cow::Owned(
+                serde_json::to_vec(&value).map_err(Error::JsonError)?,
+            )),
+            Self::Bytes(value) => Ok(Cow::Borrowed(value)),
+            Self::Text(value) => Ok(Cow::Borrowed(value.as_bytes())),
+            Self::Multipart(parts) => Ok(Cow::Owned(Self::encode_multipart(parts))),
         }
     }
 
+    /// Write this payload to a Write that may not accept it all at once (e.g. a non-blocking
+    /// socket), resuming from byte offset `*num_sent`.  `*num_sent` is advanced by the number of
+    /// bytes written.
+    /// Returns Ok(true) once the whole payload has been sent.
+    /// Returns Ok(false) if `fd` would block first; the caller should try again once `fd` is
+    /// writable.
+    pub fn send_nonblocking<W: Write>(
+        &self,
+        num_sent: &mut usize,
+        fd: &mut W,
+    ) -> Result<bool, Error> {
+        let bytes = self.to_bytes()?;
+        let remaining = bytes.get(*num_sent..).ok_or_else(|| {
+            Error::WriteError(io::Error::new(
+                io::ErrorKind::InvalidInput,
+                "Payload offset is past the end of the payload",
+            ))
+        })?;
+        *num_sent += write_nonblocking(fd, remaining).map_err(Error::WriteError)?;
+        Ok(*num_sent == bytes.len())
+    }
+
     /// Write this payload to a Write, but as a single HTTP chunk.
     /// This is here for the times where you've already sent the HTTP preamble while designating
     /// chunked-enoding, but you don't (yet) have a Streamer implementation for your body.
@@ -688,6 +1327,7 @@ impl HttpResponsePayload {
             }
             Self::Bytes(value) => bytes.extend_from_slice(&value[..]),
             Self::Text(value) => bytes.extend_from_slice(&value.as_bytes()[..]),
+            Self::Multipart(parts) => bytes.extend_from_slice(&Self::encode_multipart(parts)),
         }
         let mut encoded_bytes = vec![];
         {
@@ -702,6 +1342,35 @@ impl HttpResponsePayload {
         debug!("encoded: {:?}", &encoded_bytes);
         Ok(())
     }
+
+    /// Like `send_chunked()`, but the body is pulled from `reader` and chunk-encoded as it is
+    /// read, so at most a couple of chunks' worth of it is held in memory at once.  Use this to
+    /// relay a large body, such as a block read from a file.
+    /// Returns the number of body bytes sent.
+    pub fn send_chunked_streaming<R: Read, W: Write>(
+        reader: &mut R,
+        chunk_size: usize,
+        fd: &mut W,
+    ) -> Result<u64, Error> {
+        let mut encoder = HttpChunkedTransferWriterState::new(chunk_size);
+        let mut chunker_fd = HttpChunkedTransferWriter::from_writer_state(fd, &mut encoder);
+        let mut buf = vec![0u8; chunk_size];
+        let mut num_sent = 0;
+        loop {
+            let nr = match reader.read(&mut buf) {
+                Ok(0) => break,
+                Ok(nr) => nr,
+                Err(e) if e.kind() == io::ErrorKind::Interrupted => continue,
+                Err(e) => return Err(Error::ReadError(e)),
+            };
+            chunker_fd
+                .write_all(&buf[0..nr])
+                .map_err(Error::WriteError)?;
+            num_sent += nr as u64;
+        }
+        chunker_fd.flush().map_err(Error::WriteError)?;
+        Ok(num_sent)
+    }
 }
 
 /// Convert into the inner Bytes
@@ -728,6 +1397,20 @@ impl TryInto<String> for HttpResponsePayload {
     }
 }
 
+/// Convert into the inner multipart parts
+impl TryInto<Vec<MultipartPart>> for HttpResponsePayload {
+    type Error = Error;
+    fn try_into(self) -> Result<Vec<MultipartPart>, Error> {
+        match self {
+            HttpResponsePayload::Empty => Ok(vec![]),
+            HttpResponsePayload::Multipart(parts) => Ok(parts),
+            _ => Err(Error::DecodeError(
+                "Http payload is not Multipart".to_string(),
+            )),
+        }
+    }
+}
+
 /// Convert into the inner JSON
 impl TryInto<serde_json::Value> for HttpResponsePayload {
     type Error = Error;
@@ -768,4 +1451,141 @@ pub trait HttpResponse: Send + HttpResponseClone {
         preamble: &HttpResponsePreamble,
         body: &[u8],
     ) -> Result<HttpResponsePayload, Error>;
+
+    /// How to decode a `text/plain` body that is not valid UTF-8.
+    /// By default, such a body fails to decode.
+    fn text_decode_mode(&self) -> TextDecodeMode {
+        TextDecodeMode::Strict
+    }
+
+    /// The non-error status codes this response may carry.
+    /// By default, any status code is allowed.
+    fn allowed_status_codes(&self) -> Option<&'static [u16]> {
+        None
+    }
+}
+
+/// Read a decompressed body from `decoder`, rejecting it if it is longer than `max_len` bytes
+fn read_decoded_body<R: Read>(decoder: R, max_len: u64, encoding: &str) -> Result<Vec<u8>, Error> {
+    let mut decoded = vec![];
+    decoder
+        .take(max_len.saturating_add(1))
+        .read_to_end(&mut decoded)
+        .map_err(|e| Error::DecodeError(format!("Failed to decompress {encoding} body: {e}")))?;
+    if decoded.len() as u64 > max_len {
+        return Err(Error::DecodeError(format!(
+            "Decompressed body is longer than {max_len} bytes"
+        )));
+    }
+    Ok(decoded)
+}
+
+/// Does `body` start with a zlib (RFC 1950) header?
+fn is_zlib_wrapped(body: &[u8]) -> bool {
+    let [cmf, flg, ..] = body else {
+        return false;
+    };
+    // the compression method must be deflate, and the header is a multiple of 31
+    cmf & 0x0f == 8 && ((u16::from(*cmf) << 8) | u16::from(*flg)) % 31 == 0
+}
+
+/// Read the body of an HTTP response whose preamble has already been read from `fd`.  If the
+/// preamble has a content length, exactly that many bytes are read; otherwise, the body is
+/// decoded from chunked transfer encoding.  Bodies longer than `MAX_MESSAGE_LEN` are rejected.
+/// Any `Content-Encoding` is left in place (see `decode_content_encoding()`).
+pub fn read_response_body<R: Read>(
+    preamble: &HttpResponsePreamble,
+    fd: &mut R,
+) -> Result<Vec<u8>, Error> {
+    let max_len = u64::from(MAX_MESSAGE_LEN);
+    if let Some(content_length) = preamble.content_length {
+        if u64::from(content_length) > max_len {
+            return Err(Error::DecodeError(format!(
+                "Response body is longer than {max_len} bytes"
+            )));
+        }
+        let mut body = vec![0u8; content_length as usize];
+        fd.read_exact(&mut body).map_err(Error::ReadError)?;
+        Ok(body)
+    } else {
+        let mut state = HttpChunkedTransferReaderState::new(max_len);
+        let mut body = vec![];
+        let mut buf = [0u8; 4096];
+        while !state.is_eof() {
+            let (decoded, consumed) = state.do_read(fd, &mut buf).map_err(Error::ReadError)?;
+            if consumed == 0 {
+                return Err(Error::ReadError(io::Error::new(
+                    io::ErrorKind::UnexpectedEof,
+                    "Chunk-encoded response body ended early",
+                )));
+            }
+            body.extend_from_slice(&buf[..decoded]);
+        }
+        Ok(body)
+    }
+}
+
+/// Undo the `Content-Encoding` of a response body, if any, so that it can be handed to a payload
+/// parser.  Gzip- and deflate-encoded (and, with the `zstd_encoding` feature, zstd-encoded) bodies
+/// are decompressed, up to `max_len` bytes; longer bodies are rejected.  Deflate bodies may be
+/// zlib-wrapped or raw, since servers disagree on which one the name means.
+pub fn decode_content_encoding<'a>(
+    preamble: &HttpResponsePreamble,
+    body: &'a [u8],
+    max_len: u64,
+) -> Result<Cow<'a, [u8]>, Error> {
+    let Some(encoding) = preamble.headers.get("content-encoding") else {
+        return Ok(Cow::Borrowed(body));
+    };
+    match encoding.trim().to_lowercase().as_str() {
+        "identity" => Ok(Cow::Borrowed(body)),
+        "gzip" | "x-gzip" => Ok(Cow::Owned(read_decoded_body(
+            GzDecoder::new(body),
+            max_len,
+            "gzip",
+        )?)),
+        "deflate" => {
+            let decoded = if is_zlib_wrapped(body) {
+                read_decoded_body(ZlibDecoder::new(body), max_len, "deflate")?
+            } else {
+                read_decoded_body(DeflateDecoder::new(body), max_len, "deflate")?
+            };
+            Ok(Cow::Owned(decoded))
+        }
+        #[cfg(feature = "zstd_encoding")]
+        "zstd" => {
+            let decoder = zstd::stream::read::Decoder::new(body)
+                .map_err(|e| Error::DecodeError(format!("Failed to decompress zstd body: {e}")))?;
+            Ok(Cow::Owned(read_decoded_body(decoder, max_len, "zstd")?))
+        }
+        other => Err(Error::DecodeError(format!(
+            "Unsupported content encoding '{other}'"
+        ))),
+    }
+}
+
+/// Decode an HTTP response with `handler`, first checking that its status code is one the handler
+/// allows.  Error responses (status code 400 and above) are not checked, so that they can still be
+/// decoded as errors; those with an `HttpErrorPayload` JSON body are returned as
+/// `Error::HttpErrorResponse`.  The body is decompressed first if it has a `Content-Encoding`.
+pub fn try_decode_response<H: HttpResponse + ?Sized>(
+    handler: &H,
+    preamble: &HttpResponsePreamble,
+    body: &[u8],
+) -> Result<HttpResponsePayload, Error> {
+    if preamble.status_code < 400 {
+        if let Some(allowed) = handler.allowed_status_codes() {
+            if !allowed.contains(&preamble.status_code) {
+                return Err(Error::UnexpectedStatus {
+                    got: preamble.status_code,
+                    allowed: allowed.to_vec(),
+                });
+            }
+        }
+    }
+    let body = decode_content_encoding(preamble, body, MAX_MESSAGE_LEN.into())?;
+    if let Some(payload) = HttpErrorPayload::try_from_response(preamble, &body) {
+        return Err(Error::HttpErrorResponse(payload));
+    }
+    handler.try_parse_response(preamble, &body)
 }
@@ -14,15 +14,20 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use std::io;
-use std::io::{Read, Write};
+use std::io::{Read, Seek, SeekFrom, Write};
+use std::path::PathBuf;
+use std::sync::mpsc::{sync_channel, Receiver, RecvTimeoutError, SyncSender};
+use std::time::Duration;
+use std::{fs, io, thread};
 
+use flate2::read::GzDecoder;
+use flate2::write::{GzEncoder, ZlibEncoder};
+use flate2::Compression;
 use rand::{thread_rng, Rng};
 use stacks_common::types::chainstate::{BlockHeaderHash, StacksBlockId};
 use stacks_common::util::chunked_encoding::{
     HttpChunkedTransferWriter, HttpChunkedTransferWriterState,
 };
-use stacks_common::util::pipe::PipeWrite;
 
 use crate::burnchains::Txid;
 use crate::chainstate::stacks::db::StacksChainState;
@@ -30,12 +35,30 @@ use crate::chainstate::stacks::{
     Error as ChainstateError, StacksBlock, StacksBlockHeader, StacksMicroblock,
 };
 use crate::core::mempool::{MemPoolDB, MemPoolSyncData};
+use crate::net::http::HttpContentEncoding;
 use crate::util_lib::db::Error as DBError;
 
+/// How long an SSE stream waits for an event before sending a keep-alive comment
+pub const SSE_KEEPALIVE_INTERVAL_SECS: u64 = 15;
+
 pub trait HttpChunkGenerator: Send {
     fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String>;
     fn hint_chunk_size(&self) -> usize;
 
+    /// Names of the trailer headers this generator sends after the last chunk.  These are
+    /// advertised in the response's `Trailer` header, so they must be known up front.
+    /// By default, there are none.
+    fn trailer_names(&self) -> Vec<String> {
+        vec![]
+    }
+
+    /// Trailer headers to send after the last chunk, such as a digest of the body.  This is
+    /// called once the last chunk has been generated.
+    /// By default, there are none.
+    fn trailers(&self) -> Vec<(String, String)> {
+        vec![]
+    }
+
     /// Stream one chunk to the pipe writer.  This never blocks.
     /// Returns Ok(num-bytes > 0) if there are more chunks (i.e. the caller should call this again)
     /// Returns Ok(0) if there are no more chunks (i.e. the caller should not call this again)
@@ -44,20 +67,25 @@ pub trait HttpChunkGenerator: Send {
     fn stream_to(
         &mut self,
         encoder_state: &mut HttpChunkedTransferWriterState,
-        fd: &mut PipeWrite,
+        mut fd: &mut dyn Write,
     ) -> Result<u64, io::Error> {
         let chunk = self.generate_next_chunk().map_err(|e| {
             warn!("Chunk generator failed: {}", &e);
             io::ErrorKind::Other
         })?;
 
-        let mut encoder = HttpChunkedTransferWriter::from_writer_state(fd, encoder_state);
+        let mut encoder = HttpChunkedTransferWriter::from_writer_state(&mut fd, encoder_state);
 
         if chunk.is_empty() {
             // no more chunks, but be sure to cork the stream
             if !encoder.corked() {
-                encoder.flush()?;
-                encoder.cork();
+                let trailers = self.trailers();
+                if trailers.is_empty() {
+                    encoder.flush()?;
+                    encoder.cork();
+                } else {
+                    encoder.finish_with_trailers(&trailers)?;
+                }
             }
         } else {
             encoder.write_all(&chunk)?;
@@ -76,3 +104,491 @@ pub trait Streamer {
     /// how much data has been sent so far.
     fn add_bytes(&mut self, nw: u64);
 }
+
+/// Stream a gzip-compressed file from disk.  If the client accepts gzip, then the compressed
+/// bytes are passed through as-is (and the response should carry `Content-Encoding: gzip`).
+/// Otherwise, the bytes are decompressed on the fly.
+pub struct GzipFileStream {
+    /// path to the gzip-compressed file
+    path: PathBuf,
+    /// whether or not to send the compressed bytes verbatim
+    passthrough: bool,
+    /// offset into the compressed file (only used for passthrough)
+    offset: u64,
+    /// decompressor over the file (only used if not passthrough)
+    decoder: Option<GzDecoder<fs::File>>,
+    /// total number of bytes generated so far
+    pub total_bytes: u64,
+}
+
+impl GzipFileStream {
+    pub fn new(path: PathBuf, passthrough: bool) -> GzipFileStream {
+        GzipFileStream {
+            path,
+            passthrough,
+            offset: 0,
+            decoder: None,
+            total_bytes: 0,
+        }
+    }
+
+    /// Get the Content-Encoding that a response streamed from this should carry, if any
+    pub fn content_encoding(&self) -> Option<HttpContentEncoding> {
+        if self.passthrough {
+            Some(HttpContentEncoding::Gzip)
+        } else {
+            None
+        }
+    }
+
+    fn open(&self) -> Result<fs::File, String> {
+        fs::OpenOptions::new()
+            .read(true)
+            .write(false)
+            .create(false)
+            .truncate(false)
+            .open(&self.path)
+            .map_err(|e| {
+                let msg = format!("Failed to open {}: {:?}", self.path.display(), &e);
+                warn!("{}", &msg);
+                msg
+            })
+    }
+}
+
+impl HttpChunkGenerator for GzipFileStream {
+    #[cfg(test)]
+    fn hint_chunk_size(&self) -> usize {
+        // make this hurt
+        32
+    }
+
+    #[cfg(not(test))]
+    fn hint_chunk_size(&self) -> usize {
+        4096
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        let mut buf = vec![0u8; self.hint_chunk_size()];
+        let num_read = if self.passthrough {
+            // Like the other file streamers, re-open the file on each call so we don't exhaust
+            // the supply of file descriptors.
+            let mut file_fd = self.open()?;
+            file_fd.seek(SeekFrom::Start(self.offset)).map_err(|e| {
+                let msg = format!("Failed to read {}: {:?}", self.path.display(), &e);
+                warn!("{}", &msg);
+                msg
+            })?;
+            let num_read = file_fd.read(&mut buf).map_err(|e| {
+                let msg = format!("Failed to read {}: {:?}", self.path.display(), &e);
+                warn!("{}", &msg);
+                msg
+            })?;
+            self.offset += num_read as u64;
+            num_read
+        } else {
+            // The decompressor is stateful, so it must hold the file open until the stream is
+            // exhausted.
+            if self.decoder.is_none() {
+                self.decoder = Some(GzDecoder::new(self.open()?));
+            }
+            let decoder = self
+                .decoder
+                .as_mut()
+                .expect("infallible: decoder was just set");
+            let num_read = decoder.read(&mut buf).map_err(|e| {
+                let msg = format!("Failed to decompress {}: {:?}", self.path.display(), &e);
+                warn!("{}", &msg);
+                msg
+            })?;
+            num_read
+        };
+
+        buf.truncate(num_read);
+        self.total_bytes += num_read as u64;
+        Ok(buf)
+    }
+}
+
+/// A `Write`-based compressor whose compressed output is buffered in memory, so that a
+/// `CompressEncodeStream` can hand it out a chunk at a time.
+pub trait StreamCompressor: Write + Send {
+    /// Take the compressed bytes buffered so far
+    fn take_output(&mut self) -> Vec<u8>;
+    /// Finish the compressed stream, returning its remaining bytes
+    fn finish_output(self) -> Result<Vec<u8>, io::Error>;
+}
+
+impl StreamCompressor for GzEncoder<Vec<u8>> {
+    fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(self.get_mut())
+    }
+
+    fn finish_output(self) -> Result<Vec<u8>, io::Error> {
+        self.finish()
+    }
+}
+
+impl StreamCompressor for ZlibEncoder<Vec<u8>> {
+    fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(self.get_mut())
+    }
+
+    fn finish_output(self) -> Result<Vec<u8>, io::Error> {
+        self.finish()
+    }
+}
+
+#[cfg(feature = "zstd_encoding")]
+impl StreamCompressor for zstd::stream::write::Encoder<'static, Vec<u8>> {
+    fn take_output(&mut self) -> Vec<u8> {
+        std::mem::take(self.get_mut())
+    }
+
+    fn finish_output(self) -> Result<Vec<u8>, io::Error> {
+        self.finish()
+    }
+}
+
+/// Stream the output of another chunk generator, compressed with a `StreamCompressor`.  The
+/// response should carry the compressor's `Content-Encoding`.  Any trailers of the inner
+/// stream are passed through.
+pub struct CompressEncodeStream<C: StreamCompressor> {
+    /// the stream whose output gets compressed
+    inner: Box<dyn HttpChunkGenerator>,
+    /// compressor over the inner stream's output (`None` once the stream has been finished)
+    compressor: Option<C>,
+    /// total number of compressed bytes generated so far
+    pub total_bytes: u64,
+}
+
+/// Stream the output of another chunk generator, compressed with gzip
+pub type GzipEncodeStream = CompressEncodeStream<GzEncoder<Vec<u8>>>;
+
+/// Stream the output of another chunk generator, compressed with deflate.  The output is
+/// zlib-wrapped (RFC 1950), which is what the `deflate` content-coding means; clients that expect
+/// raw (RFC 1951) deflate tend to accept zlib-wrapped data too, while the converse does not hold.
+pub type DeflateEncodeStream = CompressEncodeStream<ZlibEncoder<Vec<u8>>>;
+
+/// Stream the output of another chunk generator, compressed with zstd
+#[cfg(feature = "zstd_encoding")]
+pub type ZstdEncodeStream = CompressEncodeStream<zstd::stream::write::Encoder<'static, Vec<u8>>>;
+
+impl<C: StreamCompressor> CompressEncodeStream<C> {
+    pub fn with_compressor(inner: Box<dyn HttpChunkGenerator>, compressor: C) -> Self {
+        CompressEncodeStream {
+            inner,
+            compressor: Some(compressor),
+            total_bytes: 0,
+        }
+    }
+}
+
+impl GzipEncodeStream {
+    pub fn new(inner: Box<dyn HttpChunkGenerator>) -> GzipEncodeStream {
+        Self::with_compressor(inner, GzEncoder::new(vec![], Compression::default()))
+    }
+}
+
+impl DeflateEncodeStream {
+    pub fn new(inner: Box<dyn HttpChunkGenerator>) -> DeflateEncodeStream {
+        Self::with_compressor(inner, ZlibEncoder::new(vec![], Compression::default()))
+    }
+}
+
+#[cfg(feature = "zstd_encoding")]
+impl ZstdEncodeStream {
+    pub fn new(inner: Box<dyn HttpChunkGenerator>) -> Result<ZstdEncodeStream, io::Error> {
+        Ok(Self::with_compressor(
+            inner,
+            zstd::stream::write::Encoder::new(vec![], zstd::DEFAULT_COMPRESSION_LEVEL)?,
+        ))
+    }
+}
+
+/// Make a stream of the output of `inner`, compressed with `encoding`
+pub fn encode_stream(
+    inner: Box<dyn HttpChunkGenerator>,
+    encoding: HttpContentEncoding,
+) -> Result<Box<dyn HttpChunkGenerator>, io::Error> {
+    let stream: Box<dyn HttpChunkGenerator> = match encoding {
+        HttpContentEncoding::Gzip => Box::new(GzipEncodeStream::new(inner)),
+        HttpContentEncoding::Deflate => Box::new(DeflateEncodeStream::new(inner)),
+        #[cfg(feature = "zstd_encoding")]
+        HttpContentEncoding::Zstd => Box::new(ZstdEncodeStream::new(inner)?),
+    };
+    Ok(stream)
+}
+
+impl<C: StreamCompressor> HttpChunkGenerator for CompressEncodeStream<C> {
+    fn hint_chunk_size(&self) -> usize {
+        self.inner.hint_chunk_size()
+    }
+
+    fn trailer_names(&self) -> Vec<String> {
+        self.inner.trailer_names()
+    }
+
+    fn trailers(&self) -> Vec<(String, String)> {
+        self.inner.trailers()
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        // An empty chunk ends the stream, so keep feeding the compressor until it produces
+        // output or the inner stream is exhausted.
+        loop {
+            let Some(compressor) = self.compressor.as_mut() else {
+                // end of stream
+                return Ok(vec![]);
+            };
+            let chunk = self.inner.generate_next_chunk()?;
+            let compressed = if chunk.is_empty() {
+                let compressor = self
+                    .compressor
+                    .take()
+                    .expect("infallible: compressor was just checked");
+                compressor
+                    .finish_output()
+                    .map_err(|e| format!("Failed to finish compressed stream: {e:?}"))?
+            } else {
+                compressor
+                    .write_all(&chunk)
+                    .and_then(|_| compressor.flush())
+                    .map_err(|e| format!("Failed to compress chunk: {e:?}"))?;
+                compressor.take_output()
+            };
+            if !compressed.is_empty() {
+                self.total_bytes += compressed.len() as u64;
+                return Ok(compressed);
+            }
+        }
+    }
+}
+
+/// Stream a body held in RAM, one chunk at a time.  This lets an in-memory body be passed
+/// through an encoding stream (e.g. `GzipEncodeStream`) that compresses it as it is sent.
+pub struct RamChunkStream {
+    /// the body being streamed
+    data: Vec<u8>,
+    /// offset into `data` of the next chunk
+    offset: usize,
+}
+
+impl RamChunkStream {
+    pub fn new(data: Vec<u8>) -> RamChunkStream {
+        RamChunkStream { data, offset: 0 }
+    }
+}
+
+impl HttpChunkGenerator for RamChunkStream {
+    fn hint_chunk_size(&self) -> usize {
+        4096
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        let end = self
+            .offset
+            .saturating_add(self.hint_chunk_size())
+            .min(self.data.len());
+        let chunk = self.data[self.offset..end].to_vec();
+        self.offset = end;
+        Ok(chunk)
+    }
+}
+
+/// Stream a value as JSON.  The value is serialized on a helper thread, which hands over the
+/// serialized bytes a chunk at a time as the stream asks for them, so serialization only advances
+/// as fast as the response is sent and the whole serialized value is never held in memory.
+pub struct JsonStream<T: serde::Serialize + Send + 'static> {
+    /// the value to serialize (taken once the serializer thread starts)
+    value: Option<T>,
+    /// serialized chunks from the serializer thread, once it has started
+    chunks: Option<Receiver<Result<Vec<u8>, String>>>,
+}
+
+impl<T: serde::Serialize + Send + 'static> JsonStream<T> {
+    pub fn new(value: T) -> JsonStream<T> {
+        JsonStream {
+            value: Some(value),
+            chunks: None,
+        }
+    }
+
+    /// Start serializing `value` on a new thread, returning the receiving end of its chunks
+    fn spawn_serializer(
+        value: T,
+        chunk_size: usize,
+    ) -> Result<Receiver<Result<Vec<u8>, String>>, String> {
+        // a rendezvous channel, so the serializer waits for each chunk to be asked for
+        let (chunk_tx, chunk_rx) = sync_channel(0);
+        thread::Builder::new()
+            .name("json-stream".into())
+            .spawn(move || {
+                let mut writer = JsonChunkWriter {
+                    chunk_tx,
+                    buf: Vec::with_capacity(chunk_size),
+                    chunk_size,
+                };
+                let res = serde_json::to_writer(&mut writer, &value)
+                    .map_err(|e| format!("Failed to serialize JSON: {e:?}"))
+                    .and_then(|_| {
+                        writer
+                            .send_buf()
+                            .map_err(|e| format!("Failed to send JSON: {e:?}"))
+                    });
+                if let Err(e) = res {
+                    // the stream may already be gone, in which case there's no one to tell
+                    let _ = writer.chunk_tx.send(Err(e));
+                }
+            })
+            .map_err(|e| format!("Failed to spawn JSON serializer thread: {e:?}"))?;
+        Ok(chunk_rx)
+    }
+}
+
+/// Buffers the JSON serializer's output, and hands it to a `JsonStream` a chunk at a time
+struct JsonChunkWriter {
+    /// where to send each chunk
+    chunk_tx: SyncSender<Result<Vec<u8>, String>>,
+    /// bytes that have yet to be sent
+    buf: Vec<u8>,
+    /// number of bytes to buffer before sending them
+    chunk_size: usize,
+}
+
+impl JsonChunkWriter {
+    /// Send the buffered bytes, if there are any.  Fails if the stream has been dropped.
+    fn send_buf(&mut self) -> Result<(), io::Error> {
+        if self.buf.is_empty() {
+            return Ok(());
+        }
+        let chunk = std::mem::replace(&mut self.buf, Vec::with_capacity(self.chunk_size));
+        self.chunk_tx
+            .send(Ok(chunk))
+            .map_err(|_| io::Error::new(io::ErrorKind::BrokenPipe, "JSON stream was dropped"))
+    }
+}
+
+impl Write for JsonChunkWriter {
+    fn write(&mut self, bytes: &[u8]) -> Result<usize, io::Error> {
+        self.buf.extend_from_slice(bytes);
+        if self.buf.len() >= self.chunk_size {
+            self.send_buf()?;
+        }
+        Ok(bytes.len())
+    }
+
+    fn flush(&mut self) -> Result<(), io::Error> {
+        self.send_buf()
+    }
+}
+
+impl<T: serde::Serialize + Send + 'static> HttpChunkGenerator for JsonStream<T> {
+    fn hint_chunk_size(&self) -> usize {
+        4096
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        if let Some(value) = self.value.take() {
+            self.chunks = Some(Self::spawn_serializer(value, self.hint_chunk_size())?);
+        }
+        let Some(chunks) = self.chunks.as_ref() else {
+            // end of stream
+            return Ok(vec![]);
+        };
+        match chunks.recv() {
+            Ok(chunk) => chunk,
+            Err(_) => {
+                // the serializer is done
+                self.chunks = None;
+                Ok(vec![])
+            }
+        }
+    }
+}
+
+/// Stream Server-Sent Events.  Each JSON event received on the channel is sent as a single
+/// `data: {json}\n\n` frame, which is flushed to the client as soon as it is written.  The
+/// stream ends once every sender has been dropped.
+///
+/// Generating the next chunk waits up to the keep-alive interval for the next event.  If none
+/// arrives, an SSE comment is sent instead, so a client that has gone away is noticed on the
+/// next write rather than holding its serving thread until the next event.
+pub struct SseEventStream {
+    /// incoming events
+    events: Receiver<serde_json::Value>,
+    /// how long to wait for an event before sending a keep-alive comment
+    keepalive_interval: Duration,
+    /// number of events sent so far
+    pub num_events: u64,
+}
+
+impl SseEventStream {
+    /// Content-Type of a response streamed from this
+    pub const CONTENT_TYPE: &'static str = "text/event-stream";
+    /// Comment frame sent when no event arrives within the keep-alive interval
+    pub const KEEPALIVE_FRAME: &'static [u8] = b": keep-alive\n\n";
+
+    pub fn new(events: Receiver<serde_json::Value>) -> SseEventStream {
+        SseEventStream {
+            events,
+            keepalive_interval: Duration::from_secs(SSE_KEEPALIVE_INTERVAL_SECS),
+            num_events: 0,
+        }
+    }
+
+    /// chain constructor for the keep-alive interval
+    pub fn with_keepalive_interval(mut self, keepalive_interval: Duration) -> Self {
+        self.keepalive_interval = keepalive_interval;
+        self
+    }
+
+    /// Encode an event as an SSE frame
+    pub fn format_event(event: &serde_json::Value) -> Vec<u8> {
+        format!("data: {event}\n\n").into_bytes()
+    }
+}
+
+impl HttpChunkGenerator for SseEventStream {
+    fn hint_chunk_size(&self) -> usize {
+        4096
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        match self.events.recv_timeout(self.keepalive_interval) {
+            Ok(event) => {
+                self.num_events += 1;
+                Ok(Self::format_event(&event))
+            }
+            Err(RecvTimeoutError::Timeout) => Ok(Self::KEEPALIVE_FRAME.to_vec()),
+            // all senders are gone
+            Err(RecvTimeoutError::Disconnected) => Ok(vec![]),
+        }
+    }
+
+    /// Like the default implementation, but flush each event to the client right away
+    fn stream_to(
+        &mut self,
+        encoder_state: &mut HttpChunkedTransferWriterState,
+        mut fd: &mut dyn Write,
+    ) -> Result<u64, io::Error> {
+        let chunk = self.generate_next_chunk().map_err(|e| {
+            warn!("Chunk generator failed: {}", &e);
+            io::ErrorKind::Other
+        })?;
+
+        let mut encoder = HttpChunkedTransferWriter::from_writer_state(&mut fd, encoder_state);
+
+        if chunk.is_empty() {
+            if !encoder.corked() {
+                encoder.flush()?;
+                encoder.cork();
+            }
+        } else {
+            encoder.write_all(&chunk)?;
+            encoder.flush_buffered()?;
+        }
+
+        Ok(chunk.len() as u64)
+    }
+}
@@ -14,13 +14,44 @@
 // You should have received a copy of the GNU General Public License
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
-use stacks_common::codec::StacksMessageCodec;
+use std::fs;
+use std::io::{self, Read, Write};
+use std::mem;
+use std::path::Path;
+use std::str::FromStr;
+use std::sync::mpsc::channel;
+use std::time::{Duration, SystemTime};
+
+use flate2::read::{GzDecoder, ZlibDecoder};
+use flate2::write::{DeflateEncoder, GzEncoder};
+use flate2::Compression;
+use stacks_common::codec::{Error as CodecError, StacksMessageCodec, MAX_MESSAGE_LEN};
 use stacks_common::types::net::{PeerAddress, PeerHost};
+use stacks_common::util::chunked_encoding::{
+    HttpChunkedTransferReader, HttpChunkedTransferWriterState,
+};
+use stacks_common::util::hash::{to_hex, Sha256Sum};
+use stacks_common::util::pipe::VecPipeWrite;
 
-use crate::net::http::common::{HTTP_PREAMBLE_MAX_ENCODED_SIZE, HTTP_PREAMBLE_MAX_NUM_HEADERS};
+use crate::net::connection::ConnectionOptions;
+use crate::net::http::common::{
+    trim_header_value, HTTP_PREAMBLE_MAX_ENCODED_SIZE, HTTP_PREAMBLE_MAX_HEADER_NAME_LEN,
+    HTTP_PREAMBLE_MAX_NUM_HEADERS,
+};
+use crate::net::http::response::{rfc7231_date, HttpResponse};
+use crate::net::http::stream::{
+    DeflateEncodeStream, GzipEncodeStream, GzipFileStream, SseEventStream,
+};
 use crate::net::http::{
-    HttpContentType, HttpRequestPreamble, HttpReservedHeader, HttpResponsePreamble, HttpVersion,
+    decode_content_encoding, decode_multipart, encode_multipart, parse_json, parse_multipart,
+    parse_text, read_response_body, try_decode_response, Error, HttpByteRange, HttpChunkGenerator,
+    HttpContentEncoding, HttpContentType, HttpErrorPayload, HttpRequestPreamble,
+    HttpReservedHeader, HttpResponseContents, HttpResponsePayload, HttpResponsePreamble,
+    HttpVersion, MetricsContentType, MultipartBoundary, MultipartPart, TextDecodeMode,
+    HTTP_DEFAULT_CHARSET,
 };
+use crate::net::httpcore::{StacksHttp, StacksHttpMessage};
+use crate::net::ProtocolFamily;
 
 #[test]
 fn test_parse_reserved_header() {
@@ -64,7 +95,20 @@ fn test_parse_reserved_header() {
                 123,
             ))),
         ),
+        (
+            "Location",
+            "http://foo:123/v2/info",
+            Some(HttpReservedHeader::Location(
+                "http://foo:123/v2/info".to_string(),
+            )),
+        ),
+        (
+            "Cache-Control",
+            "no-store",
+            Some(HttpReservedHeader::CacheControl("no-store".to_string())),
+        ),
         // errors
+        ("Location", "/v2/info\r\nFoo: bar", None),
         ("Content-Length", "-1", None),
         ("Content-Length", "asdf", None),
         ("Content-Length", "4294967296", None),
@@ -160,10 +204,6 @@ fn test_parse_http_request_preamble_err() {
             "Get /foo HTTP/1.1\r\nHost: localhost:666666\r\n\r\n",
             "Missing Host header",
         ),
-        (
-            "GET /foo HTTP/1.1\r\nHost: localhost:8080\r\nConnection: foo\r\n\r\n",
-            "invalid Connection: header",
-        ),
     ];
 
     for (data, errstr) in tests.iter() {
@@ -276,6 +316,11 @@ fn test_parse_http_response_preamble_ok() {
          HttpResponsePreamble::from_headers(400, "Bad Request".to_string(), false, Some(456), HttpContentType::JSON, vec!["foo".to_string()], vec!["Bar".to_string()])),
         ("HTTP/1.1 200 Ok\r\nConnection: close\r\nContent-Type: application/octet-stream\r\nTransfer-encoding: chunked\r\n\r\n",
          HttpResponsePreamble::from_headers(200, "Ok".to_string(), false, None, HttpContentType::Bytes, vec![], vec![])),
+        // lowest and highest valid status codes
+        ("HTTP/1.1 100 Continue\r\nContent-Length: 0\r\n\r\n",
+         HttpResponsePreamble::from_headers(100, "Continue".to_string(), true, Some(0), HttpContentType::Bytes, vec![], vec![])),
+        ("HTTP/1.1 599 Network Connect Timeout Error\r\nContent-Length: 0\r\n\r\n",
+         HttpResponsePreamble::from_headers(599, "Network Connect Timeout Error".to_string(), true, Some(0), HttpContentType::Bytes, vec![], vec![])),
     ];
 
     for (data, response) in tests.iter() {
@@ -333,7 +378,8 @@ fn test_http_response_preamble_headers() {
         "Content-Length is missing"
     );
     assert!(
-        txt.find("Content-Type: application/json\r\n").is_some(),
+        txt.find("Content-Type: application/json; charset=utf-8\r\n")
+            .is_some(),
         "Content-Type is missing"
     );
     assert!(txt.find("Date: ").is_some(), "Date header is missing");
@@ -343,7 +389,7 @@ fn test_http_response_preamble_headers() {
         "CORS header is missing"
     );
     assert!(
-        txt.find("Access-Control-Allow-Headers: origin, content-type\r\n")
+        txt.find("Access-Control-Allow-Headers: origin, content-type, authorization\r\n")
             .is_some(),
         "CORS header is missing"
     );
@@ -355,6 +401,46 @@ fn test_http_response_preamble_headers() {
     assert!(txt.find("Connection: ").is_none()); // not sent if keep_alive is true
 }
 
+#[test]
+fn test_parse_http_response_repeated_headers() {
+    let data = "HTTP/1.1 200 OK\r\n\
+                Content-Type: application/json\r\n\
+                Content-Length: 0\r\n\
+                Set-Cookie: a=1\r\n\
+                Vary: Accept-Encoding\r\n\
+                set-cookie: b=2\r\n\
+                \r\n";
+    let preamble = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+
+    // all values are kept, in order, and the first one is the header's value
+    assert_eq!(
+        preamble.get_header_all("Set-Cookie".to_string()),
+        vec!["a=1".to_string(), "b=2".to_string()]
+    );
+    assert_eq!(
+        preamble.get_header("Set-Cookie".to_string()),
+        Some("a=1".to_string())
+    );
+    assert_eq!(
+        preamble.get_header_all("Vary".to_string()),
+        vec!["Accept-Encoding".to_string()]
+    );
+    assert_eq!(
+        preamble.get_header_all("Content-Length".to_string()),
+        vec!["0".to_string()]
+    );
+    assert!(preamble.get_header_all("Foo".to_string()).is_empty());
+
+    // each value is sent on its own line
+    let mut bytes = vec![];
+    preamble.consensus_serialize(&mut bytes).unwrap();
+    let txt = String::from_utf8(bytes).unwrap();
+    assert!(
+        txt.contains("set-cookie: a=1\r\nset-cookie: b=2\r\n"),
+        "{txt}"
+    );
+}
+
 #[test]
 fn test_parse_http_response_preamble_err() {
     let tests = vec![
@@ -362,7 +448,7 @@ fn test_parse_http_response_preamble_err() {
         "failed to fill whole buffer"),
         ("HTTP/1.1 200 OK\r\nfoo: \u{2764}\r\n\r\n",
         "header value is not ASCII-US"),
-        ("HTTP/1.1 200 OK\r\nfoo: bar\r\nfoo: bar\r\n\r\n",
+        ("HTTP/1.1 200 OK\r\nContent-Length: 123\r\nContent-Length: 123\r\n\r\n",
          "duplicate header"),
         ("HTTP/1.1 200 OK\r\nContent-Type: image/png\r\n\r\n",
          "Unsupported HTTP content type"),
@@ -372,8 +458,12 @@ fn test_parse_http_response_preamble_err() {
          "missing Content-Type, Content-Length"),
         ("HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 123\r\nTransfer-Encoding: chunked\r\n\r\n",
          "incompatible transfer-encoding and content-length"),
-        ("HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 123\r\nConnection: foo\r\n\r\n",
-         "invalid Connection: header"),
+        ("HTTP/1.1 600 Weird\r\nContent-Length: 0\r\n\r\n",
+         "status code 600 is not between 100 and 599"),
+        ("HTTP/1.1 099 Weird\r\nContent-Length: 0\r\n\r\n",
+         "status code 99 is not between 100 and 599"),
+        ("HTTP/1.1 200\r\nContent-Length: 0\r\n\r\n",
+         "empty status reason"),
     ];
 
     for (data, errstr) in tests.iter() {
@@ -527,6 +617,105 @@ fn test_http_headers_too_many() {
         .is_some());
 }
 
+#[test]
+fn test_http_response_headers_with_limits() {
+    let mut headers = String::new();
+    for i in 0..HTTP_PREAMBLE_MAX_NUM_HEADERS + 5 {
+        headers.push_str(&format!("X-Header-{}: {}\r\n", i, i));
+    }
+    let preamble_txt = format!(
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 64\r\n{}\r\n",
+        &headers
+    );
+
+    // too many headers for the defaults
+    let err =
+        HttpResponsePreamble::consensus_deserialize(&mut preamble_txt.as_bytes()).unwrap_err();
+    assert!(err
+        .to_string()
+        .find("Failed to parse HTTP response: TooManyHeaders")
+        .is_some());
+
+    // but fine with a higher limit
+    let preamble = HttpResponsePreamble::consensus_deserialize_with_limits(
+        &mut preamble_txt.as_bytes(),
+        HTTP_PREAMBLE_MAX_NUM_HEADERS + 10,
+        HTTP_PREAMBLE_MAX_ENCODED_SIZE,
+    )
+    .unwrap();
+    assert_eq!(preamble.status_code, 200);
+    assert_eq!(preamble.content_length, Some(64));
+    let last = HTTP_PREAMBLE_MAX_NUM_HEADERS + 4;
+    assert_eq!(
+        preamble.get_header(format!("X-Header-{}", last)),
+        Some(format!("{}", last))
+    );
+
+    // the size limit still applies
+    let err = HttpResponsePreamble::consensus_deserialize_with_limits(
+        &mut preamble_txt.as_bytes(),
+        HTTP_PREAMBLE_MAX_NUM_HEADERS + 10,
+        64,
+    )
+    .unwrap_err();
+    assert!(
+        err.to_string()
+            .find("Not enough bytes to form a HTTP response preamble")
+            .is_some(),
+        "{err}"
+    );
+}
+
+#[test]
+fn test_http_header_name_too_long() {
+    let make_request = |name: &str| {
+        format!("GET /v2/neighbors HTTP/1.1\r\nHost: localhost:1234\r\n{name}: 1\r\n\r\n")
+    };
+    let make_response = |name: &str| {
+        format!("HTTP/1.1 200 OK\r\nServer: stacks/v2.0\r\nX-Request-ID: 123\r\nContent-Type: text/plain\r\nContent-Length: 64\r\n{name}: 1\r\n\r\n")
+    };
+
+    // a name at the limit is fine
+    let name = "A".repeat(HTTP_PREAMBLE_MAX_HEADER_NAME_LEN);
+    let request =
+        HttpRequestPreamble::consensus_deserialize(&mut make_request(&name).as_bytes()).unwrap();
+    assert_eq!(
+        request
+            .headers
+            .get(&name.to_lowercase())
+            .map(String::as_str),
+        Some("1")
+    );
+    let response =
+        HttpResponsePreamble::consensus_deserialize(&mut make_response(&name).as_bytes()).unwrap();
+    assert_eq!(
+        response
+            .headers
+            .get(&name.to_lowercase())
+            .map(String::as_str),
+        Some("1")
+    );
+
+    // a name over the limit is rejected, even though the preamble is small enough
+    let name = "A".repeat(HTTP_PREAMBLE_MAX_HEADER_NAME_LEN + 1);
+    assert!(make_request(&name).len() < HTTP_PREAMBLE_MAX_ENCODED_SIZE as usize);
+    let request_err =
+        HttpRequestPreamble::consensus_deserialize(&mut make_request(&name).as_bytes())
+            .unwrap_err();
+    let response_err =
+        HttpResponsePreamble::consensus_deserialize(&mut make_response(&name).as_bytes())
+            .unwrap_err();
+
+    assert!(
+        matches!(&request_err, CodecError::DeserializeError(msg) if msg.starts_with("Invalid HTTP request: header name is longer than")),
+        "Unexpected request error: {request_err:?}"
+    );
+    assert!(
+        matches!(&response_err, CodecError::DeserializeError(msg) if msg.starts_with("Invalid HTTP response: header name is longer than")),
+        "Unexpected response error: {response_err:?}"
+    );
+}
+
 #[test]
 fn test_http_request_version_keep_alive() {
     let requests = vec![
@@ -639,15 +828,137 @@ fn test_http_response_version_keep_alive() {
     }
 }
 
+#[test]
+fn test_http_response_keep_alive_tuning() {
+    let make_preamble = |keep_alive, timeout, max| {
+        let mut preamble = HttpResponsePreamble::new(
+            HttpVersion::Http11,
+            200,
+            "OK".into(),
+            Some(0),
+            HttpContentType::JSON,
+            keep_alive,
+        );
+        preamble.keep_alive_timeout = timeout;
+        preamble.keep_alive_max = max;
+        preamble
+    };
+
+    // (keep-alive?, timeout, max, expected Keep-Alive header)
+    let tests = [
+        (true, None, None, None),
+        (
+            true,
+            Some(60),
+            Some(1000),
+            Some("Keep-Alive: timeout=60, max=1000\r\n"),
+        ),
+        (true, Some(60), None, Some("Keep-Alive: timeout=60\r\n")),
+        (true, None, Some(1000), Some("Keep-Alive: max=1000\r\n")),
+        (false, Some(60), Some(1000), None),
+    ];
+    for (keep_alive, timeout, max, expected) in tests {
+        let preamble = make_preamble(keep_alive, timeout, max);
+        let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+        match expected {
+            Some(header) => assert!(txt.contains(header), "{header:?} is missing from {txt}"),
+            None => assert!(!txt.contains("Keep-Alive:"), "{txt}"),
+        }
+
+        // the tuning params are parsed back out of the header
+        let decoded = HttpResponsePreamble::consensus_deserialize(&mut txt.as_bytes()).unwrap();
+        assert_eq!(decoded.keep_alive, keep_alive);
+        if keep_alive {
+            assert_eq!(decoded.keep_alive_timeout, timeout);
+            assert_eq!(decoded.keep_alive_max, max);
+        } else {
+            assert_eq!(decoded.keep_alive_timeout, None);
+            assert_eq!(decoded.keep_alive_max, None);
+        }
+    }
+
+    // unknown and malformed params are ignored
+    let data = "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nKeep-Alive: timeout = 5, max=lots, foo=1, bar\r\n\r\n";
+    let decoded = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    assert_eq!(decoded.keep_alive_timeout, Some(5));
+    assert_eq!(decoded.keep_alive_max, None);
+    assert!(decoded.get_header("Keep-Alive".to_string()).is_none());
+}
+
+#[test]
+fn test_parse_connection_header_tokens() {
+    // (Connection: value, keep-alive?)
+    let tests = [
+        ("keep-alive, Upgrade", true),
+        ("Upgrade,Keep-Alive", true),
+        ("close, foo", false),
+        ("keep-alive, close", false),
+    ];
+
+    for (value, keep_alive) in tests {
+        // HTTP/1.0 defaults to closing the connection, and HTTP/1.1 to keeping it alive
+        for version in ["1.0", "1.1"] {
+            let data = format!(
+                "GET /foo HTTP/{version}\r\nHost: localhost:6270\r\nConnection: {value}\r\n\r\n"
+            );
+            let req = HttpRequestPreamble::consensus_deserialize(&mut data.as_bytes())
+                .unwrap_or_else(|e| panic!("Failed to parse {data:?}: {e:?}"));
+            assert_eq!(req.keep_alive, keep_alive, "{data:?}");
+
+            let data = format!(
+                "HTTP/{version} 200 OK\r\nContent-Type: application/json\r\nContent-Length: 0\r\nConnection: {value}\r\n\r\n"
+            );
+            let res = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes())
+                .unwrap_or_else(|e| panic!("Failed to parse {data:?}: {e:?}"));
+            assert_eq!(res.keep_alive, keep_alive, "{data:?}");
+        }
+    }
+
+    // a value without a close or keep-alive token leaves the version's default
+    for (version, keep_alive) in [("1.0", false), ("1.1", true)] {
+        let data = format!(
+            "GET /foo HTTP/{version}\r\nHost: localhost:6270\r\nConnection: Upgrade, foo\r\n\r\n"
+        );
+        let req = HttpRequestPreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+        assert_eq!(req.keep_alive, keep_alive, "{data:?}");
+    }
+}
+
+#[test]
+fn test_parse_header_values_with_surrounding_whitespace() {
+    let data = "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 123 \r\nConnection:  close\r\nFoo: \t bar  baz \r\n\r\n";
+    let res = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    assert_eq!(res.content_length, Some(123));
+    assert!(!res.keep_alive);
+    // internal whitespace is preserved
+    assert_eq!(res.headers.get("foo"), Some(&"bar  baz".to_string()));
+
+    let data = "POST /foo HTTP/1.1\r\nHost: localhost:6270\r\nContent-Length: 123 \r\nConnection:  close\r\nFoo: \t bar  baz \r\n\r\n";
+    let req = HttpRequestPreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    assert_eq!(req.content_length, Some(123));
+    assert!(!req.keep_alive);
+    assert_eq!(req.headers.get("foo"), Some(&"bar  baz".to_string()));
+
+    assert_eq!(trim_header_value(" \t123 \t"), "123");
+    assert_eq!(
+        trim_header_value("  keep-alive, close "),
+        "keep-alive, close"
+    );
+    assert_eq!(trim_header_value("   "), "");
+}
+
 #[test]
 fn test_http_live_headers() {
     // headers pulled from prod
     let live_headers = &[
-        "GET /v2/info HTTP/1.1\r\naccept-language: en-US,en;q=0.9\r\naccept-encoding: gzip, deflate, br\r\nsec-fetch-dest: document\r\nsec-fetch-user: ?1\r\nsec-fetch-mode: navigate\r\nsec-fetch-site: none\r\naccept: text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.9\r\nuser-agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nupgrade-insecure-requests: 1\r\ncache-control: max-age=0\r\nconnection: close\r\nx-forwarded-port: 443\r\nx-forwarded-host: crashy-stacky.zone117x.com\r\nx-forwarded-proto: https\r\nx-forwarded-for: 213.127.17.55\r\nx-real-ip: 213.127.17.55\r\nhost: stacks-blockchain:20443\r\n\r\n"
+        "GET /v2/info HTTP/1.1\r\naccept-language: en-US,en;q=0.9\r\naccept-encoding: gzip, deflate, br\r\nsec-fetch-dest: document\r\nsec-fetch-user: ?1\r\nsec-fetch-mode: navigate\r\nsec-fetch-site: none\r\naccept: text/html,application/xhtml+xml,application/xml;q=0.9,image/webp,image/apng,*/*;q=0.8,application/signed-exchange;v=b3;q=0.9\r\nuser-agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nupgrade-insecure-requests: 1\r\ncache-control: max-age=0\r\nconnection: close\r\nx-forwarded-port: 443\r\nx-forwarded-host: crashy-stacky.zone117x.com\r\nx-forwarded-proto: https\r\nx-forwarded-for: 213.127.17.55\r\nx-real-ip: 213.127.17.55\r\nhost: stacks-blockchain:20443\r\n\r\n",
+        // an `upgrade` token in the Connection header is ignored
+        "GET /favicon.ico HTTP/1.1\r\nConnection: upgrade\r\nHost: crashy-stacky.zone117x.com\r\nX-Real-IP: 213.127.17.55\r\nX-Forwarded-For: 213.127.17.55\r\nX-Forwarded-Proto: http\r\nX-Forwarded-Host: crashy-stacky.zone117x.com\r\nX-Forwarded-Port: 9001\r\nUser-Agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nAccept: image/webp,image/apng,image/*,*/*;q=0.8\r\nReferer: http://crashy-stacky.zone117x.com:9001/v2/info\r\nAccept-Encoding: gzip, deflate\r\nAccept-Language: en-US,en;q=0.9\r\n\r\n"
     ];
 
+    // no Host header
     let bad_live_headers = &[
-        "GET /favicon.ico HTTP/1.1\r\nConnection: upgrade\r\nHost: crashy-stacky.zone117x.com\r\nX-Real-IP: 213.127.17.55\r\nX-Forwarded-For: 213.127.17.55\r\nX-Forwarded-Proto: http\r\nX-Forwarded-Host: crashy-stacky.zone117x.com\r\nX-Forwarded-Port: 9001\r\nUser-Agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nAccept: image/webp,image/apng,image/*,*/*;q=0.8\r\nReferer: http://crashy-stacky.zone117x.com:9001/v2/info\r\nAccept-Encoding: gzip, deflate\r\nAccept-Language: en-US,en;q=0.9\r\n\r\n",
+        "GET /favicon.ico HTTP/1.1\r\nConnection: upgrade\r\nX-Real-IP: 213.127.17.55\r\nX-Forwarded-For: 213.127.17.55\r\nX-Forwarded-Proto: http\r\nX-Forwarded-Host: crashy-stacky.zone117x.com\r\nX-Forwarded-Port: 9001\r\nUser-Agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nAccept: image/webp,image/apng,image/*,*/*;q=0.8\r\nReferer: http://crashy-stacky.zone117x.com:9001/v2/info\r\nAccept-Encoding: gzip, deflate\r\nAccept-Language: en-US,en;q=0.9\r\n\r\n",
     ];
 
     for live_header in live_headers {
@@ -664,3 +975,1627 @@ fn test_http_live_headers() {
         );
     }
 }
+
+#[test]
+fn test_http_request_accepts_encoding() {
+    let tests = vec![
+        (None, "gzip", false),
+        (Some("gzip"), "gzip", true),
+        (Some("GZIP"), "gzip", true),
+        (Some("gzip, deflate, br"), "gzip", true),
+        (Some("gzip, deflate, br"), "deflate", true),
+        (Some("deflate, br"), "gzip", false),
+        (Some("gzip;q=0.5, deflate"), "gzip", true),
+        (Some("gzip;q=0, deflate"), "gzip", false),
+        (Some("gzip; q=0.0"), "gzip", false),
+        (Some("*"), "gzip", true),
+        (Some("identity"), "gzip", false),
+        // an explicit q=0 overrides the wildcard, whichever comes first
+        (Some("gzip;q=0, *"), "gzip", false),
+        (Some("*, gzip;q=0"), "gzip", false),
+        (Some("*;q=0"), "gzip", false),
+        (Some("gzip, *;q=0"), "gzip", true),
+    ];
+    for (accept_encoding, encoding, expected) in tests {
+        let mut preamble = HttpRequestPreamble::new(
+            HttpVersion::Http11,
+            "GET".to_string(),
+            "/v2/blocks".to_string(),
+            "localhost".to_string(),
+            20443,
+            true,
+        );
+        if let Some(accept_encoding) = accept_encoding {
+            preamble.add_header("Accept-Encoding".to_string(), accept_encoding.to_string());
+        }
+        assert_eq!(
+            preamble.accepts_encoding(encoding),
+            expected,
+            "Accept-Encoding: {accept_encoding:?}, encoding: {encoding}"
+        );
+    }
+}
+
+#[test]
+fn test_gzip_file_stream() {
+    let dir = "/tmp/stacks-node-tests/http-tests";
+    let path = format!("{dir}/test_gzip_file_stream.gz");
+    fs::create_dir_all(dir).unwrap();
+
+    let data: Vec<u8> = (0..10_000u32).flat_map(|i| i.to_be_bytes()).collect();
+    let mut encoder = GzEncoder::new(vec![], Compression::default());
+    encoder.write_all(&data).unwrap();
+    let compressed = encoder.finish().unwrap();
+    fs::write(&path, &compressed).unwrap();
+
+    let drain = |stream: &mut GzipFileStream| {
+        let mut out = vec![];
+        loop {
+            let chunk = stream.generate_next_chunk().unwrap();
+            if chunk.is_empty() {
+                break;
+            }
+            out.extend_from_slice(&chunk);
+        }
+        out
+    };
+
+    // a gzip client gets the on-disk bytes verbatim
+    let mut stream = GzipFileStream::new(path.clone().into(), true);
+    assert_eq!(stream.content_encoding(), Some(HttpContentEncoding::Gzip));
+    assert_eq!(drain(&mut stream), compressed);
+    assert_eq!(stream.total_bytes, compressed.len() as u64);
+
+    // an identity client gets the decompressed bytes
+    let mut stream = GzipFileStream::new(path.clone().into(), false);
+    assert_eq!(stream.content_encoding(), None);
+    assert_eq!(drain(&mut stream), data);
+    assert_eq!(stream.total_bytes, data.len() as u64);
+
+    // a missing file is an error
+    let mut stream = GzipFileStream::new(format!("{dir}/no-such-file.gz").into(), true);
+    assert!(stream.generate_next_chunk().is_err());
+}
+
+#[test]
+fn test_http_content_type_from_path() {
+    let tests = [
+        ("/snapshots/chainstate.json", HttpContentType::JSON),
+        ("/snapshots/CHAINSTATE.JSON", HttpContentType::JSON),
+        ("/snapshots/chainstate.tar.gz", HttpContentType::Gzip),
+        ("/snapshots/chainstate.bin", HttpContentType::Bytes),
+        ("/snapshots/README.txt", HttpContentType::Text),
+        ("/snapshots/chainstate.sqlite", HttpContentType::Bytes),
+        ("/snapshots/chainstate", HttpContentType::Bytes),
+    ];
+    for (path, expected) in tests {
+        let content_type = HttpContentType::from_path(Path::new(path));
+        assert_eq!(content_type, expected, "{path}");
+        // the inferred content type survives a round trip through a header
+        assert_eq!(
+            HttpContentType::from_str(content_type.as_str()).unwrap(),
+            content_type
+        );
+    }
+    assert_eq!(
+        HttpContentType::from_path(Path::new("/snapshots/chainstate.sqlite")).as_str(),
+        "application/octet-stream"
+    );
+}
+
+#[test]
+fn test_sse_event_stream() {
+    let (events_tx, events_rx) = channel();
+    let mut stream = SseEventStream::new(events_rx);
+    let mut encoder_state = HttpChunkedTransferWriterState::new(stream.hint_chunk_size());
+    let mut pipe_write = VecPipeWrite::default();
+
+    let read_pending = |pipe_write: &mut VecPipeWrite| {
+        String::from_utf8(mem::take(&mut pipe_write.bytes)).unwrap()
+    };
+
+    // each event is framed and flushed as its own chunk, without ending the stream
+    for i in 0..2 {
+        let event = serde_json::json!({ "event": i, "text": "line 1\nline 2" });
+        events_tx.send(event.clone()).unwrap();
+        let frame = SseEventStream::format_event(&event);
+        assert_eq!(frame, format!("data: {event}\n\n").into_bytes());
+        // the JSON payload must stay on a single line
+        assert_eq!(frame.iter().filter(|b| **b == b'\n').count(), 2);
+
+        let nw = stream
+            .stream_to(&mut encoder_state, &mut pipe_write)
+            .unwrap();
+        assert_eq!(nw, frame.len() as u64);
+        assert_eq!(
+            read_pending(&mut pipe_write),
+            format!(
+                "{:x}\r\n{}\r\n",
+                frame.len(),
+                String::from_utf8(frame).unwrap()
+            )
+        );
+    }
+    assert_eq!(stream.num_events, 2);
+
+    // with no event to send, a keep-alive comment is sent once the interval passes
+    let mut stream = stream.with_keepalive_interval(Duration::from_millis(10));
+    let nw = stream
+        .stream_to(&mut encoder_state, &mut pipe_write)
+        .unwrap();
+    assert_eq!(nw, SseEventStream::KEEPALIVE_FRAME.len() as u64);
+    assert_eq!(
+        read_pending(&mut pipe_write),
+        format!(
+            "{:x}\r\n: keep-alive\n\n\r\n",
+            SseEventStream::KEEPALIVE_FRAME.len()
+        )
+    );
+    assert_eq!(stream.num_events, 2);
+
+    // the stream ends once the sender is gone
+    drop(events_tx);
+    let nw = stream
+        .stream_to(&mut encoder_state, &mut pipe_write)
+        .unwrap();
+    assert_eq!(nw, 0);
+    assert_eq!(read_pending(&mut pipe_write), "0\r\n\r\n");
+}
+
+/// Decodes `text/plain` bodies with the default text decode mode
+#[derive(Clone)]
+struct StrictTextResponseHandler {}
+
+impl HttpResponse for StrictTextResponseHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        let text = parse_text(preamble, body, 1024, self.text_decode_mode())?;
+        Ok(HttpResponsePayload::Text(text))
+    }
+}
+
+/// Decodes `text/plain` bodies, replacing invalid UTF-8
+#[derive(Clone)]
+struct LossyTextResponseHandler {}
+
+impl HttpResponse for LossyTextResponseHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        let text = parse_text(preamble, body, 1024, self.text_decode_mode())?;
+        Ok(HttpResponsePayload::Text(text))
+    }
+
+    fn text_decode_mode(&self) -> TextDecodeMode {
+        TextDecodeMode::Lossy
+    }
+}
+
+#[test]
+fn test_parse_text_invalid_utf8() {
+    let body = b"hello \xF0\x28\x8C\x28 world";
+    let preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(body.len() as u32),
+        HttpContentType::Text,
+        true,
+    );
+
+    // valid UTF-8 decodes the same under both modes
+    for mode in [TextDecodeMode::Strict, TextDecodeMode::Lossy] {
+        assert_eq!(
+            parse_text(&preamble, b"hello world", 1024, mode).unwrap(),
+            "hello world"
+        );
+    }
+
+    let err = parse_text(&preamble, body, 1024, TextDecodeMode::Strict).unwrap_err();
+    assert!(matches!(err, Error::DecodeError(..)), "{err:?}");
+
+    let text = parse_text(&preamble, body, 1024, TextDecodeMode::Lossy).unwrap();
+    assert_eq!(text, "hello \u{FFFD}(\u{FFFD}( world");
+
+    // handlers decode strictly unless they opt into lossy decoding
+    let strict_handler = StrictTextResponseHandler {};
+    assert_eq!(strict_handler.text_decode_mode(), TextDecodeMode::Strict);
+    assert!(strict_handler.try_parse_response(&preamble, body).is_err());
+
+    let lossy_handler = LossyTextResponseHandler {};
+    assert_eq!(
+        lossy_handler.try_parse_response(&preamble, body).unwrap(),
+        HttpResponsePayload::Text(text)
+    );
+}
+
+#[test]
+fn test_metrics_content_type_negotiation() {
+    let metrics = "# HELP foo A counter\n# TYPE foo counter\nfoo 1\n".to_string();
+
+    // (Accept header, expected content type)
+    let tests = [
+        (None, MetricsContentType::Prometheus),
+        (Some("*/*"), MetricsContentType::Prometheus),
+        (Some("text/plain"), MetricsContentType::Prometheus),
+        (Some("text/plain; version=0.0.4"), MetricsContentType::Prometheus),
+        (Some("application/json"), MetricsContentType::Prometheus),
+        (
+            Some("application/openmetrics-text"),
+            MetricsContentType::OpenMetrics,
+        ),
+        (
+            Some("application/openmetrics-text; version=1.0.0; charset=utf-8"),
+            MetricsContentType::OpenMetrics,
+        ),
+        (
+            // what Prometheus sends when scraping
+            Some("application/openmetrics-text;version=1.0.0,application/openmetrics-text;version=0.0.1;q=0.75,text/plain;version=0.0.4;q=0.5,*/*;q=0.1"),
+            MetricsContentType::OpenMetrics,
+        ),
+        (
+            Some("text/plain;q=0.9, application/openmetrics-text;q=0.5"),
+            MetricsContentType::Prometheus,
+        ),
+        (
+            Some("application/openmetrics-text, text/plain"),
+            MetricsContentType::Prometheus,
+        ),
+    ];
+
+    for (accept, expected) in tests {
+        let content_type = MetricsContentType::from_accept_header(accept);
+        assert_eq!(content_type, expected, "Accept: {accept:?}");
+
+        let body = content_type.format_body(metrics.clone());
+        match content_type {
+            MetricsContentType::Prometheus => {
+                assert_eq!(content_type.as_str(), "text/plain; version=0.0.4");
+                assert_eq!(body, metrics);
+                assert!(!body.contains("# EOF"));
+            }
+            MetricsContentType::OpenMetrics => {
+                assert!(content_type
+                    .as_str()
+                    .starts_with("application/openmetrics-text"));
+                assert_eq!(
+                    body,
+                    "# HELP foo A counter\n# TYPE foo counter\nfoo_total 1\n# EOF\n"
+                );
+            }
+        }
+    }
+
+    // a missing trailing newline is fixed up before the EOF marker
+    assert_eq!(
+        MetricsContentType::OpenMetrics.format_body("foo 1".into()),
+        "foo 1\n# EOF\n"
+    );
+}
+
+#[test]
+fn test_prometheus_to_openmetrics() {
+    let prometheus = r#"# HELP requests_total Requests served, by "path"
+# TYPE requests_total counter
+requests_total{path="/a b}"} 3
+requests_total{path="say \"hi\""} 4 1700000000123
+# HELP temperature Current temperature
+# TYPE temperature gauge
+temperature -1.5
+
+# HELP latency Request latency
+# TYPE latency histogram
+latency_bucket{le="0.5"} 1
+latency_bucket{le="+Inf"} 2
+latency_sum 0.75
+latency_count 2
+# TYPE mystery untyped
+mystery NaN
+"#;
+    let openmetrics = r#"# HELP requests Requests served, by \"path\"
+# TYPE requests counter
+requests_total{path="/a b}"} 3
+requests_total{path="say \"hi\""} 4 1700000000.123
+# HELP temperature Current temperature
+# TYPE temperature gauge
+temperature -1.5
+# HELP latency Request latency
+# TYPE latency histogram
+latency_bucket{le="0.5"} 1
+latency_bucket{le="+Inf"} 2
+latency_sum 0.75
+latency_count 2
+# TYPE mystery unknown
+mystery NaN
+# EOF
+"#;
+    assert_eq!(
+        MetricsContentType::OpenMetrics.format_body(prometheus.to_string()),
+        openmetrics
+    );
+    assert_eq!(
+        MetricsContentType::Prometheus.format_body(prometheus.to_string()),
+        prometheus
+    );
+}
+
+/// Decodes JSON bodies, and only accepts `200 OK` and `202 Accepted` responses
+#[derive(Clone)]
+struct AcceptedJsonResponseHandler {}
+
+impl HttpResponse for AcceptedJsonResponseHandler {
+    fn try_parse_response(
+        &self,
+        preamble: &HttpResponsePreamble,
+        body: &[u8],
+    ) -> Result<HttpResponsePayload, Error> {
+        let json: serde_json::Value = parse_json(preamble, body)?;
+        Ok(HttpResponsePayload::JSON(json))
+    }
+
+    fn allowed_status_codes(&self) -> Option<&'static [u16]> {
+        Some(&[200, 202])
+    }
+}
+
+#[test]
+fn test_decode_response_unexpected_status() {
+    let handler = AcceptedJsonResponseHandler {};
+    let body = br#"{"result":"Accepted"}"#;
+
+    for status in [200, 202] {
+        let preamble = HttpResponsePreamble::new(
+            HttpVersion::Http11,
+            status,
+            "OK".to_string(),
+            Some(body.len() as u32),
+            HttpContentType::JSON,
+            true,
+        );
+        assert_eq!(
+            try_decode_response(&handler, &preamble, body).unwrap(),
+            HttpResponsePayload::JSON(serde_json::json!({"result": "Accepted"}))
+        );
+    }
+
+    // a redirect is rejected before the body is parsed, even though the body is not JSON
+    let redirect_body = b"<html>moved</html>";
+    let preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        302,
+        "Found".to_string(),
+        Some(redirect_body.len() as u32),
+        HttpContentType::Text,
+        true,
+    );
+    match try_decode_response(&handler, &preamble, redirect_body) {
+        Err(Error::UnexpectedStatus { got, allowed }) => {
+            assert_eq!(got, 302);
+            assert_eq!(allowed, vec![200, 202]);
+        }
+        res => panic!("Expected UnexpectedStatus error, got {res:?}"),
+    }
+
+    // handlers without an allowed set accept any status
+    let text_handler = StrictTextResponseHandler {};
+    assert_eq!(
+        try_decode_response(&text_handler, &preamble, redirect_body).unwrap(),
+        HttpResponsePayload::Text("<html>moved</html>".into())
+    );
+}
+
+#[test]
+fn test_multipart_response_roundtrip() {
+    let parts = vec![
+        MultipartPart::new(
+            "block",
+            HttpContentType::Bytes,
+            vec![0x00, 0x0d, 0x0a, 0x2d, 0xff],
+        ),
+        MultipartPart::new(
+            "metadata",
+            HttpContentType::JSON,
+            br#"{"height":1,"note":"--\r\n"}"#.to_vec(),
+        ),
+    ];
+    let payload = HttpResponsePayload::Multipart(parts.clone());
+    let content_type = payload.content_type();
+    let HttpContentType::Multipart(boundary) = content_type else {
+        panic!("Expected a multipart content type, got {content_type:?}");
+    };
+    assert_eq!(boundary, MultipartBoundary::from_parts(&parts));
+
+    let preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        payload.try_content_length(),
+        content_type,
+        true,
+    );
+    let mut bytes = vec![];
+    preamble.consensus_serialize(&mut bytes).unwrap();
+    payload.send(&mut bytes).unwrap();
+
+    let response = String::from_utf8_lossy(&bytes).to_string();
+    assert!(response.contains(&format!(
+        "Content-Type: multipart/mixed; boundary={boundary}\r\n"
+    )));
+    assert!(response.contains("Content-Disposition: form-data; name=\"block\""));
+    assert!(response.contains("Content-Disposition: form-data; name=\"metadata\""));
+
+    // decode the preamble, and split the body back into its parts by the boundary
+    let mut cursor = &bytes[..];
+    let decoded_preamble = HttpResponsePreamble::consensus_deserialize(&mut cursor).unwrap();
+    assert_eq!(decoded_preamble.content_type, content_type);
+    assert_eq!(decoded_preamble.content_length, Some(cursor.len() as u32));
+    let decoded_parts = parse_multipart(&decoded_preamble, cursor, 1024).unwrap();
+    assert_eq!(decoded_parts, parts);
+
+    // the parts can only be decoded with the right boundary
+    let wrong_boundary = MultipartBoundary::from_str("00000000000000000000000000000000").unwrap();
+    assert!(decode_multipart(&wrong_boundary, cursor).is_err());
+
+    // a truncated body is incomplete
+    assert!(matches!(
+        decode_multipart(&boundary, &cursor[..cursor.len() - 10]),
+        Err(Error::UnderflowError(_))
+    ));
+}
+
+#[test]
+fn test_multipart_boundary_from_other_clients() {
+    // boundaries generated by common HTTP clients, including quoted ones with spaces
+    for (header, boundary) in [
+        (
+            "multipart/mixed; boundary=----WebKitFormBoundary7MA4YWxkTrZu0gW",
+            "----WebKitFormBoundary7MA4YWxkTrZu0gW",
+        ),
+        (
+            "multipart/mixed; boundary=\"simple boundary\"",
+            "simple boundary",
+        ),
+        ("Multipart/Mixed; Boundary=AbC", "AbC"),
+        ("multipart/mixed; boundary=a", "a"),
+    ] {
+        let content_type = HttpContentType::from_str(header).unwrap();
+        let HttpContentType::Multipart(parsed) = content_type else {
+            panic!("Expected a multipart content type, got {content_type:?}");
+        };
+        assert_eq!(parsed.as_str(), boundary);
+        // the boundary survives a round trip through the header
+        assert_eq!(
+            HttpContentType::from_str(&content_type.to_string()).unwrap(),
+            content_type
+        );
+    }
+
+    let body = b"--simple boundary\r\nContent-Disposition: form-data; name=\"a\"\r\n\r\nhi\r\n--simple boundary--\r\n";
+    let parts = decode_multipart(
+        &MultipartBoundary::from_str("simple boundary").unwrap(),
+        body,
+    )
+    .unwrap();
+    assert_eq!(
+        parts,
+        vec![MultipartPart::new(
+            "a",
+            HttpContentType::Bytes,
+            b"hi".to_vec()
+        )]
+    );
+
+    // boundaries that RFC 2046 does not allow
+    for boundary in ["", "ends in a space ", "semi;colon", &"x".repeat(71)] {
+        assert!(
+            MultipartBoundary::from_str(boundary).is_err(),
+            "{boundary:?} should be rejected"
+        );
+    }
+    assert!(MultipartBoundary::from_str(&"x".repeat(70)).is_ok());
+}
+
+#[test]
+fn test_multipart_boundary_preamble_roundtrip() {
+    // the boundary's case must survive encoding and decoding a whole preamble
+    let content_type = HttpContentType::Multipart(
+        MultipartBoundary::from_str("----WebKitFormBoundary7MA4YWxkTrZu0gW").unwrap(),
+    );
+
+    let response_preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(0),
+        content_type,
+        true,
+    );
+    let mut bytes = vec![];
+    response_preamble.consensus_serialize(&mut bytes).unwrap();
+    let decoded = HttpResponsePreamble::consensus_deserialize(&mut &bytes[..]).unwrap();
+    assert_eq!(decoded.content_type, content_type);
+
+    let request_preamble = HttpRequestPreamble::new(
+        HttpVersion::Http11,
+        "POST".to_string(),
+        "/v2/upload".to_string(),
+        "localhost".to_string(),
+        20443,
+        true,
+    )
+    .with_content_type(content_type);
+    let mut bytes = vec![];
+    request_preamble.consensus_serialize(&mut bytes).unwrap();
+    let decoded = HttpRequestPreamble::consensus_deserialize(&mut &bytes[..]).unwrap();
+    assert_eq!(decoded.content_type, Some(content_type));
+}
+
+#[test]
+fn test_multipart_part_name_escaping() {
+    let parts = vec![
+        MultipartPart::new(
+            "a \"quoted\"\r\nname",
+            HttpContentType::Text,
+            b"one".to_vec(),
+        ),
+        MultipartPart::new("100%", HttpContentType::Text, b"two".to_vec()),
+    ];
+    let boundary = MultipartBoundary::from_parts(&parts);
+    let body = encode_multipart(&boundary, &parts);
+    let body_str = String::from_utf8_lossy(&body);
+    assert!(body_str.contains("name=\"a %22quoted%22%0D%0Aname\"\r\n"));
+    assert!(body_str.contains("name=\"100%25\"\r\n"));
+    assert_eq!(decode_multipart(&boundary, &body).unwrap(), parts);
+}
+
+/// A writer that accepts at most `max_write` bytes at a time, and would block on every other call
+struct TrickleWriter {
+    max_write: usize,
+    would_block: bool,
+    written: Vec<u8>,
+}
+
+impl Write for TrickleWriter {
+    fn write(&mut self, buf: &[u8]) -> io::Result<usize> {
+        if self.would_block {
+            self.would_block = false;
+            return Err(io::Error::from(io::ErrorKind::WouldBlock));
+        }
+        self.would_block = true;
+        let len = buf.len().min(self.max_write);
+        self.written.extend_from_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> io::Result<()> {
+        Ok(())
+    }
+}
+
+#[test]
+fn test_send_nonblocking_partial_writes() {
+    let payloads = vec![
+        HttpResponsePayload::Empty,
+        HttpResponsePayload::Bytes(vec![0xab; 100]),
+        HttpResponsePayload::Text("hello world".into()),
+        HttpResponsePayload::JSON(serde_json::json!({"foo": "bar", "baz": [1, 2, 3]})),
+    ];
+    for payload in payloads {
+        let mut expected = vec![];
+        payload.send(&mut expected).unwrap();
+
+        let mut fd = TrickleWriter {
+            max_write: 3,
+            would_block: false,
+            written: vec![],
+        };
+        let mut num_sent = 0;
+        let mut num_calls = 0;
+        loop {
+            let sent_before = num_sent;
+            let done = payload.send_nonblocking(&mut num_sent, &mut fd).unwrap();
+            num_calls += 1;
+            assert_eq!(num_sent, fd.written.len());
+            if done {
+                break;
+            }
+            // every call that does not finish the payload still makes progress
+            assert!(num_sent > sent_before, "send_nonblocking made no progress");
+            // let the "socket" drain
+            fd.would_block = false;
+        }
+        assert_eq!(fd.written, expected);
+        assert!(num_calls <= expected.len().div_ceil(fd.max_write).max(1));
+
+        // an offset past the end of the payload is an error
+        let mut num_sent = expected.len() + 1;
+        assert!(payload.send_nonblocking(&mut num_sent, &mut fd).is_err());
+    }
+}
+
+#[test]
+fn test_http_response_preamble_charset() {
+    // text-family content types advertise the charset, and other content types do not
+    for (content_type, expected) in [
+        (
+            HttpContentType::JSON,
+            "Content-Type: application/json; charset=utf-8\r\n",
+        ),
+        (
+            HttpContentType::Text,
+            "Content-Type: text/plain; charset=utf-8\r\n",
+        ),
+        (
+            HttpContentType::Bytes,
+            "Content-Type: application/octet-stream\r\n",
+        ),
+    ] {
+        let preamble = HttpResponsePreamble::new(
+            HttpVersion::Http11,
+            200,
+            "OK".to_string(),
+            Some(0),
+            content_type,
+            true,
+        );
+        assert_eq!(preamble.charset.as_deref(), Some(HTTP_DEFAULT_CHARSET));
+        let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+        assert!(txt.contains(expected), "{expected:?} is missing from {txt}");
+
+        // the charset is stripped from the content type on the way back in
+        let decoded = HttpResponsePreamble::consensus_deserialize(&mut txt.as_bytes()).unwrap();
+        assert_eq!(decoded.content_type, preamble.content_type);
+        assert_eq!(decoded.charset, preamble.charset);
+    }
+
+    // the charset is configurable, and can be omitted
+    let mut preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(0),
+        HttpContentType::Text,
+        true,
+    );
+    preamble.charset = Some("iso-8859-1".to_string());
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(txt.contains("Content-Type: text/plain; charset=iso-8859-1\r\n"));
+    // bodies are decoded as UTF-8, so text in another charset is rejected
+    let err = HttpResponsePreamble::consensus_deserialize(&mut txt.as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("Unsupported charset"), "{err:?}");
+
+    preamble.charset = None;
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(txt.contains("Content-Type: text/plain\r\n"));
+
+    // a charset parameter is tolerated in any case and spacing
+    for data in [
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json;charset=UTF-8\r\nContent-Length: 2\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json; charset=\"utf-8\"\r\nContent-Length: 2\r\n\r\n",
+    ] {
+        let decoded = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+        assert_eq!(decoded.content_type, HttpContentType::JSON);
+        assert_eq!(decoded.charset.as_deref(), Some("utf-8"));
+    }
+
+    // a US-ASCII body is valid UTF-8, and a binary body has no charset to honor
+    for data in [
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain; charset=us-ascii\r\nContent-Length: 2\r\n\r\n",
+        "HTTP/1.1 200 OK\r\nContent-Type: application/octet-stream; charset=binary\r\nContent-Length: 2\r\n\r\n",
+    ] {
+        HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    }
+}
+
+#[test]
+fn test_parse_content_type_parameters() {
+    for (header, expected) in [
+        ("text/plain; charset=utf-8", HttpContentType::Text),
+        ("application/json;charset=UTF-8", HttpContentType::JSON),
+        ("Application/JSON ; charset=utf-8", HttpContentType::JSON),
+        (
+            "application/octet-stream; charset=binary",
+            HttpContentType::Bytes,
+        ),
+        ("application/gzip;foo=bar", HttpContentType::Gzip),
+    ] {
+        assert_eq!(
+            HttpContentType::from_str(header).unwrap(),
+            expected,
+            "{header}"
+        );
+
+        // requests and responses both tolerate the parameters
+        let request = format!(
+            "POST /v2/transactions HTTP/1.1\r\nHost: localhost:20443\r\nContent-Type: {header}\r\nContent-Length: 0\r\n\r\n"
+        );
+        let preamble = HttpRequestPreamble::consensus_deserialize(&mut request.as_bytes()).unwrap();
+        assert_eq!(preamble.content_type, Some(expected), "{header}");
+
+        let response =
+            format!("HTTP/1.1 200 OK\r\nContent-Type: {header}\r\nContent-Length: 0\r\n\r\n");
+        let preamble =
+            HttpResponsePreamble::consensus_deserialize(&mut response.as_bytes()).unwrap();
+        assert_eq!(preamble.content_type, expected, "{header}");
+    }
+
+    // the parameters do not rescue an unsupported MIME type
+    assert!(HttpContentType::from_str("text/html; charset=utf-8").is_err());
+    assert!(HttpContentType::from_str("application/jsonp").is_err());
+}
+
+#[test]
+fn test_http_response_permanent_redirect() {
+    let preamble = HttpResponsePreamble::permanent_redirect("/v3/blocks/upload").unwrap();
+    assert_eq!(preamble.status_code, 308);
+    assert_eq!(preamble.reason, "Permanent Redirect");
+    assert_eq!(preamble.content_length, Some(0));
+    assert_eq!(
+        preamble.get_header("Location".to_string()).as_deref(),
+        Some("/v3/blocks/upload")
+    );
+
+    // the location is a reserved header, so it cannot be removed or shadowed by a plain header
+    let mut shadowed = preamble.clone();
+    assert!(!shadowed.remove_header("location".to_string()));
+    shadowed.add_header("LOCATION".to_string(), "/v2/blocks/upload".to_string());
+    assert_eq!(shadowed.location.as_deref(), Some("/v2/blocks/upload"));
+    assert!(shadowed.headers.get("location").is_none());
+
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(txt.starts_with("HTTP/1.1 308 Permanent Redirect\r\n"));
+    assert!(txt.contains("Location: /v3/blocks/upload\r\n"));
+    assert!(txt.contains("Content-Length: 0\r\n"));
+
+    // the decode path exposes the location
+    let decoded = HttpResponsePreamble::consensus_deserialize(&mut txt.as_bytes()).unwrap();
+    assert_eq!(decoded.status_code, 308);
+    assert_eq!(decoded.location.as_deref(), Some("/v3/blocks/upload"));
+    assert!(decoded.headers.get("location").is_none());
+
+    // control characters would let the location inject headers
+    for location in [
+        "/v3/blocks\r\nSet-Cookie: a=b",
+        "/v3/blocks\n",
+        "/v3/\u{7f}blocks",
+    ] {
+        assert!(HttpResponsePreamble::permanent_redirect(location).is_err());
+        let mut preamble = HttpResponsePreamble::permanent_redirect("/v3/blocks/upload").unwrap();
+        preamble.add_header("Location".to_string(), location.to_string());
+        assert_eq!(preamble.location.as_deref(), Some("/v3/blocks/upload"));
+    }
+
+    // requests keep a Location header with the other headers
+    let mut request = HttpRequestPreamble::new(
+        HttpVersion::Http11,
+        "GET".to_string(),
+        "/v2/info".to_string(),
+        "localhost".to_string(),
+        20443,
+        true,
+    );
+    request.add_header("Location".to_string(), "/v3/blocks/upload".to_string());
+    assert_eq!(
+        request.get_header("location".to_string()).as_deref(),
+        Some("/v3/blocks/upload")
+    );
+}
+
+#[test]
+fn test_http_response_cache_control() {
+    // no Cache-Control header unless asked for
+    let mut preamble = make_fixed_date_preamble(Some(0));
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(!txt.contains("Cache-Control"), "{txt}");
+
+    // (max-age, immutable?, no-store?, expected directives)
+    let tests = [
+        (
+            Some(31536000),
+            true,
+            false,
+            "public, max-age=31536000, immutable",
+        ),
+        (None, false, true, "no-store"),
+        (Some(5), false, false, "public, max-age=5"),
+    ];
+    for (max_age, immutable, no_store, expected) in tests {
+        preamble.set_cache_control(max_age, immutable, no_store);
+        assert_eq!(
+            preamble.get_header("Cache-Control".to_string()).as_deref(),
+            Some(expected)
+        );
+
+        let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+        assert!(
+            txt.contains(&format!("Cache-Control: {expected}\r\n")),
+            "{txt}"
+        );
+
+        // the decode path exposes the directives
+        let decoded = HttpResponsePreamble::consensus_deserialize(&mut txt.as_bytes()).unwrap();
+        assert_eq!(decoded.cache_control.as_deref(), Some(expected));
+        assert!(decoded.headers.get("cache-control").is_none());
+    }
+
+    // directives split across several headers are combined
+    let data = "HTTP/1.1 200 OK\r\nContent-Length: 0\r\nCache-Control: public\r\nCache-Control: max-age=60\r\n\r\n";
+    let decoded = HttpResponsePreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    assert_eq!(decoded.cache_control.as_deref(), Some("public, max-age=60"));
+}
+
+#[test]
+fn test_decode_gzip_encoded_response() {
+    let handler = AcceptedJsonResponseHandler {};
+    let json = serde_json::json!({"result": "Accepted", "message": "x".repeat(1000)});
+    let mut encoder = GzEncoder::new(vec![], Compression::default());
+    encoder
+        .write_all(serde_json::to_string(&json).unwrap().as_bytes())
+        .unwrap();
+    let compressed = encoder.finish().unwrap();
+
+    let mut preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(compressed.len() as u32),
+        HttpContentType::JSON,
+        true,
+    );
+    preamble.add_header("Content-Encoding".into(), "gzip".into());
+
+    // the body is decompressed before it is parsed
+    assert_eq!(
+        try_decode_response(&handler, &preamble, &compressed).unwrap(),
+        HttpResponsePayload::JSON(json.clone())
+    );
+
+    // bodies that decompress to more than the cap are rejected
+    let decoded_len = serde_json::to_vec(&json).unwrap().len() as u64;
+    assert_eq!(
+        decode_content_encoding(&preamble, &compressed, decoded_len).unwrap(),
+        serde_json::to_vec(&json).unwrap()
+    );
+    match decode_content_encoding(&preamble, &compressed, decoded_len - 1) {
+        Err(Error::DecodeError(msg)) => assert!(msg.contains("longer than"), "{msg}"),
+        res => panic!("Expected DecodeError, got {res:?}"),
+    }
+
+    // unencoded bodies pass through untouched, and unknown encodings are rejected
+    let plain = serde_json::to_vec(&json).unwrap();
+    preamble.remove_header("content-encoding".into());
+    assert_eq!(
+        try_decode_response(&handler, &preamble, &plain).unwrap(),
+        HttpResponsePayload::JSON(json)
+    );
+    preamble.add_header("Content-Encoding".into(), "br".into());
+    assert!(matches!(
+        try_decode_response(&handler, &preamble, &plain),
+        Err(Error::DecodeError(_))
+    ));
+}
+
+/// Generates a fixed list of chunks
+struct TestChunkStream {
+    chunks: Vec<Vec<u8>>,
+}
+
+impl HttpChunkGenerator for TestChunkStream {
+    fn hint_chunk_size(&self) -> usize {
+        4
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        if self.chunks.is_empty() {
+            return Ok(vec![]);
+        }
+        Ok(self.chunks.remove(0))
+    }
+}
+
+/// Make a response preamble with a fixed date, so its encoding is deterministic
+fn make_fixed_date_preamble(content_length: Option<u32>) -> HttpResponsePreamble {
+    let mut preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        content_length,
+        HttpContentType::Bytes,
+        true,
+    );
+    preamble.add_header(
+        "Date".to_string(),
+        "Thu, 01 Jan 1970 00:00:00 GMT".to_string(),
+    );
+    preamble
+}
+
+#[test]
+fn test_pipe_out_ram_response() {
+    let body = b"hello world".to_vec();
+    let expected = "HTTP/1.1 200 OK\r\n\
+                    Server: stacks/2.0\r\n\
+                    Access-Control-Allow-Origin: *\r\n\
+                    Access-Control-Allow-Headers: origin, content-type, authorization\r\n\
+                    Access-Control-Allow-Methods: POST, GET, OPTIONS\r\n\
+                    Content-Type: application/octet-stream\r\n\
+                    Content-Length: 11\r\n\
+                    date: Thu, 01 Jan 1970 00:00:00 GMT\r\n\
+                    \r\n\
+                    hello world";
+
+    for max_write in [None, Some(1), Some(3), Some(1024)] {
+        let mut pipe_write = max_write
+            .map(VecPipeWrite::with_max_write)
+            .unwrap_or_default();
+        let mut contents = HttpResponseContents::from_ram(body.clone());
+        make_fixed_date_preamble(contents.content_length())
+            .consensus_serialize(&mut pipe_write)
+            .unwrap();
+
+        // the whole body goes out in one call, however little each write accepts
+        assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), 0);
+        assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), 0);
+        assert_eq!(String::from_utf8(pipe_write.bytes).unwrap(), expected);
+    }
+}
+
+#[test]
+fn test_pipe_out_streamed_response() {
+    let preamble = "HTTP/1.1 200 OK\r\n\
+                    Server: stacks/2.0\r\n\
+                    Access-Control-Allow-Origin: *\r\n\
+                    Access-Control-Allow-Headers: origin, content-type, authorization\r\n\
+                    Access-Control-Allow-Methods: POST, GET, OPTIONS\r\n\
+                    Content-Type: application/octet-stream\r\n\
+                    Transfer-Encoding: chunked\r\n\
+                    date: Thu, 01 Jan 1970 00:00:00 GMT\r\n\
+                    \r\n";
+
+    for max_write in [None, Some(1), Some(3)] {
+        let mut pipe_write = max_write
+            .map(VecPipeWrite::with_max_write)
+            .unwrap_or_default();
+        let mut contents = HttpResponseContents::from_stream(Box::new(TestChunkStream {
+            chunks: vec![b"hello".to_vec(), b"world!".to_vec()],
+        }));
+        make_fixed_date_preamble(contents.content_length())
+            .consensus_serialize(&mut pipe_write)
+            .unwrap();
+        assert_eq!(
+            String::from_utf8(pipe_write.bytes.clone()).unwrap(),
+            preamble
+        );
+
+        // each call encodes one generated chunk, holding back whatever does not fill a whole
+        // transfer chunk until the stream ends
+        let mut expected = preamble.to_string();
+        for (chunk_len, sent) in [
+            (5, "4\r\nhell\r\n"),
+            (6, "4\r\nowor\r\n"),
+            (0, "3\r\nld!\r\n0\r\n\r\n"),
+        ] {
+            assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), chunk_len);
+            expected.push_str(sent);
+            assert_eq!(
+                String::from_utf8(pipe_write.bytes.clone()).unwrap(),
+                expected
+            );
+        }
+    }
+}
+
+#[test]
+fn test_deflate_encode_stream() {
+    let data: Vec<u8> = (0..10_000u32).flat_map(|i| i.to_be_bytes()).collect();
+    let mut stream = DeflateEncodeStream::new(Box::new(TestChunkStream {
+        chunks: data.chunks(1000).map(|chunk| chunk.to_vec()).collect(),
+    }));
+    let mut compressed = vec![];
+    loop {
+        let chunk = stream.generate_next_chunk().unwrap();
+        if chunk.is_empty() {
+            break;
+        }
+        compressed.extend_from_slice(&chunk);
+    }
+    assert_eq!(stream.total_bytes, compressed.len() as u64);
+    assert!(compressed.len() < data.len());
+
+    // the output is zlib-wrapped, so a zlib decoder recovers the data
+    let mut decoded = vec![];
+    ZlibDecoder::new(compressed.as_slice())
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, data);
+
+    // and so does the client's decode path
+    let mut preamble = HttpResponsePreamble::new(
+        HttpVersion::Http11,
+        200,
+        "OK".to_string(),
+        Some(compressed.len() as u32),
+        HttpContentType::Bytes,
+        true,
+    );
+    preamble.add_header(
+        "Content-Encoding".into(),
+        HttpContentEncoding::Deflate.to_string(),
+    );
+    assert_eq!(
+        decode_content_encoding(&preamble, &compressed, data.len() as u64).unwrap(),
+        data
+    );
+    match decode_content_encoding(&preamble, &compressed, data.len() as u64 - 1) {
+        Err(Error::DecodeError(msg)) => assert!(msg.contains("longer than"), "{msg}"),
+        res => panic!("Expected DecodeError, got {res:?}"),
+    }
+
+    // raw deflate bodies sent under the same name are decoded too
+    let mut encoder = DeflateEncoder::new(vec![], Compression::default());
+    encoder.write_all(&data).unwrap();
+    let raw_compressed = encoder.finish().unwrap();
+    assert_eq!(
+        decode_content_encoding(&preamble, &raw_compressed, data.len() as u64).unwrap(),
+        data
+    );
+}
+
+#[test]
+fn test_rfc7231_date() {
+    // the example date from RFC 7231, section 7.1.1.1
+    let time = SystemTime::UNIX_EPOCH + Duration::from_secs(784887151);
+    assert_eq!(rfc7231_date(time), "Tue, 15 Nov 1994 08:12:31 GMT");
+
+    // single-digit days, hours, minutes, and seconds are zero-padded
+    let time = SystemTime::UNIX_EPOCH + Duration::from_secs(1709629447);
+    assert_eq!(rfc7231_date(time), "Tue, 05 Mar 2024 09:04:07 GMT");
+}
+
+#[test]
+fn test_ram_compressed_round_trip() {
+    // a 1 MiB JSON blob
+    let entries: Vec<_> = (0..)
+        .map(|i| format!("entry-{i:010}"))
+        .take(1024 * 1024 / 19)
+        .collect();
+    let value = serde_json::json!({ "entries": entries });
+    let body = serde_json::to_vec(&value).unwrap();
+    assert!(body.len() >= 1024 * 1024);
+
+    let mut contents =
+        HttpResponseContents::from_ram_compressed(body.clone(), HttpContentEncoding::Gzip).unwrap();
+    assert_eq!(contents.content_encoding(), Some(HttpContentEncoding::Gzip));
+    let mut preamble = make_fixed_date_preamble(contents.content_length());
+    preamble.content_type = HttpContentType::JSON;
+    preamble.add_header(
+        "Content-Encoding".into(),
+        contents.content_encoding().unwrap().to_string(),
+    );
+    assert!(preamble.is_chunked());
+
+    // the body is compressed as it is piped out, and sent with chunked transfer encoding
+    let mut pipe_write = VecPipeWrite::default();
+    while contents.pipe_out(&mut pipe_write).unwrap() > 0 {}
+    let chunked_body = pipe_write.bytes;
+    assert!(chunked_body.len() < body.len());
+
+    let mut chunked_reader = &chunked_body[..];
+    let mut compressed = vec![];
+    HttpChunkedTransferReader::from_reader(&mut chunked_reader, MAX_MESSAGE_LEN.into())
+        .read_to_end(&mut compressed)
+        .unwrap();
+    let decoded = decode_content_encoding(&preamble, &compressed, MAX_MESSAGE_LEN.into()).unwrap();
+    assert_eq!(&decoded[..], &body[..]);
+
+    let parsed: serde_json::Value = parse_json(&preamble, &decoded).unwrap();
+    assert_eq!(parsed, value);
+}
+
+/// Make a request preamble for a body, with the given `Range` header
+fn make_range_request_preamble(range: &str) -> HttpRequestPreamble {
+    let mut preamble = HttpRequestPreamble::new(
+        HttpVersion::Http11,
+        "GET".to_string(),
+        "/v2/blocks".to_string(),
+        "localhost".to_string(),
+        20443,
+        true,
+    );
+    preamble.add_header("Range".to_string(), range.to_string());
+    preamble
+}
+
+#[test]
+fn test_http_byte_range_sub_range() {
+    let body: Vec<u8> = (0..=255u8).collect();
+    let request = make_range_request_preamble("bytes=10-19");
+    let range = request
+        .get_byte_range(body.len() as u64)
+        .unwrap()
+        .expect("Expected a byte range");
+    assert_eq!(range, HttpByteRange { start: 10, end: 19 });
+
+    let mut contents = HttpResponseContents::from_ram_range(body.clone(), &range);
+    let preamble = HttpResponsePreamble::partial_content_2xx(
+        &request,
+        &range,
+        body.len() as u64,
+        HttpContentType::Bytes,
+    );
+    assert_eq!(preamble.status_code, 206);
+    assert_eq!(preamble.content_length, Some(10));
+    assert_eq!(preamble.content_length, contents.content_length());
+    assert_eq!(
+        preamble.get_header("Content-Range".to_string()),
+        Some("bytes 10-19/256".to_string())
+    );
+
+    let mut pipe_write = VecPipeWrite::default();
+    contents.pipe_out(&mut pipe_write).unwrap();
+    assert_eq!(pipe_write.bytes, body[10..20].to_vec());
+
+    // no Range header means the whole body
+    let mut request = request;
+    request.headers.remove("range");
+    assert_eq!(request.get_byte_range(body.len() as u64).unwrap(), None);
+}
+
+#[test]
+fn test_http_byte_range_open_ended() {
+    let body: Vec<u8> = (0..1000u32).map(|i| (i % 256) as u8).collect();
+    let request = make_range_request_preamble("bytes=100-");
+    let range = request
+        .get_byte_range(body.len() as u64)
+        .unwrap()
+        .expect("Expected a byte range");
+    assert_eq!(
+        range,
+        HttpByteRange {
+            start: 100,
+            end: 999
+        }
+    );
+
+    let preamble = HttpResponsePreamble::partial_content_2xx(
+        &request,
+        &range,
+        body.len() as u64,
+        HttpContentType::Bytes,
+    );
+    assert_eq!(preamble.content_length, Some(900));
+    assert_eq!(
+        preamble.get_header("Content-Range".to_string()),
+        Some("bytes 100-999/1000".to_string())
+    );
+    let mut contents = HttpResponseContents::from_ram_range(body.clone(), &range);
+    let mut pipe_write = VecPipeWrite::default();
+    contents.pipe_out(&mut pipe_write).unwrap();
+    assert_eq!(pipe_write.bytes, body[100..].to_vec());
+
+    // a suffix range, and an end past the end of the body, are clamped to the body
+    for (range, expected) in [
+        (
+            "bytes=-10",
+            HttpByteRange {
+                start: 990,
+                end: 999,
+            },
+        ),
+        ("bytes=-5000", HttpByteRange { start: 0, end: 999 }),
+        (
+            "bytes=900-5000",
+            HttpByteRange {
+                start: 900,
+                end: 999,
+            },
+        ),
+    ] {
+        let request = make_range_request_preamble(range);
+        assert_eq!(
+            request.get_byte_range(body.len() as u64).unwrap(),
+            Some(expected),
+            "{range}"
+        );
+    }
+}
+
+#[test]
+fn test_http_byte_range_not_satisfiable() {
+    for range in [
+        // out of bounds
+        "bytes=1000-",
+        "bytes=1000-1010",
+        "bytes=-0",
+        // malformed
+        "bytes=20-10",
+        "bytes=abc-",
+        "bytes=-",
+        "bytes=0-1,5-6",
+        "items=0-10",
+    ] {
+        let request = make_range_request_preamble(range);
+        let err = request.get_byte_range(1000).unwrap_err();
+        assert!(
+            matches!(
+                err,
+                Error::RangeNotSatisfiable {
+                    total_len: 1000,
+                    ..
+                }
+            ),
+            "{range}: {err:?}"
+        );
+
+        let http_error = err.into_http_error();
+        assert_eq!(http_error.code(), 416);
+        assert_eq!(
+            http_error.headers(),
+            vec![("Content-Range".to_string(), "bytes */1000".to_string())]
+        );
+    }
+}
+
+#[test]
+fn test_json_stream_round_trip() {
+    let items: Vec<_> = (0..100_000u64)
+        .map(|i| serde_json::json!({ "index": i, "name": format!("item-{i}") }))
+        .collect();
+    let mut contents = HttpResponseContents::from_json_stream(items.clone());
+    assert_eq!(contents.content_length(), None);
+
+    // the array is serialized a chunk at a time, and sent with chunked transfer encoding
+    let mut pipe_write = VecPipeWrite::default();
+    let mut num_chunks = 0;
+    while contents.pipe_out(&mut pipe_write).unwrap() > 0 {
+        num_chunks += 1;
+    }
+    assert!(num_chunks > 1);
+
+    let mut chunked_reader = &pipe_write.bytes[..];
+    let mut body = vec![];
+    HttpChunkedTransferReader::from_reader(&mut chunked_reader, MAX_MESSAGE_LEN.into())
+        .read_to_end(&mut body)
+        .unwrap();
+    let decoded: Vec<serde_json::Value> = serde_json::from_slice(&body).unwrap();
+    assert_eq!(decoded, items);
+
+    // an empty list is still an array
+    let mut contents = HttpResponseContents::from_json_stream(Vec::<u64>::new());
+    let mut pipe_write = VecPipeWrite::default();
+    while contents.pipe_out(&mut pipe_write).unwrap() > 0 {}
+    let mut chunked_reader = &pipe_write.bytes[..];
+    let mut body = vec![];
+    HttpChunkedTransferReader::from_reader(&mut chunked_reader, MAX_MESSAGE_LEN.into())
+        .read_to_end(&mut body)
+        .unwrap();
+    assert_eq!(body, b"[]");
+
+    // any other value is streamed too
+    let value = serde_json::json!({
+        "items": items,
+        "count": items.len(),
+    });
+    let mut contents = HttpResponseContents::from_json_stream(value.clone());
+    assert_eq!(contents.content_length(), None);
+    let mut pipe_write = VecPipeWrite::default();
+    while contents.pipe_out(&mut pipe_write).unwrap() > 0 {}
+    let mut chunked_reader = &pipe_write.bytes[..];
+    let mut body = vec![];
+    HttpChunkedTransferReader::from_reader(&mut chunked_reader, MAX_MESSAGE_LEN.into())
+        .read_to_end(&mut body)
+        .unwrap();
+    let decoded: serde_json::Value = serde_json::from_slice(&body).unwrap();
+    assert_eq!(decoded, value);
+}
+
+#[test]
+fn test_error_json_payload_round_trip() {
+    let handler = AcceptedJsonResponseHandler {};
+    let payloads = [
+        HttpErrorPayload::new(
+            404,
+            "No such block".to_string(),
+            Some("Block is not stored on this node".to_string()),
+        ),
+        HttpErrorPayload::new(503, "Node is still booting".to_string(), None),
+    ];
+
+    for payload in payloads {
+        let (preamble, mut contents) =
+            HttpResponsePreamble::error_json_payload(payload.code, &payload).unwrap();
+        assert_eq!(preamble.status_code, payload.code);
+        assert_eq!(preamble.content_type, HttpContentType::JSON);
+
+        let mut pipe_write = VecPipeWrite::default();
+        preamble.consensus_serialize(&mut pipe_write).unwrap();
+        while contents.pipe_out(&mut pipe_write).unwrap() > 0 {}
+
+        let mut response = &pipe_write.bytes[..];
+        let parsed_preamble = HttpResponsePreamble::consensus_deserialize(&mut response).unwrap();
+        assert_eq!(parsed_preamble.status_code, payload.code);
+        assert_eq!(parsed_preamble.content_length, Some(response.len() as u32));
+
+        match try_decode_response(&handler, &parsed_preamble, response) {
+            Err(Error::HttpErrorResponse(decoded)) => assert_eq!(decoded, payload),
+            res => panic!("Expected HttpErrorResponse error, got {res:?}"),
+        }
+    }
+
+    // JSON error bodies of any other shape are left to the handler
+    let body = br#"{"message":"not an error payload"}"#;
+    let preamble = HttpResponsePreamble::error_json(404, "Not Found");
+    assert_eq!(
+        try_decode_response(&handler, &preamble, body).unwrap(),
+        HttpResponsePayload::JSON(serde_json::json!({"message": "not an error payload"}))
+    );
+}
+
+#[test]
+fn test_head_response_suppresses_body() {
+    let mut contents = HttpResponseContents::from_ram(b"hello world".to_vec()).into_head();
+    assert!(contents.is_head());
+    assert_eq!(contents.content_length(), Some(11));
+
+    // the preamble still advertises the length of the body
+    let mut pipe_write = VecPipeWrite::default();
+    make_fixed_date_preamble(contents.content_length())
+        .consensus_serialize(&mut pipe_write)
+        .unwrap();
+    let preamble_txt = String::from_utf8(pipe_write.bytes.clone()).unwrap();
+    assert!(
+        preamble_txt.contains("Content-Length: 11\r\n"),
+        "{preamble_txt}"
+    );
+    assert!(preamble_txt.ends_with("\r\n\r\n"));
+
+    // ...but none of it is sent
+    assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), 0);
+    assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), 0);
+    assert_eq!(String::from_utf8(pipe_write.bytes).unwrap(), preamble_txt);
+
+    // a compressed stream keeps its encoding, so the headers match those of a GET
+    let mut contents = HttpResponseContents::from_ram_compressed(
+        b"hello world".to_vec(),
+        HttpContentEncoding::Gzip,
+    )
+    .unwrap()
+    .into_head();
+    assert_eq!(contents.content_length(), None);
+    assert_eq!(contents.content_encoding(), Some(HttpContentEncoding::Gzip));
+    let mut pipe_write = VecPipeWrite::default();
+    assert_eq!(contents.pipe_out(&mut pipe_write).unwrap(), 0);
+    assert!(pipe_write.bytes.is_empty());
+}
+
+#[test]
+fn test_http_response_server_timing() {
+    // no header unless asked for
+    let mut preamble = make_fixed_date_preamble(Some(0));
+    preamble.add_server_timing(vec![]);
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(!txt.to_lowercase().contains("server-timing"), "{txt}");
+
+    preamble.add_server_timing(vec![
+        ("db".to_string(), Duration::from_micros(12_300)),
+        ("serialize".to_string(), Duration::from_micros(4_540)),
+    ]);
+    assert_eq!(
+        preamble.get_header("Server-Timing".to_string()),
+        Some("db;dur=12.3, serialize;dur=4.5".to_string())
+    );
+    let txt = String::from_utf8(preamble.serialize_to_vec()).unwrap();
+    assert!(
+        txt.contains("server-timing: db;dur=12.3, serialize;dur=4.5\r\n"),
+        "{txt}"
+    );
+
+    // further calls add further headers
+    preamble.add_server_timing(vec![("total".to_string(), Duration::from_millis(20))]);
+    assert_eq!(
+        preamble.get_header_all("Server-Timing".to_string()),
+        vec![
+            "db;dur=12.3, serialize;dur=4.5".to_string(),
+            "total;dur=20.0".to_string()
+        ]
+    );
+}
+
+/// Streams its chunks, and then sends a SHA-256 digest of them in a `Digest` trailer
+struct DigestChunkStream {
+    chunks: Vec<Vec<u8>>,
+    sent: Vec<u8>,
+}
+
+impl HttpChunkGenerator for DigestChunkStream {
+    fn hint_chunk_size(&self) -> usize {
+        4
+    }
+
+    fn generate_next_chunk(&mut self) -> Result<Vec<u8>, String> {
+        if self.chunks.is_empty() {
+            return Ok(vec![]);
+        }
+        let chunk = self.chunks.remove(0);
+        self.sent.extend_from_slice(&chunk);
+        Ok(chunk)
+    }
+
+    fn trailer_names(&self) -> Vec<String> {
+        vec!["Digest".to_string()]
+    }
+
+    fn trailers(&self) -> Vec<(String, String)> {
+        vec![(
+            "Digest".to_string(),
+            format!("sha-256={}", to_hex(&Sha256Sum::from_data(&self.sent).0)),
+        )]
+    }
+}
+
+#[test]
+fn test_chunked_response_trailers() {
+    let body = b"hello world!".to_vec();
+    let digest = format!("sha-256={}", to_hex(&Sha256Sum::from_data(&body).0));
+    let mut contents = HttpResponseContents::from_stream(Box::new(DigestChunkStream {
+        chunks: vec![b"hello".to_vec(), b" world!".to_vec()],
+        sent: vec![],
+    }));
+    assert_eq!(contents.trailer_names(), vec!["Digest".to_string()]);
+
+    // the trailer is advertised in the preamble...
+    let mut preamble = make_fixed_date_preamble(contents.content_length());
+    preamble.add_header("Trailer".to_string(), contents.trailer_names().join(", "));
+    let mut pipe_write = VecPipeWrite::default();
+    preamble.consensus_serialize(&mut pipe_write).unwrap();
+    let preamble_len = pipe_write.bytes.len();
+    let preamble_txt = String::from_utf8(pipe_write.bytes.clone()).unwrap();
+    assert!(preamble_txt.contains("Transfer-Encoding: chunked\r\n"));
+    assert!(
+        preamble_txt.contains("trailer: Digest\r\n"),
+        "{preamble_txt}"
+    );
+
+    // ...and sent after the last chunk
+    while contents.pipe_out(&mut pipe_write).unwrap() > 0 {}
+    let encoded_body = String::from_utf8(pipe_write.bytes[preamble_len..].to_vec()).unwrap();
+    assert!(
+        encoded_body.ends_with(&format!("\r\n0\r\nDigest: {digest}\r\n\r\n")),
+        "{encoded_body}"
+    );
+
+    // the receiver gets both the body and the trailer
+    let mut http = StacksHttp::new_client(
+        "127.0.0.1:20443".parse().unwrap(),
+        &ConnectionOptions::default(),
+    );
+    let (decoded_preamble, offset) = http.read_preamble(&pipe_write.bytes).unwrap();
+    assert_eq!(offset, preamble_len);
+    let msg = http
+        .stream_payload(&decoded_preamble, &mut &pipe_write.bytes[offset..])
+        .unwrap();
+    match msg {
+        (Some((StacksHttpMessage::Response(response), _)), _) => {
+            assert_eq!(response.preamble().trailers.get("digest"), Some(&digest));
+            assert!(response.preamble().headers.get("digest").is_none());
+            assert_eq!(
+                response.get_http_payload_ok().unwrap(),
+                HttpResponsePayload::Bytes(body)
+            );
+        }
+        _ => panic!("Expected a response, got {msg:?}"),
+    }
+}
+
+#[test]
+fn test_compressed_stream_forwards_trailers() {
+    let body = b"hello world!".to_vec();
+    let digest = format!("sha-256={}", to_hex(&Sha256Sum::from_data(&body).0));
+    let mut stream = GzipEncodeStream::new(Box::new(DigestChunkStream {
+        chunks: vec![b"hello".to_vec(), b" world!".to_vec()],
+        sent: vec![],
+    }));
+    assert_eq!(stream.trailer_names(), vec!["Digest".to_string()]);
+
+    let mut compressed = vec![];
+    loop {
+        let chunk = stream.generate_next_chunk().unwrap();
+        if chunk.is_empty() {
+            break;
+        }
+        compressed.extend_from_slice(&chunk);
+    }
+    let mut decoded = vec![];
+    GzDecoder::new(compressed.as_slice())
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded, body);
+
+    // the digest is of the inner stream's uncompressed output
+    assert_eq!(stream.trailers(), vec![("Digest".to_string(), digest)]);
+}
+
+#[test]
+fn test_send_chunked_streaming() {
+    // a 5 MiB body
+    let body: Vec<u8> = (0..5 * 1024 * 1024).map(|i| (i % 251) as u8).collect();
+    let chunk_size = 8192;
+
+    let mut encoded = vec![];
+    let num_sent =
+        HttpResponsePayload::send_chunked_streaming(&mut &body[..], chunk_size, &mut encoded)
+            .unwrap();
+    assert_eq!(num_sent, body.len() as u64);
+
+    // the body is split into chunk_size chunks, and the stream is terminated
+    assert!(encoded.starts_with(format!("{:x}\r\n", chunk_size).as_bytes()));
+    assert!(encoded.ends_with(b"\r\n0\r\n\r\n"));
+
+    let mut decoded = vec![];
+    HttpChunkedTransferReader::from_reader(&mut &encoded[..], body.len() as u64)
+        .read_to_end(&mut decoded)
+        .unwrap();
+    assert_eq!(decoded.len(), body.len());
+    assert!(decoded == body);
+
+    // an empty body is just the terminating chunk
+    let mut encoded = vec![];
+    let num_sent =
+        HttpResponsePayload::send_chunked_streaming(&mut io::empty(), chunk_size, &mut encoded)
+            .unwrap();
+    assert_eq!(num_sent, 0);
+    assert_eq!(encoded, b"0\r\n\r\n");
+}
+
+#[test]
+fn test_read_response_body() {
+    // content-length body; bytes after it are left unread
+    let response = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 11\r\n\r\nhello worldHTTP/1.1";
+    let mut fd = &response[..];
+    let preamble = HttpResponsePreamble::consensus_deserialize(&mut fd).unwrap();
+    assert_eq!(preamble.content_length, Some(11));
+    let body = read_response_body(&preamble, &mut fd).unwrap();
+    assert_eq!(body, b"hello world");
+    assert_eq!(fd, b"HTTP/1.1");
+
+    // content-length body that is cut short
+    let response =
+        b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 20\r\n\r\nhello world";
+    let mut fd = &response[..];
+    let preamble = HttpResponsePreamble::consensus_deserialize(&mut fd).unwrap();
+    match read_response_body(&preamble, &mut fd).unwrap_err() {
+        Error::ReadError(e) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+        e => panic!("Unexpected error: {:?}", &e),
+    }
+
+    // content-length body that is too long
+    let mut preamble = preamble;
+    preamble.content_length = Some(MAX_MESSAGE_LEN + 1);
+    match read_response_body(&preamble, &mut &b""[..]).unwrap_err() {
+        Error::DecodeError(msg) => assert!(msg.contains("longer than")),
+        e => panic!("Unexpected error: {:?}", &e),
+    }
+
+    // chunked body with multiple chunks
+    let response = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n1\r\n \r\n5\r\nworld\r\n0\r\n\r\n";
+    let mut fd = &response[..];
+    let preamble = HttpResponsePreamble::consensus_deserialize(&mut fd).unwrap();
+    assert!(preamble.is_chunked());
+    let body = read_response_body(&preamble, &mut fd).unwrap();
+    assert_eq!(body, b"hello world");
+    assert!(fd.is_empty());
+
+    // truncated chunked body
+    let response = b"HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nTransfer-Encoding: chunked\r\n\r\n5\r\nhello\r\n6\r\n wo";
+    let mut fd = &response[..];
+    let preamble = HttpResponsePreamble::consensus_deserialize(&mut fd).unwrap();
+    match read_response_body(&preamble, &mut fd).unwrap_err() {
+        Error::ReadError(e) => assert_eq!(e.kind(), io::ErrorKind::UnexpectedEof),
+        e => panic!("Unexpected error: {:?}", &e),
+    }
+}
@@ -26,7 +26,7 @@ use clarity::vm::types::{QualifiedContractIdentifier, BOUND_VALUE_SERIALIZATION_
 use clarity::vm::{ClarityName, ContractName};
 use percent_encoding::percent_decode_str;
 use regex::{Captures, Regex};
-use stacks_common::codec::{read_next, Error as CodecError, StacksMessageCodec, MAX_MESSAGE_LEN};
+use stacks_common::codec::{Error as CodecError, StacksMessageCodec, MAX_MESSAGE_LEN};
 use stacks_common::types::chainstate::{
     ConsensusHash, StacksAddress, StacksBlockId, StacksPublicKey,
 };
@@ -45,12 +45,14 @@ use crate::chainstate::nakamoto::NakamotoChainState;
 use crate::chainstate::stacks::db::{StacksChainState, StacksHeaderInfo};
 use crate::core::{MemPoolDB, StacksEpoch};
 use crate::net::connection::{ConnectionOptions, NetworkConnection};
-use crate::net::http::common::{parse_raw_bytes, HTTP_PREAMBLE_MAX_ENCODED_SIZE};
+use crate::net::http::common::HTTP_PREAMBLE_MAX_ENCODED_SIZE;
 use crate::net::http::{
-    http_reason, parse_bytes, parse_json, Error as HttpError, HttpBadRequest, HttpContentType,
-    HttpErrorResponse, HttpNotFound, HttpRequest, HttpRequestContents, HttpRequestPreamble,
-    HttpResponse, HttpResponseContents, HttpResponsePayload, HttpResponsePreamble, HttpServerError,
-    HttpVersion,
+    decode_content_encoding, http_reason, parse_bytes, parse_json, parse_multipart, parse_text,
+    try_decode_response, Error as HttpError, HttpBadRequest, HttpContentType, HttpErrorPayload,
+    HttpErrorResponse, HttpExpectationFailed, HttpGatewayTimeout, HttpHeaderMap, HttpNotFound,
+    HttpRequest, HttpRequestContents, HttpRequestPreamble, HttpResponse, HttpResponseContents,
+    HttpResponsePayload, HttpResponsePreamble, HttpServerError, HttpServiceUnavailable,
+    HttpStreamBufferPool, HttpVersion, HttpVersionNotSupported, TextDecodeMode,
 };
 use crate::net::p2p::PeerNetwork;
 use crate::net::server::HttpPeer;
@@ -70,6 +72,10 @@ pub const STACKS_REQUEST_ID: &str = "X-Request-Id";
 /// from non-Stacks nodes (like Gaia hubs, CDNs, vanilla HTTP servers, and so on).
 pub const HTTP_REQUEST_ID_RESERVED: u32 = 0;
 
+/// HTTP methods that are always rejected with a 405, since they would echo the request (and any
+/// credentials in it) back to the client
+pub const HTTP_REJECTED_METHODS: [&str; 2] = ["TRACE", "TRACK"];
+
 /// All representations of the `tip=` query parameter value
 #[derive(Debug, Clone, PartialEq)]
 pub enum TipRequest {
@@ -624,36 +630,89 @@ impl StacksHttpResponse {
         error: &dyn HttpErrorResponse,
     ) -> StacksHttpResponse {
         let payload = error.payload();
-        let content_type = match &payload {
-            HttpResponsePayload::Empty => HttpContentType::Bytes,
-            HttpResponsePayload::Bytes(..) => HttpContentType::Bytes,
-            HttpResponsePayload::Text(..) => HttpContentType::Text,
-            HttpResponsePayload::JSON(..) => HttpContentType::JSON,
-        };
+        let content_type = payload.content_type();
         let content_length = payload.try_content_length();
-        let preamble = HttpResponsePreamble::from_http_request_preamble(
+        let mut preamble = HttpResponsePreamble::from_http_request_preamble(
             preamble,
             error.code(),
             http_reason(error.code()),
             content_length,
             content_type,
         );
+        for (key, value) in error.headers() {
+            preamble.add_header(key, value);
+        }
         StacksHttpResponse::new(preamble, payload)
     }
 
+    /// Make a new 504 response, for when an upstream that the request depends on timed out
+    pub fn new_gateway_timeout(message: String) -> StacksHttpResponse {
+        StacksHttpResponse::new(
+            HttpResponsePreamble::gateway_timeout(),
+            HttpGatewayTimeout::new(message).payload(),
+        )
+    }
+
+    /// Make a new 417 response, for a request whose `Expect` header cannot be met
+    pub fn new_expectation_failed(message: String) -> StacksHttpResponse {
+        StacksHttpResponse::new(
+            HttpResponsePreamble::expectation_failed(),
+            HttpExpectationFailed::new(message).payload(),
+        )
+    }
+
+    /// Make a new 505 response, for a request that uses an unsupported HTTP version
+    pub fn new_version_not_supported(message: String) -> StacksHttpResponse {
+        StacksHttpResponse::new(
+            HttpResponsePreamble::version_not_supported(),
+            HttpVersionNotSupported::new(message).payload(),
+        )
+    }
+
+    /// Convert an error returned by an RPC handler into the error response to send back to the
+    /// client.  An upstream timeout becomes a 504 so the client is not left with an opaque error.
+    /// Returns the error back if it is irrecoverable.
+    pub fn from_handler_error(
+        request_preamble: &HttpRequestPreamble,
+        error: NetError,
+    ) -> Result<StacksHttpResponse, NetError> {
+        match error {
+            NetError::Http(e) => Ok(StacksHttpResponse::new_error(
+                request_preamble,
+                &*e.into_http_error(),
+            )),
+            NetError::RecvTimeout => Ok(StacksHttpResponse::new_gateway_timeout(format!(
+                "Timed out waiting for upstream while handling '{} {}'",
+                &request_preamble.verb, &request_preamble.path_and_query_str
+            ))),
+            e => Err(e),
+        }
+    }
+
     /// Make a new HTTP error response for text, apropos of nothing
     pub fn new_empty_error(error: &dyn HttpErrorResponse) -> StacksHttpResponse {
         let code = error.code();
         let payload = error.payload();
         let reason = http_reason(code);
-        let preamble = match &payload {
+        let mut preamble = match &payload {
             HttpResponsePayload::Empty => HttpResponsePreamble::error_bytes(code, reason),
             HttpResponsePayload::Bytes(..) => HttpResponsePreamble::error_bytes(code, reason),
             HttpResponsePayload::JSON(..) => HttpResponsePreamble::error_json(code, reason),
             HttpResponsePayload::Text(ref txt) => {
                 HttpResponsePreamble::error_text(code, reason, txt)
             }
+            HttpResponsePayload::Multipart(..) => HttpResponsePreamble::new(
+                HttpVersion::Http11,
+                code,
+                reason.to_string(),
+                payload.try_content_length(),
+                payload.content_type(),
+                false,
+            ),
         };
+        for (key, value) in error.headers() {
+            preamble.add_header(key, value);
+        }
 
         StacksHttpResponse::new(preamble, payload)
     }
@@ -732,6 +791,17 @@ impl StacksMessageCodec for StacksHttpPreamble {
     }
 
     fn consensus_deserialize<R: Read>(fd: &mut R) -> Result<StacksHttpPreamble, CodecError> {
+        Self::consensus_deserialize_with_allowlist(fd, None)
+    }
+}
+
+impl StacksHttpPreamble {
+    /// Decode a request or response preamble.  If `allowed_response_content_types` is given, a
+    /// response preamble whose content type is not in it fails to decode.
+    pub fn consensus_deserialize_with_allowlist<R: Read>(
+        fd: &mut R,
+        allowed_response_content_types: Option<&[HttpContentType]>,
+    ) -> Result<StacksHttpPreamble, CodecError> {
         let mut retry_fd = RetryReader::new(fd);
 
         // the byte stream can decode to a http request or a http response, but not both.
@@ -740,7 +810,10 @@ impl StacksMessageCodec for StacksHttpPreamble {
             Err(e_request) => {
                 // maybe a http response?
                 retry_fd.set_position(0);
-                match HttpResponsePreamble::consensus_deserialize(&mut retry_fd) {
+                match HttpResponsePreamble::consensus_deserialize_with_allowlist(
+                    &mut retry_fd,
+                    allowed_response_content_types,
+                ) {
                     Ok(response) => Ok(StacksHttpPreamble::Response(response)),
                     Err(e) => {
                         // underflow?
@@ -793,12 +866,13 @@ impl StacksHttpRecvStream {
     }
 
     /// Feed data into our chunked transfer reader state.  If we finish reading a stream, return
-    /// the decoded bytes (as Some(Vec<u8>) and the total number of encoded bytes consumed).
+    /// the decoded bytes (as Some(Vec<u8>), the total number of encoded bytes consumed, and the
+    /// trailer headers sent after the last chunk).
     /// Always returns the number of bytes consumed.
     pub fn consume_data<R: Read>(
         &mut self,
         fd: &mut R,
-    ) -> Result<(Option<(Vec<u8>, usize)>, usize), NetError> {
+    ) -> Result<(Option<(Vec<u8>, usize, HttpHeaderMap)>, usize), NetError> {
         let mut consumed = 0;
         let mut blocked = false;
         while !blocked {
@@ -845,11 +919,15 @@ impl StacksHttpRecvStream {
             // reset
             let message_data = mem::replace(&mut self.data, vec![]);
             let total_consumed = self.total_consumed;
+            let mut trailers = HttpHeaderMap::new();
+            for (name, value) in self.state.trailers() {
+                trailers.append(name.clone(), value.clone());
+            }
 
             self.state = HttpChunkedTransferReaderState::new(self.state.max_size);
             self.total_consumed = 0;
 
-            Ok((Some((message_data, total_consumed)), consumed))
+            Ok((Some((message_data, total_consumed, trailers)), consumed))
         } else {
             Ok((None, consumed))
         }
@@ -873,7 +951,7 @@ impl HttpResponse for RPCArbitraryResponseHandler {
         body: &[u8],
     ) -> Result<HttpResponsePayload, HttpError> {
         match preamble.content_type {
-            HttpContentType::Bytes => {
+            HttpContentType::Bytes | HttpContentType::Gzip => {
                 let bytes = parse_bytes(preamble, body, MAX_MESSAGE_LEN.into())?;
                 Ok(HttpResponsePayload::Bytes(bytes))
             }
@@ -888,17 +966,25 @@ impl HttpResponse for RPCArbitraryResponseHandler {
                 Ok(HttpResponsePayload::JSON(json))
             }
             HttpContentType::Text => {
-                let text_bytes = parse_raw_bytes(
+                let text = parse_text(
                     preamble,
                     body,
                     MAX_MESSAGE_LEN.into(),
-                    HttpContentType::Text,
+                    self.text_decode_mode(),
                 )?;
-                let text = String::from_utf8_lossy(&text_bytes).to_string();
                 Ok(HttpResponsePayload::Text(text))
             }
+            HttpContentType::Multipart(_) => {
+                let parts = parse_multipart(preamble, body, MAX_MESSAGE_LEN.into())?;
+                Ok(HttpResponsePayload::Multipart(parts))
+            }
         }
     }
+
+    /// Arbitrary responses come from peers we know nothing about, so prefer resilience
+    fn text_decode_mode(&self) -> TextDecodeMode {
+        TextDecodeMode::Lossy
+    }
 }
 
 /// Stacks HTTP state machine implementation, for bufferring up data.
@@ -936,8 +1022,17 @@ pub struct StacksHttp {
     pub read_only_call_limit: ExecutionCost,
     /// The authorization token to enable access to privileged features, such as the block proposal RPC endpoint
     pub auth_token: Option<String>,
+    /// Header names that cross-origin requests may use, as answered to CORS preflight requests
+    pub cors_allowed_headers: Vec<String>,
     /// Allow arbitrary responses to be handled in addition to request handlers
     allow_arbitrary_response: bool,
+    /// If set, the only content types accepted in HTTP responses
+    pub allowed_response_content_types: Option<Vec<HttpContentType>>,
+    /// Budget for the encoder buffers of streamed responses.  The server shares one pool across
+    /// all of its connections.
+    pub stream_buffers: HttpStreamBufferPool,
+    /// Content type of responses whose handler does not set one
+    pub default_response_content_type: HttpContentType,
 }
 
 impl StacksHttp {
@@ -956,7 +1051,11 @@ impl StacksHttp {
             maximum_call_argument_size: conn_opts.maximum_call_argument_size,
             read_only_call_limit: conn_opts.read_only_call_limit.clone(),
             auth_token: conn_opts.auth_token.clone(),
+            cors_allowed_headers: conn_opts.cors_allowed_headers.clone(),
             allow_arbitrary_response: false,
+            allowed_response_content_types: None,
+            stream_buffers: HttpStreamBufferPool::new(conn_opts.max_http_stream_buffer_bytes),
+            default_response_content_type: conn_opts.default_response_content_type,
         };
         http.register_rpc_methods();
         http
@@ -977,7 +1076,11 @@ impl StacksHttp {
             maximum_call_argument_size: conn_opts.maximum_call_argument_size,
             read_only_call_limit: conn_opts.read_only_call_limit.clone(),
             auth_token: conn_opts.auth_token.clone(),
+            cors_allowed_headers: conn_opts.cors_allowed_headers.clone(),
             allow_arbitrary_response: true,
+            allowed_response_content_types: None,
+            stream_buffers: HttpStreamBufferPool::new(conn_opts.max_http_stream_buffer_bytes),
+            default_response_content_type: conn_opts.default_response_content_type,
         }
     }
 
@@ -1009,6 +1112,24 @@ impl StacksHttp {
         None
     }
 
+    /// The methods that some registered handler serves for `request_path`, for use in an `Allow`
+    /// header.  The methods in `HTTP_REJECTED_METHODS` are never included.
+    pub fn allowed_methods(&self, request_path: &str) -> Vec<String> {
+        let mut methods: Vec<String> = vec![];
+        for (verb, regex, _) in self.request_handlers.iter() {
+            if methods.contains(verb)
+                || HTTP_REJECTED_METHODS
+                    .iter()
+                    .any(|method| verb.eq_ignore_ascii_case(method))
+                || !regex.is_match(request_path)
+            {
+                continue;
+            }
+            methods.push(verb.clone());
+        }
+        methods
+    }
+
     /// Force the state machine to expect a response
     #[cfg(test)]
     pub fn set_response_handler(&mut self, request_verb: &str, request_path: &str) {
@@ -1063,6 +1184,17 @@ impl StacksHttp {
         let (decoded_path, query) = decode_request_path(&preamble.path_and_query_str)?;
         test_debug!("decoded_path: '{}', query: '{}'", &decoded_path, &query);
 
+        // Never route a request that would echo itself back to the client
+        if HTTP_REJECTED_METHODS
+            .iter()
+            .any(|method| preamble.verb.eq_ignore_ascii_case(method))
+        {
+            return Err(NetError::Http(HttpError::MethodNotAllowed {
+                method: preamble.verb.clone(),
+                allowed: self.allowed_methods(&decoded_path),
+            }));
+        }
+
         // NOTE: This loop starts out like `find_response_handler()`, but `captures`'s lifetime is
         // bound to `regex` so we can't just return it from `find_response_handler()`.  Thus, it's
         // duplicated here.
@@ -1139,6 +1271,8 @@ impl StacksHttp {
 
     /// Try to parse an inbound HTTP response, given its decoded HTTP preamble, and the HTTP
     /// version and request path that had originally sent.  The body will be read from `fd`.
+    /// Error responses with an `HttpErrorPayload` JSON body are returned as
+    /// `HttpError::HttpErrorResponse`, like `try_decode_response()` does.
     pub fn try_parse_response(
         &mut self,
         request_handler_index: usize,
@@ -1146,14 +1280,18 @@ impl StacksHttp {
         body: &[u8],
     ) -> Result<StacksHttpResponse, NetError> {
         if preamble.status_code >= 400 {
-            return Self::try_parse_error_response(preamble, body);
+            let body = decode_content_encoding(preamble, body, MAX_MESSAGE_LEN.into())?;
+            if let Some(payload) = HttpErrorPayload::try_from_response(preamble, &body) {
+                return Err(NetError::Http(HttpError::HttpErrorResponse(payload)));
+            }
+            return Self::try_parse_error_response(preamble, &body);
         }
 
         let (_, _, parser) = self
             .request_handlers
             .get(request_handler_index)
             .expect("FATAL: tried to use nonexistent response handler");
-        let payload = parser.try_parse_response(preamble, body)?;
+        let payload = try_decode_response(parser.as_ref(), preamble, body)?;
         let response = StacksHttpResponse::new(preamble.clone(), payload);
         return Ok(response);
     }
@@ -1167,6 +1305,16 @@ impl StacksHttp {
         request: StacksHttpRequest,
         node: &mut StacksNodeState,
     ) -> Result<(HttpResponsePreamble, HttpResponseContents), NetError> {
+        // The only expectation we can meet is `100-continue`, since we always read the body
+        if let Some(expect) = request.preamble().headers.get("expect") {
+            if !expect.eq_ignore_ascii_case("100-continue") {
+                return StacksHttpResponse::new_expectation_failed(format!(
+                    "Unsupported expectation '{expect}'"
+                ))
+                .try_into_contents();
+            }
+        }
+
         let (decoded_path, _) = decode_request_path(&request.preamble().path_and_query_str)?;
         let Some(response_handler_index) = request
             .response_handler_index
@@ -1193,11 +1341,11 @@ impl StacksHttp {
             request_handler.try_handle_request(request.preamble, request.contents, node);
         request_handler.restart();
 
-        let (response_preamble, response_contents) = match request_result {
+        let (mut response_preamble, mut response_contents) = match request_result {
             Ok((rp, rc)) => (rp, rc),
-            Err(NetError::Http(e)) => {
+            Err(e @ (NetError::Http(..) | NetError::RecvTimeout)) => {
                 debug!("RPC handler for {} failed: {:?}", decoded_path, &e);
-                return StacksHttpResponse::new_error(&request_preamble, &*e.into_http_error())
+                return StacksHttpResponse::from_handler_error(&request_preamble, e)?
                     .try_into_contents();
             }
             Err(e) => {
@@ -1205,6 +1353,30 @@ impl StacksHttp {
                 return Err(e);
             }
         };
+
+        response_preamble.set_default_content_type(self.default_response_content_type);
+
+        // streamed responses hold an encoder buffer until they are sent, so don't take on more
+        // of them than the server can afford
+        if let Some(buffer_size) = response_contents.stream_buffer_size() {
+            let Some(reservation) = self.stream_buffers.try_reserve(buffer_size) else {
+                debug!(
+                    "Not enough stream buffer space to serve request";
+                    "path" => %request_preamble.path_and_query_str,
+                    "buffer_size" => buffer_size,
+                    "reserved_bytes" => self.stream_buffers.reserved_bytes(),
+                    "max_bytes" => self.stream_buffers.max_bytes()
+                );
+                return StacksHttpResponse::new_error(
+                    &request_preamble,
+                    &HttpServiceUnavailable::new(
+                        "Too many streamed responses in progress; try again later".to_string(),
+                    ),
+                )
+                .try_into_contents();
+            };
+            response_contents.set_stream_buffer_reservation(reservation);
+        }
         Ok((response_preamble, response_contents))
     }
 
@@ -1260,13 +1432,13 @@ impl StacksHttp {
     /// Used for processing chunk-encoded streams.
     /// Given the preamble and a Read, stream the bytes into a chunk-decoder.  Return the decoded
     /// bytes if we decode an entire stream.  Always return the number of bytes consumed.
-    /// Returns Ok((Some(decoded bytes we got, total number of encoded bytes), number of bytes gotten in this call)) if we're done decoding.
+    /// Returns Ok((Some(decoded bytes we got, total number of encoded bytes, trailer headers), number of bytes gotten in this call)) if we're done decoding.
     /// Returns Ok((None, number of bytes gotten in this call)) if there's more to decode.
     pub fn consume_data<R: Read>(
         &mut self,
         preamble: &HttpResponsePreamble,
         fd: &mut R,
-    ) -> Result<(Option<(Vec<u8>, usize)>, usize), NetError> {
+    ) -> Result<(Option<(Vec<u8>, usize, HttpHeaderMap)>, usize), NetError> {
         if !preamble.is_chunked() {
             return Err(NetError::InvalidState);
         }
@@ -1274,10 +1446,10 @@ impl StacksHttp {
             match reply.stream.consume_data(fd).inspect_err(|_e| {
                 self.reset();
             })? {
-                (Some((byte_vec, bytes_total)), sz) => {
+                (Some((byte_vec, bytes_total, trailers)), sz) => {
                     // done receiving
                     self.reply = None;
-                    Ok((Some((byte_vec, bytes_total)), sz))
+                    Ok((Some((byte_vec, bytes_total, trailers)), sz))
                 }
                 res => Ok(res),
             }
@@ -1437,8 +1609,15 @@ impl ProtocolFamily for StacksHttp {
         let preamble = {
             let mut rd =
                 BoundReader::from_reader(&mut cursor, HTTP_PREAMBLE_MAX_ENCODED_SIZE as u64);
-            let preamble: StacksHttpPreamble = read_next(&mut rd)?;
-            preamble
+            StacksHttpPreamble::consensus_deserialize_with_allowlist(
+                &mut rd,
+                self.allowed_response_content_types.as_deref(),
+            )
+            .map_err(|e| match HttpRequestPreamble::unsupported_version(buf) {
+                // a request we can answer, but not serve
+                Some(version) => NetError::Http(HttpError::VersionNotSupported(version)),
+                None => e.into(),
+            })?
         };
 
         let preamble_len = cursor.position() as usize;
@@ -1493,13 +1672,16 @@ impl ProtocolFamily for StacksHttp {
                 })?;
 
                 match message_bytes_opt {
-                    Some((message_bytes, total_bytes_consumed)) => {
+                    Some((message_bytes, total_bytes_consumed, trailers)) => {
                         // can parse!
                         test_debug!(
                             "read http response payload of {} bytes (just buffered {})",
                             message_bytes.len(),
                             num_read,
                         );
+                        let mut http_response_preamble = http_response_preamble.clone();
+                        http_response_preamble.trailers = trailers;
+                        let http_response_preamble = &http_response_preamble;
 
                         let parse_res = if self.request_handler_index.is_none()
                             && self.allow_arbitrary_response
@@ -478,6 +478,9 @@ pub struct PeerNetwork {
     // refreshed whenever the burnchain advances
     pub chain_view: BurnchainView,
     pub burnchain_tip: BlockSnapshot,
+    /// Height of the highest burnchain block header downloaded so far, which may be ahead of
+    /// `burnchain_tip` while the node is still processing burnchain blocks
+    pub burnchain_headers_height: u64,
     pub chain_view_stable_consensus_hash: ConsensusHash,
     pub ast_rules: ASTRules,
 
@@ -712,6 +715,7 @@ impl PeerNetwork {
                 &first_burn_header_hash,
                 first_burn_header_ts as u64,
             ),
+            burnchain_headers_height: first_block_height,
             stacks_tip: StacksTipInfo::empty(),
             parent_stacks_tip: StacksTipInfo::empty(),
             tenure_start_block_id: StacksBlockId([0x00; 32]),
@@ -5557,6 +5561,12 @@ impl PeerNetwork {
         self.refresh_sortition_view(sortdb)
             .expect("FATAL: failed to refresh sortition view from sortition DB");
 
+        self.burnchain_headers_height = indexer
+            .get_burnchain_headers_height()
+            // N.B. the indexer reports 1 + num_headers
+            .map(|burnchain_height| burnchain_height.saturating_sub(1))
+            .unwrap_or(self.burnchain_tip.block_height);
+
         // This operation needs to be performed before any early return:
         // Events are being parsed and dispatched here once and we want to
         // enqueue them.
@@ -5580,15 +5590,9 @@ impl PeerNetwork {
         })
         .expect("FATAL: with_network_state should be infallable (not connected)");
 
-        let burnchain_height = indexer
-            .get_burnchain_headers_height()
-            // N.B. the indexer reports 1 + num_headers
-            .map(|burnchain_height| burnchain_height.saturating_sub(1))
-            .unwrap_or(self.burnchain_tip.block_height);
-
         self.dispatch_network(
             &mut network_result,
-            burnchain_height,
+            self.burnchain_headers_height,
             sortdb,
             mempool,
             chainstate,
@@ -67,9 +67,10 @@ use crate::cost_estimates::{CostEstimator, FeeEstimator};
 use crate::net::atlas::{AtlasDB, Attachment, MAX_ATTACHMENT_INV_PAGES_PER_REQUEST};
 use crate::net::connection::{ConnectionHttp, ConnectionOptions, ReplyHandleHttp};
 use crate::net::db::PeerDB;
-use crate::net::http::{HttpRequestContents, HttpResponseContents};
+use crate::net::http::{HttpRequestContents, HttpResponseContents, HttpStreamBufferPool};
 use crate::net::httpcore::{
-    StacksHttp, StacksHttpMessage, StacksHttpRequest, StacksHttpResponse, HTTP_REQUEST_ID_RESERVED,
+    RPCRequestHandler, StacksHttp, StacksHttpMessage, StacksHttpRequest, StacksHttpResponse,
+    HTTP_REQUEST_ID_RESERVED,
 };
 use crate::net::p2p::{PeerMap, PeerNetwork};
 use crate::net::relay::Relayer;
@@ -101,6 +102,10 @@ pub struct ConversationHttp {
     total_request_count: u64,
     /// number of messages sent
     total_reply_count: u64,
+    /// number of bytes received
+    total_bytes_recv: u64,
+    /// number of bytes sent
+    total_bytes_sent: u64,
     /// absolute timestamp of the last time we recieved at least 1 byte
     last_request_timestamp: u64,
     /// absolute timestamp of the last time we sent at least 1 byte
@@ -167,6 +172,8 @@ impl ConversationHttp {
             keep_alive: true,
             total_request_count: 0,
             total_reply_count: 0,
+            total_bytes_recv: 0,
+            total_bytes_sent: 0,
             last_request_timestamp: 0,
             last_response_timestamp: 0,
             socket_send_buffer_size,
@@ -249,6 +256,7 @@ impl ConversationHttp {
 
         // account for the request
         self.total_request_count += 1;
+        monitoring::increment_http_connection_requests_counter();
 
         // make the relay handle. There may not have been a valid request in the first place, so
         // we'll use a relay handle (not a reply handle) to push out the error.
@@ -278,6 +286,13 @@ impl ConversationHttp {
 
         // make sure content-length is properly set, based on how we're about to stream data back
         response_preamble.content_length = response_body.content_length();
+        if let Some(encoding) = response_body.content_encoding() {
+            response_preamble.add_header("Content-Encoding".into(), encoding.to_string());
+        }
+        let trailer_names = response_body.trailer_names();
+        if !trailer_names.is_empty() {
+            response_preamble.add_header("Trailer".into(), trailer_names.join(", "));
+        }
 
         // buffer up response headers into the reply handle
         response_preamble.consensus_serialize(&mut reply)?;
@@ -511,6 +526,16 @@ impl ConversationHttp {
         self.last_response_timestamp
     }
 
+    /// How many bytes have we received on this conversation?
+    pub fn get_total_bytes_recv(&self) -> u64 {
+        self.total_bytes_recv
+    }
+
+    /// How many bytes have we sent on this conversation?
+    pub fn get_total_bytes_sent(&self) -> u64 {
+        self.total_bytes_sent
+    }
+
     /// When was this converation conencted?
     #[cfg_attr(test, mutants::skip)]
     pub fn get_connection_time(&self) -> u64 {
@@ -537,6 +562,7 @@ impl ConversationHttp {
                 StacksHttpMessage::Request(req) => {
                     // new request that we can handle
                     self.total_request_count += 1;
+                    monitoring::increment_http_connection_requests_counter();
                     self.last_request_timestamp = get_epoch_time_secs();
                     let latency = req.duration_ms();
                     let start_time = Instant::now();
@@ -616,7 +642,9 @@ impl ConversationHttp {
                 break;
             }
         }
+        self.total_bytes_recv += total_recv as u64;
         monitoring::update_inbound_rpc_bandwidth(total_recv as i64);
+        monitoring::increment_http_connection_bytes_counter("in", total_recv as i64);
         Ok(total_recv)
     }
 
@@ -647,7 +675,9 @@ impl ConversationHttp {
                 break;
             }
         }
+        self.total_bytes_sent += total_sz as u64;
         monitoring::update_inbound_rpc_bandwidth(total_sz as i64);
+        monitoring::increment_http_connection_bytes_counter("out", total_sz as i64);
         Ok(total_sz)
     }
 
@@ -658,4 +688,18 @@ impl ConversationHttp {
     pub fn metrics_identifier(&self, req: &mut StacksHttpRequest) -> &str {
         self.connection.protocol.metrics_identifier(req)
     }
+
+    /// Draw the encoder buffers of this conversation's streamed responses from `pool`, so they
+    /// count against a budget shared with other conversations
+    pub fn set_stream_buffer_pool(&mut self, pool: HttpStreamBufferPool) {
+        self.connection.protocol.stream_buffers = pool;
+    }
+
+    /// Register an API RPC endpoint on this conversation, in addition to the built-in ones
+    pub fn register_rpc_endpoint<Handler: RPCRequestHandler + 'static>(
+        &mut self,
+        handler: Handler,
+    ) {
+        self.connection.protocol.register_rpc_endpoint(handler);
+    }
 }
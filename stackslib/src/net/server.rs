@@ -62,6 +62,9 @@ pub struct HttpPeer {
 
     /// connection options
     pub connection_opts: ConnectionOptions,
+
+    /// budget for the encoder buffers of streamed responses, shared by all conversations
+    pub stream_buffers: HttpStreamBufferPool,
 }
 
 impl HttpPeer {
@@ -70,6 +73,7 @@ impl HttpPeer {
         server_handle: usize,
         server_addr: SocketAddr,
     ) -> HttpPeer {
+        let stream_buffers = HttpStreamBufferPool::new(conn_opts.max_http_stream_buffer_bytes);
         HttpPeer {
             peers: HashMap::new(),
             sockets: HashMap::new(),
@@ -79,6 +83,7 @@ impl HttpPeer {
             http_server_addr: server_addr,
 
             connection_opts: conn_opts,
+            stream_buffers,
         }
     }
 
@@ -257,6 +262,7 @@ impl HttpPeer {
             event_id,
             send_buffer_size,
         );
+        new_convo.set_stream_buffer_pool(self.stream_buffers.clone());
 
         debug!(
             "Registered HTTP {:?} as event {} (outbound={:?})",
@@ -467,6 +473,35 @@ impl HttpPeer {
                         }
                     }
                 }
+                net_error::Http(http::Error::VersionNotSupported(version)) => {
+                    // got a request for an HTTP version we don't speak.  Tell the client so with
+                    // a HTTP 505, and close the socket.
+                    debug!(
+                        "Got a request for unsupported HTTP version {} on socket {:?}",
+                        &version, &client_sock
+                    );
+                    let response = StacksHttpResponse::new_version_not_supported(format!(
+                        "HTTP version {version} is not supported"
+                    ));
+                    match convo.reply_error(response) {
+                        Ok(_) => {
+                            // prime the socket
+                            if let Err(e) = HttpPeer::saturate_http_socket(client_sock, convo) {
+                                debug!(
+                                    "Failed to flush HTTP 505 to socket {:?}: {:?}",
+                                    &client_sock, &e
+                                );
+                            }
+                        }
+                        Err(e) => {
+                            debug!(
+                                "Failed to reply HTTP 505 to socket {:?}: {:?}",
+                                &client_sock, &e
+                            );
+                            convo_dead = true;
+                        }
+                    }
+                }
                 _ => {
                     debug!(
                         "Failed to receive HTTP data on event {} (socket {:?}): {:?}",
@@ -1191,9 +1226,9 @@ mod test {
             1,
             0,
             |client_id, _| {
-                // live example -- should fail because we don't support `Connection:
-                // upgrade`
-                let request_txt = "GET /favicon.ico HTTP/1.1\r\nConnection: upgrade\r\nHost: crashy-stacky.zone117x.com\r\nX-Real-IP: 213.127.17.55\r\nX-Forwarded-For: 213.127.17.55\r\nX-Forwarded-Proto: http\r\nX-Forwarded-Host: crashy-stacky.zone117x.com\r\nX-Forwarded-Port: 9001\r\nUser-Agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nAccept: image/webp,image/apng,image/*,*/*;q=0.8\r\nReferer: http://crashy-stacky.zone117x.com:9001/v2/info\r\nAccept-Encoding: gzip, deflate\r\nAccept-Language: en-US,en;q=0.9\r\n\r\n";
+                // live example, minus its Host header -- should fail because the Host header
+                // is required
+                let request_txt = "GET /favicon.ico HTTP/1.1\r\nConnection: upgrade\r\nX-Real-IP: 213.127.17.55\r\nX-Forwarded-For: 213.127.17.55\r\nX-Forwarded-Proto: http\r\nX-Forwarded-Host: crashy-stacky.zone117x.com\r\nX-Forwarded-Port: 9001\r\nUser-Agent: Mozilla/5.0 (Macintosh; Intel Mac OS X 10_15_3) AppleWebKit/537.36 (KHTML, like Gecko) Chrome/81.0.4044.113 Safari/537.36\r\nAccept: image/webp,image/apng,image/*,*/*;q=0.8\r\nReferer: http://crashy-stacky.zone117x.com:9001/v2/info\r\nAccept-Encoding: gzip, deflate\r\nAccept-Language: en-US,en;q=0.9\r\n\r\n";
                 request_txt.as_bytes().to_vec()
             },
             |client_id, http_response_bytes_res| {
@@ -1234,6 +1269,34 @@ mod test {
         );
     }
 
+    #[test]
+    fn test_http_505() {
+        test_http_server(
+            function_name!(),
+            51074,
+            51075,
+            ConnectionOptions::default(),
+            1,
+            0,
+            |client_id, _| {
+                // HTTP/2 isn't supported
+                let request_txt = "GET /v2/info HTTP/2.0\r\nHost: 127.0.0.1:51075\r\n\r\n";
+                request_txt.as_bytes().to_vec()
+            },
+            |client_id, http_response_bytes_res| {
+                // should be a HTTP 505 error
+                eprintln!("{:?}", &http_response_bytes_res);
+                let http_response_bytes = http_response_bytes_res.unwrap();
+                let http_response_str = String::from_utf8(http_response_bytes).unwrap();
+                eprintln!("HTTP response\n{}", http_response_str);
+                assert!(http_response_str
+                    .find("505 HTTP Version not supported")
+                    .is_some());
+                true
+            },
+        );
+    }
+
     #[test]
     fn test_http_no_connecting_event_id_leak() {
         use std::net::TcpListener;
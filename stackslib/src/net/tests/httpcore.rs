@@ -39,16 +39,17 @@ use crate::chainstate::stacks::{
 use crate::net::api::getneighbors::{RPCNeighbor, RPCNeighborsInfo};
 use crate::net::connection::ConnectionOptions;
 use crate::net::http::{
-    http_error_from_code_and_text, http_reason, HttpContentType, HttpErrorResponse,
-    HttpRequestContents, HttpRequestPreamble, HttpReservedHeader, HttpResponsePayload,
-    HttpResponsePreamble, HttpVersion, HTTP_PREAMBLE_MAX_NUM_HEADERS,
+    http_error_from_code_and_text, http_reason, Error as HttpError, HttpContentType,
+    HttpErrorPayload, HttpErrorResponse, HttpRequestContents, HttpRequestPreamble,
+    HttpReservedHeader, HttpResponsePayload, HttpResponsePreamble, HttpVersion,
+    HTTP_PREAMBLE_MAX_NUM_HEADERS,
 };
 use crate::net::httpcore::{
     send_http_request, HttpPreambleExtensions, HttpRequestContentsExtensions, StacksHttp,
     StacksHttpMessage, StacksHttpPreamble, StacksHttpRequest, StacksHttpResponse,
 };
 use crate::net::rpc::ConversationHttp;
-use crate::net::{ProtocolFamily, TipRequest};
+use crate::net::{Error as NetError, ProtocolFamily, TipRequest};
 
 #[test]
 fn test_parse_stacks_http_preamble_request_err() {
@@ -85,10 +86,6 @@ fn test_parse_stacks_http_preamble_request_err() {
             "Get /foo HTTP/1.1\r\nHost: localhost:666666\r\n\r\n",
             "Failed to decode HTTP request or HTTP response",
         ),
-        (
-            "GET /foo HTTP/1.1\r\nHost: localhost:8080\r\nConnection: foo\r\n\r\n",
-            "Failed to decode HTTP request or HTTP response",
-        ),
     ];
 
     for (data, errstr) in tests.iter() {
@@ -124,8 +121,6 @@ fn test_parse_stacks_http_preamble_response_err() {
          "Failed to decode HTTP request or HTTP response"),
         ("HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 123\r\nTransfer-Encoding: chunked\r\n\r\n",
          "Failed to decode HTTP request or HTTP response"),
-        ("HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 123\r\nConnection: foo\r\n\r\n",
-         "Failed to decode HTTP request or HTTP response"),
     ];
 
     for (data, errstr) in tests.iter() {
@@ -144,6 +139,67 @@ fn test_parse_stacks_http_preamble_response_err() {
     }
 }
 
+#[test]
+fn test_parse_stacks_http_preamble_response_content_type_allowlist() {
+    let allowed = [HttpContentType::JSON];
+    let json_response =
+        "HTTP/1.1 200 OK\r\nContent-Type: application/json\r\nContent-Length: 2\r\n\r\n{}";
+    let text_response =
+        "HTTP/1.1 200 OK\r\nContent-Type: text/plain\r\nContent-Length: 2\r\n\r\nhi";
+    let untyped_response = "HTTP/1.1 200 OK\r\nContent-Length: 2\r\n\r\nhi";
+
+    // an allowed content type decodes
+    let preamble = StacksHttpPreamble::consensus_deserialize_with_allowlist(
+        &mut json_response.as_bytes(),
+        Some(&allowed),
+    )
+    .unwrap()
+    .expect_response();
+    assert_eq!(preamble.content_type, HttpContentType::JSON);
+
+    // a disallowed content type is rejected, as is a missing one
+    for (data, content_type) in [
+        (text_response, "text/plain"),
+        (untyped_response, "application/octet-stream"),
+    ] {
+        let err = StacksHttpPreamble::consensus_deserialize_with_allowlist(
+            &mut data.as_bytes(),
+            Some(&allowed),
+        )
+        .unwrap_err()
+        .to_string();
+        assert!(
+            err.contains(&format!(
+                "content type '{content_type}' is not one of [application/json]"
+            )),
+            "{err}"
+        );
+
+        // without an allowlist, both decode
+        StacksHttpPreamble::consensus_deserialize(&mut data.as_bytes()).unwrap();
+    }
+
+    // the allowlist is threaded into the protocol state machine's decoder
+    let mut http = StacksHttp::new_client(
+        "127.0.0.1:20443".parse().unwrap(),
+        &ConnectionOptions::default(),
+    );
+    http.allowed_response_content_types = Some(allowed.to_vec());
+    let err = http.read_preamble(text_response.as_bytes()).unwrap_err();
+    assert!(err.to_string().contains("text/plain"), "{err}");
+
+    let mut http = StacksHttp::new_client(
+        "127.0.0.1:20443".parse().unwrap(),
+        &ConnectionOptions::default(),
+    );
+    http.allowed_response_content_types = Some(allowed.to_vec());
+    let (preamble, _) = http.read_preamble(json_response.as_bytes()).unwrap();
+    assert_eq!(
+        preamble.expect_response().content_type,
+        HttpContentType::JSON
+    );
+}
+
 fn make_test_transaction() -> StacksTransaction {
     let privk = StacksPrivateKey::from_hex(
         "6d430bb91222408e7706c9001cfaeb91b08c2be6d5ac95779ab52c6b431950e001",
@@ -849,6 +905,58 @@ fn test_http_response_type_codec_err() {
     }
 }
 
+#[test]
+fn test_http_response_error_payload() {
+    let payload = HttpErrorPayload::new(
+        404,
+        "No such block".to_string(),
+        Some("Block is not stored on this node".to_string()),
+    );
+    let body = serde_json::to_string(&payload).unwrap();
+    let response = format!(
+        "HTTP/1.1 404 Not Found\r\nServer: stacks/v2.0\r\nX-Request-Id: 123\r\nContent-Type: application/json\r\nContent-length: {}\r\n\r\n{body}",
+        body.len()
+    );
+
+    let mut http = StacksHttp::new(
+        "127.0.0.1:20443".parse().unwrap(),
+        &ConnectionOptions::default(),
+    );
+    http.set_response_handler(
+        "GET",
+        "/v2/blocks/1111111111111111111111111111111111111111111111111111111111111111",
+    );
+    let (preamble, offset) = http.read_preamble(response.as_bytes()).unwrap();
+    match http.read_payload(&preamble, &response.as_bytes()[offset..]) {
+        Err(NetError::Http(HttpError::HttpErrorResponse(decoded))) => {
+            assert_eq!(decoded, payload)
+        }
+        res => panic!("Expected HttpErrorResponse error, got {res:?}"),
+    }
+
+    // JSON error bodies of any other shape are still decoded as error responses
+    let body = r#"{"error":"No such block"}"#;
+    let response = format!(
+        "HTTP/1.1 404 Not Found\r\nServer: stacks/v2.0\r\nX-Request-Id: 123\r\nContent-Type: application/json\r\nContent-length: {}\r\n\r\n{body}",
+        body.len()
+    );
+    http.set_response_handler(
+        "GET",
+        "/v2/blocks/1111111111111111111111111111111111111111111111111111111111111111",
+    );
+    let (preamble, offset) = http.read_preamble(response.as_bytes()).unwrap();
+    let Ok((StacksHttpMessage::Response(response), _)) =
+        http.read_payload(&preamble, &response.as_bytes()[offset..])
+    else {
+        panic!("Failed to decode the error response");
+    };
+    assert_eq!(response.preamble().status_code, 404);
+    assert_eq!(
+        response.get_http_payload_ok().unwrap_err().to_string(),
+        NetError::NotFoundError.to_string()
+    );
+}
+
 #[test]
 fn test_http_duplicate_concurrent_streamed_response_fails() {
     // do not permit multiple in-flight chunk-encoded HTTP responses with the same request ID.
@@ -1227,6 +1335,10 @@ fn parse_http_response(response: StacksHttpResponse) -> String {
         HttpResponsePayload::Empty => "".to_string(),
         HttpResponsePayload::JSON(js) => serde_json::to_string(&js).unwrap(),
         HttpResponsePayload::Bytes(bytes) => String::from_utf8_lossy(bytes.as_slice()).to_string(),
+        HttpResponsePayload::Multipart(parts) => parts
+            .iter()
+            .map(|part| String::from_utf8_lossy(part.data.as_slice()).to_string())
+            .collect(),
     };
     response_txt
 }
@@ -1271,3 +1383,37 @@ fn test_send_request_success() {
         result
     );
 }
+
+#[test]
+fn test_http_trace_and_track_rejected() {
+    for verb in ["TRACE", "TRACK"] {
+        let mut http = StacksHttp::new(
+            "127.0.0.1:20443".parse().unwrap(),
+            &ConnectionOptions::default(),
+        );
+        let request = format!(
+            "{verb} /v2/info HTTP/1.1\r\nUser-Agent: stacks/2.0\r\nHost: bad:123\r\nCookie: secret\r\nContent-Length: 0\r\n\r\n"
+        );
+        let (preamble, offset) = http.read_preamble(request.as_bytes()).unwrap();
+        let Ok((StacksHttpMessage::Error(path, response), _)) =
+            http.read_payload(&preamble, &request.as_bytes()[offset..])
+        else {
+            panic!("Expected an error response to {verb}");
+        };
+        assert_eq!(path, "/v2/info");
+        assert_eq!(response.preamble().status_code, 405);
+
+        // the request is not echoed back, and the methods that are served are listed
+        let allow = response
+            .preamble()
+            .headers
+            .get("allow")
+            .expect("Missing Allow header");
+        let allowed: Vec<_> = allow.split(", ").collect();
+        assert!(allowed.contains(&"GET"), "{allow}");
+        assert!(!allowed.contains(&"TRACE"), "{allow}");
+        assert!(!allowed.contains(&"TRACK"), "{allow}");
+        let response_txt = String::from_utf8(response.try_serialize().unwrap()).unwrap();
+        assert!(!response_txt.contains("secret"), "{response_txt}");
+    }
+}
@@ -2,6 +2,7 @@ use async_std::net::{TcpListener, TcpStream};
 use async_std::prelude::*;
 use async_std::task;
 use http_types::{Body, Response, StatusCode};
+use stacks::net::http::MetricsContentType;
 use stacks::prometheus::{gather, Encoder, TextEncoder};
 
 use super::MonitoringError;
@@ -46,15 +47,19 @@ pub fn start_serving_prometheus_metrics(bind_address: String) -> Result<(), Moni
 
 async fn accept(stream: TcpStream) -> http_types::Result<()> {
     debug!("Handle Prometheus polling ({})", stream.peer_addr()?);
-    async_h1::accept(stream.clone(), |_| async {
+    async_h1::accept(stream.clone(), |req| async move {
+        let content_type = MetricsContentType::from_accept_header(
+            req.header("Accept").map(|values| values.last().as_str()),
+        );
         let encoder = TextEncoder::new();
         let metric_families = gather();
         let mut buffer = vec![];
         encoder.encode(&metric_families, &mut buffer).unwrap();
+        let body = content_type.format_body(String::from_utf8(buffer).unwrap());
 
         let mut response = Response::new(StatusCode::Ok);
-        response.append_header("Content-Type", encoder.format_type());
-        response.set_body(Body::from(buffer));
+        response.append_header("Content-Type", content_type.as_str());
+        response.set_body(Body::from(body));
 
         Ok(response)
     })
@@ -231,7 +231,7 @@ impl StackerDBListener {
             }) else {
                 continue;
             };
-            let SignerEvent::SignerMessages(signer_set, messages) = signer_event else {
+            let SignerEvent::SignerMessages(signer_set, messages, _) = signer_event else {
                 debug!("StackerDBListener: Received signer event other than a signer message. Ignoring.");
                 continue;
             };
@@ -418,6 +418,9 @@ impl StackerDBListener {
                     SignerMessageV0::BlockPushed(_) => {
                         debug!("Received block pushed message. Ignoring.");
                     }
+                    SignerMessageV0::ResponseRequest { .. } => {
+                        debug!("Received response request message. Ignoring.");
+                    }
                     SignerMessageV0::MockSignature(_)
                     | SignerMessageV0::MockProposal(_)
                     | SignerMessageV0::MockBlock(_) => {
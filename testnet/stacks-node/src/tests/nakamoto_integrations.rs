@@ -92,7 +92,7 @@ use stacks_common::types::{set_test_coinbase_schedule, CoinbaseInterval, StacksP
 use stacks_common::util::hash::{to_hex, Hash160, Sha512Trunc256Sum};
 use stacks_common::util::secp256k1::{MessageSignature, Secp256k1PrivateKey, Secp256k1PublicKey};
 use stacks_common::util::{get_epoch_time_secs, sleep_ms};
-use stacks_signer::chainstate::{ProposalEvalConfig, SortitionsView};
+use stacks_signer::chainstate::{BlockKindMask, ProposalEvalConfig, SignerClock, SortitionsView};
 use stacks_signer::signerdb::{BlockInfo, BlockState, ExtraBlockInfo, SignerDb};
 use stacks_signer::v0::SpawnedSigner;
 
@@ -6580,6 +6580,13 @@ fn signer_chainstate() {
             tenure_idle_timeout: Duration::from_secs(300),
             tenure_idle_timeout_buffer: Duration::from_secs(2),
             reorg_attempts_activity_timeout: Duration::from_secs(30),
+            clock: SignerClock::default(),
+            max_tenure_cost: None,
+            require_commit_confirmations: 0,
+            max_block_bytes: None,
+            max_burn_height_ahead: u64::MAX,
+            sign_block_kinds: BlockKindMask::ALL,
+            burnchain_reorg_grace_period: Duration::from_secs(0),
         };
         let mut sortitions_view =
             SortitionsView::fetch_view(proposal_conf, &signer_client).unwrap();
@@ -6661,6 +6668,7 @@ fn signer_chainstate() {
                 ext: ExtraBlockInfo::None,
                 state: BlockState::Unprocessed,
                 validation_time_ms: None,
+                validation_cost: None,
             })
             .unwrap();
 
@@ -6713,6 +6721,13 @@ fn signer_chainstate() {
             tenure_idle_timeout: Duration::from_secs(300),
             tenure_idle_timeout_buffer: Duration::from_secs(2),
             reorg_attempts_activity_timeout: Duration::from_secs(30),
+            clock: SignerClock::default(),
+            max_tenure_cost: None,
+            require_commit_confirmations: 0,
+            max_block_bytes: None,
+            max_burn_height_ahead: u64::MAX,
+            sign_block_kinds: BlockKindMask::ALL,
+            burnchain_reorg_grace_period: Duration::from_secs(0),
         };
         let burn_block_height = SortitionDB::get_canonical_burn_chain_tip(sortdb.conn())
             .unwrap()
@@ -6751,6 +6766,7 @@ fn signer_chainstate() {
                 ext: ExtraBlockInfo::None,
                 state: BlockState::GloballyAccepted,
                 validation_time_ms: Some(1000),
+                validation_cost: None,
             })
             .unwrap();
 
@@ -6794,6 +6810,13 @@ fn signer_chainstate() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut sortitions_view = SortitionsView::fetch_view(proposal_conf, &signer_client).unwrap();
     assert!(
@@ -14,7 +14,7 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 mod v0;
 
-use std::collections::HashSet;
+use std::collections::{HashMap, HashSet};
 use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
 use std::sync::{Arc, Mutex};
 use std::thread;
@@ -25,10 +25,12 @@ use clarity::vm::types::PrincipalData;
 use libsigner::v0::messages::{
     BlockAccepted, BlockRejection, BlockResponse, MessageSlotID, PeerInfo, SignerMessage,
 };
-use libsigner::{BlockProposal, SignerEntries, SignerEventTrait};
+use libsigner::{BlockProposal, SignerEntries, SignerEventTrait, SignerSession, StackerDBSession};
+use serde::Deserialize;
+use stacks::burnchains::{Burnchain, Txid};
 use stacks::chainstate::coordinator::comm::CoordinatorChannels;
 use stacks::chainstate::nakamoto::signer_set::NakamotoSigners;
-use stacks::chainstate::nakamoto::NakamotoBlock;
+use stacks::chainstate::nakamoto::{NakamotoBlock, NakamotoBlockHeader};
 use stacks::chainstate::stacks::boot::{NakamotoSignerEntry, SIGNERS_NAME};
 use stacks::chainstate::stacks::StacksPrivateKey;
 use stacks::config::{Config as NeonConfig, EventKeyType, EventObserverConfig, InitialBalance};
@@ -48,9 +50,11 @@ use stacks_common::util::tests::TestFlag;
 use stacks_signer::client::{ClientError, SignerSlotID, StackerDB, StacksClient};
 use stacks_signer::config::{build_signer_config_tomls, GlobalConfig as SignerConfig, Network};
 use stacks_signer::runloop::{SignerResult, State, StateInfo};
+use stacks_signer::v0::tests::TEST_SIGNER_CLOCK_OFFSET;
 use stacks_signer::{Signer, SpawnedSigner};
 
 use super::nakamoto_integrations::{check_nakamoto_empty_block_heuristics, wait_for};
+use crate::event_dispatcher::MinedNakamotoBlockEvent;
 use crate::neon::{Counters, RunLoopCounter};
 use crate::run_loop::boot_nakamoto;
 use crate::tests::bitcoin_regtest::BitcoinCoreController;
@@ -59,10 +63,10 @@ use crate::tests::nakamoto_integrations::{
     POX_4_DEFAULT_STACKER_BALANCE,
 };
 use crate::tests::neon_integrations::{
-    get_chain_info, next_block_and_wait, run_until_burnchain_height, test_observer,
-    wait_for_runloop,
+    get_account, get_chain_info, next_block_and_wait, run_until_burnchain_height, submit_tx,
+    test_observer, wait_for_runloop,
 };
-use crate::tests::to_addr;
+use crate::tests::{make_stacks_transfer, to_addr};
 use crate::BitcoinRegtestController;
 
 // Helper struct for holding the btc and stx neon nodes
@@ -103,6 +107,179 @@ pub struct SignerTest<S> {
     pub stacks_client: StacksClient,
     /// The number of cycles to stack for
     pub num_stacking_cycles: u64,
+    /// Config of a second stacks node following the same chain, if the test runs one.
+    /// Convergence checks assert that it agrees with the first node on the chain tip.
+    pub secondary_node_conf: Option<NeonConfig>,
+    /// Counters of the second stacks node, if the test runs one and it mines.  Mining then
+    /// waits for both nodes' block-commits before each burn block.
+    pub secondary_node_counters: Option<Counters>,
+}
+
+/// When each step of one block's signing lifecycle was first seen by the test observer
+#[derive(Debug, Clone)]
+pub struct BlockTimeline {
+    /// The block's signer signature hash
+    pub signer_signature_hash: Sha512Trunc256Sum,
+    /// When the miner posted the block proposal
+    pub proposal_posted: Instant,
+    /// When the node posted each validate-ok response for the block (one per signer submission)
+    pub validate_ok: Vec<Instant>,
+    /// When each signer posted its response to the block, keyed by its StackerDB slot
+    pub responses: HashMap<u32, Instant>,
+    /// When the node processed the signed block
+    pub finalized: Instant,
+}
+
+impl BlockTimeline {
+    /// Are the timeline's events ordered as proposal <= validate-ok <= response <= finalization?
+    /// Each validate-ok must follow the proposal, each response must follow the first
+    /// validate-ok, and finalization must follow the first response.
+    pub fn is_ordered(&self) -> bool {
+        let Some(first_validate_ok) = self.validate_ok.iter().min() else {
+            return false;
+        };
+        let Some(first_response) = self.responses.values().min() else {
+            return false;
+        };
+        self.validate_ok
+            .iter()
+            .all(|validate_ok| self.proposal_posted <= *validate_ok)
+            && self
+                .responses
+                .values()
+                .all(|response| first_validate_ok <= response)
+            && *first_response <= self.finalized
+    }
+}
+
+/// Why the node's mempool rejected a transaction, decoded from its `/v2/transactions` response
+#[derive(Debug, Clone, PartialEq)]
+pub enum MempoolRejection {
+    /// The transaction's fee is below the minimum the mempool accepts
+    FeeTooLow { expected: u64, actual: u64 },
+    /// The transaction's origin or sponsor nonce does not match its account nonce
+    BadNonce {
+        expected: u64,
+        actual: u64,
+        principal: String,
+        is_origin: bool,
+    },
+    /// The transaction's origin or sponsor cannot afford it
+    NotEnoughFunds { expected: u128, actual: u128 },
+    /// Any other rejection, with the node's reason code and reason data
+    Other {
+        reason: String,
+        reason_data: Option<serde_json::Value>,
+    },
+    /// The node's response was not a mempool rejection
+    Unexpected { status: u16, body: String },
+}
+
+/// The body of the node's response to a transaction its mempool rejected
+#[derive(Debug, Deserialize)]
+struct MempoolRejectionResponse {
+    reason: String,
+    reason_data: Option<serde_json::Value>,
+}
+
+impl MempoolRejection {
+    /// Decode the body of a failed `/v2/transactions` response with the given HTTP status
+    fn from_response(status: u16, body: String) -> Self {
+        let Ok(response) = serde_json::from_str::<MempoolRejectionResponse>(&body) else {
+            return Self::Unexpected { status, body };
+        };
+        response
+            .reason_data
+            .as_ref()
+            .and_then(|reason_data| Self::decode_reason(&response.reason, reason_data))
+            .unwrap_or(Self::Other {
+                reason: response.reason,
+                reason_data: response.reason_data,
+            })
+    }
+
+    /// Decode the reason codes that carry typed reason data
+    fn decode_reason(reason: &str, reason_data: &serde_json::Value) -> Option<Self> {
+        let get_u64 = |key: &str| reason_data.get(key)?.as_u64();
+        let get_hex_u128 = |key: &str| {
+            let hex = reason_data.get(key)?.as_str()?.strip_prefix("0x")?;
+            u128::from_str_radix(hex, 16).ok()
+        };
+        match reason {
+            "FeeTooLow" => Some(Self::FeeTooLow {
+                expected: get_u64("expected")?,
+                actual: get_u64("actual")?,
+            }),
+            "BadNonce" => Some(Self::BadNonce {
+                expected: get_u64("expected")?,
+                actual: get_u64("actual")?,
+                principal: reason_data.get("principal")?.as_str()?.to_string(),
+                is_origin: reason_data.get("is_origin")?.as_bool()?,
+            }),
+            "NotEnoughFunds" => Some(Self::NotEnoughFunds {
+                expected: get_hex_u128("expected")?,
+                actual: get_hex_u128("actual")?,
+            }),
+            _ => None,
+        }
+    }
+}
+
+/// A block lifecycle event seen by the test observer
+#[derive(Debug, Clone, PartialEq, Eq, Hash)]
+enum BlockTimelineEvent {
+    Proposal(Sha512Trunc256Sum),
+    /// The n-th validate-ok response for the block
+    ValidateOk(Sha512Trunc256Sum, usize),
+    /// A signer's response, from the given slot
+    Response(Sha512Trunc256Sum, u32),
+    Finalized(Sha512Trunc256Sum),
+}
+
+/// Collect every block lifecycle event that the test observer has seen so far
+fn observed_block_timeline_events() -> Vec<BlockTimelineEvent> {
+    let mut events = vec![];
+    for chunk in test_observer::get_stackerdb_chunks()
+        .into_iter()
+        .flat_map(|chunk| chunk.modified_slots)
+    {
+        match SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()) {
+            Ok(SignerMessage::BlockProposal(proposal)) => events.push(
+                BlockTimelineEvent::Proposal(proposal.block.header.signer_signature_hash()),
+            ),
+            Ok(SignerMessage::BlockResponse(response)) => events.push(
+                BlockTimelineEvent::Response(response.signer_signature_hash(), chunk.slot_id),
+            ),
+            _ => {}
+        }
+    }
+    let mut validate_ok_counts: HashMap<Sha512Trunc256Sum, usize> = HashMap::new();
+    for response in test_observer::get_proposal_responses() {
+        let BlockValidateResponse::Ok(validation) = response else {
+            continue;
+        };
+        let count = validate_ok_counts
+            .entry(validation.signer_signature_hash)
+            .or_default();
+        events.push(BlockTimelineEvent::ValidateOk(
+            validation.signer_signature_hash,
+            *count,
+        ));
+        *count += 1;
+    }
+    for block in test_observer::get_blocks() {
+        // non-nakamoto blocks do not supply this field
+        let Some(sighash) = block
+            .get("signer_signature_hash")
+            .and_then(|sighash| sighash.as_str())
+            .and_then(|sighash| sighash.strip_prefix("0x"))
+            .and_then(|sighash| Sha512Trunc256Sum::from_hex(sighash).ok())
+        else {
+            continue;
+        };
+        events.push(BlockTimelineEvent::Finalized(sighash));
+    }
+    events
 }
 
 impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<SpawnedSigner<S, T>> {
@@ -117,6 +294,23 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         )
     }
 
+    /// Create a signer test whose node moves the given epochs to start at the given burn
+    /// block heights. See [`set_epoch_boundaries`].
+    pub fn new_with_epoch_boundaries(
+        num_signers: usize,
+        initial_balances: Vec<(StacksAddress, u64)>,
+        boundaries: &[(StacksEpochId, u64)],
+    ) -> Self {
+        Self::new_with_config_modifications(
+            num_signers,
+            initial_balances,
+            |_| {},
+            |node_config| set_epoch_boundaries(node_config, boundaries),
+            None,
+            None,
+        )
+    }
+
     pub fn new_with_config_modifications<F: FnMut(&mut SignerConfig), G: FnMut(&mut NeonConfig)>(
         num_signers: usize,
         initial_balances: Vec<(StacksAddress, u64)>,
@@ -216,6 +410,8 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
             stacks_client,
             num_stacking_cycles: 12_u64,
             signer_configs,
+            secondary_node_conf: None,
+            secondary_node_counters: None,
         }
     }
 
@@ -285,6 +481,27 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         .expect("Timed out while waiting for the signers to be registered for both reward cycles");
     }
 
+    /// Stop the signer at `index` and spawn a fresh one in its place with the same config,
+    /// returning once the new signer has re-entered the `RegisteredSigners` state.
+    pub fn restart_signer(&mut self, index: usize) {
+        let signer_config = self.signer_configs[index].clone();
+        info!("Restarting signer #{index}"; "endpoint" => %signer_config.endpoint);
+        let _ = self.spawned_signers.remove(index).stop();
+        self.spawned_signers
+            .insert(index, SpawnedSigner::new(signer_config));
+
+        let others: HashSet<usize> = (0..self.spawned_signers.len())
+            .filter(|ix| *ix != index)
+            .collect();
+        wait_for(30, || {
+            self.send_status_request(&others);
+            thread::sleep(Duration::from_secs(1));
+            let state = self.get_states(&others).swap_remove(index);
+            Ok(state.is_some_and(|state| state.runloop_state == State::RegisteredSigners))
+        })
+        .expect("Timed out while waiting for the restarted signer to be registered");
+    }
+
     pub fn wait_for_cycle(&mut self, timeout_secs: u64, reward_cycle: u64) {
         let mut finished_signers = HashSet::new();
         wait_for(timeout_secs, || {
@@ -305,6 +522,234 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         }).unwrap();
     }
 
+    /// Assert that every signer's status response reports the same current reward cycle as the
+    /// node. Call this once the signers have had a chance to process the latest burn block.
+    pub fn assert_reward_cycle_consensus(&mut self) {
+        let node_reward_cycle = self.get_current_reward_cycle();
+        let exclude = HashSet::new();
+        self.send_status_request(&exclude);
+        let mut states = vec![None; self.spawned_signers.len()];
+        wait_for(30, || {
+            for (ix, state) in self.get_states(&exclude).into_iter().enumerate() {
+                if state.is_some() {
+                    states[ix] = state;
+                }
+            }
+            Ok(states.iter().all(Option::is_some))
+        })
+        .expect("Timed out waiting for signer status responses");
+        assert_eq!(
+            self.get_current_reward_cycle(),
+            node_reward_cycle,
+            "The node's reward cycle changed while checking reward cycle consensus"
+        );
+
+        for (ix, state) in states.into_iter().enumerate() {
+            let signer_reward_cycle = state
+                .unwrap()
+                .reward_cycle_info
+                .map(|info| info.reward_cycle);
+            assert_eq!(
+                signer_reward_cycle,
+                Some(node_reward_cycle),
+                "Signer #{ix} disagrees with the node on the current reward cycle"
+            );
+        }
+    }
+
+    /// Tally the block rejections the signers of `reward_cycle` have written to their block
+    /// response slots over the run so far, keyed by the rejection's reason code.
+    /// Useful for checking that a test saw no unexpected rejections.
+    pub fn collect_all_rejections(&mut self, reward_cycle: u64) -> HashMap<String, usize> {
+        let contract_id = MessageSlotID::BlockResponse
+            .stacker_db_contract(self.running_nodes.conf.is_mainnet(), reward_cycle);
+        let mut rejections = HashMap::new();
+        for chunk in test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .filter(|event| event.contract_id == contract_id)
+            .flat_map(|event| event.modified_slots)
+        {
+            let Ok(SignerMessage::BlockResponse(BlockResponse::Rejected(rejection))) =
+                SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+            else {
+                continue;
+            };
+            *rejections
+                .entry(format!("{:?}", rejection.reason_code))
+                .or_insert(0) += 1;
+        }
+        rejections
+    }
+
+    /// Assert that, within `window`, no signer of `reward_cycle` writes a new message to its
+    /// `slot` StackerDB slot.
+    /// Useful for checking the absence of messages, which cannot be waited for.
+    pub fn assert_slot_empty_for(
+        &mut self,
+        slot: MessageSlotID,
+        reward_cycle: u64,
+        window: Duration,
+    ) {
+        let contract_id =
+            slot.stacker_db_contract(self.running_nodes.conf.is_mainnet(), reward_cycle);
+        let count_messages = || {
+            test_observer::get_stackerdb_chunks()
+                .into_iter()
+                .filter(|event| event.contract_id == contract_id)
+                .flat_map(|event| event.modified_slots)
+                .filter(|chunk| !chunk.data.is_empty())
+                .count()
+        };
+        let messages_before = count_messages();
+        let start = Instant::now();
+        loop {
+            let new_messages = count_messages().saturating_sub(messages_before);
+            assert_eq!(
+                new_messages, 0,
+                "Signers of reward cycle {reward_cycle} wrote {new_messages} new message(s) to their {slot:?} slots within {window:?}"
+            );
+            if start.elapsed() >= window {
+                return;
+            }
+            thread::sleep(Duration::from_millis(500));
+        }
+    }
+
+    /// Get the current version of the given signer's `slot` StackerDB slot in the current reward
+    /// cycle, as reported by the node.
+    /// Useful for checking that a (re-)broadcast bumped the slot's version.
+    pub fn slot_version(&mut self, slot: MessageSlotID, signer_slot_id: SignerSlotID) -> u32 {
+        let contract_id = slot.stacker_db_contract(
+            self.running_nodes.conf.is_mainnet(),
+            self.get_current_reward_cycle(),
+        );
+        let mut session =
+            StackerDBSession::new(&self.running_nodes.conf.node.rpc_bind, contract_id);
+        session
+            .list_chunks()
+            .expect("Failed to list StackerDB chunks")
+            .into_iter()
+            .find(|metadata| metadata.slot_id == signer_slot_id.0)
+            .unwrap_or_else(|| panic!("No StackerDB slot {signer_slot_id} for {slot}"))
+            .slot_version
+    }
+
+    /// Assert that, within `timeout`, every signer responds to the block proposal with
+    /// `signer_signature_hash` by writing either an acceptance or a rejection to its block
+    /// response slot.
+    /// The node's block validate responses do not say which signer submitted the proposal, so
+    /// a signer is only counted once its own signed block response is seen.
+    /// Useful in multi-node setups, to catch proposals that did not reach every signer.
+    pub fn assert_all_signers_saw_proposal(
+        &mut self,
+        signer_signature_hash: &Sha512Trunc256Sum,
+        timeout: Duration,
+    ) {
+        let signer_pubkeys: Vec<_> = self
+            .signer_stacks_private_keys
+            .iter()
+            .map(StacksPublicKey::from_private)
+            .collect();
+        let get_responders = || {
+            test_observer::get_stackerdb_chunks()
+                .into_iter()
+                .flat_map(|chunk| chunk.modified_slots)
+                .filter_map(|chunk| {
+                    let Ok(SignerMessage::BlockResponse(response)) =
+                        SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                    else {
+                        return None;
+                    };
+                    match response {
+                        BlockResponse::Accepted(accepted)
+                            if accepted.signer_signature_hash == *signer_signature_hash =>
+                        {
+                            signer_pubkeys
+                                .iter()
+                                .find(|pk| {
+                                    pk.verify(
+                                        accepted.signer_signature_hash.bits(),
+                                        &accepted.signature,
+                                    )
+                                    .unwrap_or(false)
+                                })
+                                .cloned()
+                        }
+                        BlockResponse::Rejected(rejection)
+                            if rejection.signer_signature_hash == *signer_signature_hash =>
+                        {
+                            rejection.recover_public_key().ok()
+                        }
+                        _ => None,
+                    }
+                })
+                .filter(|pk| signer_pubkeys.contains(pk))
+                .collect::<HashSet<_>>()
+        };
+        let result = wait_for(timeout.as_secs(), || {
+            Ok(get_responders().len() == signer_pubkeys.len())
+        });
+        if result.is_err() {
+            let responders = get_responders();
+            let missing: Vec<_> = signer_pubkeys
+                .iter()
+                .enumerate()
+                .filter(|(_, pk)| !responders.contains(pk))
+                .map(|(ix, _)| ix)
+                .collect();
+            panic!(
+                "Signers {missing:?} did not respond to block proposal {signer_signature_hash:?} within {timeout:?}"
+            );
+        }
+    }
+
+    /// Request the status of every signer not in `finished_signers`, and add those that are
+    /// registered and running a signer for `reward_cycle` to `finished_signers`.
+    /// Returns true once every signer has been added.
+    pub fn poll_signers_running_cycle(
+        &mut self,
+        reward_cycle: u64,
+        finished_signers: &mut HashSet<usize>,
+    ) -> bool {
+        self.send_status_request(finished_signers);
+        thread::sleep(Duration::from_secs(1));
+        let latest_states = self.get_states(finished_signers);
+        for (ix, state) in latest_states.iter().enumerate() {
+            let Some(state) = state else {
+                continue;
+            };
+            if state.runloop_state == State::RegisteredSigners
+                && state.running_signers.contains(&reward_cycle)
+            {
+                finished_signers.insert(ix);
+            } else {
+                debug!(
+                    "Signer #{ix} returned state = {:?}, running signers = {:?}. Will wait for it to run reward cycle {reward_cycle}",
+                    state.runloop_state, state.running_signers
+                );
+            }
+        }
+        finished_signers.len() == self.spawned_signers.len()
+    }
+
+    /// Mine one burn block so that the signers refresh their state, then wait until every signer
+    /// is registered and running a signer for `reward_cycle`.
+    /// Panics if the signers have not caught up within `timeout`.
+    pub fn advance_and_wait_for_signers_cycle(&mut self, reward_cycle: u64, timeout: Duration) {
+        next_block_and_wait(
+            &mut self.running_nodes.btc_regtest_controller,
+            &self.running_nodes.blocks_processed,
+        );
+        let start = Instant::now();
+        let mut finished_signers = HashSet::new();
+        while !self.poll_signers_running_cycle(reward_cycle, &mut finished_signers) {
+            assert!(
+                start.elapsed() < timeout,
+                "Timed out waiting for the signers to run reward cycle {reward_cycle}. Finished signers: {finished_signers:?}"
+            );
+        }
+    }
+
     /// Get status check results (if returned) from each signer without blocking
     /// Returns Some() or None() for each signer, in order of `self.spawned_signers`
     pub fn get_states(&mut self, exclude: &HashSet<usize>) -> Vec<Option<StateInfo>> {
@@ -333,6 +778,24 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         output
     }
 
+    /// Request the status of the signer at `signer_ix` and return the height of the last burn
+    /// block it processed, or 0 if it has processed none yet.
+    pub fn signer_burn_height(&mut self, signer_ix: usize) -> u64 {
+        let exclude: HashSet<usize> = (0..self.spawned_signers.len())
+            .filter(|ix| *ix != signer_ix)
+            .collect();
+        self.send_status_request(&exclude);
+        let mut state = None;
+        wait_for(30, || {
+            state = self.get_states(&exclude).swap_remove(signer_ix);
+            Ok(state.is_some())
+        })
+        .expect("Timed out waiting for the signer's status response");
+        state
+            .and_then(|state| state.last_processed_burn_block_height)
+            .unwrap_or(0)
+    }
+
     /// Mine a BTC block and wait for a new Stacks block to be mined
     /// Note: do not use nakamoto blocks mined heuristic if running a test with multiple miners
     fn mine_nakamoto_block(&mut self, timeout: Duration, use_nakamoto_blocks_mined: bool) {
@@ -406,6 +869,100 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         .expect("Timed out waiting for nakamoto block to be mined");
     }
 
+    /// Sleep for `window` and assert that the miner did not mine a nakamoto block in that time.
+    pub fn assert_no_block_mined_for(&self, window: Duration) {
+        let blocks_before = self.running_nodes.nakamoto_blocks_mined.get();
+        thread::sleep(window);
+        let blocks_after = self.running_nodes.nakamoto_blocks_mined.get();
+        assert_eq!(
+            blocks_after,
+            blocks_before,
+            "Expected no nakamoto block to be mined within {window:?}, but {} were mined",
+            blocks_after.saturating_sub(blocks_before)
+        );
+    }
+
+    /// Submit a raw transaction to the node.  Returns its txid if the node's mempool accepted it,
+    /// or the typed reason the mempool rejected it.
+    pub fn submit_tx_checked(&mut self, raw_tx: &[u8]) -> Result<Txid, MempoolRejection> {
+        let http_origin = format!("http://{}", &self.running_nodes.conf.node.rpc_bind);
+        let response = reqwest::blocking::Client::new()
+            .post(format!("{http_origin}/v2/transactions"))
+            .header("Content-Type", "application/octet-stream")
+            .body(raw_tx.to_vec())
+            .send()
+            .expect("Failed to submit transaction");
+        let status = response.status();
+        let body = response.text().expect("Failed to read response body");
+        if !status.is_success() {
+            return Err(MempoolRejection::from_response(status.as_u16(), body));
+        }
+        let txid: Txid = serde_json::from_str(&body)
+            .unwrap_or_else(|e| panic!("Node returned an invalid txid {body:?}: {e}"));
+        Ok(txid)
+    }
+
+    /// Submit `count` STX transfers of `amount` from `sender_sk` to `recipient`, using sequential
+    /// nonces starting at the sender's current account nonce. Returns the txids in nonce order.
+    pub fn submit_transfers(
+        &mut self,
+        sender_sk: &StacksPrivateKey,
+        count: u64,
+        fee: u64,
+        amount: u64,
+        recipient: &PrincipalData,
+    ) -> Vec<Txid> {
+        let http_origin = format!("http://{}", &self.running_nodes.conf.node.rpc_bind);
+        let start_nonce = get_account(&http_origin, &to_addr(sender_sk)).nonce;
+        (start_nonce..start_nonce + count)
+            .map(|nonce| {
+                let transfer_tx = make_stacks_transfer(
+                    sender_sk,
+                    nonce,
+                    fee,
+                    self.running_nodes.conf.burnchain.chain_id,
+                    recipient,
+                    amount,
+                );
+                let txid = submit_tx(&http_origin, &transfer_tx);
+                Txid::from_hex(&txid).expect("Node returned an invalid txid")
+            })
+            .collect()
+    }
+
+    /// Wait for the transaction `txid` to be included in a block reported to the test observer.
+    /// Returns the height of the block that included it.
+    pub fn wait_for_tx_in_block(&self, txid: &Txid, timeout_secs: u64) -> u64 {
+        let txid_str = format!("0x{txid}");
+        let mut block_height = None;
+        wait_for(timeout_secs, || {
+            block_height = test_observer::get_blocks().into_iter().find_map(|block| {
+                let transactions = block.get("transactions")?.as_array()?;
+                transactions
+                    .iter()
+                    .any(|tx| tx.get("txid").and_then(|t| t.as_str()) == Some(&txid_str))
+                    .then(|| block.get("block_height").and_then(|h| h.as_u64()))
+                    .flatten()
+            });
+            Ok(block_height.is_some())
+        })
+        .unwrap_or_else(|_| panic!("Timed out waiting for tx {txid} to be mined"));
+        block_height.unwrap()
+    }
+
+    /// Get the Nakamoto blocks reported to the test observer whose heights are within
+    /// `from_height..=to_height`, in the order they were mined.
+    pub fn mined_blocks_between(
+        &self,
+        from_height: u64,
+        to_height: u64,
+    ) -> Vec<MinedNakamotoBlockEvent> {
+        test_observer::get_mined_nakamoto_blocks()
+            .into_iter()
+            .filter(|block| (from_height..=to_height).contains(&block.stacks_height))
+            .collect()
+    }
+
     /// Wait for a confirmed block and return a list of individual
     /// signer signatures
     fn wait_for_confirmed_block_v0(
@@ -456,6 +1013,91 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         panic!("Timed out while waiting for confirmation of block with signer sighash = {block_signer_sighash}")
     }
 
+    /// Mine a BTC block, wait for the resulting Stacks block to be processed and signed by every
+    /// signer, and return when each step of the block's lifecycle was first seen.
+    /// Events are polled from the test observer, so their times are accurate to within the
+    /// polling interval.
+    pub fn capture_block_timeline(&mut self, timeout: Duration) -> BlockTimeline {
+        let stop = Arc::new(AtomicBool::new(false));
+        let timed_events = Arc::new(Mutex::new(vec![]));
+        let poller = {
+            let stop = stop.clone();
+            let timed_events = timed_events.clone();
+            thread::spawn(move || {
+                let mut seen = HashSet::new();
+                while !stop.load(Ordering::SeqCst) {
+                    let now = Instant::now();
+                    for event in observed_block_timeline_events() {
+                        if seen.insert(event.clone()) {
+                            timed_events.lock().unwrap().push((now, event));
+                        }
+                    }
+                    thread::sleep(Duration::from_millis(100));
+                }
+            })
+        };
+
+        self.mine_nakamoto_block(timeout, true);
+        let tip = self.get_peer_info().stacks_tip;
+        let signer_signature_hash = self
+            .get_miner_proposal_messages()
+            .into_iter()
+            .find(|proposal| proposal.block.header.block_hash() == tip)
+            .expect("Failed to find the proposal for the mined block")
+            .block
+            .header
+            .signer_signature_hash();
+
+        let num_signers = self.spawned_signers.len();
+        wait_for(timeout.as_secs(), || {
+            let timed_events = timed_events.lock().unwrap();
+            let finalized = timed_events.iter().any(|(_, event)| {
+                *event == BlockTimelineEvent::Finalized(signer_signature_hash)
+            });
+            let num_responses = timed_events
+                .iter()
+                .filter(|(_, event)| {
+                    matches!(event, BlockTimelineEvent::Response(sighash, _) if *sighash == signer_signature_hash)
+                })
+                .count();
+            Ok(finalized && num_responses >= num_signers)
+        })
+        .expect("Timed out waiting for every signer to respond to the block");
+        stop.store(true, Ordering::SeqCst);
+        poller.join().expect("Block timeline poller panicked");
+
+        let mut proposal_posted = None;
+        let mut validate_ok = vec![];
+        let mut responses = HashMap::new();
+        let mut finalized = None;
+        for (time, event) in timed_events.lock().unwrap().iter() {
+            match event {
+                BlockTimelineEvent::Proposal(sighash) if *sighash == signer_signature_hash => {
+                    proposal_posted.get_or_insert(*time);
+                }
+                BlockTimelineEvent::ValidateOk(sighash, _) if *sighash == signer_signature_hash => {
+                    validate_ok.push(*time);
+                }
+                BlockTimelineEvent::Response(sighash, slot_id)
+                    if *sighash == signer_signature_hash =>
+                {
+                    responses.insert(*slot_id, *time);
+                }
+                BlockTimelineEvent::Finalized(sighash) if *sighash == signer_signature_hash => {
+                    finalized.get_or_insert(*time);
+                }
+                _ => {}
+            }
+        }
+        BlockTimeline {
+            signer_signature_hash,
+            proposal_posted: proposal_posted.expect("Block proposal was not observed"),
+            validate_ok,
+            responses,
+            finalized: finalized.expect("Block finalization was not observed"),
+        }
+    }
+
     fn wait_for_validate_ok_response(&mut self, timeout: Duration) -> BlockValidateOk {
         // Wait for the block to show up in the test observer
         let t_start = Instant::now();
@@ -579,6 +1221,50 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         entries.signer_pks
     }
 
+    /// Skew the clock of the signer in the given slot forward by `offset`.
+    /// The signer uses the skewed clock when checking miner and tenure timeouts.
+    pub fn set_signer_clock_offset(&mut self, slot_id: u32, offset: Duration) {
+        let reward_cycle = self.get_current_reward_cycle();
+        let signer_pk = *self
+            .get_signer_public_keys(reward_cycle)
+            .get(slot_id as usize)
+            .unwrap_or_else(|| panic!("No signer in slot #{slot_id}"));
+        let mut offsets = TEST_SIGNER_CLOCK_OFFSET.get();
+        offsets.insert(signer_pk, offset);
+        TEST_SIGNER_CLOCK_OFFSET.set(offsets);
+    }
+
+    /// Get the public keys of the miners that the signer in the given slot currently considers
+    /// valid, as reported in its status response
+    pub fn valid_miners(&mut self, slot_id: u32) -> Vec<StacksPublicKey> {
+        let reward_cycle = self.get_current_reward_cycle();
+        let signer_pk = *self
+            .get_signer_public_keys(reward_cycle)
+            .get(slot_id as usize)
+            .unwrap_or_else(|| panic!("No signer in slot #{slot_id}"));
+        let signer_ix = self
+            .signer_stacks_private_keys
+            .iter()
+            .position(|sk| StacksPublicKey::from_private(sk) == signer_pk)
+            .unwrap_or_else(|| panic!("Signer in slot #{slot_id} is not a spawned signer"));
+        let exclude: HashSet<_> = (0..self.spawned_signers.len())
+            .filter(|ix| *ix != signer_ix)
+            .collect();
+
+        let mut valid_miners = None;
+        wait_for(30, || {
+            self.send_status_request(&exclude);
+            thread::sleep(Duration::from_secs(1));
+            valid_miners = self
+                .get_states(&exclude)
+                .swap_remove(signer_ix)
+                .map(|state| state.valid_miners);
+            Ok(valid_miners.is_some())
+        })
+        .unwrap_or_else(|_| panic!("Timed out waiting for a status response from slot #{slot_id}"));
+        valid_miners.unwrap()
+    }
+
     /// Get the signers for the given reward cycle
     pub fn get_reward_set_signers(&self, reward_cycle: u64) -> Vec<NakamotoSignerEntry> {
         self.stacks_client
@@ -587,20 +1273,101 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
             .unwrap()
     }
 
+    /// Get the signing weight that the node requires to finalize a block signed by `signers`
+    pub fn threshold_weight_of(signers: &[NakamotoSignerEntry]) -> u64 {
+        let total_weight: u64 = signers.iter().map(|signer| u64::from(signer.weight)).sum();
+        let total_weight =
+            u32::try_from(total_weight).expect("Total signer weight does not fit in a u32");
+        NakamotoBlockHeader::compute_voting_weight_threshold(total_weight)
+            .expect("Failed to compute the signing weight threshold")
+            .into()
+    }
+
+    /// Get the signing weight that the node requires to finalize a block in `reward_cycle`
+    pub fn threshold_weight(&self, reward_cycle: u64) -> u64 {
+        Self::threshold_weight_of(&self.get_reward_set_signers(reward_cycle))
+    }
+
+    /// Assert that burn height `height` falls within `reward_cycle` on `burnchain`, naming the
+    /// cycle's range of burn heights if it does not.  A cycle runs from its modulo 0 block up to
+    /// (but excluding) the next cycle's modulo 0 block.
+    pub fn assert_height_in_burnchain_cycle(burnchain: &Burnchain, height: u64, reward_cycle: u64) {
+        let cycle_start = burnchain.nakamoto_first_block_of_cycle(reward_cycle);
+        let cycle_end = burnchain.nakamoto_first_block_of_cycle(reward_cycle.saturating_add(1));
+        assert!(
+            (cycle_start..cycle_end).contains(&height),
+            "Burn height {height} is not in reward cycle {reward_cycle}, which spans burn heights {cycle_start}..{cycle_end}"
+        );
+        assert_eq!(
+            burnchain.block_height_to_reward_cycle(height),
+            Some(reward_cycle),
+            "Burn height {height} is in the range of reward cycle {reward_cycle}, but is not mapped to it"
+        );
+    }
+
+    /// Assert that burn height `height` falls within `reward_cycle`
+    pub fn assert_height_in_cycle(&self, height: u64, reward_cycle: u64) {
+        Self::assert_height_in_burnchain_cycle(
+            &self.running_nodes.btc_regtest_controller.get_burnchain(),
+            height,
+            reward_cycle,
+        );
+    }
+
+    /// Assert that each signer in the reward set for `reward_cycle` contributed at most one of
+    /// `signatures` over `signer_signature_hash`
+    pub fn assert_no_duplicate_signers(
+        &self,
+        signer_signature_hash: &Sha512Trunc256Sum,
+        signatures: &[MessageSignature],
+        reward_cycle: u64,
+    ) {
+        let signers = self.get_reward_set_signers(reward_cycle);
+        let mut signed_by = HashMap::new();
+        for (signature_index, signature) in signatures.iter().enumerate() {
+            let public_key =
+                StacksPublicKey::recover_to_pubkey(signer_signature_hash.bits(), signature)
+                    .expect("Failed to recover public key from signature");
+            let Some(signer_index) = signers.iter().position(|signer| {
+                signer.signing_key.as_slice() == public_key.to_bytes_compressed().as_slice()
+            }) else {
+                continue;
+            };
+            if let Some(prior_index) = signed_by.insert(signer_index, signature_index) {
+                panic!(
+                    "Signer #{signer_index} ({}) contributed both signature #{prior_index} and signature #{signature_index} to block {signer_signature_hash}",
+                    public_key.to_hex()
+                );
+            }
+        }
+    }
+
     #[allow(dead_code)]
     fn get_signer_metrics(&self) -> String {
+        self.get_signer_metrics_at(0)
+    }
+
+    /// Get the metrics served by the signer at `index`
+    #[allow(dead_code)]
+    fn get_signer_metrics_at(&self, index: usize) -> String {
         #[cfg(feature = "monitoring_prom")]
         {
+            let metrics_endpoint = self.signer_configs[index]
+                .metrics_endpoint
+                .expect("Signer does not serve metrics");
             let client = reqwest::blocking::Client::new();
             client
-                .get("http://localhost:9000/metrics")
+                .get(format!("http://{metrics_endpoint}/metrics"))
                 .send()
                 .unwrap()
                 .text()
                 .unwrap()
         }
         #[cfg(not(feature = "monitoring_prom"))]
-        String::new()
+        {
+            let _ = index;
+            String::new()
+        }
     }
 
     pub fn shutdown(self) {
@@ -656,6 +1423,131 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
         })
     }
 
+    /// Wait until at least `min_count` signers have sent valid signatures over the block with
+    /// `signer_signature_hash`, and return one signature per signer.  A signature is valid if it
+    /// was made by one of this test's signers.  Errors if fewer signatures arrive before `timeout`.
+    pub fn wait_for_signature_count(
+        &self,
+        signer_signature_hash: &Sha512Trunc256Sum,
+        min_count: usize,
+        timeout: Duration,
+    ) -> Result<Vec<MessageSignature>, String> {
+        let signer_pubkeys: Vec<_> = self
+            .signer_stacks_private_keys
+            .iter()
+            .map(StacksPublicKey::from_private)
+            .collect();
+        let mut signatures = HashMap::new();
+        wait_for(timeout.as_secs(), || {
+            for chunk in test_observer::get_stackerdb_chunks()
+                .into_iter()
+                .flat_map(|chunk| chunk.modified_slots)
+            {
+                let Ok(SignerMessage::BlockResponse(BlockResponse::Accepted(accepted))) =
+                    SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                else {
+                    continue;
+                };
+                if accepted.signer_signature_hash != *signer_signature_hash {
+                    continue;
+                }
+                let Some(signer_pubkey) = signer_pubkeys.iter().find(|pk| {
+                    pk.verify(signer_signature_hash.bits(), &accepted.signature)
+                        .unwrap_or(false)
+                }) else {
+                    continue;
+                };
+                signatures.insert(*signer_pubkey, accepted.signature);
+            }
+            Ok(signatures.len() >= min_count)
+        })
+        .map_err(|e| {
+            format!(
+                "Collected {} of {min_count} signatures for block {signer_signature_hash:?}: {e}",
+                signatures.len()
+            )
+        })?;
+        Ok(signatures.into_values().collect())
+    }
+
+    /// Assert that exactly `num_signers` distinct signers mock signed each burn block from
+    /// `from_height` through `to_height`, waiting up to `timeout` for the signatures to arrive.
+    /// Mock signatures are read from the miner's mock blocks and, unless `mock_blocks_only` is
+    /// set, from the signers' own messages.  Each burn block's signatures are checked against the
+    /// reward set of the reward cycle it falls in, so the range may span reward cycle rollovers.
+    pub fn assert_mock_signatures_for_range(
+        &mut self,
+        from_height: u64,
+        to_height: u64,
+        num_signers: usize,
+        mock_blocks_only: bool,
+        timeout: Duration,
+    ) {
+        assert!(
+            from_height <= to_height,
+            "Invalid burn block range {from_height}..={to_height}"
+        );
+        let burnchain = self.running_nodes.btc_regtest_controller.get_burnchain();
+        let mut signer_pubkeys_by_cycle = HashMap::new();
+        for height in from_height..=to_height {
+            let reward_cycle = burnchain
+                .block_height_to_reward_cycle(height)
+                .expect("Burn height is before the first reward cycle");
+            signer_pubkeys_by_cycle
+                .entry(reward_cycle)
+                .or_insert_with(|| self.get_signer_public_keys(reward_cycle));
+        }
+
+        let mut signers_by_height: HashMap<u64, HashSet<StacksPublicKey>> = HashMap::new();
+        let result = wait_for(timeout.as_secs(), || {
+            for chunk in test_observer::get_stackerdb_chunks()
+                .into_iter()
+                .flat_map(|chunk| chunk.modified_slots)
+            {
+                let mock_signatures =
+                    match SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()) {
+                        Ok(SignerMessage::MockSignature(mock_signature)) if !mock_blocks_only => {
+                            vec![mock_signature]
+                        }
+                        Ok(SignerMessage::MockBlock(mock_block)) => mock_block.mock_signatures,
+                        _ => continue,
+                    };
+                for mock_signature in mock_signatures {
+                    let height = mock_signature.mock_proposal.peer_info.burn_block_height;
+                    if !(from_height..=to_height).contains(&height) {
+                        continue;
+                    }
+                    let reward_cycle = burnchain
+                        .block_height_to_reward_cycle(height)
+                        .expect("Burn height is before the first reward cycle");
+                    let Some(signer_pubkey) = signer_pubkeys_by_cycle[&reward_cycle]
+                        .iter()
+                        .find(|pk| mock_signature.verify(pk).unwrap_or(false))
+                    else {
+                        continue;
+                    };
+                    signers_by_height
+                        .entry(height)
+                        .or_default()
+                        .insert(*signer_pubkey);
+                }
+            }
+            Ok((from_height..=to_height).all(|height| {
+                signers_by_height
+                    .get(&height)
+                    .is_some_and(|signers| signers.len() >= num_signers)
+            }))
+        });
+
+        for height in from_height..=to_height {
+            let num_mock_signers = signers_by_height.get(&height).map_or(0, HashSet::len);
+            assert_eq!(
+                num_mock_signers, num_signers,
+                "Expected {num_signers} signers to mock sign burn block {height}, but {num_mock_signers} did ({result:?})"
+            );
+        }
+    }
+
     pub fn wait_for_block_rejections(
         &self,
         timeout_secs: u64,
@@ -828,6 +1720,27 @@ impl<S: Signer<T> + Send + 'static, T: SignerEventTrait + 'static> SignerTest<Sp
     }
 }
 
+/// Move the given epochs to start at the given burn block heights.
+/// Each epoch's predecessor is ended at the same height so the epoch list stays contiguous.
+pub fn set_epoch_boundaries(naka_conf: &mut NeonConfig, boundaries: &[(StacksEpochId, u64)]) {
+    let epochs = naka_conf
+        .burnchain
+        .epochs
+        .as_mut()
+        .expect("Expected epochs to be set");
+    for (epoch_id, start_height) in boundaries {
+        epochs[*epoch_id].start_height = *start_height;
+        let prior_epoch_id = epochs
+            .iter()
+            .map(|epoch| epoch.epoch_id)
+            .filter(|prior_epoch_id| prior_epoch_id < epoch_id)
+            .max();
+        if let Some(prior_epoch_id) = prior_epoch_id {
+            epochs[prior_epoch_id].end_height = *start_height;
+        }
+    }
+}
+
 fn setup_stx_btc_node<G: FnMut(&mut NeonConfig)>(
     mut naka_conf: NeonConfig,
     signer_stacks_private_keys: &[StacksPrivateKey],
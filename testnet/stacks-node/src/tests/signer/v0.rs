@@ -14,29 +14,31 @@
 // along with this program.  If not, see <http://www.gnu.org/licenses/>.
 
 use std::cmp::min;
-use std::collections::{HashMap, HashSet};
+use std::collections::{BTreeMap, HashMap, HashSet};
 use std::ops::Add;
 use std::str::FromStr;
 use std::sync::atomic::Ordering;
 use std::time::{Duration, Instant};
 use std::{env, thread};
 
+use clarity::vm::costs::ExecutionCost;
 use clarity::vm::types::PrincipalData;
 use libsigner::v0::messages::{
     BlockAccepted, BlockRejection, BlockResponse, MessageSlotID, MinerSlotID, RejectCode,
     SignerMessage,
 };
 use libsigner::{
-    BlockProposal, BlockProposalData, SignerSession, StackerDBSession, VERSION_STRING,
+    BlockProposal, BlockProposalData, ReevaluateBlockRequest, SignerSession, StackerDBSession,
+    VERSION_STRING,
 };
 use serde::Deserialize;
 use stacks::address::AddressHashMode;
-use stacks::burnchains::Txid;
+use stacks::burnchains::{Burnchain, Txid};
 use stacks::chainstate::burn::db::sortdb::SortitionDB;
 use stacks::chainstate::burn::operations::LeaderBlockCommitOp;
 use stacks::chainstate::nakamoto::{NakamotoBlock, NakamotoBlockHeader, NakamotoChainState};
 use stacks::chainstate::stacks::address::PoxAddress;
-use stacks::chainstate::stacks::boot::MINERS_NAME;
+use stacks::chainstate::stacks::boot::{NakamotoSignerEntry, MINERS_NAME};
 use stacks::chainstate::stacks::db::{StacksBlockHeaderTypes, StacksChainState, StacksHeaderInfo};
 use stacks::chainstate::stacks::miner::{TransactionEvent, TransactionSuccessEvent};
 use stacks::chainstate::stacks::{StacksTransaction, TenureChangeCause, TransactionPayload};
@@ -44,17 +46,20 @@ use stacks::codec::StacksMessageCodec;
 use stacks::config::{EventKeyType, EventObserverConfig};
 use stacks::core::{StacksEpochId, CHAIN_ID_TESTNET};
 use stacks::libstackerdb::StackerDBChunkData;
+use stacks::net::api::getrewardsetsigners::parse_next_page;
 use stacks::net::api::getsigner::GetSignerResponse;
 use stacks::net::api::postblock_proposal::{
     BlockValidateResponse, ValidateRejectCode, TEST_VALIDATE_DELAY_DURATION_SECS,
     TEST_VALIDATE_STALL,
 };
 use stacks::net::relay::fault_injection::set_ignore_block;
-use stacks::types::chainstate::{StacksAddress, StacksBlockId, StacksPrivateKey, StacksPublicKey};
+use stacks::types::chainstate::{
+    ConsensusHash, StacksAddress, StacksBlockId, StacksPrivateKey, StacksPublicKey,
+};
 use stacks::types::PublicKey;
 use stacks::util::get_epoch_time_secs;
 use stacks::util::hash::{hex_bytes, Hash160, MerkleHashFunc, Sha512Trunc256Sum};
-use stacks::util::secp256k1::{Secp256k1PrivateKey, Secp256k1PublicKey};
+use stacks::util::secp256k1::{MessageSignature, Secp256k1PrivateKey, Secp256k1PublicKey};
 use stacks::util_lib::boot::boot_code_id;
 use stacks::util_lib::signed_structured_data::pox4::{
     make_pox_4_signer_key_signature, Pox4SignatureTopic,
@@ -62,19 +67,24 @@ use stacks::util_lib::signed_structured_data::pox4::{
 use stacks_common::bitvec::BitVec;
 use stacks_common::types::chainstate::TrieHash;
 use stacks_common::util::sleep_ms;
-use stacks_signer::chainstate::{ProposalEvalConfig, SortitionsView};
+use stacks_signer::chainstate::{
+    BlockKind, BlockKindMask, ProposalEvalConfig, SignerClock, SortitionsView,
+};
 use stacks_signer::client::{SignerSlotID, StackerDB};
 use stacks_signer::config::{build_signer_config_tomls, GlobalConfig as SignerConfig, Network};
+use stacks_signer::runloop::State;
 use stacks_signer::signerdb::SignerDb;
+use stacks_signer::utils::verify_threshold_signatures;
 use stacks_signer::v0::tests::{
-    TEST_IGNORE_ALL_BLOCK_PROPOSALS, TEST_PAUSE_BLOCK_BROADCAST, TEST_REJECT_ALL_BLOCK_PROPOSAL,
+    TEST_FAIL_BLOCK_VALIDATION_SUBMISSION, TEST_IGNORE_ALL_BLOCK_PROPOSALS, TEST_NODE_BURN_LAG,
+    TEST_PAUSE_BLOCK_BROADCAST, TEST_REJECT_ALL_BLOCK_PROPOSAL, TEST_SIGNER_CLOCK_OFFSET,
     TEST_SKIP_BLOCK_BROADCAST, TEST_SKIP_SIGNER_CLEANUP, TEST_STALL_BLOCK_VALIDATION_SUBMISSION,
 };
 use stacks_signer::v0::SpawnedSigner;
 use tracing_subscriber::prelude::*;
 use tracing_subscriber::{fmt, EnvFilter};
 
-use super::SignerTest;
+use super::{set_epoch_boundaries, MempoolRejection, SignerTest};
 use crate::event_dispatcher::{MinedNakamotoBlockEvent, TEST_SKIP_BLOCK_ANNOUNCEMENT};
 use crate::nakamoto_node::miner::{
     TEST_BLOCK_ANNOUNCE_STALL, TEST_BROADCAST_PROPOSAL_STALL, TEST_MINE_STALL,
@@ -227,11 +237,7 @@ impl SignerTest<SpawnedSigner> {
         // Manually consume one more block to ensure signers refresh their state
         debug!("Waiting for signers to initialize.");
         info!("Advancing to the first full Epoch 2.5 reward cycle boundary...");
-        next_block_and_wait(
-            &mut self.running_nodes.btc_regtest_controller,
-            &self.running_nodes.blocks_processed,
-        );
-        self.wait_for_registered(30);
+        self.advance_and_wait_for_signers_cycle(reward_cycle, Duration::from_secs(60));
         debug!("Signers initialized");
 
         let current_burn_block_height = self
@@ -243,6 +249,24 @@ impl SignerTest<SpawnedSigner> {
 
     /// Run the test until the epoch 3 boundary
     pub fn boot_to_epoch_3(&mut self) {
+        self.boot_to_epoch_3_boundary();
+
+        // Wait until we see the first block of epoch 3.0.
+        // Note, we don't use `nakamoto_blocks_mined` counter, because there
+        // could be other miners mining blocks.
+        let height_before = get_chain_info(&self.running_nodes.conf).stacks_tip_height;
+        info!("Waiting for first Nakamoto block: {}", height_before + 1);
+        self.mine_nakamoto_block(Duration::from_secs(30), false);
+        wait_for(30, || {
+            Ok(get_chain_info(&self.running_nodes.conf).stacks_tip_height > height_before)
+        })
+        .expect("Timed out waiting for first Nakamoto block after 3.0 boundary");
+        info!("Ready to mine Nakamoto blocks!");
+    }
+
+    /// Run the test until the burn block before the epoch 3.0 boundary, with the signers
+    /// registered and the network ready to mine the first Nakamoto tenure.
+    pub fn boot_to_epoch_3_boundary(&mut self) {
         boot_to_epoch_3_reward_set(
             &self.running_nodes.conf,
             &self.running_nodes.blocks_processed,
@@ -270,11 +294,7 @@ impl SignerTest<SpawnedSigner> {
 
         // Manually consume one more block to ensure signers refresh their state
         info!("Waiting for signers to initialize.");
-        next_block_and_wait(
-            &mut self.running_nodes.btc_regtest_controller,
-            &self.running_nodes.blocks_processed,
-        );
-        self.wait_for_registered(30);
+        self.advance_and_wait_for_signers_cycle(reward_cycle, Duration::from_secs(60));
         info!("Signers initialized");
 
         self.run_until_epoch_3_boundary();
@@ -282,18 +302,6 @@ impl SignerTest<SpawnedSigner> {
             Ok(get_chain_info_opt(&self.running_nodes.conf).is_some())
         })
         .expect("Timed out waiting for network to restart after 3.0 boundary reached");
-
-        // Wait until we see the first block of epoch 3.0.
-        // Note, we don't use `nakamoto_blocks_mined` counter, because there
-        // could be other miners mining blocks.
-        let height_before = get_chain_info(&self.running_nodes.conf).stacks_tip_height;
-        info!("Waiting for first Nakamoto block: {}", height_before + 1);
-        self.mine_nakamoto_block(Duration::from_secs(30), false);
-        wait_for(30, || {
-            Ok(get_chain_info(&self.running_nodes.conf).stacks_tip_height > height_before)
-        })
-        .expect("Timed out waiting for first Nakamoto block after 3.0 boundary");
-        info!("Ready to mine Nakamoto blocks!");
     }
 
     // Only call after already past the epoch 3.0 boundary
@@ -327,53 +335,16 @@ impl SignerTest<SpawnedSigner> {
         info!("Verifying signatures against signers for reward cycle {reward_cycle:?}");
         let signers = self.get_reward_set_signers(reward_cycle);
 
-        // Verify that the signers signed the proposed block
-        let mut signer_index = 0;
-        let mut signature_index = 0;
-        let mut signing_keys = HashSet::new();
-        let start = Instant::now();
+        let total_weight = signers.iter().map(|signer| signer.weight).sum();
+        let threshold_weight = NakamotoBlockHeader::compute_voting_weight_threshold(total_weight)
+            .expect("Failed to compute voting weight threshold");
         debug!(
             "Validating {} signatures against {num_signers} signers",
             signature.len()
         );
-        let validated = loop {
-            // Since we've already checked `signature.len()`, this means we've
-            //  validated all the signatures in this loop
-            let Some(signature) = signature.get(signature_index) else {
-                break true;
-            };
-            let Some(signer) = signers.get(signer_index) else {
-                error!("Failed to validate the mined nakamoto block: ran out of signers to try to validate signatures");
-                break false;
-            };
-            if !signing_keys.insert(signer.signing_key) {
-                panic!("Duplicate signing key detected: {:?}", signer.signing_key);
-            }
-            let stacks_public_key = Secp256k1PublicKey::from_slice(signer.signing_key.as_slice())
-                .expect("Failed to convert signing key to StacksPublicKey");
-            let valid = stacks_public_key
-                .verify(&message, signature)
-                .expect("Failed to verify signature");
-            if !valid {
-                info!(
-                    "Failed to verify signature for signer, will attempt to validate without this signer";
-                    "signer_pk" => stacks_public_key.to_hex(),
-                    "signer_index" => signer_index,
-                    "signature_index" => signature_index,
-                );
-                signer_index += 1;
-            } else {
-                signer_index += 1;
-                signature_index += 1;
-            }
-            // Shouldn't really ever timeout, but do this in case there is some sort of overflow/underflow happening.
-            assert!(
-                start.elapsed() < timeout,
-                "Timed out waiting to confirm block signatures"
-            );
-        };
-
-        assert!(validated);
+        verify_threshold_signatures(&message, &signature, &signers, threshold_weight.into())
+            .expect("Failed to validate the mined nakamoto block's signatures");
+        self.assert_no_duplicate_signers(&proposed_signer_signature_hash, &signature, reward_cycle);
     }
 
     // Only call after already past the epoch 3.0 boundary
@@ -396,13 +367,20 @@ impl SignerTest<SpawnedSigner> {
 
     /// Propose a block to the signers
     fn propose_block(&mut self, block: NakamotoBlock, timeout: Duration) {
-        let miners_contract_id = boot_code_id(MINERS_NAME, false);
-        let mut session =
-            StackerDBSession::new(&self.running_nodes.conf.node.rpc_bind, miners_contract_id);
         let burn_height = self
             .running_nodes
             .btc_regtest_controller
             .get_headers_height();
+        self.propose_block_at_burn_height(block, burn_height, timeout);
+    }
+
+    /// Propose a block to the signers, as if the miner's burnchain view is at `burn_height`
+    fn propose_block_at_burn_height(
+        &mut self,
+        block: NakamotoBlock,
+        burn_height: u64,
+        timeout: Duration,
+    ) {
         let reward_cycle = self.get_current_reward_cycle();
         let signer_signature_hash = block.header.signer_signature_hash();
         let message = SignerMessage::BlockProposal(BlockProposal {
@@ -411,21 +389,33 @@ impl SignerTest<SpawnedSigner> {
             reward_cycle,
             block_proposal_data: BlockProposalData::empty(),
         });
+        debug!("Proposing block to signers: {signer_signature_hash}");
+        self.send_miner_message(message, MinerSlotID::BlockProposal, timeout);
+    }
+
+    /// Write a message to one of the miner's slots, as the miner
+    fn send_miner_message(
+        &mut self,
+        message: SignerMessage,
+        miner_slot_id: MinerSlotID,
+        timeout: Duration,
+    ) {
+        let miners_contract_id = boot_code_id(MINERS_NAME, false);
+        let mut session =
+            StackerDBSession::new(&self.running_nodes.conf.node.rpc_bind, miners_contract_id);
         let miner_sk = self
             .running_nodes
             .conf
             .miner
             .mining_key
             .expect("No mining key");
-        // Submit the block proposal to the miner's slot
+        // Submit the message to the miner's slot
         let mut accepted = false;
         let mut version = 0;
-        let slot_id = MinerSlotID::BlockProposal.to_u8() as u32;
+        let slot_id = miner_slot_id.to_u8() as u32;
         let start = Instant::now();
-        debug!("Proposing block to signers: {signer_signature_hash}");
         while !accepted {
-            let mut chunk =
-                StackerDBChunkData::new(slot_id * 2, version, message.serialize_to_vec());
+            let mut chunk = StackerDBChunkData::new(slot_id, version, message.serialize_to_vec());
             chunk.sign(&miner_sk).expect("Failed to sign message chunk");
             debug!("Produced a signature: {:?}", chunk.sig);
             let result = session.put_chunk(&chunk).expect("Failed to put chunk");
@@ -434,7 +424,233 @@ impl SignerTest<SpawnedSigner> {
             debug!("Test Put Chunk ACK: {result:?}");
             assert!(
                 start.elapsed() < timeout,
-                "Timed out waiting for block proposal to be accepted"
+                "Timed out waiting for miner message to be accepted"
+            );
+        }
+    }
+
+    /// Assert that the mined block `mined` was built directly atop `parent`, naming both blocks'
+    /// hashes and heights if it was not.
+    fn assert_parent_is(mined: &MinedNakamotoBlockEvent, parent: &StacksHeaderInfo) {
+        let parent_block_id = parent.index_block_hash().to_string();
+        assert_eq!(
+            mined.parent_block_id,
+            parent_block_id,
+            "Mined block {} at height {} has parent {}, but expected parent {} at height {}",
+            mined.block_id,
+            mined.stacks_height,
+            mined.parent_block_id,
+            parent_block_id,
+            parent.stacks_block_height
+        );
+    }
+
+    /// Assert that `tip` is not the block with `signer_signature_hash`, naming the tip's height
+    /// if it is.
+    fn assert_header_is_not(tip: &StacksHeaderInfo, signer_signature_hash: &Sha512Trunc256Sum) {
+        let tip_signer_signature_hash = tip
+            .anchored_header
+            .as_stacks_nakamoto()
+            .map(|header| header.signer_signature_hash());
+        assert_ne!(
+            tip_signer_signature_hash.as_ref(),
+            Some(signer_signature_hash),
+            "Block {signer_signature_hash} should not be the chain tip, but it is the tip at height {}",
+            tip.stacks_block_height
+        );
+    }
+
+    /// Assert that the block with `signer_signature_hash` is not the canonical chain tip
+    fn assert_block_not_tip(&self, signer_signature_hash: &Sha512Trunc256Sum) {
+        let conf = &self.running_nodes.conf;
+        let sortdb = conf.get_burnchain().open_sortition_db(true).unwrap();
+        let (chainstate, _) = StacksChainState::open(
+            conf.is_mainnet(),
+            conf.burnchain.chain_id,
+            &conf.get_chainstate_path_str(),
+            None,
+        )
+        .unwrap();
+        let tip = NakamotoChainState::get_canonical_block_header(chainstate.db(), &sortdb)
+            .unwrap()
+            .expect("No canonical chain tip");
+        Self::assert_header_is_not(&tip, signer_signature_hash);
+    }
+
+    /// Assert that exactly `tenures` tenures were mined above Stacks height `before_height`.
+    /// Tenures are counted by their distinct consensus hashes, so a tenure with several blocks
+    /// counts once.
+    fn assert_advanced_tenures(&self, before_height: u64, tenures: u64) {
+        let headers: Vec<_> = get_nakamoto_headers(&self.running_nodes.conf)
+            .into_iter()
+            .filter(|header| header.stacks_block_height > before_height)
+            .collect();
+        let tenure_consensus_hashes: HashSet<_> =
+            headers.iter().map(|header| header.consensus_hash).collect();
+        assert_eq!(
+            tenure_consensus_hashes.len() as u64,
+            tenures,
+            "Expected {tenures} tenures above height {before_height}, but found {} tenures across {} blocks",
+            tenure_consensus_hashes.len(),
+            headers.len()
+        );
+    }
+
+    /// Mine `count` tenures, one burn block each.  After each tenure, assert that the Stacks tip
+    /// advanced and, if `secondary_node_conf` is set, that the secondary node converged on the
+    /// same tip.  If `secondary_node_counters` is set, each burn block waits for both nodes'
+    /// block-commits.  Returns the header of the final canonical tip.
+    pub fn mine_tenures_and_assert_convergence(
+        &mut self,
+        count: u64,
+        timeout: Duration,
+    ) -> StacksHeaderInfo {
+        for tenure in 1..=count {
+            info!("------------------------- Mining tenure {tenure} of {count} -------------------------");
+            let height_before = get_chain_info(&self.running_nodes.conf).stacks_tip_height;
+            match (
+                self.secondary_node_conf.clone(),
+                self.secondary_node_counters.clone(),
+            ) {
+                (Some(secondary_conf), Some(secondary_counters)) => {
+                    let conf = self.running_nodes.conf.clone();
+                    let counters = self.running_nodes.counters.clone();
+                    self.mine_block_wait_on_processing(
+                        &[&conf, &secondary_conf],
+                        &[&counters, &secondary_counters],
+                        timeout,
+                    );
+                }
+                // the nakamoto blocks mined heuristic is only reliable with a single miner
+                _ => self.mine_nakamoto_block(timeout, self.secondary_node_conf.is_none()),
+            }
+            let info = get_chain_info(&self.running_nodes.conf);
+            assert!(
+                info.stacks_tip_height > height_before,
+                "Tenure {tenure} did not advance the Stacks tip past height {height_before}"
+            );
+            let Some(secondary_conf) = self.secondary_node_conf.as_ref() else {
+                continue;
+            };
+            wait_for(timeout.as_secs(), || {
+                let info = get_chain_info(&self.running_nodes.conf);
+                let Some(secondary_info) = get_chain_info_opt(secondary_conf) else {
+                    return Ok(false);
+                };
+                Ok(info.stacks_tip_height == secondary_info.stacks_tip_height
+                    && info.stacks_tip == secondary_info.stacks_tip
+                    && info.stacks_tip_consensus_hash == secondary_info.stacks_tip_consensus_hash)
+            })
+            .unwrap_or_else(|_| {
+                let info = get_chain_info(&self.running_nodes.conf);
+                let secondary_info = get_chain_info_opt(secondary_conf);
+                panic!(
+                    "Timed out waiting for the nodes to converge after tenure {tenure}: tip {} at height {}, secondary node tip {:?} at height {:?}",
+                    info.stacks_tip,
+                    info.stacks_tip_height,
+                    secondary_info.as_ref().map(|info| info.stacks_tip),
+                    secondary_info.as_ref().map(|info| info.stacks_tip_height)
+                )
+            });
+        }
+
+        let conf = &self.running_nodes.conf;
+        let sortdb = conf.get_burnchain().open_sortition_db(true).unwrap();
+        let (chainstate, _) = StacksChainState::open(
+            conf.is_mainnet(),
+            conf.burnchain.chain_id,
+            &conf.get_chainstate_path_str(),
+            None,
+        )
+        .unwrap();
+        let tip = NakamotoChainState::get_canonical_block_header(chainstate.db(), &sortdb)
+            .unwrap()
+            .expect("No canonical chain tip");
+        assert_eq!(
+            tip.stacks_block_height,
+            get_chain_info(conf).stacks_tip_height,
+            "Canonical tip does not match the node's reported Stacks tip"
+        );
+        tip
+    }
+
+    /// Assert that each Stacks height in the tenure with `tenure_consensus_hash` was finalized
+    /// exactly once: the test observer saw one block processed at each of the tenure's heights,
+    /// and it is the canonical chain's block at that height.
+    fn assert_unique_finalizations(&self, tenure_consensus_hash: &ConsensusHash) {
+        let finalized: Vec<_> = get_nakamoto_headers(&self.running_nodes.conf)
+            .into_iter()
+            .filter(|header| header.consensus_hash == *tenure_consensus_hash)
+            .collect();
+        let from_height = finalized
+            .iter()
+            .map(|header| header.stacks_block_height)
+            .min()
+            .unwrap_or_else(|| {
+                panic!("No blocks were finalized in tenure {tenure_consensus_hash}")
+            });
+        let canonical = self.canonical_block_ids_since(from_height);
+        Self::assert_finalized_once(tenure_consensus_hash, &finalized, &canonical);
+    }
+
+    /// Get the IDs of the canonical chain's blocks, keyed by Stacks height, from the canonical
+    /// tip back to `from_height`
+    fn canonical_block_ids_since(&self, from_height: u64) -> HashMap<u64, StacksBlockId> {
+        let conf = &self.running_nodes.conf;
+        let sortdb = conf.get_burnchain().open_sortition_db(true).unwrap();
+        let (chainstate, _) = StacksChainState::open(
+            conf.is_mainnet(),
+            conf.burnchain.chain_id,
+            &conf.get_chainstate_path_str(),
+            None,
+        )
+        .unwrap();
+        let mut header = NakamotoChainState::get_canonical_block_header(chainstate.db(), &sortdb)
+            .unwrap()
+            .expect("No canonical chain tip");
+        let mut canonical = HashMap::new();
+        while header.stacks_block_height >= from_height {
+            canonical.insert(header.stacks_block_height, header.index_block_hash());
+            let Some(parent_block_id) = header
+                .anchored_header
+                .as_stacks_nakamoto()
+                .map(|nakamoto_header| nakamoto_header.parent_block_id)
+            else {
+                break;
+            };
+            header = NakamotoChainState::get_block_header(chainstate.db(), &parent_block_id)
+                .unwrap()
+                .expect("Canonical block's parent is missing");
+        }
+        canonical
+    }
+
+    /// Assert that the `finalized` blocks of the tenure with `tenure_consensus_hash` include
+    /// exactly one block at each height, and that it is the `canonical` block at that height
+    fn assert_finalized_once(
+        tenure_consensus_hash: &ConsensusHash,
+        finalized: &[StacksHeaderInfo],
+        canonical: &HashMap<u64, StacksBlockId>,
+    ) {
+        let mut block_ids_by_height: BTreeMap<u64, HashSet<StacksBlockId>> = BTreeMap::new();
+        for header in finalized {
+            block_ids_by_height
+                .entry(header.stacks_block_height)
+                .or_default()
+                .insert(header.index_block_hash());
+        }
+        for (height, block_ids) in block_ids_by_height {
+            assert_eq!(
+                block_ids.len(),
+                1,
+                "Tenure {tenure_consensus_hash} finalized {} blocks at height {height}: {block_ids:?}",
+                block_ids.len()
+            );
+            let block_id = block_ids.into_iter().next().unwrap();
+            assert_eq!(
+                canonical.get(&height),
+                Some(&block_id),
+                "Tenure {tenure_consensus_hash} finalized block {block_id} at height {height}, but it is not on the canonical chain"
             );
         }
     }
@@ -501,6 +717,13 @@ fn block_proposal_rejection() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut block = NakamotoBlock {
         header: NakamotoBlockHeader::empty(),
@@ -518,8 +741,10 @@ fn block_proposal_rejection() {
 
     // Propose a block to the signers that passes initial checks but will be rejected by the stacks node
     let view = SortitionsView::fetch_view(proposal_conf, &signer_test.stacks_client).unwrap();
-    block.header.pox_treatment = BitVec::ones(1).unwrap();
-    block.header.consensus_hash = view.cur_sortition.consensus_hash;
+    block.header = block
+        .header
+        .with_pox_treatment(BitVec::ones(1).unwrap())
+        .with_consensus_hash(view.cur_sortition.consensus_hash);
     block.header.chain_length = 35; // We have mined 35 blocks so far.
 
     let block_signer_signature_hash_2 = block.header.signer_signature_hash();
@@ -579,6 +804,100 @@ fn block_proposal_rejection() {
     signer_test.shutdown();
 }
 
+#[test]
+#[ignore]
+/// Test that signers distinguish a block proposal that does not match their sortition view from
+/// one that was proposed from a stale burnchain view.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// Two blocks with an unknown consensus hash are forcibly written to the miner's slot: one at the
+/// current burn height, and one at the burn height before it.
+///
+/// Test Assertion:
+/// The first block is rejected with `RejectCode::SortitionViewMismatch`, and the second with
+/// `RejectCode::StaleView`.
+fn block_proposal_rejection_stale_view() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+    let short_timeout = Duration::from_secs(30);
+    let burn_height = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height();
+
+    info!("------------------------- Send Block Proposals To Signers -------------------------");
+    // Neither block has the consensus hash of a known sortition, so both fail the signers'
+    // sortition view checks before they are submitted to the node for validation.
+    let mut block = NakamotoBlock {
+        header: NakamotoBlockHeader::empty(),
+        txs: vec![],
+    };
+    block.header.timestamp = get_epoch_time_secs();
+    block.header = block.header.with_pox_treatment(BitVec::ones(1).unwrap());
+
+    block.header.chain_length = 1;
+    let mismatch_signer_signature_hash = block.header.signer_signature_hash();
+    signer_test.propose_block_at_burn_height(block.clone(), burn_height, short_timeout);
+
+    block.header.chain_length = 2;
+    let stale_signer_signature_hash = block.header.signer_signature_hash();
+    signer_test.propose_block_at_burn_height(block, burn_height - 1, short_timeout);
+
+    info!("------------------------- Test Block Proposals Rejected -------------------------");
+    let mut mismatch_rejections = HashSet::new();
+    let mut stale_rejections = HashSet::new();
+    wait_for(short_timeout.as_secs(), || {
+        for chunk in test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+        {
+            let Ok(message) = SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+            else {
+                continue;
+            };
+            let SignerMessage::BlockResponse(BlockResponse::Rejected(rejection)) = message else {
+                continue;
+            };
+            let Ok(signer_key) = rejection.recover_public_key() else {
+                continue;
+            };
+            if rejection.signer_signature_hash == mismatch_signer_signature_hash {
+                assert_eq!(
+                    rejection.reason_code,
+                    RejectCode::SortitionViewMismatch,
+                    "Expected a sortition view mismatch rejection"
+                );
+                mismatch_rejections.insert(signer_key);
+            } else if rejection.signer_signature_hash == stale_signer_signature_hash {
+                assert_eq!(
+                    rejection.reason_code,
+                    RejectCode::StaleView,
+                    "Expected a stale view rejection"
+                );
+                stale_rejections.insert(signer_key);
+            }
+        }
+        Ok(mismatch_rejections.len() == num_signers && stale_rejections.len() == num_signers)
+    })
+    .expect("Timed out waiting for every signer to reject both block proposals");
+    signer_test.shutdown();
+}
+
 // Basic test to ensure that miners are able to gather block responses
 // from signers and create blocks.
 #[test]
@@ -631,19 +950,20 @@ fn miner_gather_signatures() {
 
 #[test]
 #[ignore]
-/// Test that signers can handle a transition between Nakamoto reward cycles
+#[cfg(feature = "monitoring_prom")]
+/// Test that signers count the block proposals they have validated
 ///
 /// Test Setup:
 /// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
 /// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
 ///
 /// Test Execution:
-/// The node mines 2 full Nakamoto reward cycles, sending blocks to observing signers to sign and return.
+/// The node mines a few Nakamoto blocks.
 ///
 /// Test Assertion:
-/// All signers sign all blocks successfully.
-/// The chain advances 2 full reward cycles.
-fn mine_2_nakamoto_reward_cycles() {
+/// The signers' `stacks_signer_proposals_validated_total` counter, and the count for the current
+/// reward cycle, are at least the number of blocks mined.
+fn signer_metrics_count_validated_proposals() {
     if env::var("BITCOIND_TEST") != Ok("1".into()) {
         return;
     }
@@ -654,79 +974,357 @@ fn mine_2_nakamoto_reward_cycles() {
         .init();
 
     info!("------------------------- Test Setup -------------------------");
-    let nmb_reward_cycles = 2;
     let num_signers = 5;
+    let num_blocks = 3;
     let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
-    let timeout = Duration::from_secs(200);
+    let timeout = Duration::from_secs(30);
     signer_test.boot_to_epoch_3();
-    let curr_reward_cycle = signer_test.get_current_reward_cycle();
-    // Mine 2 full Nakamoto reward cycles (epoch 3 starts in the middle of one, hence the + 1)
-    let next_reward_cycle = curr_reward_cycle.saturating_add(1);
-    let final_reward_cycle = next_reward_cycle.saturating_add(nmb_reward_cycles);
-    let final_reward_cycle_height_boundary = signer_test
-        .running_nodes
-        .btc_regtest_controller
-        .get_burnchain()
-        .reward_cycle_to_block_height(final_reward_cycle)
-        .saturating_sub(1);
 
-    info!("------------------------- Test Mine 2 Nakamoto Reward Cycles -------------------------");
-    signer_test.run_until_burnchain_height_nakamoto(
-        timeout,
-        final_reward_cycle_height_boundary,
-        num_signers,
-    );
+    info!("------------------------- Test Mine Nakamoto Blocks -------------------------");
+    for _ in 0..num_blocks {
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    }
 
-    let current_burnchain_height = signer_test
-        .running_nodes
-        .btc_regtest_controller
-        .get_headers_height();
-    assert_eq!(current_burnchain_height, final_reward_cycle_height_boundary);
+    info!("------------------------- Test Metrics -------------------------");
+    let metric_value = |metrics: &str, name: &str| -> u64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .map(|value| value as u64)
+            .unwrap_or(0)
+    };
+    wait_for(30, || {
+        let metrics = signer_test.get_signer_metrics();
+        Ok(
+            metric_value(&metrics, "stacks_signer_proposals_validated_total") >= num_blocks
+                && metric_value(&metrics, "stacks_signer_proposals_validated_current_cycle")
+                    >= num_blocks,
+        )
+    })
+    .expect("Timed out waiting for the validated proposals metric to count the mined blocks");
     signer_test.shutdown();
 }
 
 #[test]
 #[ignore]
-fn forked_tenure_invalid() {
+/// Test that a signer which is restarted mid-run recovers and resumes signing
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a Nakamoto block, then one signer is stopped and respawned with the same
+/// config while the network keeps running. The node then mines more Nakamoto blocks.
+///
+/// Test Assertion:
+/// Every block mined after the restart is accepted by all five signers, including the restarted one.
+fn restarted_signer_resumes_signing() {
     if env::var("BITCOIND_TEST") != Ok("1".into()) {
         return;
     }
-    let result = forked_tenure_testing(Duration::from_secs(5), Duration::from_secs(7), false);
 
-    assert_ne!(
-        result.tip_b.index_block_hash(),
-        result.tip_a.index_block_hash()
-    );
-    assert_eq!(
-        result.tip_b.index_block_hash(),
-        result.tip_c.index_block_hash()
-    );
-    assert_ne!(result.tip_c, result.tip_a);
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
 
-    // Block B was built atop block A
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Restart Signer -------------------------");
+    signer_test.restart_signer(0);
+
+    info!("------------------------- Test Mine Nakamoto Blocks -------------------------");
+    let all_signers = signer_test.get_signer_public_keys(signer_test.get_current_reward_cycle());
+    for _ in 0..2 {
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+        let signer_signature_hash = test_observer::get_mined_nakamoto_blocks()
+            .last()
+            .unwrap()
+            .signer_signature_hash;
+        wait_for(30, || {
+            let signed_by: HashSet<_> = test_observer::get_stackerdb_chunks()
+                .into_iter()
+                .flat_map(|chunk| chunk.modified_slots)
+                .filter_map(|chunk| {
+                    let Ok(SignerMessage::BlockResponse(BlockResponse::Accepted(accepted))) =
+                        SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                    else {
+                        return None;
+                    };
+                    if accepted.signer_signature_hash != signer_signature_hash {
+                        return None;
+                    }
+                    all_signers.iter().position(|pk| {
+                        pk.verify(signer_signature_hash.bits(), &accepted.signature)
+                            .unwrap_or(false)
+                    })
+                })
+                .collect();
+            Ok(signed_by.len() == num_signers)
+        })
+        .expect("Timed out waiting for every signer to accept the block after the restart");
+    }
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that the duplicate signer check catches a signer contributing two signatures to a block
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a Nakamoto block, whose signatures pass the duplicate signer check. Extra
+/// acceptances of the block are then injected on behalf of one of the signers.
+///
+/// Test Assertion:
+/// The duplicate signer check fails on the acceptances seen for the block.
+fn duplicate_signer_signature_detected() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Test Mine Nakamoto Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let mined_block = test_observer::get_mined_nakamoto_blocks().pop().unwrap();
+    let signer_signature_hash = mined_block.signer_signature_hash;
+    let block = signer_test
+        .get_miner_proposal_messages()
+        .into_iter()
+        .map(|proposal| proposal.block)
+        .find(|block| block.header.signer_signature_hash() == signer_signature_hash)
+        .expect("Failed to find the proposal for the mined block");
+
+    info!("------------------------- Test Inject Duplicate Signature -------------------------");
+    let accepted_signatures = || {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                let Ok(SignerMessage::BlockResponse(BlockResponse::Accepted(accepted))) =
+                    SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                else {
+                    return None;
+                };
+                (accepted.signer_signature_hash == signer_signature_hash)
+                    .then_some(accepted.signature)
+            })
+            .collect::<Vec<_>>()
+    };
+    // Inject twice, so the duplicate is there whether or not the signer's own acceptance is
+    let num_accepted = accepted_signatures().len();
+    for _ in 0..2 {
+        signer_test.inject_accept_signature(
+            &block,
+            &signer_test.signer_stacks_private_keys[0],
+            reward_cycle,
+        );
+    }
+    wait_for(30, || Ok(accepted_signatures().len() >= num_accepted + 2))
+        .expect("Timed out waiting for the injected acceptances");
+
+    let check = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        signer_test.assert_no_duplicate_signers(
+            &signer_signature_hash,
+            &accepted_signatures(),
+            reward_cycle,
+        )
+    }));
+    assert!(
+        check.is_err(),
+        "Expected the duplicate signer check to fail"
+    );
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers can handle a transition between Nakamoto reward cycles
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines 2 full Nakamoto reward cycles, sending blocks to observing signers to sign and return.
+///
+/// Test Assertion:
+/// All signers sign all blocks successfully.
+/// The chain advances 2 full reward cycles.
+fn mine_2_nakamoto_reward_cycles() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let nmb_reward_cycles = 2;
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(200);
+    signer_test.boot_to_epoch_3();
+    let curr_reward_cycle = signer_test.get_current_reward_cycle();
+    // Mine 2 full Nakamoto reward cycles (epoch 3 starts in the middle of one, hence the + 1)
+    let next_reward_cycle = curr_reward_cycle.saturating_add(1);
+    let final_reward_cycle = next_reward_cycle.saturating_add(nmb_reward_cycles);
+    let final_reward_cycle_height_boundary = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_burnchain()
+        .reward_cycle_to_block_height(final_reward_cycle)
+        .saturating_sub(1);
+
+    info!("------------------------- Test Mine 2 Nakamoto Reward Cycles -------------------------");
+    signer_test.run_until_burnchain_height_nakamoto(
+        timeout,
+        final_reward_cycle_height_boundary,
+        num_signers,
+    );
+
+    let current_burnchain_height = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height();
+    assert_eq!(current_burnchain_height, final_reward_cycle_height_boundary);
+    // The boundary is the first burn block of the final reward cycle
+    signer_test.assert_height_in_cycle(current_burnchain_height, final_reward_cycle);
+    signer_test.assert_height_in_cycle(current_burnchain_height - 1, final_reward_cycle - 1);
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `mine_tenures_and_assert_convergence` mines the requested number of tenures on a
+/// single node and returns the resulting chain tip.
+fn mine_tenures_and_assert_convergence_single_node() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let num_tenures = 3;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+    let info_before = get_chain_info(&signer_test.running_nodes.conf);
+
+    info!("------------------------- Mine {num_tenures} Tenures -------------------------");
+    let tip = signer_test.mine_tenures_and_assert_convergence(num_tenures, Duration::from_secs(30));
+
+    let info_after = get_chain_info(&signer_test.running_nodes.conf);
+    assert_eq!(tip.stacks_block_height, info_after.stacks_tip_height);
+    assert_eq!(tip.consensus_hash, info_after.stacks_tip_consensus_hash);
     assert_eq!(
-        result.tip_b.stacks_block_height,
-        result.tip_a.stacks_block_height + 1
+        tip.anchored_header.block_hash(),
+        info_after.stacks_tip,
+        "Returned header is not the node's Stacks tip"
+    );
+    signer_test.assert_advanced_tenures(info_before.stacks_tip_height, num_tenures);
+    signer_test.shutdown();
+}
+
+#[test]
+fn assert_height_in_cycle_accepts_cycle_boundaries() {
+    let mut burnchain = Burnchain::regtest("/tmp/stacks-node-tests/assert-height-in-cycle");
+    burnchain.first_block_height = 100;
+    burnchain.pox_constants.reward_cycle_length = 20;
+
+    // (burn height, reward cycle)
+    let cases = [
+        // first block of the first cycle
+        (100, 0),
+        // last block of the first cycle
+        (119, 0),
+        // the modulo 0 block belongs to the cycle it starts
+        (120, 1),
+        // the modulo 1 block, where rewards start
+        (121, 1),
+        (139, 1),
+        (300, 10),
+        (319, 10),
+    ];
+    for (height, reward_cycle) in cases {
+        SignerTest::<SpawnedSigner>::assert_height_in_burnchain_cycle(
+            &burnchain,
+            height,
+            reward_cycle,
+        );
+    }
+}
+
+#[test]
+#[should_panic(
+    expected = "Burn height 140 is not in reward cycle 1, which spans burn heights 120..140"
+)]
+fn assert_height_in_cycle_reports_range() {
+    let mut burnchain = Burnchain::regtest("/tmp/stacks-node-tests/assert-height-in-cycle");
+    burnchain.first_block_height = 100;
+    burnchain.pox_constants.reward_cycle_length = 20;
+
+    SignerTest::<SpawnedSigner>::assert_height_in_burnchain_cycle(&burnchain, 140, 1);
+}
+
+#[test]
+#[ignore]
+fn forked_tenure_invalid() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+    let result = forked_tenure_testing(Duration::from_secs(5), Duration::from_secs(7), false);
+
+    assert_ne!(
+        result.tip_b.index_block_hash(),
+        result.tip_a.index_block_hash()
     );
     assert_eq!(
-        result.mined_b.parent_block_id,
-        result.tip_a.index_block_hash().to_string()
+        result.tip_b.index_block_hash(),
+        result.tip_c.index_block_hash()
     );
+    assert_ne!(result.tip_c, result.tip_a);
 
-    // Block C was built AFTER Block B was built, but BEFORE it was broadcasted, so it should be built off of Block A
+    // Block B was built atop block A
     assert_eq!(
-        result.mined_c.parent_block_id,
-        result.tip_a.index_block_hash().to_string()
+        result.tip_b.stacks_block_height,
+        result.tip_a.stacks_block_height + 1
     );
-    assert_ne!(
-        result
-            .tip_c
-            .anchored_header
-            .as_stacks_nakamoto()
-            .unwrap()
-            .signer_signature_hash(),
-        result.mined_c.signer_signature_hash,
-        "Mined block during tenure C should not have become the chain tip"
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_b, &result.tip_a);
+
+    // Block C was built AFTER Block B was built, but BEFORE it was broadcasted, so it should be built off of Block A
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_c, &result.tip_a);
+    // Mined block during tenure C should not have become the chain tip
+    SignerTest::<SpawnedSigner>::assert_header_is_not(
+        &result.tip_c,
+        &result.mined_c.signer_signature_hash,
     );
 
     assert!(result.tip_c_2.is_none());
@@ -745,10 +1343,7 @@ fn forked_tenure_invalid() {
         result.tip_d.stacks_block_height,
         result.tip_b.stacks_block_height + 1,
     );
-    assert_eq!(
-        result.mined_d.parent_block_id,
-        result.tip_b.index_block_hash().to_string()
-    );
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_d, &result.tip_b);
 }
 
 #[test]
@@ -769,20 +1364,14 @@ fn forked_tenure_okay() {
         result.tip_b.stacks_block_height,
         result.tip_a.stacks_block_height + 1
     );
-    assert_eq!(
-        result.mined_b.parent_block_id,
-        result.tip_a.index_block_hash().to_string()
-    );
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_b, &result.tip_a);
 
     // Block C was built AFTER Block B was built, but BEFORE it was broadcasted, so it should be built off of Block A
     assert_eq!(
         result.tip_c.stacks_block_height,
         result.tip_a.stacks_block_height + 1
     );
-    assert_eq!(
-        result.mined_c.parent_block_id,
-        result.tip_a.index_block_hash().to_string()
-    );
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_c, &result.tip_a);
 
     let tenure_c_2 = result.tip_c_2.unwrap();
     assert_ne!(result.tip_c, tenure_c_2);
@@ -794,9 +1383,9 @@ fn forked_tenure_okay() {
         tenure_c_2.stacks_block_height,
         result.tip_c.stacks_block_height + 1,
     );
-    assert_eq!(
-        result.mined_c_2.unwrap().parent_block_id,
-        result.tip_c.index_block_hash().to_string()
+    SignerTest::<SpawnedSigner>::assert_parent_is(
+        result.mined_c_2.as_ref().unwrap(),
+        &result.tip_c,
     );
 
     // Tenure D builds off of the second block of tenure C
@@ -804,9 +1393,75 @@ fn forked_tenure_okay() {
         result.tip_d.stacks_block_height,
         tenure_c_2.stacks_block_height + 1,
     );
-    assert_eq!(
-        result.mined_d.parent_block_id,
-        tenure_c_2.index_block_hash().to_string()
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_d, &tenure_c_2);
+}
+
+#[test]
+#[should_panic(
+    expected = "Mined block 0202020202020202020202020202020202020202020202020202020202020202 at height 6 has parent 0101010101010101010101010101010101010101010101010101010101010101, but expected parent"
+)]
+fn assert_parent_is_reports_mismatch() {
+    let mut parent = StacksHeaderInfo::regtest_genesis();
+    parent.stacks_block_height = 5;
+
+    let mut mined = MinedNakamotoBlockEvent {
+        target_burn_height: 100,
+        parent_block_id: parent.index_block_hash().to_string(),
+        block_hash: "03".repeat(32),
+        block_id: "02".repeat(32),
+        stacks_height: 6,
+        block_size: 0,
+        cost: ExecutionCost::ZERO,
+        miner_signature: MessageSignature::empty(),
+        miner_signature_hash: Sha512Trunc256Sum([0; 32]),
+        signer_signature_hash: Sha512Trunc256Sum([0; 32]),
+        tx_events: vec![],
+        signer_bitvec: String::new(),
+        signer_signature: vec![],
+    };
+    // The recorded parent matches, so this passes
+    SignerTest::<SpawnedSigner>::assert_parent_is(&mined, &parent);
+
+    mined.parent_block_id = "01".repeat(32);
+    SignerTest::<SpawnedSigner>::assert_parent_is(&mined, &parent);
+}
+
+#[test]
+#[should_panic(
+    expected = "Tenure 0101010101010101010101010101010101010101 finalized 2 blocks at height 7"
+)]
+fn assert_finalized_once_reports_duplicates() {
+    let tenure_consensus_hash = ConsensusHash([0x01; 20]);
+    let header_at = |height: u64, timestamp: u64| {
+        let mut block_header = NakamotoBlockHeader::empty();
+        block_header.consensus_hash = tenure_consensus_hash;
+        block_header.chain_length = height;
+        block_header.timestamp = timestamp;
+        let mut header = StacksHeaderInfo::regtest_genesis();
+        header.anchored_header = block_header.into();
+        header.consensus_hash = tenure_consensus_hash;
+        header.stacks_block_height = height;
+        header
+    };
+    let block_6 = header_at(6, 1);
+    let block_7 = header_at(7, 1);
+    let conflicting_block_7 = header_at(7, 2);
+    let canonical = HashMap::from([
+        (6, block_6.index_block_hash()),
+        (7, block_7.index_block_hash()),
+    ]);
+
+    // One canonical block at each height, so this passes
+    SignerTest::<SpawnedSigner>::assert_finalized_once(
+        &tenure_consensus_hash,
+        &[block_6.clone(), block_7.clone()],
+        &canonical,
+    );
+
+    SignerTest::<SpawnedSigner>::assert_finalized_once(
+        &tenure_consensus_hash,
+        &[block_6, block_7, conflicting_block_7],
+        &canonical,
     );
 }
 
@@ -1099,10 +1754,7 @@ fn forked_tenure_testing(
 
     // Block B was built atop block A
     assert_eq!(tip_b.stacks_block_height, tip_a.stacks_block_height + 1);
-    assert_eq!(
-        mined_b.parent_block_id,
-        tip_a.index_block_hash().to_string()
-    );
+    SignerTest::<SpawnedSigner>::assert_parent_is(&mined_b, &tip_a);
     assert_ne!(tip_b, tip_a);
 
     if !expect_tenure_c {
@@ -1243,6 +1895,13 @@ fn forked_tenure_testing(
     // allow block C2 to be processed
     sleep_ms(1000);
 
+    // Whichever of blocks B and C lost the fork must not be the tip
+    if expect_tenure_c {
+        signer_test.assert_block_not_tip(&mined_b.signer_signature_hash);
+    } else {
+        signer_test.assert_block_not_tip(&mined_c.signer_signature_hash);
+    }
+
     info!("Starting Tenure D.");
 
     // Submit a block commit op for tenure D and mine a stacks block
@@ -1267,6 +1926,7 @@ fn forked_tenure_testing(
         .unwrap();
     let blocks = test_observer::get_mined_nakamoto_blocks();
     let mined_d = blocks.last().unwrap().clone();
+
     signer_test.shutdown();
     TenureForkingResult {
         tip_a,
@@ -1293,17 +1953,10 @@ fn bitcoind_forking_test() {
     let sender_addr = tests::to_addr(&sender_sk);
     let send_amt = 100;
     let send_fee = 180;
-    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_epoch_boundaries(
         num_signers,
         vec![(sender_addr, send_amt + send_fee)],
-        |_| {},
-        |node_config| {
-            let epochs = node_config.burnchain.epochs.as_mut().unwrap();
-            epochs[StacksEpochId::Epoch30].end_height = 3_015;
-            epochs[StacksEpochId::Epoch31].start_height = 3_015;
-        },
-        None,
-        None,
+        &[(StacksEpochId::Epoch31, 3_015)],
     );
     let conf = signer_test.running_nodes.conf.clone();
     let http_origin = format!("http://{}", &conf.node.rpc_bind);
@@ -1622,6 +2275,8 @@ fn multiple_miners() {
         .name("run_loop_2".into())
         .spawn(move || run_loop_2.start(None, 0))
         .unwrap();
+    signer_test.secondary_node_conf = Some(conf_node_2.clone());
+    signer_test.secondary_node_counters = Some(rl2_counters);
 
     signer_test.boot_to_epoch_3();
 
@@ -1644,8 +2299,6 @@ fn multiple_miners() {
     //  is that we keep track of how many tenures each miner produced, and once enough sortitions
     //  have been produced such that each miner has produced 3 tenures, we stop and check the
     //  results at the end
-    let rl1_counters = signer_test.running_nodes.counters.clone();
-
     let miner_1_pk = StacksPublicKey::from_private(conf.miner.mining_key.as_ref().unwrap());
     let miner_2_pk = StacksPublicKey::from_private(conf_node_2.miner.mining_key.as_ref().unwrap());
     let mut btc_blocks_mined = 1;
@@ -1662,11 +2315,7 @@ fn multiple_miners() {
 
         info!("Issue next block-build request\ninfo 1: {info_1:?}\ninfo 2: {info_2:?}\n");
 
-        signer_test.mine_block_wait_on_processing(
-            &[&conf, &conf_node_2],
-            &[&rl1_counters, &rl2_counters],
-            Duration::from_secs(30),
-        );
+        signer_test.mine_tenures_and_assert_convergence(1, Duration::from_secs(30));
 
         btc_blocks_mined += 1;
         let blocks = get_nakamoto_headers(&conf);
@@ -1711,19 +2360,49 @@ fn multiple_miners() {
 
     info!("New chain info: {:?}", get_chain_info(&conf_node_2));
 
-    let peer_1_height = get_chain_info(&conf).stacks_tip_height;
-    let peer_2_height = get_chain_info(&conf_node_2).stacks_tip_height;
-    info!("Peer height information"; "peer_1" => peer_1_height, "peer_2" => peer_2_height, "pre_naka_height" => pre_nakamoto_peer_1_height);
-    assert_eq!(peer_1_height, peer_2_height);
-    assert_eq!(
-        peer_1_height,
-        pre_nakamoto_peer_1_height + btc_blocks_mined as u64 - 1
-    );
+    signer_test.assert_advanced_tenures(pre_nakamoto_peer_1_height, btc_blocks_mined as u64 - 1);
     assert_eq!(
         btc_blocks_mined,
         u32::try_from(miner_1_tenures + miner_2_tenures).unwrap()
     );
 
+    info!("------------------------- Check Valid Miners -------------------------");
+    // Once the two most recent tenures were won by different miners, every signer considers both valid
+    let last_two_tenures_differ = || {
+        let mut blocks = get_nakamoto_headers(&conf);
+        blocks.sort_by_key(|header| header.stacks_block_height);
+        let mined_by_miner_1: Vec<_> = blocks
+            .iter()
+            .rev()
+            .take(2)
+            .map(|header| {
+                let header = header.anchored_header.as_stacks_nakamoto().unwrap();
+                miner_1_pk
+                    .verify(
+                        header.miner_signature_hash().as_bytes(),
+                        &header.miner_signature,
+                    )
+                    .unwrap()
+            })
+            .collect();
+        mined_by_miner_1.len() == 2 && mined_by_miner_1[0] != mined_by_miner_1[1]
+    };
+    while !last_two_tenures_differ() {
+        assert!(
+            max_nakamoto_tenures >= btc_blocks_mined,
+            "Produced {btc_blocks_mined} sortitions, but the last two tenures never had different miners"
+        );
+        signer_test.mine_tenures_and_assert_convergence(1, Duration::from_secs(30));
+        btc_blocks_mined += 1;
+    }
+    for slot_id in 0..num_signers as u32 {
+        let valid_miners = signer_test.valid_miners(slot_id);
+        assert!(
+            valid_miners.contains(&miner_1_pk) && valid_miners.contains(&miner_2_pk),
+            "Signer in slot #{slot_id} does not consider both miners valid: {valid_miners:?}"
+        );
+    }
+
     rl2_coord_channels
         .lock()
         .expect("Mutex poisoned")
@@ -2301,6 +2980,9 @@ fn end_of_tenure() {
         signer_test.get_current_reward_cycle(),
         final_reward_cycle - 1
     );
+    // This is the last burn block of the cycle, so the next one starts the final reward cycle
+    signer_test.assert_height_in_cycle(final_reward_cycle_height_boundary, final_reward_cycle - 1);
+    signer_test.assert_height_in_cycle(final_reward_cycle_height_boundary + 1, final_reward_cycle);
 
     info!("------------------------- Test Block Validation Stalled -------------------------");
     TEST_VALIDATE_STALL.set(true);
@@ -2365,9 +3047,10 @@ fn end_of_tenure() {
     .expect("Timed out waiting for burn block events");
 
     signer_test.wait_for_cycle(30, final_reward_cycle);
+    signer_test.assert_reward_cycle_consensus();
 
     info!("Block proposed and burn blocks consumed. Verifying that stacks block is still not processed");
-
+    signer_test.assert_no_block_mined_for(Duration::from_secs(5));
     assert_eq!(
         get_chain_info(&signer_test.running_nodes.conf).stacks_tip_height,
         blocks_before
@@ -2494,14 +3177,7 @@ fn retry_on_rejection() {
 
     info!("Block proposed, verifying that it is not processed");
     // Wait 10 seconds to be sure that the timeout has occurred
-    std::thread::sleep(Duration::from_secs(10));
-    assert_eq!(
-        signer_test
-            .running_nodes
-            .nakamoto_blocks_mined
-            .load(Ordering::SeqCst),
-        blocks_before
-    );
+    signer_test.assert_no_block_mined_for(Duration::from_secs(10));
 
     // resume signing
     info!("Disable unconditional rejection and wait for the block to be processed");
@@ -3444,7 +4120,7 @@ fn empty_sortition() {
 
     info!("------------------------- Test Delayed Block is Rejected  -------------------------");
     let reward_cycle = signer_test.get_current_reward_cycle();
-    let mut stackerdb = StackerDB::new_normal(
+    let mut stackerdb = StackerDB::<MessageSlotID>::new_normal(
         &signer_test.running_nodes.conf.node.rpc_bind,
         StacksPrivateKey::random(), // We are just reading so don't care what the key is
         false,
@@ -3452,40 +4128,28 @@ fn empty_sortition() {
         SignerSlotID(0), // We are just reading so again, don't care about index.
     );
 
-    let signer_slot_ids: Vec<_> = signer_test
-        .get_signer_indices(reward_cycle)
-        .iter()
-        .map(|id| id.0)
-        .collect();
+    let signer_slot_ids = signer_test.get_signer_indices(reward_cycle);
     assert_eq!(signer_slot_ids.len(), num_signers);
 
     // The miner's proposed block should get rejected by all the signers
     let mut found_rejections = Vec::new();
     wait_for(short_timeout.as_secs(), || {
-        for slot_id in signer_slot_ids.iter() {
-            if found_rejections.contains(slot_id) {
+        let responses = stackerdb
+            .get_all_block_responses(reward_cycle)
+            .expect("Failed to get block responses from stackerdb");
+        for (slot_id, response) in responses {
+            if found_rejections.contains(&slot_id) {
                 continue;
             }
-            let mut latest_msgs = StackerDB::get_messages(
-                stackerdb
-                    .get_session_mut(&MessageSlotID::BlockResponse)
-                    .expect("Failed to get BlockResponse stackerdb session"),
-                &[*slot_id]
-            ).expect("Failed to get message from stackerdb");
-            assert!(latest_msgs.len() <= 1);
-            let Some(latest_msg) = latest_msgs.pop() else {
-                info!("No message yet from slot #{slot_id}, will wait to try again");
-                continue;
-            };
             if let SignerMessage::BlockResponse(BlockResponse::Rejected(BlockRejection {
                 reason_code,
                 metadata,
                 ..
-            })) = latest_msg
+            })) = response
             {
                 assert!(matches!(reason_code, RejectCode::SortitionViewMismatch));
                 assert_eq!(metadata.server_version, VERSION_STRING.to_string());
-                found_rejections.push(*slot_id);
+                found_rejections.push(slot_id);
             } else {
                 info!("Latest message from slot #{slot_id} isn't a block rejection, will wait to see if the signer updates to a rejection");
             }
@@ -3848,11 +4512,10 @@ fn mock_sign_epoch_25() {
         |_| {},
         |node_config| {
             node_config.miner.pre_nakamoto_mock_signing = true;
-            let epochs = node_config.burnchain.epochs.as_mut().unwrap();
-            epochs[StacksEpochId::Epoch25].end_height = 251;
-            epochs[StacksEpochId::Epoch30].start_height = 251;
-            epochs[StacksEpochId::Epoch30].end_height = 265;
-            epochs[StacksEpochId::Epoch31].start_height = 265;
+            set_epoch_boundaries(
+                node_config,
+                &[(StacksEpochId::Epoch30, 251), (StacksEpochId::Epoch31, 265)],
+            );
         },
         None,
         None,
@@ -3875,12 +4538,9 @@ fn mock_sign_epoch_25() {
     // Mine until epoch 3.0 and ensure that no more mock signatures are received
     let reward_cycle = signer_test.get_current_reward_cycle();
     let signer_slot_ids = signer_test.get_signer_indices(reward_cycle).into_iter();
-    let signer_public_keys = signer_test.get_signer_public_keys(reward_cycle);
     assert_eq!(signer_slot_ids.count(), num_signers);
 
-    let miners_stackerdb_contract = boot_code_id(MINERS_NAME, false);
-
-    // Mine until epoch 3.0 and ensure we get a new mock block per epoch 2.5 sortition
+    // Mine until epoch 3.0 and ensure every signer mock signs each epoch 2.5 sortition
     let main_poll_time = Instant::now();
     // Only advance to the boundary as the epoch 2.5 miner will be shut down at this point.
     while signer_test
@@ -3889,8 +4549,6 @@ fn mock_sign_epoch_25() {
         .get_headers_height()
         < epoch_3_boundary
     {
-        let mut mock_block_mesage = None;
-        let mock_poll_time = Instant::now();
         signer_test
             .running_nodes
             .btc_regtest_controller
@@ -3899,53 +4557,14 @@ fn mock_sign_epoch_25() {
             .running_nodes
             .btc_regtest_controller
             .get_headers_height();
-        debug!("Waiting for mock miner message for burn block height {current_burn_block_height}");
-        while mock_block_mesage.is_none() {
-            std::thread::sleep(Duration::from_millis(100));
-            let chunks = test_observer::get_stackerdb_chunks();
-            for chunk in chunks
-                .into_iter()
-                .filter_map(|chunk| {
-                    if chunk.contract_id != miners_stackerdb_contract {
-                        return None;
-                    }
-                    Some(chunk.modified_slots)
-                })
-                .flatten()
-            {
-                if chunk.data.is_empty() {
-                    continue;
-                }
-                let SignerMessage::MockBlock(mock_block) =
-                    SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
-                        .expect("Failed to deserialize SignerMessage")
-                else {
-                    continue;
-                };
-                if mock_block.mock_proposal.peer_info.burn_block_height == current_burn_block_height
-                {
-                    mock_block
-                        .mock_signatures
-                        .iter()
-                        .for_each(|mock_signature| {
-                            assert!(signer_public_keys.iter().any(|signer| {
-                                mock_signature
-                                    .verify(
-                                        &StacksPublicKey::from_slice(signer.to_bytes().as_slice())
-                                            .unwrap(),
-                                    )
-                                    .expect("Failed to verify mock signature")
-                            }));
-                        });
-                    mock_block_mesage = Some(mock_block);
-                    break;
-                }
-            }
-            assert!(
-                mock_poll_time.elapsed() <= Duration::from_secs(15),
-                "Failed to find mock miner message within timeout"
-            );
-        }
+        debug!("Waiting for mock signatures for burn block height {current_burn_block_height}");
+        signer_test.assert_mock_signatures_for_range(
+            current_burn_block_height,
+            current_burn_block_height,
+            num_signers,
+            true,
+            Duration::from_secs(15),
+        );
         assert!(
             main_poll_time.elapsed() <= Duration::from_secs(145),
             "Timed out waiting to advance epoch 3.0 boundary"
@@ -4006,11 +4625,10 @@ fn multiple_miners_mock_sign_epoch_25() {
             config.burnchain.local_mining_public_key = Some(btc_miner_1_pk.to_hex());
             config.miner.mining_key = Some(Secp256k1PrivateKey::from_seed(&[1]));
             config.miner.pre_nakamoto_mock_signing = true;
-            let epochs = config.burnchain.epochs.as_mut().unwrap();
-            epochs[StacksEpochId::Epoch25].end_height = 251;
-            epochs[StacksEpochId::Epoch30].start_height = 251;
-            epochs[StacksEpochId::Epoch30].end_height = 265;
-            epochs[StacksEpochId::Epoch31].start_height = 265;
+            set_epoch_boundaries(
+                config,
+                &[(StacksEpochId::Epoch30, 251), (StacksEpochId::Epoch31, 265)],
+            );
             config.events_observers.retain(|listener| {
                 let Ok(addr) = std::net::SocketAddr::from_str(&listener.endpoint) else {
                     warn!(
@@ -4998,14 +5616,10 @@ fn partial_tenure_fork() {
 
             // Move epoch 2.5 and 3.0 earlier, so we have more time for the
             // test before re-stacking is required.
-            if let Some(epochs) = config.burnchain.epochs.as_mut() {
-                epochs[StacksEpochId::Epoch24].end_height = 131;
-                epochs[StacksEpochId::Epoch25].start_height = 131;
-                epochs[StacksEpochId::Epoch25].end_height = 166;
-                epochs[StacksEpochId::Epoch30].start_height = 166;
-            } else {
-                panic!("Expected epochs to be set");
-            }
+            set_epoch_boundaries(
+                config,
+                &[(StacksEpochId::Epoch25, 131), (StacksEpochId::Epoch30, 166)],
+            );
         },
         Some(vec![btc_miner_1_pk, btc_miner_2_pk]),
         None,
@@ -7826,6 +8440,13 @@ fn block_validation_response_timeout() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut block = NakamotoBlock {
         header: NakamotoBlockHeader::empty(),
@@ -8105,6 +8726,13 @@ fn block_validation_pending_table() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut block = NakamotoBlock {
         header: NakamotoBlockHeader::empty(),
@@ -10796,6 +11424,13 @@ fn incoming_signers_ignore_block_proposals() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut block = NakamotoBlock {
         header: NakamotoBlockHeader::empty(),
@@ -10973,6 +11608,13 @@ fn outgoing_signers_ignore_block_proposals() {
         tenure_idle_timeout: Duration::from_secs(300),
         tenure_idle_timeout_buffer: Duration::from_secs(2),
         reorg_attempts_activity_timeout: Duration::from_secs(30),
+        clock: SignerClock::default(),
+        max_tenure_cost: None,
+        require_commit_confirmations: 0,
+        max_block_bytes: None,
+        max_burn_height_ahead: u64::MAX,
+        sign_block_kinds: BlockKindMask::ALL,
+        burnchain_reorg_grace_period: Duration::from_secs(0),
     };
     let mut block = NakamotoBlock {
         header: NakamotoBlockHeader::empty(),
@@ -16334,3 +16976,2944 @@ fn non_blocking_minority_configured_to_favour_prev_miner() {
     run_loop_2_thread.join().unwrap();
     signer_test.shutdown();
 }
+
+#[test]
+#[ignore]
+/// Test that signers still reach threshold when one signer's clock is skewed forward,
+/// and that the skewed signer does not prematurely mark the miner as timed out.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers use a block proposal timeout of 60 seconds.
+///
+/// Test Execution:
+/// The signer in slot 0 has its clock skewed forward by 30 seconds, i.e. by less than the
+/// block proposal timeout. Two tenures are mined.
+///
+/// Test Assertion:
+/// Each tenure's block is signed by the signers, and the skewed signer accepts it.
+fn signer_clock_skew() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let block_proposal_timeout = Duration::from_secs(60);
+    let clock_skew = Duration::from_secs(30);
+    let skewed_slot = 0;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |config| {
+            config.block_proposal_timeout = block_proposal_timeout;
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(30);
+
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Skew Signer Clock -------------------------");
+    signer_test.set_signer_clock_offset(skewed_slot, clock_skew);
+
+    for _ in 0..2 {
+        info!("------------------------- Mine Tenure With Skewed Signer -------------------------");
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+        let mined_block = test_observer::get_mined_nakamoto_blocks()
+            .pop()
+            .expect("No mined block");
+        // The skewed signer must have accepted the block rather than flagging the miner
+        wait_for(30, || {
+            let response = signer_test.get_latest_block_response(skewed_slot);
+            Ok(response.as_block_accepted().is_some_and(|accepted| {
+                accepted.signer_signature_hash == mined_block.signer_signature_hash
+            }))
+        })
+        .expect("Skewed signer did not accept the block");
+    }
+
+    TEST_SIGNER_CLOCK_OFFSET.set(HashMap::new());
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers reject a block that would push the tenure over their configured
+/// execution budget.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers are configured with a `max_tenure_cost` whose runtime budget is too small to
+/// fit a contract deploy but leaves every other dimension unbounded.
+///
+/// Test Execution:
+/// A tenure is started and a zero-runtime STX transfer is mined into it. A contract deploy is
+/// then submitted in the same tenure.
+///
+/// Test Assertion:
+/// The STX transfer block is accepted. The block containing the contract deploy is rejected
+/// with `RejectCode::TenureBudgetExceeded` and the chain tip does not advance.
+fn tenure_budget_exceeded() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let deploy_fee = 1_000_000;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![(sender_addr, send_amt + send_fee + deploy_fee)],
+        |config| {
+            config.max_tenure_cost = Some(ExecutionCost {
+                write_length: u64::MAX,
+                write_count: u64::MAX,
+                read_length: u64::MAX,
+                read_count: u64::MAX,
+                runtime: 10_000,
+            });
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let http_origin = format!("http://{}", &signer_test.running_nodes.conf.node.rpc_bind);
+    let timeout = Duration::from_secs(30);
+
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Start Tenure -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Mine STX Transfer Within Budget -------------------------");
+    let info_before = get_chain_info(&signer_test.running_nodes.conf);
+    let transfer_tx = make_stacks_transfer(
+        &sender_sk,
+        0,
+        send_fee,
+        signer_test.running_nodes.conf.burnchain.chain_id,
+        &recipient,
+        send_amt,
+    );
+    submit_tx(&http_origin, &transfer_tx);
+    wait_for(30, || {
+        let info = get_chain_info(&signer_test.running_nodes.conf);
+        Ok(info.stacks_tip_height > info_before.stacks_tip_height)
+    })
+    .expect("Timed out waiting for the STX transfer to be mined");
+
+    info!("------------------------- Submit Contract Deploy Over Budget -------------------------");
+    let contract_src = format!(
+        r#"
+(define-data-var my-var uint u0)
+(define-public (f) (begin {} (ok 1))) (begin (f))
+        "#,
+        ["(var-get my-var)"; 250].join(" ")
+    );
+    let contract_tx = make_contract_publish(
+        &sender_sk,
+        1,
+        deploy_fee,
+        signer_test.running_nodes.conf.burnchain.chain_id,
+        "over-budget",
+        &contract_src,
+    );
+    let info_before = get_chain_info(&signer_test.running_nodes.conf);
+    submit_tx(&http_origin, &contract_tx);
+
+    wait_for(60, || {
+        let rejections = test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                let message = SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                    .expect("Failed to deserialize SignerMessage");
+                match message {
+                    SignerMessage::BlockResponse(BlockResponse::Rejected(rejection))
+                        if rejection.reason_code == RejectCode::TenureBudgetExceeded =>
+                    {
+                        rejection.recover_public_key().ok()
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<HashSet<_>>();
+        Ok(rejections.len() > num_signers * 3 / 10)
+    })
+    .expect("Timed out waiting for TenureBudgetExceeded rejections");
+
+    let info_after = get_chain_info(&signer_test.running_nodes.conf);
+    assert_eq!(info_before.stacks_tip, info_after.stacks_tip);
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `SignerTest::new_with_epoch_boundaries` moves the configured epoch boundaries.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// Epoch 3.0 is moved to start at burn block 236 and Epoch 3.1 at burn block 251.
+///
+/// Test Execution:
+/// The network is booted to Epoch 3.0.
+///
+/// Test Assertion:
+/// The node reports the custom boundaries in its PoX info, and Epoch 2.5 ends where Epoch 3.0 starts.
+fn custom_epoch_boundaries() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let epoch_3_start_height = 236;
+    let epoch_3_1_start_height = 251;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_epoch_boundaries(
+        num_signers,
+        vec![],
+        &[
+            (StacksEpochId::Epoch30, epoch_3_start_height),
+            (StacksEpochId::Epoch31, epoch_3_1_start_height),
+        ],
+    );
+    let http_origin = format!("http://{}", &signer_test.running_nodes.conf.node.rpc_bind);
+
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Check Reported Epoch Boundaries -------------------------");
+    let pox_info = get_pox_info(&http_origin).expect("Failed to get PoX info");
+    let reported_epoch = |epoch_id: StacksEpochId| {
+        pox_info
+            .epochs
+            .iter()
+            .find(|epoch| epoch.epoch_id == epoch_id)
+            .unwrap_or_else(|| panic!("Node did not report epoch {epoch_id}"))
+    };
+    assert_eq!(
+        reported_epoch(StacksEpochId::Epoch25).end_height,
+        epoch_3_start_height
+    );
+    assert_eq!(
+        reported_epoch(StacksEpochId::Epoch30).start_height,
+        epoch_3_start_height
+    );
+    assert_eq!(
+        reported_epoch(StacksEpochId::Epoch30).end_height,
+        epoch_3_1_start_height
+    );
+    assert_eq!(
+        reported_epoch(StacksEpochId::Epoch31).start_height,
+        epoch_3_1_start_height
+    );
+
+    let info = get_chain_info(&signer_test.running_nodes.conf);
+    assert!(info.burn_block_height >= epoch_3_start_height);
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `SignerTest::assert_no_block_mined_for` holds while block validation is stalled.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// Block validation is stalled on the node and a STX transfer is submitted, so the miner proposes
+/// a block that the signers cannot validate.
+///
+/// Test Assertion:
+/// No block is mined while validation is stalled. Once the stall is lifted, the block is mined.
+fn no_block_mined_during_validation_stall() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> =
+        SignerTest::new(num_signers, vec![(sender_addr, send_amt + send_fee)]);
+    let http_origin = format!("http://{}", &signer_test.running_nodes.conf.node.rpc_bind);
+    let timeout = Duration::from_secs(30);
+
+    signer_test.boot_to_epoch_3();
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Stall Block Validation -------------------------");
+    TEST_VALIDATE_STALL.set(true);
+
+    let proposals_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_proposed
+        .load(Ordering::SeqCst);
+    let blocks_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_mined
+        .load(Ordering::SeqCst);
+
+    let transfer_tx = make_stacks_transfer(
+        &sender_sk,
+        0,
+        send_fee,
+        signer_test.running_nodes.conf.burnchain.chain_id,
+        &recipient,
+        send_amt,
+    );
+    submit_tx(&http_origin, &transfer_tx);
+
+    wait_for(timeout.as_secs(), || {
+        Ok(signer_test
+            .running_nodes
+            .nakamoto_blocks_proposed
+            .load(Ordering::SeqCst)
+            > proposals_before)
+    })
+    .expect("Timed out waiting for block proposal");
+
+    info!("------------------------- Verify No Block Is Mined -------------------------");
+    signer_test.assert_no_block_mined_for(Duration::from_secs(20));
+
+    info!("------------------------- Unstall Block Validation -------------------------");
+    TEST_VALIDATE_STALL.set(false);
+    wait_for(timeout.as_secs(), || {
+        Ok(signer_test
+            .running_nodes
+            .nakamoto_blocks_mined
+            .load(Ordering::SeqCst)
+            > blocks_before)
+    })
+    .expect("Timed out waiting for block to be mined");
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers configured with `require_commit_confirmations` refuse to sign the first
+/// block of a tenure until the miner's block-commit is confirmed deeply enough.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers require two confirmations of a tenure's block-commit.
+///
+/// Test Execution:
+/// The network is booted to the epoch 3.0 boundary and a burn block is mined, so the miner
+/// proposes the first block of its tenure while its commit has a single confirmation. The miner
+/// then stops submitting block-commits and another burn block is mined, without a sortition.
+///
+/// Test Assertion:
+/// The first proposal is rejected with `RejectCode::CommitNotConfirmed` and the chain does not
+/// advance. Once the commit has two confirmations, the tenure's first block is signed.
+fn commit_confirmations_required() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |config| {
+            config.require_commit_confirmations = 2;
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(60);
+
+    signer_test.boot_to_epoch_3_boundary();
+
+    info!("------------------------- Mine First Tenure -------------------------");
+    let info_before = get_chain_info(&signer_test.running_nodes.conf);
+    let proposals_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_proposed
+        .load(Ordering::SeqCst);
+    signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .build_next_block(1);
+    wait_for(timeout.as_secs(), || {
+        Ok(signer_test
+            .running_nodes
+            .nakamoto_blocks_proposed
+            .load(Ordering::SeqCst)
+            > proposals_before)
+    })
+    .expect("Timed out waiting for the tenure's first block to be proposed");
+
+    info!(
+        "------------------------- Verify Unconfirmed Commit Rejection -------------------------"
+    );
+    wait_for(timeout.as_secs(), || {
+        let rejections = test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                let message = SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                    .expect("Failed to deserialize SignerMessage");
+                match message {
+                    SignerMessage::BlockResponse(BlockResponse::Rejected(rejection))
+                        if rejection.reason_code == RejectCode::CommitNotConfirmed =>
+                    {
+                        rejection.recover_public_key().ok()
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<HashSet<_>>();
+        Ok(rejections.len() > num_signers * 3 / 10)
+    })
+    .expect("Timed out waiting for CommitNotConfirmed rejections");
+    assert_eq!(
+        get_chain_info(&signer_test.running_nodes.conf).stacks_tip_height,
+        info_before.stacks_tip_height
+    );
+
+    info!("------------------------- Confirm Commit Without A New Sortition -------------------------");
+    signer_test
+        .running_nodes
+        .nakamoto_test_skip_commit_op
+        .set(true);
+    signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .build_next_block(1);
+
+    wait_for(timeout.as_secs(), || {
+        Ok(
+            get_chain_info(&signer_test.running_nodes.conf).stacks_tip_height
+                > info_before.stacks_tip_height,
+        )
+    })
+    .expect("Timed out waiting for the tenure's first block to be signed");
+
+    signer_test
+        .running_nodes
+        .nakamoto_test_skip_commit_op
+        .set(false);
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a batch of transfers submitted with `SignerTest::submit_transfers` is mined.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The sender is funded for five transfers.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0, five transfers are submitted with sequential nonces, and
+/// up to two tenures are mined.
+///
+/// Test Assertion:
+/// All five transfers are included in a block and the sender's nonce advances by five.
+fn submit_transfers_batch() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let num_transfers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(
+        num_signers,
+        vec![(sender_addr, (send_amt + send_fee) * num_transfers)],
+    );
+    let http_origin = format!("http://{}", &signer_test.running_nodes.conf.node.rpc_bind);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Submit Transfers -------------------------");
+    let txids =
+        signer_test.submit_transfers(&sender_sk, num_transfers, send_fee, send_amt, &recipient);
+    assert_eq!(txids.len(), num_transfers as usize);
+
+    info!("------------------------- Mine Until Included -------------------------");
+    for _ in 0..2 {
+        if get_account(&http_origin, &sender_addr).nonce >= num_transfers {
+            break;
+        }
+        signer_test.mine_nakamoto_block(timeout, true);
+    }
+
+    for txid in &txids {
+        let block_height = signer_test.wait_for_tx_in_block(txid, timeout.as_secs());
+        info!("Transfer {txid} mined in block {block_height}");
+    }
+    assert_eq!(get_account(&http_origin, &sender_addr).nonce, num_transfers);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers reject block proposals that exceed their configured maximum block size
+/// without submitting them to the node for validation.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers accept blocks of at most 2,000 bytes.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0 and enough transfers are submitted to pad the next block
+/// well past the size limit.
+///
+/// Test Assertion:
+/// The oversized block is rejected with `RejectCode::BlockTooLarge` and the node never returns a
+/// validation response for it.
+fn block_too_large() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let num_transfers = 40;
+    let max_block_bytes = 2_000;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![(sender_addr, (send_amt + send_fee) * num_transfers)],
+        |config| {
+            config.max_block_bytes = Some(max_block_bytes);
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(60);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Submit Transfers -------------------------");
+    signer_test.submit_transfers(&sender_sk, num_transfers, send_fee, send_amt, &recipient);
+
+    info!("------------------------- Verify Oversized Block Rejection -------------------------");
+    let mut rejected_sighash = None;
+    wait_for(timeout.as_secs(), || {
+        let mut rejecters = HashSet::new();
+        for chunk in test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+        {
+            let message = SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                .expect("Failed to deserialize SignerMessage");
+            if let SignerMessage::BlockResponse(BlockResponse::Rejected(rejection)) = message {
+                if rejection.reason_code == RejectCode::BlockTooLarge {
+                    rejected_sighash = Some(rejection.signer_signature_hash);
+                    if let Ok(key) = rejection.recover_public_key() {
+                        rejecters.insert(key);
+                    }
+                }
+            }
+        }
+        Ok(rejecters.len() > num_signers * 3 / 10)
+    })
+    .expect("Timed out waiting for BlockTooLarge rejections");
+
+    let rejected_sighash = rejected_sighash.unwrap();
+    assert!(
+        test_observer::get_proposal_responses()
+            .iter()
+            .all(|response| response.signer_signature_hash() != rejected_sighash),
+        "Oversized block should not have been submitted for validation"
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that booting to epoch 3.0 does not return before every signer is running the
+/// current reward cycle.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0, and each signer's status is requested once.
+///
+/// Test Assertion:
+/// Every signer reports that it is registered and running a signer for the current reward cycle.
+fn boot_waits_for_signers_in_cycle() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let exclude = HashSet::new();
+    signer_test.send_status_request(&exclude);
+    let mut states = vec![None; num_signers];
+    wait_for(30, || {
+        for (ix, state) in signer_test.get_states(&exclude).into_iter().enumerate() {
+            if state.is_some() {
+                states[ix] = state;
+            }
+        }
+        Ok(states.iter().all(Option::is_some))
+    })
+    .expect("Timed out waiting for signer status responses");
+
+    for (ix, state) in states.into_iter().enumerate() {
+        let state = state.unwrap();
+        assert_eq!(
+            state.runloop_state,
+            State::RegisteredSigners,
+            "Signer #{ix} is not registered"
+        );
+        assert!(
+            state.running_signers.contains(&reward_cycle),
+            "Signer #{ix} is not running reward cycle {reward_cycle}: {:?}",
+            state.running_signers
+        );
+    }
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a signer re-broadcasts its stored block response, unchanged, when a miner
+/// requests it.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0 and a Nakamoto block is mined. The signers' responses to
+/// it are recorded, the test observer's view of them is cleared, and a `ResponseRequest` for the
+/// block is posted to the miner's block pushed slot.
+///
+/// Test Assertion:
+/// Every signer re-broadcasts a response to the block that is identical to its original response.
+fn signer_rebroadcasts_response_on_request() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Mine Nakamoto Block -------------------------");
+    signer_test.mine_nakamoto_block(timeout, true);
+    let signer_signature_hash = test_observer::get_mined_nakamoto_blocks()
+        .last()
+        .expect("No Nakamoto block was mined")
+        .signer_signature_hash;
+
+    let get_responses = || {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                let message = SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                    .expect("Failed to deserialize SignerMessage");
+                match message {
+                    SignerMessage::BlockResponse(response)
+                        if response.signer_signature_hash() == signer_signature_hash =>
+                    {
+                        Some(response)
+                    }
+                    _ => None,
+                }
+            })
+            .collect::<Vec<_>>()
+    };
+
+    let mut original_responses = vec![];
+    wait_for(timeout.as_secs(), || {
+        original_responses = get_responses();
+        Ok(original_responses.len() == num_signers)
+    })
+    .expect("Timed out waiting for every signer to respond to the block");
+
+    info!("------------------------- Request Responses -------------------------");
+    test_observer::clear();
+    // use the block pushed slot, so the request does not overwrite the block's proposal
+    signer_test.send_miner_message(
+        SignerMessage::ResponseRequest {
+            signer_signature_hash,
+        },
+        MinerSlotID::BlockPushed,
+        timeout,
+    );
+
+    info!("------------------------- Verify Re-broadcast Responses -------------------------");
+    let mut rebroadcast_responses = vec![];
+    wait_for(timeout.as_secs(), || {
+        rebroadcast_responses = get_responses();
+        Ok(rebroadcast_responses.len() >= num_signers)
+    })
+    .expect("Timed out waiting for the signers to re-broadcast their responses");
+
+    for response in rebroadcast_responses {
+        assert!(
+            original_responses.contains(&response),
+            "Re-broadcast response differs from every original response: {response:?}"
+        );
+    }
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that the node and the signers agree on the current reward cycle at every burn block
+/// around a reward cycle boundary.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0 and Nakamoto tenures are mined up to just before the next
+/// reward cycle boundary. Tenures are then mined one at a time until the node reports the next
+/// reward cycle.
+///
+/// Test Assertion:
+/// After each tenure, once the signers have processed its burn block, the node and every signer
+/// report the same current reward cycle.
+fn reward_cycle_consensus_at_boundary() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(60);
+    signer_test.boot_to_epoch_3();
+
+    let curr_reward_cycle = signer_test.get_current_reward_cycle();
+    let next_reward_cycle = curr_reward_cycle + 1;
+    let reward_cycle_height_boundary = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_burnchain()
+        .reward_cycle_to_block_height(next_reward_cycle)
+        - 2;
+
+    info!("------------------------- Mine to Reward Cycle Boundary -------------------------");
+    signer_test.run_until_burnchain_height_nakamoto(
+        timeout,
+        reward_cycle_height_boundary,
+        num_signers,
+    );
+    assert_eq!(signer_test.get_current_reward_cycle(), curr_reward_cycle);
+    signer_test.wait_for_cycle(30, curr_reward_cycle);
+    signer_test.assert_reward_cycle_consensus();
+
+    info!("------------------------- Cross Reward Cycle Boundary -------------------------");
+    // The node may report the next reward cycle one burn block before or after the boundary, so
+    // check consensus at every burn block until it does.
+    while signer_test.get_current_reward_cycle() < next_reward_cycle {
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+        let node_reward_cycle = signer_test.get_current_reward_cycle();
+        signer_test.wait_for_cycle(30, node_reward_cycle);
+        signer_test.assert_reward_cycle_consensus();
+    }
+    assert_eq!(signer_test.get_current_reward_cycle(), next_reward_cycle);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers defer block proposals that are too far ahead of their burn view, rather
+/// than rejecting them.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, configured with `max_burn_height_ahead = 10`, one miner
+/// Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// An invalid block is proposed to the signers with a burn height 100 blocks ahead of the current
+/// burn height. The same block is then proposed with the current burn height.
+///
+/// Test Assertion:
+/// No signer responds to the far-future proposal. Every signer rejects the block once it is
+/// proposed at the current burn height, showing the first proposal was not recorded.
+fn block_proposal_deferred_when_ahead() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let max_burn_height_ahead = 10;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |config| {
+            config.max_burn_height_ahead = max_burn_height_ahead;
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let short_timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    let mut block = NakamotoBlock {
+        header: NakamotoBlockHeader::empty(),
+        txs: vec![],
+    };
+    block.header.timestamp = get_epoch_time_secs();
+    let block_signer_signature_hash = block.header.signer_signature_hash();
+
+    let count_responses = || {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()).ok()
+            })
+            .filter(|message| {
+                matches!(
+                    message,
+                    SignerMessage::BlockResponse(response)
+                        if response.signer_signature_hash() == block_signer_signature_hash
+                )
+            })
+            .count()
+    };
+
+    info!("------------------------- Propose Far-Future Block -------------------------");
+    let burn_height = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    signer_test.send_miner_message(
+        SignerMessage::BlockProposal(BlockProposal {
+            block: block.clone(),
+            burn_height: burn_height + 100,
+            reward_cycle,
+            block_proposal_data: BlockProposalData::empty(),
+        }),
+        MinerSlotID::BlockProposal,
+        short_timeout,
+    );
+
+    info!("------------------------- Verify Proposal Deferred -------------------------");
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(10) {
+        assert_eq!(
+            count_responses(),
+            0,
+            "Signers responded to a proposal too far ahead of their burn view"
+        );
+        std::thread::sleep(Duration::from_secs(1));
+    }
+
+    info!(
+        "------------------------- Propose Block at Current Burn Height -------------------------"
+    );
+    signer_test.propose_block(block, short_timeout);
+    wait_for(short_timeout.as_secs(), || {
+        Ok(count_responses() == num_signers)
+    })
+    .expect("Timed out waiting for the signers to respond to the block proposal");
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a run in which every block is accepted leaves no rejections in the signers' block
+/// response slots.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// The network is booted to epoch 3.0 and three Nakamoto tenures are mined.
+///
+/// Test Assertion:
+/// The tally of rejections for the current reward cycle is empty.
+fn clean_run_has_no_rejections() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Mine Nakamoto Tenures -------------------------");
+    for _ in 0..3 {
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    }
+
+    info!("------------------------- Verify No Rejections -------------------------");
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let rejections = signer_test.collect_all_rejections(reward_cycle);
+    assert!(
+        rejections.is_empty(),
+        "Unexpected rejections in a clean run: {rejections:?}"
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a signer abstains on the kinds of block its signing policy excludes
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The first signer is configured to sign every kind of block except empty blocks.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines an empty tenure block, and then a block containing a STX transfer.
+///
+/// Test Assertion:
+/// The first signer sends no response for the empty block, and accepts the block with the transfer.
+/// The other signers sign both blocks.
+fn signer_abstains_on_excluded_block_kinds() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![(sender_addr, send_amt + send_fee)],
+        |config| {
+            if config.endpoint.port() == 3000 {
+                config.sign_block_kinds = BlockKindMask::ALL.without(BlockKind::Empty);
+            }
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let abstaining_slot = signer_test
+        .get_signer_slot_id(
+            reward_cycle,
+            &tests::to_addr(&signer_test.signer_stacks_private_keys[0]),
+        )
+        .expect("Failed to get signer slot id")
+        .expect("Signer does not have a slot id");
+    let contract_id = MessageSlotID::BlockResponse
+        .stacker_db_contract(signer_test.running_nodes.conf.is_mainnet(), reward_cycle);
+    let abstaining_signer_response = |signer_signature_hash: Sha512Trunc256Sum| {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .filter(|event| event.contract_id == contract_id)
+            .flat_map(|event| event.modified_slots)
+            .filter(|chunk| chunk.slot_id == abstaining_slot.0)
+            .find_map(|chunk| {
+                let Ok(SignerMessage::BlockResponse(response)) =
+                    SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                else {
+                    return None;
+                };
+                (response.get_signer_signature_hash() == signer_signature_hash).then_some(response)
+            })
+    };
+
+    info!("------------------------- Mine Empty Tenure Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let empty_block = test_observer::get_mined_nakamoto_blocks().pop().unwrap();
+    assert!(
+        empty_block.tx_events.iter().all(|event| {
+            !matches!(
+                event,
+                TransactionEvent::Success(TransactionSuccessEvent { fee, .. }) if *fee > 0
+            )
+        }),
+        "Expected the tenure block to carry no user transactions"
+    );
+    // The other signers have signed the block, so give the abstaining signer time to respond
+    thread::sleep(Duration::from_secs(5));
+    assert!(
+        abstaining_signer_response(empty_block.signer_signature_hash).is_none(),
+        "Expected the first signer to abstain on the empty block"
+    );
+
+    info!("------------------------- Mine Block With Transfer -------------------------");
+    let txids = signer_test.submit_transfers(&sender_sk, 1, send_fee, send_amt, &recipient);
+    let block_height = signer_test.wait_for_tx_in_block(&txids[0], timeout.as_secs());
+    let transfer_block = test_observer::get_mined_nakamoto_blocks()
+        .into_iter()
+        .find(|block| block.stacks_height == block_height)
+        .expect("Failed to find the block with the transfer");
+    wait_for(30, || {
+        Ok(matches!(
+            abstaining_signer_response(transfer_block.signer_signature_hash),
+            Some(BlockResponse::Accepted(..))
+        ))
+    })
+    .expect("Timed out waiting for the first signer to accept the block with the transfer");
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that signers ignore block proposals for a reward cycle that has not started yet, and
+/// count them
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// A block is proposed to the signers with a reward cycle after the current one.
+///
+/// Test Assertion:
+/// No signer responds to the proposal, and the signers' `stacks_signer_future_cycle_proposals_total`
+/// counter is incremented.
+fn block_proposal_for_future_reward_cycle_ignored() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let short_timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    let mut block = NakamotoBlock {
+        header: NakamotoBlockHeader::empty(),
+        txs: vec![],
+    };
+    block.header.timestamp = get_epoch_time_secs();
+    let block_signer_signature_hash = block.header.signer_signature_hash();
+
+    info!("------------------------- Propose Future Cycle Block -------------------------");
+    let burn_height = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    signer_test.send_miner_message(
+        SignerMessage::BlockProposal(BlockProposal {
+            block,
+            burn_height,
+            reward_cycle: reward_cycle + 1,
+            block_proposal_data: BlockProposalData::empty(),
+        }),
+        MinerSlotID::BlockProposal,
+        short_timeout,
+    );
+
+    info!("------------------------- Verify Proposal Ignored -------------------------");
+    let metric_value = |metrics: &str, name: &str| -> u64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .map(|value| value as u64)
+            .unwrap_or(0)
+    };
+    wait_for(short_timeout.as_secs(), || {
+        let metrics = signer_test.get_signer_metrics();
+        Ok(metric_value(&metrics, "stacks_signer_future_cycle_proposals_total") > 0)
+    })
+    .expect("Timed out waiting for the future cycle proposals metric to count the proposal");
+
+    let start = Instant::now();
+    while start.elapsed() < Duration::from_secs(10) {
+        let num_responses = test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()).ok()
+            })
+            .filter(|message| {
+                matches!(
+                    message,
+                    SignerMessage::BlockResponse(response)
+                        if response.signer_signature_hash() == block_signer_signature_hash
+                )
+            })
+            .count();
+        assert_eq!(
+            num_responses, 0,
+            "Signers responded to a proposal for a future reward cycle"
+        );
+        std::thread::sleep(Duration::from_secs(1));
+    }
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that signers record the margin by which blocks reach the signing threshold
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a Nakamoto block.
+///
+/// Test Assertion:
+/// The signer's `stacks_signer_finalization_margin_weight` histogram gains exactly one observation
+/// for the block, even though more signatures arrive after the threshold is reached, and the
+/// observed margin is the weight of the signatures that first reached the threshold, less the
+/// threshold.
+fn signer_metrics_record_finalization_margin() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    let metric_value = |metrics: &str, name: &str| -> f64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+    let metrics = signer_test.get_signer_metrics();
+    let observations_before =
+        metric_value(&metrics, "stacks_signer_finalization_margin_weight_count");
+    let margin_before = metric_value(&metrics, "stacks_signer_finalization_margin_weight_sum");
+
+    // the signers are equally weighted, so the threshold is first reached by the fewest
+    // signatures whose weight covers it
+    let signers = signer_test.get_reward_set_signers(signer_test.get_current_reward_cycle());
+    let signer_weight = u64::from(signers[0].weight);
+    assert!(signers
+        .iter()
+        .all(|signer| u64::from(signer.weight) == signer_weight));
+    let threshold = SignerTest::<SpawnedSigner>::threshold_weight_of(&signers);
+    let expected_margin = threshold.div_ceil(signer_weight) * signer_weight - threshold;
+
+    info!("------------------------- Test Mine Nakamoto Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Test Metrics -------------------------");
+    wait_for(timeout.as_secs(), || {
+        let metrics = signer_test.get_signer_metrics();
+        Ok(
+            metric_value(&metrics, "stacks_signer_finalization_margin_weight_count")
+                > observations_before,
+        )
+    })
+    .expect("Timed out waiting for a finalization margin to be recorded");
+
+    // give the signer time to process the signatures that arrive after the threshold
+    std::thread::sleep(Duration::from_secs(5));
+    let metrics = signer_test.get_signer_metrics();
+    assert_eq!(
+        metric_value(&metrics, "stacks_signer_finalization_margin_weight_count"),
+        observations_before + 1.0
+    );
+    assert_eq!(
+        metric_value(&metrics, "stacks_signer_finalization_margin_weight_sum") - margin_before,
+        expected_margin as f64
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `assert_advanced_tenures` counts tenures rather than blocks
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a tenure, then a second block in the same tenure containing a STX transfer.
+///
+/// Test Assertion:
+/// The chain advanced by two blocks, and `assert_advanced_tenures` accepts a count of one tenure
+/// but rejects a count of two.
+fn assert_advanced_tenures_counts_multi_block_tenure() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> =
+        SignerTest::new(num_signers, vec![(sender_addr, send_amt + send_fee)]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    let before_height = get_chain_info(&signer_test.running_nodes.conf).stacks_tip_height;
+
+    info!("------------------------- Mine Two Blocks In One Tenure -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let tenure_start_height = get_chain_info(&signer_test.running_nodes.conf).stacks_tip_height;
+    let txids = signer_test.submit_transfers(&sender_sk, 1, send_fee, send_amt, &recipient);
+    let transfer_height = signer_test.wait_for_tx_in_block(&txids[0], timeout.as_secs());
+    assert_eq!(transfer_height, tenure_start_height + 1);
+    wait_for(timeout.as_secs(), || {
+        Ok(get_nakamoto_headers(&signer_test.running_nodes.conf)
+            .iter()
+            .any(|header| header.stacks_block_height == transfer_height))
+    })
+    .expect("Timed out waiting for the transfer block to be processed");
+
+    info!("------------------------- Verify Tenure Count -------------------------");
+    assert_eq!(transfer_height, before_height + 2);
+    signer_test.assert_advanced_tenures(before_height, 1);
+    let check = std::panic::catch_unwind(std::panic::AssertUnwindSafe(|| {
+        signer_test.assert_advanced_tenures(before_height, 2)
+    }));
+    assert!(
+        check.is_err(),
+        "Expected two blocks in one tenure to count as one tenure"
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers report the height of the last burn block they processed
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// Several burn blocks are mined.
+///
+/// Test Assertion:
+/// Every signer's reported burn block height is within one block of the node's burn block height.
+fn signers_report_last_processed_burn_height() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let num_burn_blocks = 3;
+    let max_lag = 1;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Mine Burn Blocks -------------------------");
+    for _ in 0..num_burn_blocks {
+        signer_test.mine_nakamoto_block(timeout, true);
+    }
+
+    info!("------------------------- Verify Signer Burn Heights -------------------------");
+    let node_burn_height = get_chain_info(&signer_test.running_nodes.conf).burn_block_height;
+    for signer_ix in 0..num_signers {
+        wait_for(timeout.as_secs(), || {
+            let signer_burn_height = signer_test.signer_burn_height(signer_ix);
+            info!("Signer #{signer_ix} burn height: {signer_burn_height}, node burn height: {node_burn_height}");
+            Ok(signer_burn_height + max_lag >= node_burn_height)
+        })
+        .unwrap_or_else(|_| {
+            panic!("Signer #{signer_ix} fell more than {max_lag} burn blocks behind the node")
+        });
+        assert!(signer_test.signer_burn_height(signer_ix) <= node_burn_height);
+    }
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `capture_block_timeline` records a block's lifecycle in order
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a Nakamoto block while its timeline is captured.
+///
+/// Test Assertion:
+/// Every signer's response is captured, and the timeline's events are ordered as
+/// proposal <= validate-ok <= response <= finalization.
+fn capture_block_timeline_is_ordered() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Test Capture Block Timeline -------------------------");
+    let timeline = signer_test.capture_block_timeline(timeout);
+    info!("Captured block timeline"; "timeline" => ?timeline);
+
+    let tip = signer_test.get_peer_info().stacks_tip;
+    assert!(signer_test
+        .get_miner_proposal_messages()
+        .iter()
+        .any(|proposal| proposal.block.header.block_hash() == tip
+            && proposal.block.header.signer_signature_hash() == timeline.signer_signature_hash));
+    assert!(!timeline.validate_ok.is_empty());
+    assert_eq!(timeline.responses.len(), num_signers);
+    assert!(
+        timeline.is_ordered(),
+        "Block timeline events are out of order: {timeline:?}"
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that signers give a miner a grace period after a burnchain reorg before marking it as
+/// having timed out
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers use a short block proposal timeout, and a longer burnchain reorg grace period.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The miner wins a tenure while its block proposals are stalled, and the burn block with that
+/// sortition is replaced by a shallow fork. The miner's proposals are released after the block
+/// proposal timeout, but within the grace period. Then the miner wins another tenure while its
+/// proposals are stalled, that burn block is forked out too, and the proposals are only released
+/// after the grace period.
+///
+/// Test Assertion:
+/// The signers accept the miner's block after the first reorg, and reject its block after the
+/// second.
+fn signers_wait_for_reorg_grace_period_before_flagging_miner() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let block_proposal_timeout = Duration::from_secs(5);
+    let burnchain_reorg_grace_period = Duration::from_secs(40);
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |config| {
+            config.block_proposal_timeout = block_proposal_timeout;
+            config.burnchain_reorg_grace_period = burnchain_reorg_grace_period;
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let miner_sk = signer_test.running_nodes.conf.miner.mining_key.unwrap();
+    let miner_pk = StacksPublicKey::from_private(&miner_sk);
+    let short_timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    // Mine a tenure whose proposals are stalled, then replace its burn block with a shallow fork.
+    // The commit that won the sortition is mined again in the forking burn block.
+    let mine_stalled_tenure_and_fork = |signer_test: &mut SignerTest<SpawnedSigner>| {
+        TEST_BROADCAST_PROPOSAL_STALL.set(vec![miner_pk]);
+        let commits_before = signer_test
+            .running_nodes
+            .commits_submitted
+            .load(Ordering::SeqCst);
+        next_block_and(
+            &mut signer_test.running_nodes.btc_regtest_controller,
+            60,
+            || {
+                let commits_count = signer_test
+                    .running_nodes
+                    .commits_submitted
+                    .load(Ordering::SeqCst);
+                Ok(commits_count > commits_before)
+            },
+        )
+        .unwrap();
+
+        let burn_block_height = get_chain_info(&signer_test.running_nodes.conf).burn_block_height;
+        let burn_header_hash_to_fork = signer_test
+            .running_nodes
+            .btc_regtest_controller
+            .get_block_hash(burn_block_height);
+        signer_test
+            .running_nodes
+            .btc_regtest_controller
+            .invalidate_block(&burn_header_hash_to_fork);
+        signer_test
+            .running_nodes
+            .btc_regtest_controller
+            .build_next_block(1);
+        wait_for(short_timeout.as_secs(), || {
+            let info = get_chain_info(&signer_test.running_nodes.conf);
+            Ok(info.burn_block_height == burn_block_height
+                && signer_test
+                    .running_nodes
+                    .btc_regtest_controller
+                    .get_block_hash(burn_block_height)
+                    != burn_header_hash_to_fork)
+        })
+        .expect("Timed out waiting for the node to process the shallow fork");
+    };
+
+    info!("------------------------- Test Release Proposals Within Grace Period -------------------------");
+    signer_test.mine_nakamoto_block(short_timeout, true);
+    mine_stalled_tenure_and_fork(&mut signer_test);
+    let blocks_before = signer_test.running_nodes.nakamoto_blocks_mined.get();
+    let rejected_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_rejected
+        .load(Ordering::SeqCst);
+    std::thread::sleep(block_proposal_timeout.add(Duration::from_secs(5)));
+    TEST_BROADCAST_PROPOSAL_STALL.set(vec![]);
+
+    wait_for(short_timeout.as_secs(), || {
+        Ok(signer_test.running_nodes.nakamoto_blocks_mined.get() > blocks_before)
+    })
+    .expect("Timed out waiting for the signers to accept the miner's block after the reorg");
+    assert_eq!(
+        signer_test
+            .running_nodes
+            .nakamoto_blocks_rejected
+            .load(Ordering::SeqCst),
+        rejected_before,
+        "Signers rejected the miner's block during the reorg grace period"
+    );
+
+    info!("------------------------- Test Release Proposals After Grace Period -------------------------");
+    mine_stalled_tenure_and_fork(&mut signer_test);
+    let rejected_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_rejected
+        .load(Ordering::SeqCst);
+    std::thread::sleep(burnchain_reorg_grace_period.add(Duration::from_secs(1)));
+    TEST_BROADCAST_PROPOSAL_STALL.set(vec![]);
+
+    wait_for(short_timeout.as_secs(), || {
+        Ok(signer_test
+            .running_nodes
+            .nakamoto_blocks_rejected
+            .load(Ordering::SeqCst)
+            > rejected_before)
+    })
+    .expect("Timed out waiting for the signers to reject the stalled miner's block");
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `assert_slot_empty_for` passes for the block response slots of a reward cycle whose
+/// signers have not started signing yet
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines a Nakamoto block, which the signers of the current reward cycle sign.
+///
+/// Test Assertion:
+/// The block response slots of the next reward cycle stay empty.
+fn assert_slot_empty_for_next_reward_cycle() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+
+    info!("------------------------- Test Mine Nakamoto Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Test Next Cycle Slots Are Empty -------------------------");
+    signer_test.assert_slot_empty_for(
+        MessageSlotID::BlockResponse,
+        reward_cycle + 1,
+        Duration::from_secs(10),
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `mined_blocks_between` selects the mined blocks by height
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The node mines three Nakamoto blocks.
+///
+/// Test Assertion:
+/// A range covering only the middle block's height returns exactly that block, and a range
+/// covering all three heights returns all three blocks in order.
+fn mined_blocks_between_selects_by_height() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Test Mine Three Nakamoto Blocks -------------------------");
+    let start_height = signer_test.get_peer_info().stacks_tip_height;
+    for _ in 0..3 {
+        signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    }
+    let end_height = signer_test.get_peer_info().stacks_tip_height;
+    assert_eq!(end_height, start_height + 3);
+
+    info!("------------------------- Test Select Mined Blocks -------------------------");
+    let middle_height = start_height + 2;
+    let middle = signer_test.mined_blocks_between(middle_height, middle_height);
+    assert_eq!(middle.len(), 1);
+    assert_eq!(middle[0].stacks_height, middle_height);
+
+    let all = signer_test.mined_blocks_between(start_height + 1, end_height);
+    let heights: Vec<_> = all.iter().map(|block| block.stacks_height).collect();
+    assert_eq!(heights, vec![start_height + 1, middle_height, end_height]);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that signers count undecodable messages from other signers and keep signing blocks
+///
+/// Test Setup:
+/// The test spins up five stacks signers, configured to re-read a signer slot after a single
+/// undecodable message from it, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// Garbage is written into the first signer's block response slot, and then the node mines a
+/// Nakamoto block.
+///
+/// Test Assertion:
+/// The signers' `stacks_signer_undecodable_messages_total` counter increments, and the block is
+/// still signed by every signer.
+fn signer_counts_undecodable_messages() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |config| {
+            config.undecodable_message_resync_threshold = Some(1);
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+
+    let metric_value = |metrics: &str, name: &str| -> u64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+    let undecodable_before = metric_value(
+        &signer_test.get_signer_metrics(),
+        "stacks_signer_undecodable_messages_total",
+    );
+
+    info!("------------------------- Test Write Garbage Response -------------------------");
+    let garbage_signer = &signer_test.signer_stacks_private_keys[0];
+    let mut stackerdb = StackerDB::new_normal(
+        &signer_test.running_nodes.conf.node.rpc_bind,
+        garbage_signer.clone(),
+        false,
+        reward_cycle,
+        signer_test
+            .get_signer_slot_id(reward_cycle, &tests::to_addr(garbage_signer))
+            .expect("Failed to get signer slot id")
+            .expect("Signer does not have a slot id"),
+    );
+    let ack = stackerdb
+        .send_message_bytes_with_retry(&MessageSlotID::BlockResponse, vec![0xff; 32])
+        .expect("Failed to write garbage to the signer's slot");
+    assert!(ack.accepted);
+
+    info!("------------------------- Test Undecodable Messages Counted -------------------------");
+    wait_for(timeout.as_secs(), || {
+        let metrics = signer_test.get_signer_metrics();
+        Ok(metric_value(&metrics, "stacks_signer_undecodable_messages_total") > undecodable_before)
+    })
+    .expect("Timed out waiting for the undecodable message to be counted");
+
+    info!("------------------------- Test Signers Keep Signing -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that the reward set signers can be fetched page by page
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The reward set signers of the current reward cycle are fetched two at a time, following the
+/// `next` link of each page until there are no more pages.
+///
+/// Test Assertion:
+/// The pages span several requests, and reassemble into the signers returned by
+/// `get_reward_set_signers`.
+fn reward_set_signers_paginated() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let http_origin = format!("http://{}", &signer_test.running_nodes.conf.node.rpc_bind);
+
+    info!("------------------------- Test Fetch Reward Set Pages -------------------------");
+    let page_size = 2;
+    let mut signers: Vec<NakamotoSignerEntry> = vec![];
+    let mut num_pages = 0;
+    let mut next_page = Some(0);
+    while let Some(page) = next_page {
+        let url = format!(
+            "{http_origin}/v3/stacker_set/{reward_cycle}/signers?page={page}&page_size={page_size}"
+        );
+        info!("Send request: GET {url}");
+        let response =
+            reqwest::blocking::get(&url).unwrap_or_else(|e| panic!("GET request failed: {e}"));
+        assert!(response.status().is_success());
+        next_page = response
+            .headers()
+            .get("link")
+            .and_then(|link| link.to_str().ok())
+            .and_then(parse_next_page);
+        let page_signers = response.json::<Vec<NakamotoSignerEntry>>().unwrap();
+        assert!(page_signers.len() <= page_size);
+        signers.extend(page_signers);
+        num_pages += 1;
+    }
+
+    info!("------------------------- Test Reassembled Reward Set -------------------------");
+    let expected_signers = signer_test.get_reward_set_signers(reward_cycle);
+    assert_eq!(signers.len(), expected_signers.len());
+    assert_eq!(signers, expected_signers);
+    assert_eq!(num_pages, expected_signers.len().div_ceil(page_size));
+    assert!(num_pages > 1);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a block proposal reaches every signer when the signers are split across two nodes
+///
+/// Test Setup:
+/// The test spins up five stacks signers and two miner Nakamoto nodes, with roughly half of the
+/// signers using each node for RPC and events, and a corresponding bitcoind.
+/// The stacks nodes are then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// A tenure is started and its block is mined.
+///
+/// Test Assertion:
+/// All five signers respond to the mined block's proposal, whichever node they are attached to.
+fn all_signers_saw_proposal_across_nodes() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let timeout = Duration::from_secs(30);
+
+    let btc_miner_1_seed = vec![1, 1, 1, 1];
+    let btc_miner_2_seed = vec![2, 2, 2, 2];
+    let btc_miner_1_pk = Keychain::default(btc_miner_1_seed.clone()).get_pub_key();
+    let btc_miner_2_pk = Keychain::default(btc_miner_2_seed.clone()).get_pub_key();
+
+    let node_1_rpc = gen_random_port();
+    let node_1_p2p = gen_random_port();
+    let node_2_rpc = gen_random_port();
+    let node_2_p2p = gen_random_port();
+
+    let localhost = "127.0.0.1";
+    let node_1_rpc_bind = format!("{localhost}:{node_1_rpc}");
+    let node_2_rpc_bind = format!("{localhost}:{node_2_rpc}");
+    let mut node_2_listeners = Vec::new();
+
+    // partition the signer set so that ~half are listening and using node 1 for RPC and events,
+    //  and the rest are using node 2
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |signer_config| {
+            let node_host = if signer_config.endpoint.port() % 2 == 0 {
+                &node_1_rpc_bind
+            } else {
+                &node_2_rpc_bind
+            };
+            signer_config.node_host = node_host.to_string();
+        },
+        |config| {
+            config.node.rpc_bind = format!("{localhost}:{node_1_rpc}");
+            config.node.p2p_bind = format!("{localhost}:{node_1_p2p}");
+            config.node.data_url = format!("http://{localhost}:{node_1_rpc}");
+            config.node.p2p_address = format!("{localhost}:{node_1_p2p}");
+            config.node.pox_sync_sample_secs = 30;
+
+            config.node.seed = btc_miner_1_seed.clone();
+            config.node.local_peer_seed = btc_miner_1_seed.clone();
+            config.burnchain.local_mining_public_key = Some(btc_miner_1_pk.to_hex());
+            config.miner.mining_key = Some(Secp256k1PrivateKey::from_seed(&[1]));
+
+            config.events_observers.retain(|listener| {
+                let Ok(addr) = std::net::SocketAddr::from_str(&listener.endpoint) else {
+                    warn!(
+                        "Cannot parse {} to a socket, assuming it isn't a signer-listener binding",
+                        listener.endpoint
+                    );
+                    return true;
+                };
+                if addr.port() % 2 == 0 || addr.port() == test_observer::EVENT_OBSERVER_PORT {
+                    return true;
+                }
+                node_2_listeners.push(listener.clone());
+                false
+            })
+        },
+        Some(vec![btc_miner_1_pk, btc_miner_2_pk]),
+        None,
+    );
+    let conf = signer_test.running_nodes.conf.clone();
+    let mut conf_node_2 = conf.clone();
+    conf_node_2.node.rpc_bind = format!("{localhost}:{node_2_rpc}");
+    conf_node_2.node.p2p_bind = format!("{localhost}:{node_2_p2p}");
+    conf_node_2.node.data_url = format!("http://{localhost}:{node_2_rpc}");
+    conf_node_2.node.p2p_address = format!("{localhost}:{node_2_p2p}");
+    conf_node_2.node.seed = btc_miner_2_seed.clone();
+    conf_node_2.burnchain.local_mining_public_key = Some(btc_miner_2_pk.to_hex());
+    conf_node_2.node.local_peer_seed = btc_miner_2_seed;
+    conf_node_2.miner.mining_key = Some(Secp256k1PrivateKey::from_seed(&[2]));
+    conf_node_2.node.miner = true;
+    conf_node_2.events_observers.clear();
+    conf_node_2.events_observers.extend(node_2_listeners);
+    assert!(!conf_node_2.events_observers.is_empty());
+
+    let node_1_sk = Secp256k1PrivateKey::from_seed(&conf.node.local_peer_seed);
+    let node_1_pk = StacksPublicKey::from_private(&node_1_sk);
+
+    conf_node_2.node.working_dir = format!("{}-1", conf_node_2.node.working_dir);
+
+    conf_node_2.node.set_bootstrap_nodes(
+        format!("{}@{}", &node_1_pk.to_hex(), conf.node.p2p_bind),
+        conf.burnchain.chain_id,
+        conf.burnchain.peer_version,
+    );
+
+    let mut run_loop_2 = boot_nakamoto::BootRunLoop::new(conf_node_2.clone()).unwrap();
+    let run_loop_stopper_2 = run_loop_2.get_termination_switch();
+    let rl2_coord_channels = run_loop_2.coordinator_channels();
+    let rl2_counters = run_loop_2.counters();
+    let run_loop_2_thread = thread::Builder::new()
+        .name("run_loop_2".into())
+        .spawn(move || run_loop_2.start(None, 0))
+        .unwrap();
+
+    signer_test.boot_to_epoch_3();
+
+    wait_for(120, || {
+        let Some(node_1_info) = get_chain_info_opt(&conf) else {
+            return Ok(false);
+        };
+        let Some(node_2_info) = get_chain_info_opt(&conf_node_2) else {
+            return Ok(false);
+        };
+        Ok(node_1_info.stacks_tip_height == node_2_info.stacks_tip_height)
+    })
+    .expect("Timed out waiting for boostrapped node to catch up to the miner");
+
+    info!("------------------------- Test Mine Block -------------------------");
+    let rl1_counters = signer_test.running_nodes.counters.clone();
+    signer_test.mine_block_wait_on_processing(
+        &[&conf, &conf_node_2],
+        &[&rl1_counters, &rl2_counters],
+        timeout,
+    );
+    let mined_block = test_observer::get_mined_nakamoto_blocks()
+        .pop()
+        .expect("No Nakamoto block was mined");
+
+    info!("------------------------- Test All Signers Saw Proposal -------------------------");
+    signer_test.assert_all_signers_saw_proposal(&mined_block.signer_signature_hash, timeout);
+
+    rl2_coord_channels
+        .lock()
+        .expect("Mutex poisoned")
+        .stop_chains_coordinator();
+    run_loop_stopper_2.store(false, Ordering::SeqCst);
+    run_loop_2_thread.join().unwrap();
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that a restarted signer does not re-sign a block it signed before the restart
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// A Nakamoto block is mined and signed. The last signer is restarted, and the miner's proposal
+/// for the signed block is written to the miner slot again.
+///
+/// Test Assertion:
+/// The restarted signer's `stacks_signer_resign_skipped_total` counter increments, and it writes
+/// no second response to the block: it has a single BlockResponse chunk for the block, and none of
+/// its StackerDB slot versions change after the re-proposal.
+fn signer_skips_resigning_after_restart() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    let metric_value = |metrics: &str, name: &str| -> u64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<u64>().ok())
+            .unwrap_or(0)
+    };
+
+    info!("------------------------- Test Mine Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let signer_signature_hash = test_observer::get_mined_nakamoto_blocks()
+        .pop()
+        .expect("No Nakamoto block was mined")
+        .signer_signature_hash;
+    let proposal = test_observer::get_stackerdb_chunks()
+        .into_iter()
+        .flat_map(|chunk| chunk.modified_slots)
+        .find_map(|chunk| {
+            match SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()).ok()? {
+                SignerMessage::BlockProposal(proposal)
+                    if proposal.block.header.signer_signature_hash() == signer_signature_hash =>
+                {
+                    Some(proposal)
+                }
+                _ => None,
+            }
+        })
+        .expect("Failed to find the proposal for the mined block");
+
+    let restarted_ix = num_signers - 1;
+    let restarted_pk =
+        StacksPublicKey::from_private(&signer_test.signer_stacks_private_keys[restarted_ix]);
+    signer_test
+        .wait_for_block_acceptance(timeout.as_secs(), &signer_signature_hash, &[restarted_pk])
+        .expect("Timed out waiting for the signer to sign the block");
+
+    // the chunks the restarted signer has written to its StackerDB slots
+    let restarted_signer_chunks = || {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter(|chunk| chunk.recover_pk().is_ok_and(|pk| pk == restarted_pk))
+            .collect::<Vec<_>>()
+    };
+    let slot_versions = |chunks: &[StackerDBChunkData]| {
+        let mut versions = HashMap::new();
+        for chunk in chunks {
+            let version = versions.entry(chunk.slot_id).or_insert(chunk.slot_version);
+            *version = (*version).max(chunk.slot_version);
+        }
+        versions
+    };
+
+    info!("------------------------- Test Restart Signer -------------------------");
+    signer_test.restart_signer(restarted_ix);
+    let skipped_before = metric_value(
+        &signer_test.get_signer_metrics_at(restarted_ix),
+        "stacks_signer_resign_skipped_total",
+    );
+    let slot_versions_before = slot_versions(&restarted_signer_chunks());
+
+    info!("------------------------- Test Re-Propose Block -------------------------");
+    signer_test.send_miner_message(
+        SignerMessage::BlockProposal(proposal),
+        MinerSlotID::BlockProposal,
+        timeout,
+    );
+    wait_for(timeout.as_secs(), || {
+        let metrics = signer_test.get_signer_metrics_at(restarted_ix);
+        Ok(metric_value(&metrics, "stacks_signer_resign_skipped_total") > skipped_before)
+    })
+    .expect("Timed out waiting for the skipped re-sign to be counted");
+
+    // give a response to the re-proposal time to reach the observer
+    std::thread::sleep(Duration::from_secs(5));
+    let chunks = restarted_signer_chunks();
+    assert_eq!(
+        slot_versions(&chunks),
+        slot_versions_before,
+        "The restarted signer wrote to StackerDB after the re-proposal"
+    );
+    let responses = chunks
+        .iter()
+        .filter(|chunk| {
+            matches!(
+                SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()),
+                Ok(SignerMessage::BlockResponse(response))
+                    if response.get_signer_signature_hash() == signer_signature_hash
+            )
+        })
+        .count();
+    assert_eq!(
+        responses, 1,
+        "The restarted signer responded to the block more than once"
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that the signing weight threshold matches 70% of the reward set's total weight
+///
+/// Test Setup:
+/// The test spins up five stacks signers that stack equal amounts, one miner Nakamoto node, and a
+/// corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The threshold weight is computed for the current reward set, and for reward sets with unequal
+/// weights.
+///
+/// Test Assertion:
+/// Each threshold is 70% of the total weight, rounded up, and is enough weight to finalize a block
+/// while any less is not.
+fn signer_threshold_weight_matches_reward_set() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+    let reward_cycle = signer_test.get_current_reward_cycle();
+
+    info!("------------------------- Test Equal Weights -------------------------");
+    let signers = signer_test.get_reward_set_signers(reward_cycle);
+    assert_eq!(signers.len(), num_signers);
+    assert!(signers
+        .iter()
+        .all(|signer| signer.weight == signers[0].weight));
+    let total_weight: u64 = signers.iter().map(|signer| u64::from(signer.weight)).sum();
+    let threshold = signer_test.threshold_weight(reward_cycle);
+    assert_eq!(threshold, (total_weight * 7).div_ceil(10));
+    assert!(threshold * 10 >= total_weight * 7);
+    assert!((threshold - 1) * 10 < total_weight * 7);
+
+    info!("------------------------- Test Unequal Weights -------------------------");
+    let with_weights = |weights: &[u32]| -> Vec<NakamotoSignerEntry> {
+        weights
+            .iter()
+            .map(|weight| NakamotoSignerEntry {
+                weight: *weight,
+                ..signers[0].clone()
+            })
+            .collect()
+    };
+    // (weights, expected threshold)
+    let cases: [(&[u32], u64); 4] = [
+        // 70% of 7 is 4.9
+        (&[3, 1, 1, 1, 1], 5),
+        // 70% of 20 is exactly 14
+        (&[10, 4, 3, 2, 1], 14),
+        // 70% of 3 is 2.1
+        (&[2, 1], 3),
+        // 70% of 101 is 70.7
+        (&[97, 1, 1, 1, 1], 71),
+    ];
+    for (weights, expected) in cases {
+        assert_eq!(
+            SignerTest::<SpawnedSigner>::threshold_weight_of(&with_weights(weights)),
+            expected,
+            "weights = {weights:?}"
+        );
+    }
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that an observer-only signer tracks the signer set without ever posting to StackerDB
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind,
+/// along with an observer-only signer that has no signing key and receives the node's events
+/// like the other signers. The stacks node is then advanced to Epoch 3.0 boundary to allow
+/// block signing.
+///
+/// Test Execution:
+/// A Nakamoto block is proposed, signed, and mined.
+///
+/// Test Assertion:
+/// The observer reports that it is running the current reward cycle and serves metrics, but none
+/// of the StackerDB chunks written while the block was signed are from the observer.
+fn observer_only_signer_posts_no_responses() {
+    use stacks_signer::runloop::{KeySelfCheck, SignerResult};
+
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let timeout = Duration::from_secs(30);
+    let rpc_bind = "127.0.0.1:51024".to_string();
+    let observer_port = 3000 + num_signers;
+    let observer_metrics_port = 9000 + num_signers;
+    // The key is ignored by an observer, which runs with an ephemeral one
+    let observer_toml = build_signer_config_tomls(
+        &[StacksPrivateKey::random()],
+        &rpc_bind,
+        Some(Duration::from_millis(128)), // Timeout defaults to 5 seconds. Let's override it to 128 milliseconds.
+        &Network::Testnet,
+        "12345",
+        rand::random(),
+        observer_port,
+        Some(100_000),
+        None,
+        Some(observer_metrics_port),
+        None,
+    )
+    .pop()
+    .unwrap();
+    let observer_config =
+        SignerConfig::load_from_str(&format!("{observer_toml}\nobserver_only = true\n")).unwrap();
+    assert!(observer_config.observer_only);
+    let observer_address = observer_config.stacks_address;
+
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![],
+        |_| {},
+        |naka_conf| {
+            naka_conf.events_observers.insert(EventObserverConfig {
+                endpoint: format!("{}", observer_config.endpoint),
+                events_keys: vec![
+                    EventKeyType::StackerDBChunks,
+                    EventKeyType::BlockProposal,
+                    EventKeyType::BurnchainBlocks,
+                ],
+                timeout_ms: 1000,
+            });
+            naka_conf.node.rpc_bind = rpc_bind.clone();
+        },
+        None,
+        None,
+    );
+    let observer = SpawnedSigner::new(observer_config);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Test Mine Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let reward_cycle = signer_test.get_current_reward_cycle();
+
+    info!("------------------------- Test Observer State -------------------------");
+    let client = reqwest::blocking::Client::new();
+    let response = client
+        .get(format!("http://localhost:{observer_port}/status"))
+        .send()
+        .expect("Failed to send status request to the observer");
+    assert!(response.status().is_success());
+    let state_info = observer
+        .res_recv
+        .recv_timeout(timeout)
+        .expect("Timed out waiting for the observer's status")
+        .into_iter()
+        .map(|SignerResult::StatusCheck(state_info)| state_info)
+        .next()
+        .expect("The observer did not report its status");
+    assert_eq!(state_info.runloop_state, State::RegisteredSigners);
+    assert!(state_info.running_signers.contains(&reward_cycle));
+    assert_eq!(state_info.key_self_check, KeySelfCheck::NotChecked);
+
+    let metrics = client
+        .get(format!("http://localhost:{observer_metrics_port}/metrics"))
+        .send()
+        .expect("Failed to fetch the observer's metrics")
+        .text()
+        .unwrap();
+    assert!(metrics.contains("stacks_signer_block_proposals_received"));
+
+    info!("------------------------- Test No Observer Responses -------------------------");
+    let chunks: Vec<_> = test_observer::get_stackerdb_chunks()
+        .into_iter()
+        .flat_map(|chunk| chunk.modified_slots)
+        .collect();
+    assert!(chunks.iter().any(|chunk| matches!(
+        SignerMessage::consensus_deserialize(&mut chunk.data.as_slice()),
+        Ok(SignerMessage::BlockResponse(_))
+    )));
+    assert!(
+        chunks
+            .iter()
+            .all(|chunk| !chunk.verify(&observer_address).unwrap_or(false)),
+        "The observer-only signer wrote to StackerDB"
+    );
+
+    observer.stop();
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `wait_for_signature_count` returns once enough signers have signed a block, and
+/// times out if too few have.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing. One signer
+/// ignores all block proposals.
+///
+/// Test Execution:
+/// A Nakamoto block is mined with the signatures of the other four signers, which meet the
+/// signing threshold.
+///
+/// Test Assertion:
+/// Waiting for four signatures returns promptly with four distinct signatures, and waiting for
+/// five times out.
+fn wait_for_signature_count_at_threshold() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    let ignoring_signer =
+        StacksPublicKey::from_private(&signer_test.signer_stacks_private_keys[num_signers - 1]);
+    TEST_IGNORE_ALL_BLOCK_PROPOSALS.set(vec![ignoring_signer]);
+
+    info!("------------------------- Test Mine Block -------------------------");
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+    let signer_signature_hash = test_observer::get_mined_nakamoto_blocks()
+        .pop()
+        .expect("No Nakamoto block was mined")
+        .signer_signature_hash;
+    let threshold_count = num_signers - 1;
+
+    info!("------------------------- Test Threshold Count -------------------------");
+    let start = Instant::now();
+    let signatures = signer_test
+        .wait_for_signature_count(&signer_signature_hash, threshold_count, timeout)
+        .expect("Failed to collect the threshold count of signatures");
+    assert!(
+        start.elapsed() < Duration::from_secs(5),
+        "Waited {:?} for signatures that were already available",
+        start.elapsed()
+    );
+    assert_eq!(signatures.len(), threshold_count);
+    assert_eq!(
+        signatures.iter().collect::<HashSet<_>>().len(),
+        threshold_count
+    );
+
+    info!("------------------------- Test Too Few Signatures -------------------------");
+    assert!(signer_test
+        .wait_for_signature_count(&signer_signature_hash, num_signers, Duration::from_secs(5))
+        .is_err());
+
+    TEST_IGNORE_ALL_BLOCK_PROPOSALS.set(vec![]);
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that a block which is mined and then forked away never becomes the chain tip again.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// Tenure B mines a block, which is stored but not processed before tenure C starts.  Tenure C is
+/// then mined off of tenure A, forking block B away, and tenure D builds on tenure C.
+///
+/// Test Assertion:
+/// Block B is not the chain tip after tenure C's blocks or tenure D's block is processed.
+fn forked_away_block_is_not_tip() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    let result = forked_tenure_testing(Duration::from_secs(360), Duration::from_secs(0), true);
+
+    info!("------------------------- Test Assertions -------------------------");
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_b, &result.tip_a);
+    SignerTest::<SpawnedSigner>::assert_parent_is(&result.mined_c, &result.tip_a);
+
+    let mined_b_hash = &result.mined_b.signer_signature_hash;
+    SignerTest::<SpawnedSigner>::assert_header_is_not(&result.tip_c, mined_b_hash);
+    SignerTest::<SpawnedSigner>::assert_header_is_not(
+        result
+            .tip_c_2
+            .as_ref()
+            .expect("Tenure C did not mine a second block"),
+        mined_b_hash,
+    );
+    SignerTest::<SpawnedSigner>::assert_header_is_not(&result.tip_d, mined_b_hash);
+}
+
+#[test]
+#[ignore]
+/// Test that an operator can make a signer re-evaluate a block it abstained from.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// One signer fails to submit block proposals for validation, as if it could not reach its node,
+/// while a block is mined and signed by the other signers.  The failure is then lifted, and the
+/// signer is sent a re-evaluate command for the block.
+///
+/// Test Assertion:
+/// The signer posts no response for the block until it is told to re-evaluate it, and posts one
+/// afterwards.
+fn reevaluate_abstained_block() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let timeout = Duration::from_secs(30);
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    signer_test.boot_to_epoch_3();
+
+    let abstaining_signer_config = signer_test.signer_configs[0].clone();
+    let abstaining_signer_pk =
+        StacksPublicKey::from_private(&abstaining_signer_config.stacks_private_key);
+    let has_response_from_abstaining_signer = |signer_signature_hash: &Sha512Trunc256Sum| {
+        test_observer::get_stackerdb_chunks()
+            .into_iter()
+            .flat_map(|chunk| chunk.modified_slots)
+            .filter_map(|chunk| {
+                let Ok(SignerMessage::BlockResponse(response)) =
+                    SignerMessage::consensus_deserialize(&mut chunk.data.as_slice())
+                else {
+                    return None;
+                };
+                Some(response)
+            })
+            .any(|response| match response {
+                BlockResponse::Accepted(accepted) => {
+                    accepted.signer_signature_hash == *signer_signature_hash
+                        && abstaining_signer_pk
+                            .verify(signer_signature_hash.bits(), &accepted.signature)
+                            .unwrap_or(false)
+                }
+                BlockResponse::Rejected(rejected) => {
+                    rejected.signer_signature_hash == *signer_signature_hash
+                        && rejected.verify(&abstaining_signer_pk).unwrap_or(false)
+                }
+            })
+    };
+
+    info!("------------------------- Mine a Block While Signer Abstains -------------------------");
+    TEST_FAIL_BLOCK_VALIDATION_SUBMISSION.set(vec![abstaining_signer_pk]);
+    signer_test.mine_nakamoto_block(timeout, true);
+    let signer_signature_hash = test_observer::get_mined_nakamoto_blocks()
+        .last()
+        .expect("No block was mined")
+        .signer_signature_hash;
+
+    // give the abstaining signer a chance to (wrongly) respond
+    sleep_ms(5000);
+    assert!(
+        !has_response_from_abstaining_signer(&signer_signature_hash),
+        "Signer responded to a block it could not validate"
+    );
+
+    info!("------------------------- Re-evaluate the Block -------------------------");
+    TEST_FAIL_BLOCK_VALIDATION_SUBMISSION.set(vec![]);
+    let client = reqwest::blocking::Client::new();
+    let response = client
+        .post(format!(
+            "http://{}/reevaluate_block",
+            abstaining_signer_config.endpoint
+        ))
+        .header("Authorization", &abstaining_signer_config.auth_password)
+        .json(&ReevaluateBlockRequest {
+            signer_signature_hash,
+        })
+        .send()
+        .expect("Failed to send re-evaluate block request");
+    assert!(response.status().is_success());
+
+    wait_for(timeout.as_secs(), || {
+        Ok(has_response_from_abstaining_signer(&signer_signature_hash))
+    })
+    .expect("Signer did not respond to the re-evaluated block");
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that every Epoch 2.5 signer mock signs each burn block in a range that crosses a reward
+/// cycle boundary.
+///
+/// Test Setup:
+/// The test spins up five stacks signers and a stacks node with pre-nakamoto mock signing
+/// enabled, and boots to Epoch 2.5.
+///
+/// Test Execution:
+/// Burn blocks are mined one at a time until the chain has advanced one block into the next
+/// reward cycle.
+///
+/// Test Assertion:
+/// Each signer mock signed every burn block that was mined, on both sides of the reward cycle
+/// boundary.
+fn mock_signatures_cover_burn_block_range() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![(sender_addr, send_amt + send_fee)],
+        |_| {},
+        |node_config| {
+            node_config.miner.pre_nakamoto_mock_signing = true;
+            set_epoch_boundaries(
+                node_config,
+                &[(StacksEpochId::Epoch30, 251), (StacksEpochId::Epoch31, 265)],
+            );
+        },
+        None,
+        None,
+    );
+
+    signer_test.boot_to_epoch_25_reward_cycle();
+
+    info!("------------------------- Test Execution -------------------------");
+    let burnchain = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_burnchain();
+    let start_reward_cycle = signer_test.get_current_reward_cycle();
+    let from_height = signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height()
+        + 1;
+    let to_height = burnchain.nakamoto_first_block_of_cycle(start_reward_cycle + 1) + 1;
+    let epochs = signer_test
+        .running_nodes
+        .conf
+        .burnchain
+        .epochs
+        .clone()
+        .unwrap();
+    // the epoch 2.5 miner is shut down at the epoch 3.0 boundary
+    assert!(
+        to_height < epochs[StacksEpochId::Epoch30].start_height,
+        "Reward cycle {} does not start within epoch 2.5",
+        start_reward_cycle + 1
+    );
+    while signer_test
+        .running_nodes
+        .btc_regtest_controller
+        .get_headers_height()
+        < to_height
+    {
+        next_block_and_wait(
+            &mut signer_test.running_nodes.btc_regtest_controller,
+            &signer_test.running_nodes.blocks_processed,
+        );
+    }
+    signer_test.assert_height_in_cycle(from_height, start_reward_cycle);
+    signer_test.assert_height_in_cycle(to_height, start_reward_cycle + 1);
+
+    info!("------------------------- Test Assertion -------------------------");
+    signer_test.assert_mock_signatures_for_range(
+        from_height,
+        to_height,
+        num_signers,
+        false,
+        Duration::from_secs(30),
+    );
+}
+
+#[test]
+#[ignore]
+/// Test that signers abstain from block proposals while their stacks node's burnchain view lags
+/// the bitcoin tip by more than `max_node_burn_lag`, and resume once it catches up.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The signers are configured with a `max_node_burn_lag` of 2 blocks.
+///
+/// Test Execution:
+/// The signers are made to see a node that lags the bitcoin tip by 10 blocks, and a STX transfer
+/// is submitted. The lag is then cleared.
+///
+/// Test Assertion:
+/// No block is mined while the node's burnchain view lags. Once the lag clears, the block with
+/// the transfer is mined.
+fn signer_abstains_while_node_burn_view_lags() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new_with_config_modifications(
+        num_signers,
+        vec![(sender_addr, send_amt + send_fee)],
+        |signer_config| {
+            signer_config.max_node_burn_lag = 2;
+        },
+        |_| {},
+        None,
+        None,
+    );
+    let timeout = Duration::from_secs(30);
+
+    signer_test.boot_to_epoch_3();
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    info!("------------------------- Lag the Node's Burnchain View -------------------------");
+    TEST_NODE_BURN_LAG.set(Some(10));
+
+    let proposals_before = signer_test
+        .running_nodes
+        .nakamoto_blocks_proposed
+        .load(Ordering::SeqCst);
+    let txids = signer_test.submit_transfers(&sender_sk, 1, send_fee, send_amt, &recipient);
+
+    wait_for(timeout.as_secs(), || {
+        Ok(signer_test
+            .running_nodes
+            .nakamoto_blocks_proposed
+            .load(Ordering::SeqCst)
+            > proposals_before)
+    })
+    .expect("Timed out waiting for block proposal");
+
+    info!("------------------------- Verify No Block Is Mined -------------------------");
+    signer_test.assert_no_block_mined_for(Duration::from_secs(20));
+
+    info!("------------------------- Clear the Lag -------------------------");
+    TEST_NODE_BURN_LAG.set(None);
+    signer_test.wait_for_tx_in_block(&txids[0], timeout.as_secs());
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `SignerTest::submit_tx_checked` returns the mempool's typed rejection for a
+/// transaction that pays too low a fee.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+///
+/// Test Execution:
+/// A STX transfer paying no fee is submitted, followed by one paying enough fee.
+///
+/// Test Assertion:
+/// The first transfer is rejected with `MempoolRejection::FeeTooLow`, reporting its fee and the
+/// minimum fee for its size. The second transfer is accepted with its txid.
+fn submit_tx_checked_rejects_low_fee() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> =
+        SignerTest::new(num_signers, vec![(sender_addr, send_amt + send_fee)]);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Submit Transfer With Too Low Fee -------------------------");
+    let chain_id = signer_test.running_nodes.conf.burnchain.chain_id;
+    let low_fee_tx = make_stacks_transfer(&sender_sk, 0, 0, chain_id, &recipient, send_amt);
+    let rejection = signer_test
+        .submit_tx_checked(&low_fee_tx)
+        .expect_err("Transfer with no fee was accepted");
+    assert_eq!(
+        rejection,
+        MempoolRejection::FeeTooLow {
+            expected: low_fee_tx.len() as u64,
+            actual: 0,
+        }
+    );
+
+    info!("------------------------- Submit Transfer With Enough Fee -------------------------");
+    let transfer_tx = make_stacks_transfer(&sender_sk, 0, send_fee, chain_id, &recipient, send_amt);
+    let txid = signer_test
+        .submit_tx_checked(&transfer_tx)
+        .expect("Transfer with enough fee was rejected");
+    assert_eq!(
+        txid,
+        StacksTransaction::consensus_deserialize(&mut &transfer_tx[..])
+            .unwrap()
+            .txid()
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `SignerTest::slot_version` reports the version of a signer's StackerDB slot, and
+/// that each write to the slot bumps it by one.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// The same block acceptance is posted twice to the first signer's block response slot.
+///
+/// Test Assertion:
+/// The slot's version increases by exactly one after each post.
+fn slot_version_increments_on_rebroadcast() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let mut signer_test: SignerTest<SpawnedSigner> =
+        SignerTest::new(num_signers, vec![(sender_addr, send_amt + send_fee)]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+    signer_test.mine_and_verify_confirmed_naka_block(timeout, num_signers, true);
+
+    let reward_cycle = signer_test.get_current_reward_cycle();
+    let signer_sk = signer_test.signer_stacks_private_keys[0].clone();
+    let signer_slot_id = signer_test
+        .get_signer_slot_id(reward_cycle, &tests::to_addr(&signer_sk))
+        .expect("Failed to get signer slot id")
+        .expect("Signer does not have a slot id");
+    // A block no miner proposed, so no signer or miner acts on the acceptances
+    let block = NakamotoBlock {
+        header: NakamotoBlockHeader::empty(),
+        txs: vec![],
+    };
+
+    info!("------------------------- Post Block Acceptance Twice -------------------------");
+    let initial_version = signer_test.slot_version(MessageSlotID::BlockResponse, signer_slot_id);
+
+    signer_test.inject_accept_signature(&block, &signer_sk, reward_cycle);
+    assert_eq!(
+        signer_test.slot_version(MessageSlotID::BlockResponse, signer_slot_id),
+        initial_version + 1
+    );
+
+    signer_test.inject_accept_signature(&block, &signer_sk, reward_cycle);
+    assert_eq!(
+        signer_test.slot_version(MessageSlotID::BlockResponse, signer_slot_id),
+        initial_version + 2
+    );
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+#[cfg(feature = "monitoring_prom")]
+/// Test that signers time how long they take to process each burn block
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// A single burn block is mined.
+///
+/// Test Assertion:
+/// Each signer records one observation of the burn block in its
+/// `stacks_signer_burn_block_processing_seconds` histogram.
+fn signer_metrics_record_burn_block_processing_time() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(num_signers, vec![]);
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    let metric_value = |metrics: &str, name: &str| -> f64 {
+        metrics
+            .lines()
+            .find_map(|line| line.strip_prefix(&format!("{name} ")))
+            .and_then(|value| value.trim().parse::<f64>().ok())
+            .unwrap_or(0.0)
+    };
+    let count_metric = "stacks_signer_burn_block_processing_seconds_count";
+    let observations_before = metric_value(&signer_test.get_signer_metrics(), count_metric);
+
+    info!("------------------------- Test Mine Burn Block -------------------------");
+    signer_test.mine_nakamoto_block(timeout, true);
+
+    info!("------------------------- Test Metrics -------------------------");
+    // The signers run in this process, so they share one metrics registry
+    let expected_observations = observations_before + num_signers as f64;
+    wait_for(timeout.as_secs(), || {
+        let metrics = signer_test.get_signer_metrics();
+        Ok(metric_value(&metrics, count_metric) >= expected_observations)
+    })
+    .expect("Timed out waiting for burn block processing times to be recorded");
+
+    let metrics = signer_test.get_signer_metrics();
+    assert_eq!(metric_value(&metrics, count_metric), expected_observations);
+    assert!(metric_value(&metrics, "stacks_signer_burn_block_processing_seconds_sum") >= 0.0);
+
+    signer_test.shutdown();
+}
+
+#[test]
+#[ignore]
+/// Test that `SignerTest::assert_unique_finalizations` accepts a normal tenure in which the miner
+/// mines several blocks.
+///
+/// Test Setup:
+/// The test spins up five stacks signers, one miner Nakamoto node, and a corresponding bitcoind.
+/// The stacks node is then advanced to Epoch 3.0 boundary to allow block signing.
+///
+/// Test Execution:
+/// A new tenure is started, and then several STX transfers are submitted one at a time, each
+/// mined in its own block within the tenure.
+///
+/// Test Assertion:
+/// Each Stacks height in the tenure was finalized exactly once, by the canonical block.
+fn unique_finalizations_in_multi_block_tenure() {
+    if env::var("BITCOIND_TEST") != Ok("1".into()) {
+        return;
+    }
+
+    tracing_subscriber::registry()
+        .with(fmt::layer())
+        .with(EnvFilter::from_default_env())
+        .init();
+
+    info!("------------------------- Test Setup -------------------------");
+    let num_signers = 5;
+    let num_transfers = 3;
+    let sender_sk = Secp256k1PrivateKey::random();
+    let sender_addr = tests::to_addr(&sender_sk);
+    let send_amt = 100;
+    let send_fee = 180;
+    let recipient = PrincipalData::from(StacksAddress::burn_address(false));
+    let mut signer_test: SignerTest<SpawnedSigner> = SignerTest::new(
+        num_signers,
+        vec![(sender_addr, (send_amt + send_fee) * num_transfers)],
+    );
+    let timeout = Duration::from_secs(30);
+    signer_test.boot_to_epoch_3();
+
+    info!("------------------------- Mine Multi-Block Tenure -------------------------");
+    signer_test.mine_nakamoto_block(timeout, true);
+    let tenure_consensus_hash = signer_test.get_peer_info().stacks_tip_consensus_hash;
+    for _ in 0..num_transfers {
+        let txids = signer_test.submit_transfers(&sender_sk, 1, send_fee, send_amt, &recipient);
+        signer_test.wait_for_tx_in_block(&txids[0], timeout.as_secs());
+    }
+    assert_eq!(
+        signer_test.get_peer_info().stacks_tip_consensus_hash,
+        tenure_consensus_hash,
+        "Expected the transfers to be mined in the same tenure"
+    );
+
+    info!("------------------------- Check Finalizations -------------------------");
+    signer_test.assert_unique_finalizations(&tenure_consensus_hash);
+
+    signer_test.shutdown();
+}